@@ -1,7 +1,7 @@
 use nu_engine::eval_block;
 use nu_parser::{flatten_expression, parse};
 use nu_protocol::{
-    ast::Statement,
+    ast::{Expr, Expression, Statement},
     engine::{EngineState, Stack, StateWorkingSet},
     PipelineData, Span,
 };
@@ -12,11 +12,14 @@ const SEP: char = std::path::MAIN_SEPARATOR;
 #[derive(Clone)]
 pub struct NuCompleter {
     engine_state: EngineState,
+    // The directory relative paths are completed from, which is PWD rather than the directory
+    // of the process
+    cwd: String,
 }
 
 impl NuCompleter {
-    pub fn new(engine_state: EngineState) -> Self {
-        Self { engine_state }
+    pub fn new(engine_state: EngineState, cwd: String) -> Self {
+        Self { engine_state, cwd }
     }
 }
 
@@ -59,6 +62,26 @@ impl Completer for NuCompleter {
                     let flattened = flatten_expression(&working_set, &expr);
                     for flat in flattened {
                         if pos >= flat.0.start && pos <= flat.0.end {
+                            let prefix = working_set.get_span_contents(flat.0);
+                            if is_flag_prefix(prefix) {
+                                let results = flag_completions(&working_set, &expr, prefix);
+
+                                if !results.is_empty() {
+                                    return results
+                                        .into_iter()
+                                        .map(move |x| {
+                                            (
+                                                reedline::Span {
+                                                    start: flat.0.start - offset,
+                                                    end: flat.0.end - offset,
+                                                },
+                                                x,
+                                            )
+                                        })
+                                        .collect();
+                                }
+                            }
+
                             match &flat.1 {
                                 nu_parser::FlatShape::Custom(custom_completion) => {
                                     let prefix = working_set.get_span_contents(flat.0).to_vec();
@@ -81,18 +104,17 @@ impl Completer for NuCompleter {
                                     let v: Vec<_> = match result {
                                         Ok(pd) => pd
                                             .into_iter()
-                                            .map(move |x| {
-                                                let s = x.as_string().expect(
-                                        "FIXME: better error handling for custom completions",
-                                    );
+                                            // Anything that isn't a string can't be a completion
+                                            .filter_map(move |x| {
+                                                let s = x.as_string().ok()?;
 
-                                                (
+                                                Some((
                                                     reedline::Span {
                                                         start: flat.0.start - offset,
                                                         end: flat.0.end - offset,
                                                     },
                                                     s,
-                                                )
+                                                ))
                                             })
                                             .filter(|x| x.1.as_bytes().starts_with(&prefix))
                                             .collect(),
@@ -108,7 +130,7 @@ impl Completer for NuCompleter {
                                     let results = working_set.find_commands_by_prefix(prefix);
 
                                     let prefix = String::from_utf8_lossy(prefix).to_string();
-                                    let results2 = file_path_completion(flat.0, &prefix)
+                                    let results2 = file_path_completion(flat.0, &prefix, &self.cwd)
                                         .into_iter()
                                         .map(move |x| {
                                             (
@@ -140,7 +162,7 @@ impl Completer for NuCompleter {
                                     let prefix = working_set.get_span_contents(flat.0);
                                     let prefix = String::from_utf8_lossy(prefix).to_string();
 
-                                    let results = file_path_completion(flat.0, &prefix);
+                                    let results = file_path_completion(flat.0, &prefix, &self.cwd);
 
                                     return results
                                         .into_iter()
@@ -201,9 +223,79 @@ impl Completer for NuCompleter {
     }
 }
 
+// Whether the text being completed is the start of a flag rather than, say, a negative number
+fn is_flag_prefix(prefix: &[u8]) -> bool {
+    match prefix {
+        [b'-', b'-', ..] | [b'-'] => true,
+        [b'-', c, ..] => c.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
+// The flags of the command called by the expression, long ones first and in the order of its
+// signature
+fn flag_completions(
+    working_set: &StateWorkingSet,
+    expr: &Expression,
+    prefix: &[u8],
+) -> Vec<String> {
+    let call = match &expr.expr {
+        Expr::Call(call) => call,
+        _ => return vec![],
+    };
+
+    let signature = working_set.get_decl(call.decl_id).signature();
+    let mut output = vec![];
+
+    for flag in &signature.named {
+        if flag.deprecated.is_some() {
+            continue;
+        }
+
+        let long = format!("--{}", flag.long);
+        if long.as_bytes().starts_with(prefix) {
+            output.push(long);
+        }
+    }
+
+    if !prefix.starts_with(b"--") {
+        for flag in &signature.named {
+            if let Some(short) = flag.short {
+                let short = format!("-{}", short);
+                if flag.deprecated.is_none() && short.as_bytes().starts_with(prefix) {
+                    output.push(short);
+                }
+            }
+        }
+    }
+
+    output
+}
+
+// Quotes the path when it would otherwise be parsed as more than a single bare word
+fn quote_path(path: String) -> String {
+    let needs_quotes = path.starts_with('$')
+        || path.chars().any(|c| {
+            c.is_whitespace()
+                || matches!(
+                    c,
+                    '\'' | '"' | '|' | ';' | '#' | '(' | ')' | '[' | ']' | '{' | '}'
+                )
+        });
+
+    if !needs_quotes {
+        path
+    } else if path.contains('"') {
+        format!("'{}'", path)
+    } else {
+        format!("\"{}\"", path)
+    }
+}
+
 fn file_path_completion(
     span: nu_protocol::Span,
     partial: &str,
+    cwd: &str,
 ) -> Vec<(nu_protocol::Span, String)> {
     use std::path::{is_separator, Path};
 
@@ -230,7 +322,7 @@ fn file_path_completion(
         (base, rest)
     };
 
-    let base_dir = nu_path::expand_path(&base_dir_name);
+    let base_dir = nu_path::expand_path_with(&base_dir_name, cwd);
     // This check is here as base_dir.read_dir() with base_dir == "" will open the current dir
     // which we don't want in this case (if we did, base_dir would already be ".")
     if base_dir == Path::new("") {
@@ -249,11 +341,7 @@ fn file_path_completion(
                             file_name.push(SEP);
                         }
 
-                        if path.contains(' ') {
-                            path = format!("\"{}\"", path);
-                        }
-
-                        Some((span, path))
+                        Some((span, quote_path(path)))
                     } else {
                         None
                     }
@@ -269,3 +357,74 @@ fn matches(partial: &str, from: &str) -> bool {
     from.to_ascii_lowercase()
         .starts_with(&partial.to_ascii_lowercase())
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nu_protocol::{ast::Pipeline, Signature, SyntaxShape};
+
+    #[test]
+    fn quotes_paths_that_are_not_a_bare_word() {
+        assert_eq!(quote_path("notes.txt".into()), "notes.txt");
+        assert_eq!(quote_path("my notes.txt".into()), "\"my notes.txt\"");
+        assert_eq!(quote_path("$HOME".into()), "\"$HOME\"");
+        assert_eq!(quote_path("a$b".into()), "a$b");
+        assert_eq!(quote_path("it's.txt".into()), "\"it's.txt\"");
+        assert_eq!(quote_path("say \"hi\".txt".into()), "'say \"hi\".txt'");
+        assert_eq!(quote_path("notes(1).txt".into()), "\"notes(1).txt\"");
+    }
+
+    #[test]
+    fn flag_prefixes() {
+        assert!(is_flag_prefix(b"-"));
+        assert!(is_flag_prefix(b"--"));
+        assert!(is_flag_prefix(b"--ver"));
+        assert!(is_flag_prefix(b"-v"));
+        // A negative number isn't a flag
+        assert!(!is_flag_prefix(b"-1"));
+        assert!(!is_flag_prefix(b"v"));
+        assert!(!is_flag_prefix(b""));
+    }
+
+    #[test]
+    fn flags_filtered_by_prefix() {
+        let engine_state = EngineState::new();
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        working_set.add_decl(
+            Signature::build("foo")
+                .switch("verbose", "print more", Some('v'))
+                .switch("version", "print the version", None)
+                .named("value", SyntaxShape::Int, "a value", Some('x'))
+                .switch("old", "an old flag", Some('o'))
+                .deprecated_flag("old", "--verbose")
+                .predeclare(),
+        );
+
+        let (block, err) = parse(&mut working_set, None, b"foo", false);
+        assert!(err.is_none());
+        let expr = match &block.stmts[0] {
+            Statement::Pipeline(Pipeline { expressions }) => expressions[0].clone(),
+            _ => panic!("expected a pipeline"),
+        };
+
+        assert_eq!(
+            flag_completions(&working_set, &expr, b"-"),
+            vec![
+                "--help",
+                "--verbose",
+                "--version",
+                "--value",
+                "-h",
+                "-v",
+                "-x"
+            ]
+        );
+        assert_eq!(
+            flag_completions(&working_set, &expr, b"--ver"),
+            vec!["--verbose", "--version"]
+        );
+        assert_eq!(flag_completions(&working_set, &expr, b"-v"), vec!["-v"]);
+        assert!(flag_completions(&working_set, &expr, b"--old").is_empty());
+        assert!(flag_completions(&working_set, &expr, b"-o").is_empty());
+    }
+}
@@ -1,11 +1,15 @@
 mod completions;
 mod errors;
+mod menu;
 mod prompt;
+mod reedline_config;
 mod syntax_highlight;
 mod validation;
 
 pub use completions::NuCompleter;
 pub use errors::CliError;
+pub use menu::{history_menu_event, GridMenu, HISTORY_MENU_MARKER};
 pub use prompt::NushellPrompt;
+pub use reedline_config::{create_keybindings, KeybindingsMode};
 pub use syntax_highlight::NuHighlighter;
 pub use validation::NuValidator;
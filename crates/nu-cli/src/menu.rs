@@ -0,0 +1,200 @@
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    style::Print,
+    terminal::{self, Clear, ClearType},
+    QueueableCommand,
+};
+use nu_ansi_term::Style;
+use nu_color_config::lookup_ansi_color_style;
+use nu_protocol::MenuConfig;
+use reedline::{EditCommand, ReedlineEvent};
+use std::io::{self, Write};
+
+/// Put at the start of the line by the history menu event, so the action handler, which only
+/// sees the line, can tell it apart from the completion key. A NUL can't be typed nor be part
+/// of a command, unlike a printable prefix that a real command line could start with.
+pub const HISTORY_MENU_MARKER: char = '\0';
+
+/// The events a key sends to open the history menu, filtered by the line typed so far
+pub fn history_menu_event() -> ReedlineEvent {
+    ReedlineEvent::Multiple(vec![
+        ReedlineEvent::Edit(vec![
+            EditCommand::MoveToStart,
+            EditCommand::InsertChar(HISTORY_MENU_MARKER),
+        ]),
+        ReedlineEvent::ActionHandler,
+    ])
+}
+
+/// A menu that lays its items out in columns below the prompt and lets the user pick one
+/// with the arrow keys
+pub struct GridMenu<'a> {
+    items: &'a [String],
+    config: &'a MenuConfig,
+    selected: usize,
+    columns: usize,
+    col_width: usize,
+}
+
+impl<'a> GridMenu<'a> {
+    pub fn new(items: &'a [String], config: &'a MenuConfig) -> Self {
+        let (term_width, _) = terminal::size().unwrap_or((80, 24));
+
+        let col_width = items
+            .iter()
+            .map(|item| item.chars().count())
+            .max()
+            .unwrap_or_default()
+            + config.col_padding;
+
+        // Use as many of the configured columns as fit in the terminal
+        let columns = (term_width as usize / col_width.max(1))
+            .min(config.columns)
+            .max(1);
+
+        GridMenu {
+            items,
+            config,
+            selected: 0,
+            columns,
+            col_width,
+        }
+    }
+
+    /// Show the menu until an item is picked or the menu is closed, returning the index of
+    /// the picked item
+    pub fn select(mut self) -> io::Result<Option<usize>> {
+        if self.items.is_empty() {
+            return Ok(None);
+        }
+
+        let was_raw = terminal::is_raw_mode_enabled()?;
+        if !was_raw {
+            terminal::enable_raw_mode()?;
+        }
+
+        let result = self.run();
+
+        if !was_raw {
+            terminal::disable_raw_mode()?;
+        }
+
+        result
+    }
+
+    fn run(&mut self) -> io::Result<Option<usize>> {
+        let mut stdout = io::stdout();
+        let (cursor_col, cursor_row) = cursor::position()?;
+        let (_, term_height) = terminal::size()?;
+
+        // Make room for the menu below the prompt, scrolling the screen if needed
+        let height = self.height() as u16;
+        for _ in 0..height {
+            stdout.queue(Print("\r\n"))?;
+        }
+        let prompt_row = cursor_row.min(term_height.saturating_sub(height + 1));
+        stdout.flush()?;
+
+        let result = loop {
+            self.draw(&mut stdout, prompt_row + 1)?;
+
+            if let Event::Key(KeyEvent { code, modifiers }) = event::read()? {
+                match (code, modifiers) {
+                    (KeyCode::Enter, _) => break Some(self.selected),
+                    (KeyCode::Esc, _) => break None,
+                    (KeyCode::Char('c'), KeyModifiers::CONTROL) => break None,
+                    (KeyCode::Right, _) | (KeyCode::Tab, _) => self.move_by(1),
+                    (KeyCode::Left, _) | (KeyCode::BackTab, _) => self.move_by(-1),
+                    (KeyCode::Down, _) => self.move_by(self.columns as isize),
+                    (KeyCode::Up, _) => self.move_by(-(self.columns as isize)),
+                    (KeyCode::PageDown, _) => self.move_by(self.page_len() as isize),
+                    (KeyCode::PageUp, _) => self.move_by(-(self.page_len() as isize)),
+                    _ => {}
+                }
+            }
+        };
+
+        stdout
+            .queue(cursor::MoveTo(0, prompt_row + 1))?
+            .queue(Clear(ClearType::FromCursorDown))?
+            .queue(cursor::MoveTo(cursor_col, prompt_row))?;
+        stdout.flush()?;
+
+        Ok(result)
+    }
+
+    fn rows(&self) -> usize {
+        (self.items.len() + self.columns - 1) / self.columns
+    }
+
+    fn page_len(&self) -> usize {
+        self.config.page_size * self.columns
+    }
+
+    fn has_pages(&self) -> bool {
+        self.rows() > self.config.page_size
+    }
+
+    // Rows taken on screen, including the page indicator
+    fn height(&self) -> usize {
+        if self.has_pages() {
+            self.config.page_size + 1
+        } else {
+            self.rows()
+        }
+    }
+
+    fn move_by(&mut self, offset: isize) {
+        let last = self.items.len() as isize - 1;
+        self.selected = (self.selected as isize + offset).max(0).min(last) as usize;
+    }
+
+    fn draw(&self, stdout: &mut io::Stdout, top_row: u16) -> io::Result<()> {
+        let text_style = lookup_ansi_color_style(self.config.text_style.clone());
+        let selected_style = lookup_ansi_color_style(self.config.selected_text_style.clone());
+
+        let page = self.selected / self.page_len();
+        let first = page * self.page_len();
+        let last = (first + self.page_len()).min(self.items.len());
+
+        stdout
+            .queue(cursor::MoveTo(0, top_row))?
+            .queue(Clear(ClearType::FromCursorDown))?;
+
+        for (row, chunk) in self.items[first..last].chunks(self.columns).enumerate() {
+            if row > 0 {
+                stdout.queue(Print("\r\n"))?;
+            }
+
+            for (col, item) in chunk.iter().enumerate() {
+                let index = first + row * self.columns + col;
+                let style = if index == self.selected {
+                    selected_style
+                } else {
+                    text_style
+                };
+
+                stdout.queue(Print(self.paint_item(item, style)))?;
+            }
+        }
+
+        if self.has_pages() {
+            let pages = (self.items.len() + self.page_len() - 1) / self.page_len();
+            stdout.queue(cursor::MoveTo(0, top_row + self.config.page_size as u16))?;
+            stdout.queue(Print(format!("({}/{})", page + 1, pages)))?;
+        }
+
+        stdout.flush()
+    }
+
+    fn paint_item(&self, item: &str, style: Style) -> String {
+        let padding = self.col_width.saturating_sub(item.chars().count());
+
+        if self.columns == 1 {
+            style.paint(item).to_string()
+        } else {
+            format!("{}{}", style.paint(item), " ".repeat(padding))
+        }
+    }
+}
@@ -3,12 +3,14 @@ use {
         Prompt, PromptEditMode, PromptHistorySearch, PromptHistorySearchStatus, PromptViMode,
     },
     std::borrow::Cow,
+    unicode_width::UnicodeWidthStr,
 };
 
 /// Nushell prompt definition
 #[derive(Clone)]
 pub struct NushellPrompt {
     prompt_string: String,
+    right_prompt_string: String,
     // These are part of the struct definition in case we want to allow
     // further customization to the shell status
     default_prompt_indicator: String,
@@ -27,6 +29,7 @@ impl NushellPrompt {
     pub fn new() -> NushellPrompt {
         NushellPrompt {
             prompt_string: "".to_string(),
+            right_prompt_string: "".to_string(),
             default_prompt_indicator: "〉".to_string(),
             default_vi_insert_prompt_indicator: ": ".to_string(),
             default_vi_visual_prompt_indicator: "v ".to_string(),
@@ -38,14 +41,29 @@ impl NushellPrompt {
         self.prompt_string = prompt_string;
     }
 
+    pub fn update_prompt_right(&mut self, prompt_string: String) {
+        self.right_prompt_string = prompt_string;
+    }
+
     fn default_wrapped_custom_string(&self, str: String) -> String {
         format!("({})", str)
     }
 }
 
 impl Prompt for NushellPrompt {
-    fn render_prompt(&self, _: usize) -> Cow<str> {
-        self.prompt_string.as_str().into()
+    fn render_prompt(&self, screen_width: usize) -> Cow<str> {
+        let right_width = visible_width(&self.right_prompt_string);
+        if right_width == 0 || right_width >= screen_width {
+            return self.prompt_string.as_str().into();
+        }
+
+        // The right prompt is drawn at the end of the line first, then the cursor goes back to
+        // where it was for the left prompt and the input
+        let column = screen_width - right_width + 1;
+        Cow::Owned(format!(
+            "\x1b7\x1b[{}G{}\x1b8{}",
+            column, self.right_prompt_string, self.prompt_string
+        ))
     }
 
     fn render_prompt_indicator(&self, edit_mode: PromptEditMode) -> Cow<str> {
@@ -80,3 +98,42 @@ impl Prompt for NushellPrompt {
         ))
     }
 }
+
+// The number of columns the text takes up on the terminal, leaving out ANSI escape sequences
+fn visible_width(text: &str) -> usize {
+    let mut visible = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            visible.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            // CSI sequences, like colors, end with a byte in the range @ to ~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC sequences, like links, end with BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    visible.width()
+}
@@ -0,0 +1,396 @@
+use crate::history_menu_event;
+use crossterm::event::{KeyCode, KeyModifiers};
+use nu_protocol::{Config, ParsedKeybinding, ShellError, Span, Value};
+use reedline::{
+    default_emacs_keybindings, default_vi_insert_keybindings, default_vi_normal_keybindings,
+    EditCommand, Keybindings, ReedlineEvent,
+};
+
+/// The keybindings of the edit mode chosen in the config
+#[derive(Clone)]
+pub enum KeybindingsMode {
+    Emacs(Keybindings),
+    Vi {
+        insert_keybindings: Keybindings,
+        normal_keybindings: Keybindings,
+    },
+}
+
+/// Adds the keybindings of the config to the default ones of its edit mode
+///
+/// Bindings for an edit mode other than the one in use are checked, but otherwise left out.
+pub fn create_keybindings(config: &Config) -> Result<KeybindingsMode, ShellError> {
+    let mut emacs_keybindings = default_emacs_keybindings();
+    let mut insert_keybindings = default_vi_insert_keybindings();
+    let mut normal_keybindings = default_vi_normal_keybindings();
+
+    // Control-x opens the history menu unless the config binds the key to something else
+    for keybindings in [&mut emacs_keybindings, &mut insert_keybindings] {
+        keybindings.add_binding(
+            KeyModifiers::CONTROL,
+            KeyCode::Char('x'),
+            history_menu_event(),
+        );
+    }
+
+    for keybinding in &config.keybindings {
+        let modifier = parse_modifier(&keybinding.modifier)?;
+        let keycode = parse_keycode(&keybinding.keycode)?;
+        let event = parse_event(&keybinding.event)?;
+
+        for mode in parse_modes(keybinding)? {
+            let keybindings = match mode {
+                Mode::Emacs => &mut emacs_keybindings,
+                Mode::ViInsert => &mut insert_keybindings,
+                Mode::ViNormal => &mut normal_keybindings,
+            };
+
+            keybindings.add_binding(modifier, keycode, event.clone());
+        }
+    }
+
+    match config.edit_mode.as_str() {
+        "vi" => Ok(KeybindingsMode::Vi {
+            insert_keybindings,
+            normal_keybindings,
+        }),
+        _ => Ok(KeybindingsMode::Emacs(emacs_keybindings)),
+    }
+}
+
+enum Mode {
+    Emacs,
+    ViInsert,
+    ViNormal,
+}
+
+fn parse_modes(keybinding: &ParsedKeybinding) -> Result<Vec<Mode>, ShellError> {
+    let modes = match &keybinding.mode {
+        Value::List { vals, .. } => vals.iter().collect(),
+        mode => vec![mode],
+    };
+
+    modes
+        .into_iter()
+        .map(|mode| match mode.as_string()?.to_lowercase().as_str() {
+            "emacs" => Ok(Mode::Emacs),
+            "vi_insert" => Ok(Mode::ViInsert),
+            "vi_normal" => Ok(Mode::ViNormal),
+            other => Err(unsupported("emacs, vi_insert or vi_normal", other, mode)),
+        })
+        .collect()
+}
+
+fn parse_modifier(value: &Value) -> Result<KeyModifiers, ShellError> {
+    let text = value.as_string()?.to_lowercase();
+
+    text.split('_')
+        .try_fold(KeyModifiers::NONE, |modifiers, name| {
+            let modifier = match name {
+                "none" => KeyModifiers::NONE,
+                "control" => KeyModifiers::CONTROL,
+                "shift" => KeyModifiers::SHIFT,
+                "alt" => KeyModifiers::ALT,
+                _ => return None,
+            };
+
+            Some(modifiers | modifier)
+        })
+        .ok_or_else(|| {
+            unsupported(
+                "none, control, shift, alt or a mix like control_alt",
+                &text,
+                value,
+            )
+        })
+}
+
+fn parse_keycode(value: &Value) -> Result<KeyCode, ShellError> {
+    let text = value.as_string()?;
+    let lowercase = text.to_lowercase();
+
+    let keycode = match lowercase.as_str() {
+        "backspace" => KeyCode::Backspace,
+        "enter" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "esc" => KeyCode::Esc,
+        _ => {
+            // The case of characters matters, as shift+a is sent as A
+            let mut chars = text.strip_prefix("char_").unwrap_or_default().chars();
+            let function_key = lowercase
+                .strip_prefix('f')
+                .and_then(|number| number.parse::<u8>().ok())
+                .filter(|number| (1..=12).contains(number));
+
+            match (chars.next(), chars.next(), function_key) {
+                (Some(c), None, _) => KeyCode::Char(c),
+                (_, _, Some(number)) => KeyCode::F(number),
+                _ => {
+                    return Err(unsupported(
+                        "a key like char_a, f1, enter, tab or up",
+                        &text,
+                        value,
+                    ))
+                }
+            }
+        }
+    };
+
+    Ok(keycode)
+}
+
+fn parse_event(value: &Value) -> Result<ReedlineEvent, ShellError> {
+    if let Value::List { vals, .. } = value {
+        return vals
+            .iter()
+            .map(parse_event)
+            .collect::<Result<_, _>>()
+            .map(ReedlineEvent::Multiple);
+    }
+
+    let (cols, vals) = value.as_record()?;
+    let field = |name: &str| {
+        cols.iter()
+            .position(|col| col == name)
+            .map(|idx| &vals[idx])
+    };
+
+    if let Some(send) = field("send") {
+        let name = send.as_string()?.to_lowercase();
+
+        let event = match name.as_str() {
+            "none" => ReedlineEvent::None,
+            "actionhandler" => ReedlineEvent::ActionHandler,
+            "clearscreen" => ReedlineEvent::ClearScreen,
+            "completionmenu" => ReedlineEvent::ActionHandler,
+            "ctrlc" => ReedlineEvent::CtrlC,
+            "ctrld" => ReedlineEvent::CtrlD,
+            "down" => ReedlineEvent::Down,
+            "enter" => ReedlineEvent::Enter,
+            "historyhintcomplete" => ReedlineEvent::HistoryHintComplete,
+            "historyhintwordcomplete" => ReedlineEvent::HistoryHintWordComplete,
+            "historymenu" => history_menu_event(),
+            "left" => ReedlineEvent::Left,
+            "nexthistory" => ReedlineEvent::NextHistory,
+            "previoushistory" => ReedlineEvent::PreviousHistory,
+            "repaint" => ReedlineEvent::Repaint,
+            "right" => ReedlineEvent::Right,
+            "searchhistory" => ReedlineEvent::SearchHistory,
+            "up" => ReedlineEvent::Up,
+            _ => {
+                return Err(unsupported(
+                    "an event like clearscreen or enter",
+                    &name,
+                    send,
+                ))
+            }
+        };
+
+        return Ok(event);
+    }
+
+    if let Some(edit) = field("edit") {
+        let name = edit.as_string()?.to_lowercase();
+        let text_value = || {
+            field("value").ok_or_else(|| {
+                ShellError::MissingConfigValue(format!("'value' field for {}", name), span_of(edit))
+            })
+        };
+
+        let command = match name.as_str() {
+            "backspace" => EditCommand::Backspace,
+            "backspaceword" => EditCommand::BackspaceWord,
+            "capitalizechar" => EditCommand::CapitalizeChar,
+            "clear" => EditCommand::Clear,
+            "cutcurrentline" => EditCommand::CutCurrentLine,
+            "cutfromstart" => EditCommand::CutFromStart,
+            "cuttoend" => EditCommand::CutToEnd,
+            "cutwordleft" => EditCommand::CutWordLeft,
+            "cutwordright" => EditCommand::CutWordRight,
+            "delete" => EditCommand::Delete,
+            "deleteword" => EditCommand::DeleteWord,
+            "insertchar" => {
+                let value = text_value()?;
+                let text = value.as_string()?;
+                let mut chars = text.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => EditCommand::InsertChar(c),
+                    _ => return Err(unsupported("a single character", &text, value)),
+                }
+            }
+            "insertstring" => EditCommand::InsertString(text_value()?.as_string()?),
+            "lowercaseword" => EditCommand::LowercaseWord,
+            "moveleft" => EditCommand::MoveLeft,
+            "moveright" => EditCommand::MoveRight,
+            "movetoend" => EditCommand::MoveToEnd,
+            "movetostart" => EditCommand::MoveToStart,
+            "movewordleft" => EditCommand::MoveWordLeft,
+            "movewordright" => EditCommand::MoveWordRight,
+            "pastecutbufferafter" => EditCommand::PasteCutBufferAfter,
+            "pastecutbufferbefore" => EditCommand::PasteCutBufferBefore,
+            "redo" => EditCommand::Redo,
+            "swapgraphemes" => EditCommand::SwapGraphemes,
+            "swapwords" => EditCommand::SwapWords,
+            "undo" => EditCommand::Undo,
+            "uppercaseword" => EditCommand::UppercaseWord,
+            _ => {
+                return Err(unsupported(
+                    "an edit like movetostart or cutwordleft",
+                    &name,
+                    edit,
+                ))
+            }
+        };
+
+        return Ok(ReedlineEvent::Edit(vec![command]));
+    }
+
+    Err(ShellError::MissingConfigValue(
+        "'send' or 'edit' field".into(),
+        value.span()?,
+    ))
+}
+
+fn unsupported(expected: &str, found: &str, value: &Value) -> ShellError {
+    ShellError::UnsupportedConfigValue(expected.into(), found.into(), span_of(value))
+}
+
+fn span_of(value: &Value) -> Span {
+    value.span().unwrap_or_else(|_| Span::unknown())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(fields: &[(&str, &str)]) -> Value {
+        Value::Record {
+            cols: fields.iter().map(|(col, _)| col.to_string()).collect(),
+            vals: fields
+                .iter()
+                .map(|(_, val)| Value::test_string(*val))
+                .collect(),
+            span: Span::unknown(),
+        }
+    }
+
+    #[test]
+    fn modifiers() {
+        assert_eq!(
+            parse_modifier(&Value::test_string("none")).unwrap(),
+            KeyModifiers::NONE
+        );
+        assert_eq!(
+            parse_modifier(&Value::test_string("Control_Alt")).unwrap(),
+            KeyModifiers::CONTROL | KeyModifiers::ALT
+        );
+        assert!(matches!(
+            parse_modifier(&Value::test_string("control_hyper")),
+            Err(ShellError::UnsupportedConfigValue(..))
+        ));
+    }
+
+    #[test]
+    fn keycodes() {
+        assert_eq!(
+            parse_keycode(&Value::test_string("Enter")).unwrap(),
+            KeyCode::Enter
+        );
+        assert_eq!(
+            parse_keycode(&Value::test_string("char_A")).unwrap(),
+            KeyCode::Char('A')
+        );
+        assert_eq!(
+            parse_keycode(&Value::test_string("f12")).unwrap(),
+            KeyCode::F(12)
+        );
+
+        for keycode in ["f13", "char_ab", "char_", "space"] {
+            assert!(matches!(
+                parse_keycode(&Value::test_string(keycode)),
+                Err(ShellError::UnsupportedConfigValue(..))
+            ));
+        }
+    }
+
+    #[test]
+    fn events() {
+        assert!(matches!(
+            parse_event(&record(&[("send", "ClearScreen")])),
+            Ok(ReedlineEvent::ClearScreen)
+        ));
+        match parse_event(&record(&[("edit", "insertchar"), ("value", "x")])) {
+            Ok(ReedlineEvent::Edit(commands)) => {
+                assert!(matches!(commands[..], [EditCommand::InsertChar('x')]))
+            }
+            _ => panic!("expected an edit event"),
+        }
+
+        let events = Value::List {
+            vals: vec![record(&[("send", "up")]), record(&[("edit", "clear")])],
+            span: Span::unknown(),
+        };
+        assert!(matches!(
+            parse_event(&events),
+            Ok(ReedlineEvent::Multiple(events)) if events.len() == 2
+        ));
+    }
+
+    #[test]
+    fn event_errors() {
+        assert!(matches!(
+            parse_event(&record(&[("send", "explode")])),
+            Err(ShellError::UnsupportedConfigValue(..))
+        ));
+        assert!(matches!(
+            parse_event(&record(&[("edit", "insertchar"), ("value", "xy")])),
+            Err(ShellError::UnsupportedConfigValue(..))
+        ));
+        assert!(matches!(
+            parse_event(&record(&[("edit", "insertstring")])),
+            Err(ShellError::MissingConfigValue(..))
+        ));
+        assert!(matches!(
+            parse_event(&record(&[("value", "x")])),
+            Err(ShellError::MissingConfigValue(..))
+        ));
+    }
+
+    #[test]
+    fn modes() {
+        let keybinding = |mode| ParsedKeybinding {
+            mode,
+            modifier: Value::test_string("control"),
+            keycode: Value::test_string("char_l"),
+            event: record(&[("send", "clearscreen")]),
+        };
+
+        let modes = parse_modes(&keybinding(Value::List {
+            vals: vec![Value::test_string("emacs"), Value::test_string("VI_INSERT")],
+            span: Span::unknown(),
+        }))
+        .unwrap();
+        assert!(matches!(modes[..], [Mode::Emacs, Mode::ViInsert]));
+
+        assert!(matches!(
+            parse_modes(&keybinding(Value::test_string("vi_normal"))).unwrap()[..],
+            [Mode::ViNormal]
+        ));
+        assert!(matches!(
+            parse_modes(&keybinding(Value::test_string("vim"))),
+            Err(ShellError::UnsupportedConfigValue(..))
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::nu_style::{color_from_hex, color_string_to_nustyle};
+use crate::nu_style::{color_from_hex, color_from_name, color_string_to_nustyle};
 use nu_ansi_term::{Color, Style};
 use nu_protocol::Config;
 use nu_table::{Alignment, TextStyle};
@@ -92,7 +92,7 @@ pub fn lookup_ansi_color_style(s: String) -> Style {
             "wr" | "white_reverse" => Color::White.reverse(),
             "wbl" | "white_blink" => Color::White.blink(),
             "wst" | "white_strike" => Color::White.strikethrough(),
-            _ => Color::White.normal(),
+            name => color_from_name(name).unwrap_or(Color::White).normal(),
         }
     }
 }
@@ -9,19 +9,13 @@ struct NuStyle {
 }
 
 fn parse_nustyle(nu_style: NuStyle) -> Style {
-    // get the nu_ansi_term::Color foreground color
-    let fg_color = match nu_style.fg {
-        Some(fg) => color_from_hex(&fg).expect("error with foreground color"),
-        _ => None,
-    };
+    // get the nu_ansi_term::Color foreground color, a color that can't be read is left out
+    let fg_color = nu_style.fg.as_deref().and_then(parse_color);
     // get the nu_ansi_term::Color background color
-    let bg_color = match nu_style.bg {
-        Some(bg) => color_from_hex(&bg).expect("error with background color"),
-        _ => None,
-    };
+    let bg_color = nu_style.bg.as_deref().and_then(parse_color);
     // get the attributes
     let color_attr = match nu_style.attr {
-        Some(attr) => attr,
+        Some(attr) => attr.to_lowercase(),
         _ => "".to_string(),
     };
 
@@ -35,9 +29,20 @@ fn parse_nustyle(nu_style: NuStyle) -> Style {
     let mut hidden = false;
     let mut strikethrough = false;
 
-    // since we can combine styles like bold-italic, iterate through the chars
-    // and set the bools for later use in the nu_ansi_term::Style application
-    for ch in color_attr.to_lowercase().chars() {
+    // attributes are either whole words, like "bold underline", or one letter each, like "bu",
+    // which can be combined since we iterate through the chars
+    let words: Vec<&str> = color_attr
+        .split(|c: char| c == ' ' || c == ',' || c == '_')
+        .filter(|word| !word.is_empty())
+        .collect();
+    let codes: Vec<char> = if words.iter().all(|word| attr_code(word).is_some()) {
+        words.iter().filter_map(|word| attr_code(word)).collect()
+    } else {
+        color_attr.chars().collect()
+    };
+
+    // set the bools for later use in the nu_ansi_term::Style application
+    for ch in codes {
         match ch {
             'l' => blink = true,
             'b' => bold = true,
@@ -67,6 +72,56 @@ fn parse_nustyle(nu_style: NuStyle) -> Style {
     }
 }
 
+// The one-letter code of an attribute written out as a word
+fn attr_code(word: &str) -> Option<char> {
+    match word {
+        "blink" => Some('l'),
+        "bold" => Some('b'),
+        "dimmed" => Some('d'),
+        "hidden" => Some('h'),
+        "italic" => Some('i'),
+        "reverse" => Some('r'),
+        "strike" | "strikethrough" => Some('s'),
+        "underline" => Some('u'),
+        "normal" => Some('n'),
+        _ => None,
+    }
+}
+
+// Reads a color given as a hex code, like #ff8000, or by name
+fn parse_color(color: &str) -> Option<Color> {
+    if color.starts_with('#') {
+        color_from_hex(color).ok().flatten()
+    } else {
+        color_from_name(color)
+    }
+}
+
+/// Looks up one of the terminal colors by name, like `red`, `light_blue` or `dark_gray`
+pub fn color_from_name(name: &str) -> Option<Color> {
+    let color = match name.to_lowercase().replace(' ', "_").as_str() {
+        "black" => Color::Black,
+        "dark_gray" | "dark_grey" => Color::DarkGray,
+        "red" => Color::Red,
+        "light_red" => Color::LightRed,
+        "green" => Color::Green,
+        "light_green" => Color::LightGreen,
+        "yellow" => Color::Yellow,
+        "light_yellow" => Color::LightYellow,
+        "blue" => Color::Blue,
+        "light_blue" => Color::LightBlue,
+        "purple" | "magenta" => Color::Purple,
+        "light_purple" | "light_magenta" => Color::LightPurple,
+        "cyan" => Color::Cyan,
+        "light_cyan" => Color::LightCyan,
+        "white" => Color::White,
+        "light_gray" | "light_grey" => Color::LightGray,
+        _ => return None,
+    };
+
+    Some(color)
+}
+
 pub fn color_string_to_nustyle(color_string: String) -> Style {
     // eprintln!("color_string: {}", &color_string);
     if color_string.chars().count() < 1 {
@@ -101,3 +156,64 @@ pub fn color_from_hex(
         )))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn colors_by_name() {
+        assert_eq!(color_from_name("red"), Some(Color::Red));
+        assert_eq!(color_from_name("Light Blue"), Some(Color::LightBlue));
+        assert_eq!(color_from_name("dark_grey"), Some(Color::DarkGray));
+        assert_eq!(color_from_name("magenta"), Some(Color::Purple));
+        assert_eq!(color_from_name("teal"), None);
+    }
+
+    #[test]
+    fn colors_by_hex_code() {
+        assert_eq!(parse_color("#ff8000"), Some(Color::Rgb(255, 128, 0)));
+        assert_eq!(color_from_hex("#ff80"), Ok(None));
+        assert!(color_from_hex("#gg0000").is_err());
+        assert_eq!(parse_color("#gg0000"), None);
+    }
+
+    #[test]
+    fn attributes_as_words_or_letters() {
+        let style = |attr: &str| {
+            parse_nustyle(NuStyle {
+                fg: None,
+                bg: None,
+                attr: Some(attr.into()),
+            })
+        };
+
+        let bold_underline = Style::new().bold().underline();
+        assert_eq!(style("bold underline"), bold_underline);
+        assert_eq!(style("Bold,Underline"), bold_underline);
+        assert_eq!(style("bu"), bold_underline);
+        assert_eq!(style("strike"), Style::new().strikethrough());
+        assert_eq!(style("xyz"), Style::new());
+    }
+
+    #[test]
+    fn style_from_string() {
+        assert_eq!(
+            color_string_to_nustyle(
+                r##"{"fg": "#ff8000", "bg": "light_blue", "attr": "italic"}"##.into()
+            ),
+            Style::new()
+                .fg(Color::Rgb(255, 128, 0))
+                .on(Color::LightBlue)
+                .italic()
+        );
+        assert_eq!(
+            color_string_to_nustyle(r#"{"fg": "no_such_color", "attr": "b"}"#.into()),
+            Style::new().bold()
+        );
+        assert_eq!(
+            color_string_to_nustyle("not a style".into()),
+            Style::default()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use nu_command::create_default_context;
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::{
+    ast::Block,
+    engine::{EngineState, Stack, StateWorkingSet},
+    PipelineData, Span, Value, CONFIG_VARIABLE_ID,
+};
+
+// Pipelines that stress the per-row work of the commands scripts use the most
+const PIPELINES: &[(&str, &str)] = &[
+    ("each", "1..1000 | each { $it * 2 } | math sum"),
+    (
+        "where",
+        "1..1000 | each { {a: $it, b: ($it mod 7)} } | where b == 3 | length",
+    ),
+    (
+        "select",
+        "1..1000 | each { {a: $it, b: 'x', c: 'y', d: 'z'} } | select a c | length",
+    ),
+    (
+        "str",
+        "1..1000 | each { $it | into string | str upcase } | length",
+    ),
+];
+
+fn parse_source(engine_state: &mut EngineState, source: &str) -> Block {
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let (block, err) = parse(&mut working_set, None, source.as_bytes(), false);
+        assert!(err.is_none(), "failed to parse benchmark source: {:?}", err);
+
+        (block, working_set.render())
+    };
+
+    engine_state
+        .merge_delta(delta)
+        .expect("failed to merge the parsed definitions");
+
+    block
+}
+
+fn eval(engine_state: &EngineState, block: &Block) -> Value {
+    let mut stack = Stack::new();
+    stack.vars.insert(
+        CONFIG_VARIABLE_ID,
+        Value::Record {
+            cols: vec![],
+            vals: vec![],
+            span: Span::unknown(),
+        },
+    );
+
+    eval_block(
+        engine_state,
+        &mut stack,
+        block,
+        PipelineData::new(Span::unknown()),
+    )
+    .expect("failed to evaluate benchmark source")
+    .into_value(Span::unknown())
+}
+
+/// A script defining a table literal with the given number of rows
+fn table_script(rows: usize) -> String {
+    let mut source = String::from("let table = [[name, size, kind, modified];\n");
+
+    for row in 0..rows {
+        source.push_str(&format!(
+            "  ['file{}.txt', {}kb, file, 2021-12-{:02}]\n",
+            row,
+            row,
+            row % 28 + 1
+        ));
+    }
+    source.push_str("]\n$table | where size > 10kb | length\n");
+
+    source
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    for rows in [10, 100, 1000] {
+        let source = table_script(rows);
+        let engine_state = create_default_context();
+
+        group.bench_with_input(BenchmarkId::new("table", rows), &source, |b, source| {
+            b.iter(|| {
+                let mut working_set = StateWorkingSet::new(&engine_state);
+                black_box(parse(&mut working_set, None, source.as_bytes(), false))
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_eval(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eval");
+    let mut engine_state = create_default_context();
+
+    for (name, source) in PIPELINES {
+        let block = parse_source(&mut engine_state, source);
+        group.bench_function(*name, |b| b.iter(|| black_box(eval(&engine_state, &block))));
+    }
+
+    let block = parse_source(&mut engine_state, &table_script(1000));
+    group.bench_function("table", |b| {
+        b.iter(|| black_box(eval(&engine_state, &block)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse, bench_eval);
+criterion_main!(benches);
@@ -104,10 +104,11 @@ fn into_binary(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
@@ -163,7 +164,10 @@ pub fn action(input: &Value, span: Span) -> Value {
         },
 
         _ => Value::Error {
-            error: ShellError::UnsupportedInput("'into binary' for unsupported type".into(), span),
+            error: Box::new(ShellError::UnsupportedInput(
+                "'into binary' for unsupported type".into(),
+                span,
+            )),
         },
     }
 }
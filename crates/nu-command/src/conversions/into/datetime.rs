@@ -170,14 +170,13 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let zone_options = zone_options.clone();
-                    let format_options = format_options.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, &zone_options, &format_options, head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| {
+                        action(old, &zone_options, &format_options, head)
+                    });
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -203,10 +202,10 @@ fn action(
                 // We have to manually guard it.
                 if let Ok(t) = ts {
                     if t.abs() > TIMESTAMP_BOUND {
-                        return Value::Error{error: ShellError::UnsupportedInput(
+                        return Value::Error{error: Box::new(ShellError::UnsupportedInput(
                             "Given timestamp is out of range, it should between -8e+12 and 8e+12".to_string(),
                             head,
-                        )};
+                        ))};
                     }
                     const HOUR: i32 = 3600;
                     let stampout = match tz.item {
@@ -233,10 +232,10 @@ fn action(
                             }
                         }
                         Zone::Error => Value::Error {
-                            error: ShellError::UnsupportedInput(
+                            error: Box::new(ShellError::UnsupportedInput(
                                 "Cannot convert given timezone or offset to timestamp".to_string(),
                                 tz.span,
-                            ),
+                            )),
                         },
                     };
                     return stampout;
@@ -248,11 +247,11 @@ fn action(
                     Ok(d) => Value::Date { val: d, span: head },
                     Err(reason) => {
                         return Value::Error {
-                            error: ShellError::CantConvert(
+                            error: Box::new(ShellError::CantConvert(
                                 format!("could not parse as datetime using format '{}'", dt.0),
                                 reason.to_string(),
                                 head,
-                            ),
+                            )),
                         }
                     }
                 },
@@ -267,22 +266,22 @@ fn action(
                             LocalResult::Ambiguous(d, _) => Value::Date { val: d, span: head },
                             LocalResult::None => {
                                 return Value::Error {
-                                    error: ShellError::CantConvert(
+                                    error: Box::new(ShellError::CantConvert(
                                         "could not convert to a timezone-aware datetime"
                                             .to_string(),
                                         "local time representation is invalid".to_string(),
                                         head,
-                                    ),
+                                    )),
                                 }
                             }
                         }
                     }
                     Err(_) => {
                         return Value::Error {
-                            error: ShellError::UnsupportedInput(
+                            error: Box::new(ShellError::UnsupportedInput(
                                 "Cannot convert input string as datetime. Might be missing timezone or offset".to_string(),
                                 *span,
-                            ),
+                            )),
                         }
                     }
                 },
@@ -293,7 +292,7 @@ fn action(
         other => {
             let got = format!("Expected string, got {} instead", other.get_type());
             Value::Error {
-                error: ShellError::UnsupportedInput(got, head),
+                error: Box::new(ShellError::UnsupportedInput(got, head)),
             }
         }
     }
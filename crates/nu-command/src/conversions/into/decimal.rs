@@ -79,10 +79,11 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
@@ -101,7 +102,11 @@ fn action(input: &Value, head: Span) -> Value {
             match other.parse::<f64>() {
                 Ok(x) => Value::Float { val: x, span: head },
                 Err(reason) => Value::Error {
-                    error: ShellError::CantConvert("float".to_string(), reason.to_string(), *span),
+                    error: Box::new(ShellError::CantConvert(
+                        "float".to_string(),
+                        reason.to_string(),
+                        *span,
+                    )),
                 },
             }
         }
@@ -115,10 +120,10 @@ fn action(input: &Value, head: Span) -> Value {
                 Ok(s) => {
                     let got = format!("Expected a string, got {} instead", other.get_type());
                     Value::Error {
-                        error: ShellError::UnsupportedInput(got, s),
+                        error: Box::new(ShellError::UnsupportedInput(got, s)),
                     }
                 }
-                Err(e) => Value::Error { error: e },
+                Err(e) => Value::Error { error: Box::new(e) },
             }
         }
     }
@@ -96,10 +96,11 @@ fn into_filesize(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
@@ -120,13 +121,15 @@ pub fn action(input: &Value, span: Span) -> Value {
         },
         Value::String { val, .. } => match int_from_string(val, span) {
             Ok(val) => Value::Filesize { val, span },
-            Err(error) => Value::Error { error },
+            Err(error) => Value::Error {
+                error: Box::new(error),
+            },
         },
         _ => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 "'into filesize' for unsupported type".into(),
                 span,
-            ),
+            )),
         },
     }
 }
@@ -130,12 +130,11 @@ fn into_int(
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, head, radix)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head, radix));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
@@ -164,7 +163,9 @@ pub fn action(input: &Value, span: Span, radix: u32) -> Value {
             if radix == 10 {
                 match int_from_string(val, span) {
                     Ok(val) => Value::Int { val, span },
-                    Err(error) => Value::Error { error },
+                    Err(error) => Value::Error {
+                        error: Box::new(error),
+                    },
                 }
             } else {
                 convert_int(input, span, radix)
@@ -178,7 +179,10 @@ pub fn action(input: &Value, span: Span, radix: u32) -> Value {
             }
         }
         _ => Value::Error {
-            error: ShellError::UnsupportedInput("'into int' for unsupported type".into(), span),
+            error: Box::new(ShellError::UnsupportedInput(
+                "'into int' for unsupported type".into(),
+                span,
+            )),
         },
     }
 }
@@ -190,24 +194,28 @@ fn convert_int(input: &Value, head: Span, radix: u32) -> Value {
             if val.starts_with("0x") || val.starts_with("0b") {
                 match int_from_string(&val.to_string(), head) {
                     Ok(x) => return Value::Int { val: x, span: head },
-                    Err(e) => return Value::Error { error: e },
+                    Err(e) => return Value::Error { error: Box::new(e) },
                 }
             }
             val.to_string()
         }
         _ => {
             return Value::Error {
-                error: ShellError::UnsupportedInput(
+                error: Box::new(ShellError::UnsupportedInput(
                     "only strings or integers are supported".to_string(),
                     head,
-                ),
+                )),
             }
         }
     };
     match i64::from_str_radix(&i, radix) {
         Ok(n) => Value::Int { val: n, span: head },
         Err(reason) => Value::Error {
-            error: ShellError::CantConvert("".to_string(), reason.to_string(), head),
+            error: Box::new(ShellError::CantConvert(
+                "".to_string(),
+                reason.to_string(),
+                head,
+            )),
         },
     }
 }
@@ -4,6 +4,7 @@ mod datetime;
 mod decimal;
 mod filesize;
 mod int;
+mod record;
 mod string;
 
 pub use self::filesize::SubCommand as IntoFilesize;
@@ -12,4 +13,5 @@ pub use command::Into;
 pub use datetime::SubCommand as IntoDatetime;
 pub use decimal::SubCommand as IntoDecimal;
 pub use int::SubCommand as IntoInt;
+pub use record::SubCommand as IntoRecord;
 pub use string::SubCommand as IntoString;
@@ -0,0 +1,148 @@
+use indexmap::IndexMap;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "into record"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into record").category(Category::Conversions)
+    }
+
+    fn usage(&self) -> &str {
+        "Convert value to record"
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Lists of key/value pairs, whether as two-item lists or as the rows of a two-column
+table, use the first item of each pair as the column name. Any other list becomes a record
+with the index of each item as its column name."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let head = call.head;
+
+        Ok(into_record(input.into_value(head), head)?.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let span = Span::unknown();
+
+        vec![
+            Example {
+                description: "Convert a list of key/value pairs to a record",
+                example: "[[a 1] [b 2]] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["a".into(), "b".into()],
+                    vals: vec![Value::Int { val: 1, span }, Value::Int { val: 2, span }],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert a two-column table to a record",
+                example: "[[name value]; [a 1] [b 2]] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["a".into(), "b".into()],
+                    vals: vec![Value::Int { val: 1, span }, Value::Int { val: 2, span }],
+                    span,
+                }),
+            },
+            Example {
+                description: "Convert a list to a record indexed by position",
+                example: "[x y] | into record",
+                result: Some(Value::Record {
+                    cols: vec!["0".into(), "1".into()],
+                    vals: vec![Value::test_string("x"), Value::test_string("y")],
+                    span,
+                }),
+            },
+        ]
+    }
+}
+
+fn into_record(input: Value, head: Span) -> Result<Value, ShellError> {
+    match input {
+        Value::Record { .. } => Ok(input),
+        Value::List { vals, .. } => {
+            let record: IndexMap<String, Value> = if vals.iter().all(is_pair) {
+                vals.into_iter()
+                    .map(|pair| {
+                        let (key, val) = into_pair(pair);
+                        Ok((column_name(&key)?, val))
+                    })
+                    .collect::<Result<_, ShellError>>()?
+            } else {
+                vals.into_iter()
+                    .enumerate()
+                    .map(|(idx, val)| (idx.to_string(), val))
+                    .collect()
+            };
+
+            Ok(Value::from(Spanned {
+                item: record,
+                span: head,
+            }))
+        }
+        Value::Error { error } => Err(*error),
+        other => Err(ShellError::UnsupportedInput(
+            format!("'into record' does not support {}", other.get_type()),
+            head,
+        )),
+    }
+}
+
+// A two-item list or a two-column row holds a key and a value
+fn is_pair(val: &Value) -> bool {
+    matches!(val, Value::List { vals, .. } | Value::Record { vals, .. } if vals.len() == 2)
+}
+
+fn into_pair(pair: Value) -> (Value, Value) {
+    match pair {
+        Value::List { vals, .. } | Value::Record { vals, .. } => {
+            let mut vals = vals.into_iter();
+            match (vals.next(), vals.next()) {
+                (Some(key), Some(val)) => (key, val),
+                _ => unreachable!("pairs are checked to have two items"),
+            }
+        }
+        _ => unreachable!("pairs are checked to be lists or records"),
+    }
+}
+
+fn column_name(key: &Value) -> Result<String, ShellError> {
+    match key {
+        Value::String { val, .. } => Ok(val.clone()),
+        Value::Int { val, .. } => Ok(val.to_string()),
+        other => Err(ShellError::CantConvert(
+            "column name".into(),
+            other.get_type().to_string(),
+            other.span()?,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,12 +1,13 @@
+use crate::format_date;
+use encoding_rs::Encoding;
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::{Call, CellPath},
     engine::{Command, EngineState, Stack},
-    Category, Config, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+    get_config_locale, localize_number, number_locale, Category, Config, Example, PipelineData,
+    ShellError, Signature, Span, Spanned, SyntaxShape, Value,
 };
 
-// TODO num_format::SystemLocale once platform-specific dependencies are stable (see Cargo.toml)
-
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -29,6 +30,17 @@ impl Command for SubCommand {
                 "decimal digits to which to round",
                 Some('d'),
             )
+            .named(
+                "decode",
+                SyntaxShape::String,
+                "the encoding of binary input, like utf-16le or latin1 (utf-8 by default)",
+                None,
+            )
+            .switch(
+                "group-digits",
+                "group the digits of numbers the way the locale does",
+                Some('g'),
+            )
             .category(Category::Conversions)
     }
 
@@ -36,6 +48,15 @@ impl Command for SubCommand {
         "Convert value to string"
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"With 'locale' set in the config, numbers get the digit grouping and decimal point of that
+locale, and dates its month and day names. Otherwise numbers are written plainly, unless
+--group-digits asks for the grouping of the LC_ALL environment variable, or of English.
+
+Binary is decoded as UTF-8, or the encoding given with --decode, unless it starts with a byte
+order mark naming another. Bytes that aren't valid in the encoding are an error."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -92,6 +113,11 @@ impl Command for SubCommand {
                     span: Span::unknown(),
                 }),
             },
+            Example {
+                description: "convert an integer to string with its digits grouped",
+                example: "1234567 | into string --group-digits",
+                result: None,
+            },
             Example {
                 description: "convert string to string",
                 example: "'1234' | into string",
@@ -123,6 +149,11 @@ impl Command for SubCommand {
                 example: "ls Cargo.toml | get size | into string",
                 result: None,
             },
+            Example {
+                description: "decode binary in another encoding",
+                example: "open --raw legacy.txt | into string --decode latin1",
+                result: None,
+            },
         ]
     }
 }
@@ -138,6 +169,23 @@ fn string_helper(
     let decimals_value: Option<i64> = call.get_flag(engine_state, stack, "decimals")?;
     let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
     let config = stack.get_config().unwrap_or_default();
+    // The string is likely to be parsed again, so LC_ALL alone doesn't change how numbers look
+    let group_digits = call.has_flag("group-digits") || !config.locale.is_empty();
+
+    let encoding: Option<Spanned<String>> = call.get_flag(engine_state, stack, "decode")?;
+    let encoding = match encoding {
+        Some(label) => Encoding::for_label(label.item.as_bytes()).ok_or_else(|| {
+            ShellError::SpannedLabeledError(
+                "Unknown encoding".into(),
+                format!(
+                    "'{}' is not an encoding into string knows about",
+                    label.item
+                ),
+                label.span,
+            )
+        })?,
+        None => encoding_rs::UTF_8,
+    };
 
     if let Some(decimal_val) = decimals_value {
         if decimals && decimal_val.is_negative() {
@@ -151,19 +199,33 @@ fn string_helper(
     input.map(
         move |v| {
             if column_paths.is_empty() {
-                action(&v, head, decimals, decimals_value, false, &config)
+                action(
+                    &v,
+                    head,
+                    decimals,
+                    decimals_value,
+                    group_digits,
+                    encoding,
+                    &config,
+                )
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let config = config.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| {
-                            action(old, head, decimals, decimals_value, false, &config)
-                        }),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| {
+                        action(
+                            old,
+                            head,
+                            decimals,
+                            decimals_value,
+                            group_digits,
+                            encoding,
+                            &config,
+                        )
+                    });
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
@@ -180,12 +242,13 @@ pub fn action(
     decimals: bool,
     digits: Option<i64>,
     group_digits: bool,
+    encoding: &'static Encoding,
     config: &Config,
 ) -> Value {
     match input {
         Value::Int { val, .. } => {
             let res = if group_digits {
-                format_int(*val) // int.to_formatted_string(*locale)
+                format_int(*val, config)
             } else {
                 val.to_string()
             };
@@ -193,17 +256,20 @@ pub fn action(
             Value::String { val: res, span }
         }
         Value::Float { val, .. } => {
-            if decimals {
+            let res = if decimals {
                 let decimal_value = digits.unwrap_or(2) as usize;
-                Value::String {
-                    val: format!("{:.*}", decimal_value, val),
-                    span,
-                }
+                format!("{:.*}", decimal_value, val)
             } else {
-                Value::String {
-                    val: val.to_string(),
-                    span,
-                }
+                val.to_string()
+            };
+
+            Value::String {
+                val: if group_digits {
+                    localize(res, config)
+                } else {
+                    res
+                },
+                span,
             }
         }
         Value::Bool { val, .. } => Value::String {
@@ -211,13 +277,14 @@ pub fn action(
             span,
         },
         Value::Date { val, .. } => Value::String {
-            val: val.format("%c").to_string(),
+            val: format_date(val, "%c", config),
             span,
         },
         Value::String { val, .. } => Value::String {
             val: val.to_string(),
             span,
         },
+        Value::Binary { val, .. } => decode(val, encoding, span),
 
         Value::Filesize { val: _, .. } => Value::String {
             val: input.clone().into_string(", ", config),
@@ -232,31 +299,54 @@ pub fn action(
             vals: _,
             span: _,
         } => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 "Cannot convert Record into string".to_string(),
                 span,
-            ),
+            )),
         },
         x => Value::Error {
-            error: ShellError::CantConvert(String::from("string"), x.get_type().to_string(), span),
+            error: Box::new(ShellError::CantConvert(
+                String::from("string"),
+                x.get_type().to_string(),
+                span,
+            )),
         },
     }
 }
-fn format_int(int: i64) -> String {
-    int.to_string()
+// A byte order mark takes precedence over the encoding, the same way `lines` decodes
+fn decode(bytes: &[u8], encoding: &'static Encoding, span: Span) -> Value {
+    let (text, used, had_errors) = encoding.decode(bytes);
+
+    if had_errors {
+        Value::Error {
+            error: Box::new(ShellError::SpannedLabeledError(
+                "Can't decode binary".into(),
+                format!(
+                    "the input isn't valid {}, another encoding can be given with --decode",
+                    used.name()
+                ),
+                span,
+            )),
+        }
+    } else {
+        Value::String {
+            val: text.into_owned(),
+            span,
+        }
+    }
+}
+
+fn format_int(int: i64, config: &Config) -> String {
+    localize(int.to_string(), config)
+}
 
-    // TODO once platform-specific dependencies are stable (see Cargo.toml)
-    // #[cfg(windows)]
-    // {
-    //     int.to_formatted_string(&Locale::en)
-    // }
-    // #[cfg(not(windows))]
-    // {
-    //     match SystemLocale::default() {
-    //         Ok(locale) => int.to_formatted_string(&locale),
-    //         Err(_) => int.to_formatted_string(&Locale::en),
-    //     }
-    // }
+fn localize(number: String, config: &Config) -> String {
+    match get_config_locale(config) {
+        Some(name) => localize_number(&number, &number_locale(&name)),
+        // --group-digits without any locale groups the English way
+        None if config.locale.is_empty() => localize_number(&number, &number_locale("en")),
+        None => number,
+    }
 }
 
 #[cfg(test)]
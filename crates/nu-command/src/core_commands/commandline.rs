@@ -0,0 +1,154 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Commandline;
+
+impl Command for Commandline {
+    fn name(&self) -> &str {
+        "commandline"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("commandline")
+            .optional(
+                "cmd",
+                SyntaxShape::String,
+                "the string to put in the line buffer",
+            )
+            .named(
+                "cursor",
+                SyntaxShape::Int,
+                "move the cursor to the given position",
+                Some('c'),
+            )
+            .switch(
+                "get-cursor",
+                "get the position of the cursor instead of the buffer",
+                Some('g'),
+            )
+            .switch(
+                "append",
+                "append the string to the end of the buffer",
+                Some('a'),
+            )
+            .switch(
+                "insert",
+                "insert the string at the cursor position",
+                Some('i'),
+            )
+            .switch(
+                "replace",
+                "replace the buffer with the string (the default)",
+                Some('r'),
+            )
+            .category(Category::Core)
+    }
+
+    fn usage(&self) -> &str {
+        "View or modify the current line buffer of the REPL."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without arguments, returns the contents of the line buffer, or the cursor position
+with --get-cursor. Positions count bytes from the start of the buffer. The line editor
+starts the next line with the buffer and cursor the command leaves."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the contents of the line buffer",
+                example: "commandline",
+                result: None,
+            },
+            Example {
+                description: "Insert a path at the cursor",
+                example: "commandline --insert (ls | get name | first)",
+                result: None,
+            },
+            Example {
+                description: "Move the cursor to the start of the buffer",
+                example: "commandline --cursor 0",
+                result: None,
+            },
+            Example {
+                description: "Get the position of the cursor",
+                example: "commandline --get-cursor",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cmd: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let cursor: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "cursor")?;
+
+        let mut buffer = engine_state
+            .repl_buffer_state
+            .lock()
+            .expect("repl buffer state mutex");
+        let mut cursor_pos = engine_state
+            .repl_cursor_pos
+            .lock()
+            .expect("repl cursor pos mutex");
+
+        if cmd.is_none() && cursor.is_none() {
+            let value = if call.has_flag("get-cursor") {
+                Value::Int {
+                    val: *cursor_pos as i64,
+                    span: call.head,
+                }
+            } else {
+                Value::String {
+                    val: buffer.clone(),
+                    span: call.head,
+                }
+            };
+
+            return Ok(value.into_pipeline_data());
+        }
+
+        if let Some(cmd) = cmd {
+            if call.has_flag("append") {
+                buffer.push_str(&cmd.item);
+            } else if call.has_flag("insert") {
+                let pos = (*cursor_pos).min(buffer.len());
+                buffer.insert_str(pos, &cmd.item);
+                *cursor_pos = pos + cmd.item.len();
+            } else {
+                *buffer = cmd.item;
+                *cursor_pos = buffer.len();
+            }
+        }
+
+        if let Some(cursor) = cursor {
+            if cursor.item < 0 {
+                return Err(ShellError::UnsupportedInput(
+                    "the cursor position must be a non-negative integer".into(),
+                    cursor.span,
+                ));
+            }
+
+            // Keep the cursor inside the buffer and on a character boundary
+            let mut pos = (cursor.item as usize).min(buffer.len());
+            while !buffer.is_char_boundary(pos) {
+                pos -= 1;
+            }
+            *cursor_pos = pos;
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+}
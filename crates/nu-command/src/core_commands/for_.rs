@@ -74,7 +74,9 @@ impl Command for For {
                     //let block = engine_state.get_block(block_id);
                     match eval_block(&engine_state, &mut stack, &block, PipelineData::new(head)) {
                         Ok(pipeline_data) => pipeline_data.into_value(head),
-                        Err(error) => Value::Error { error },
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        },
                     }
                 })
                 .into_pipeline_data(ctrlc)),
@@ -86,7 +88,9 @@ impl Command for For {
                     //let block = engine_state.get_block(block_id);
                     match eval_block(&engine_state, &mut stack, &block, PipelineData::new(head)) {
                         Ok(pipeline_data) => pipeline_data.into_value(head),
-                        Err(error) => Value::Error { error },
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        },
                     }
                 })
                 .into_pipeline_data(ctrlc)),
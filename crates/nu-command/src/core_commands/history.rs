@@ -0,0 +1,300 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Value,
+};
+
+// Same escape the line editor uses so multi-line entries fit on a single line of the file
+const NEWLINE_ESCAPE: &str = "<\\n>";
+
+#[derive(Clone)]
+pub struct History;
+
+impl Command for History {
+    fn name(&self) -> &str {
+        "history"
+    }
+
+    fn usage(&self) -> &str {
+        "Get the command history."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Every shell gets its own session id, which is recorded next to each command it runs.
+Use --session to only see the commands of the current shell, which is useful when
+several terminals share the same history file."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("history")
+            .switch(
+                "session",
+                "only show the commands run in the current session",
+                Some('s'),
+            )
+            .switch("clear", "clear the history", Some('c'))
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let (history_path, session_path) = match (history_path(), history_session_path()) {
+            (Some(history_path), Some(session_path)) => (history_path, session_path),
+            _ => {
+                return Err(ShellError::SpannedLabeledError(
+                    "History unavailable".into(),
+                    "could not find the config directory".into(),
+                    head,
+                ))
+            }
+        };
+
+        if call.has_flag("clear") {
+            for path in [&history_path, &session_path] {
+                if path.exists() {
+                    fs::write(path, "").map_err(|e| io_error(e, head))?;
+                }
+            }
+
+            return Ok(PipelineData::new(head));
+        }
+
+        let entries: Vec<Value> = if call.has_flag("session") {
+            read_lines(&session_path, head)?
+                .iter()
+                .filter_map(|line| line.split_once('\t'))
+                .filter(|(id, _)| id.parse::<i64>().ok() == Some(engine_state.history_session_id))
+                .map(|(_, entry)| unescape_entry(entry))
+                .map(|entry| Value::string(entry, head))
+                .collect()
+        } else {
+            history_entries(&history_path)
+                .map_err(|e| io_error(e, head))?
+                .into_iter()
+                .map(|entry| Value::string(entry, head))
+                .collect()
+        };
+
+        Ok(entries
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Get the last 5 commands",
+                example: "history | last 5",
+                result: None,
+            },
+            Example {
+                description: "Get the commands run in this shell",
+                example: "history --session",
+                result: None,
+            },
+            Example {
+                description: "Clear the history",
+                example: "history --clear",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Path of the file where the line editor keeps the history
+pub fn history_path() -> Option<PathBuf> {
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("history.txt");
+
+    Some(path)
+}
+
+/// Path of the file that records which session each history entry came from
+pub fn history_session_path() -> Option<PathBuf> {
+    let mut path = nu_path::config_dir()?;
+    path.push("nushell");
+    path.push("history-sessions.txt");
+
+    Some(path)
+}
+
+/// Read the entries of a history file, oldest first
+pub fn history_entries(path: &Path) -> std::io::Result<Vec<String>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(unescape_entry)
+        .collect())
+}
+
+/// A history file whose lines are kept in memory, so that adding an entry only appends one
+/// line instead of reading the whole file again
+///
+/// The file is only read again when its size shows that something else changed it, like
+/// another shell or `history --clear`.
+pub struct HistoryFile {
+    path: PathBuf,
+    lines: Vec<String>,
+    // The size of the file as of the last read or write
+    len: u64,
+}
+
+impl HistoryFile {
+    /// Read the lines of the file, which is created on the first append if it doesn't exist
+    pub fn open(path: PathBuf) -> Self {
+        let mut history_file = HistoryFile {
+            path,
+            lines: vec![],
+            len: 0,
+        };
+        history_file.reload();
+
+        history_file
+    }
+
+    /// The entries in the file, oldest first
+    pub fn entries(&mut self) -> impl Iterator<Item = String> + '_ {
+        self.refresh();
+
+        self.lines
+            .iter()
+            .filter(|line| !line.is_empty())
+            .map(|line| unescape_entry(line))
+    }
+
+    /// Add an entry. The file is cut back to the `max_size` most recent entries once it grows
+    /// a tenth past that.
+    pub fn append_entry(&mut self, entry: &str, max_size: usize) -> std::io::Result<()> {
+        self.append_line(escape_entry(entry), max_size)
+    }
+
+    /// Record that the entry was run in the given session, cutting the file back like
+    /// `append_entry` does
+    pub fn record_session_entry(
+        &mut self,
+        session_id: i64,
+        entry: &str,
+        max_size: usize,
+    ) -> std::io::Result<()> {
+        self.append_line(format!("{}\t{}", session_id, escape_entry(entry)), max_size)
+    }
+
+    // The slack past `max_lines` keeps the file from being rewritten on every append once it
+    // is full
+    fn append_line(&mut self, line: String, max_lines: usize) -> std::io::Result<()> {
+        self.refresh();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)?;
+        drop(file);
+
+        self.len += line.len() as u64 + 1;
+        self.lines.push(line);
+
+        if self.lines.len() > max_lines + max_lines / 10 {
+            self.lines.drain(..self.lines.len() - max_lines);
+
+            let mut contents = String::new();
+            for line in &self.lines {
+                contents.push_str(line);
+                contents.push('\n');
+            }
+            fs::write(&self.path, &contents)?;
+
+            self.len = contents.len() as u64;
+        }
+
+        Ok(())
+    }
+
+    fn refresh(&mut self) {
+        let len = fs::metadata(&self.path).map_or(0, |metadata| metadata.len());
+
+        if len != self.len {
+            self.reload();
+        }
+    }
+
+    fn reload(&mut self) {
+        let contents = fs::read_to_string(&self.path).unwrap_or_default();
+
+        self.lines = contents.lines().map(String::from).collect();
+        self.len = contents.len() as u64;
+    }
+}
+
+fn escape_entry(entry: &str) -> String {
+    entry.replace('\n', NEWLINE_ESCAPE)
+}
+
+fn unescape_entry(entry: &str) -> String {
+    entry.replace(NEWLINE_ESCAPE, "\n")
+}
+
+fn read_lines(path: &Path, span: Span) -> Result<Vec<String>, ShellError> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let contents = fs::read_to_string(path).map_err(|e| io_error(e, span))?;
+
+    Ok(contents
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+fn io_error(error: std::io::Error, span: Span) -> ShellError {
+    ShellError::SpannedLabeledError("History file error".into(), error.to_string(), span)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn history_file_appends_and_cuts_back() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("history.txt");
+
+        let mut history = HistoryFile::open(path.clone());
+        for idx in 0..12 {
+            history.append_entry(&format!("echo {}", idx), 10)?;
+        }
+        history.append_entry("echo a\necho b", 10)?;
+
+        // The twelfth entry went past the slack and cut the file back to the last ten
+        let entries: Vec<String> = history.entries().collect();
+        assert_eq!(entries.len(), 11);
+        assert_eq!(entries[0], "echo 2");
+        assert_eq!(entries[10], "echo a\necho b");
+        assert_eq!(fs::read_to_string(&path)?.lines().count(), 11);
+
+        // A file cleared by someone else is read again
+        fs::write(&path, "")?;
+        assert_eq!(history.entries().count(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, DataSource, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Metadata;
+
+impl Command for Metadata {
+    fn name(&self) -> &str {
+        "metadata"
+    }
+
+    fn usage(&self) -> &str {
+        "Get the metadata that travels with the input, like where it came from and its content type."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("metadata").category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut cols = vec!["source".to_string()];
+        let mut vals = vec![];
+
+        match input.metadata().map(|metadata| &metadata.data_source) {
+            Some(DataSource::Ls { .. }) => vals.push(Value::string("ls", span)),
+            Some(DataSource::Open { format }) => {
+                vals.push(Value::string("open", span));
+                cols.push("format".into());
+                vals.push(Value::string(format, span));
+            }
+            Some(DataSource::None) | None => vals.push(Value::Nothing { span }),
+        }
+
+        cols.push("content_type".into());
+        vals.push(
+            match input
+                .metadata()
+                .and_then(|metadata| metadata.content_type.as_ref())
+            {
+                Some(content_type) => Value::string(content_type, span),
+                None => Value::Nothing { span },
+            },
+        );
+
+        Ok(Value::Record { cols, vals, span }.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show where the output of ls comes from",
+                example: "ls | metadata",
+                result: None,
+            },
+            Example {
+                description: "Show the content type of a downloaded file",
+                example: "fetch --raw https://example.com/data.csv | metadata | get content_type",
+                result: None,
+            },
+        ]
+    }
+}
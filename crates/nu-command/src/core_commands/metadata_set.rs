@@ -0,0 +1,83 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, DataSource, Example, PipelineData, PipelineMetadata, ShellError, Signature,
+    SyntaxShape,
+};
+
+#[derive(Clone)]
+pub struct MetadataSet;
+
+impl Command for MetadataSet {
+    fn name(&self) -> &str {
+        "metadata set"
+    }
+
+    fn usage(&self) -> &str {
+        "Set the metadata that travels with the input."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The content type tells commands further down the pipeline what the data holds. For
+example, 'save' writes text tagged as 'text/csv' to a .csv file as is, and writes a table
+tagged that way as CSV whatever the extension of the file."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("metadata set")
+            .named(
+                "content-type",
+                SyntaxShape::String,
+                "the media type of the input, like 'text/csv'",
+                Some('c'),
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let content_type: Option<String> = call.get_flag(engine_state, stack, "content-type")?;
+        let content_type = match content_type {
+            Some(content_type) => content_type,
+            None => {
+                return Err(ShellError::MissingParameter(
+                    "--content-type".into(),
+                    call.head,
+                ))
+            }
+        };
+
+        // The source is kept, so `ls` output still gets its colored file names
+        let data_source = match input.metadata() {
+            Some(metadata) => metadata.data_source.clone(),
+            None => DataSource::None,
+        };
+
+        Ok(input.set_metadata(Some(PipelineMetadata {
+            data_source,
+            content_type: Some(content_type),
+        })))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Tag text as CSV",
+                example:
+                    "'a,b' | metadata set --content-type text/csv | metadata | get content_type",
+                result: None,
+            },
+            Example {
+                description: "Save a table as JSON, though the file has no extension",
+                example: "[[a b]; [1 2]] | metadata set -c application/json | save data",
+                result: None,
+            },
+        ]
+    }
+}
@@ -1,4 +1,5 @@
 mod alias;
+mod commandline;
 mod debug;
 mod def;
 mod describe;
@@ -10,14 +11,20 @@ mod export_env;
 mod for_;
 mod help;
 mod hide;
+mod history;
 mod if_;
 mod let_;
+mod metadata;
+mod metadata_set;
 mod module;
+mod retry;
 mod source;
+mod timeout;
 mod use_;
 mod version;
 
 pub use alias::Alias;
+pub use commandline::Commandline;
 pub use debug::Debug;
 pub use def::Def;
 pub use describe::Describe;
@@ -29,10 +36,15 @@ pub use export_env::ExportEnv;
 pub use for_::For;
 pub use help::Help;
 pub use hide::Hide;
+pub use history::{history_entries, history_path, history_session_path, History, HistoryFile};
 pub use if_::If;
 pub use let_::Let;
+pub use metadata::Metadata;
+pub use metadata_set::MetadataSet;
 pub use module::Module;
+pub use retry::Retry;
 pub use source::Source;
+pub use timeout::Timeout;
 pub use use_::Use;
 pub use version::Version;
 #[cfg(feature = "plugin")]
@@ -0,0 +1,220 @@
+use std::sync::atomic::Ordering;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Value,
+};
+
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+// The delay stops growing at the longest duration nu can represent
+const MAX_DELAY: Duration = Duration::from_nanos(i64::MAX as u64);
+
+#[derive(Clone)]
+pub struct Retry;
+
+impl Command for Retry {
+    fn name(&self) -> &str {
+        "retry"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block again until it succeeds."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The block is run up to --times times. When every attempt fails, the returned error
+points at retry and names the error of the last attempt. With --backoff, the delay is
+multiplied by the given factor after each failed attempt. Ctrl-c stops the retries with
+an error."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("retry")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to run",
+            )
+            .named(
+                "times",
+                SyntaxShape::Int,
+                "how many times to try the block (default: 3)",
+                Some('t'),
+            )
+            .named(
+                "delay",
+                SyntaxShape::Duration,
+                "how long to wait between attempts (default: 0sec)",
+                Some('d'),
+            )
+            .named(
+                "backoff",
+                SyntaxShape::Number,
+                "multiply the delay by this factor after each failed attempt",
+                Some('b'),
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let block: Value = call.req(engine_state, stack, 0)?;
+        let block_id = block.as_block()?;
+        let times: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "times")?;
+        let delay: Option<i64> = call.get_flag(engine_state, stack, "delay")?;
+        let backoff: Option<Spanned<f64>> = call.get_flag(engine_state, stack, "backoff")?;
+
+        let times = match times {
+            Some(Spanned { item, span }) if item < 1 => {
+                return Err(ShellError::UnsupportedInput(
+                    "the block must be tried at least once".into(),
+                    span,
+                ))
+            }
+            Some(Spanned { item, .. }) => item as usize,
+            None => 3,
+        };
+
+        let backoff = match backoff {
+            Some(Spanned { item, span }) if item.is_nan() || item < 1.0 => {
+                return Err(ShellError::UnsupportedInput(
+                    "the backoff factor can't be smaller than 1".into(),
+                    span,
+                ))
+            }
+            Some(Spanned { item, .. }) => item,
+            None => 1.0,
+        };
+
+        let mut delay = Duration::from_nanos(delay.unwrap_or(0).max(0) as u64);
+
+        let block = engine_state.get_block(block_id);
+        let stack = stack.collect_captures(&block.captures);
+        let input = input.into_value(call.head);
+
+        let mut attempt = 1;
+        loop {
+            let mut stack = stack.clone();
+            let result = eval_block(
+                engine_state,
+                &mut stack,
+                block,
+                input.clone().into_pipeline_data(),
+            )
+            .and_then(|output| collect_output(output, call.head));
+
+            match result {
+                Ok(value) => return Ok(value.into_pipeline_data()),
+                Err(error) if attempt >= times => {
+                    return Err(ShellError::SpannedLabeledError(
+                        format!("Failed after {} attempts", times),
+                        format!("the last attempt failed with: {}", error),
+                        call.head,
+                    ))
+                }
+                Err(_) => {
+                    if !wait(engine_state, delay) {
+                        return Err(ShellError::Interrupted(call.head));
+                    }
+                    delay = next_delay(delay, backoff);
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Fetch a page, trying up to 5 times",
+                example: "retry --times 5 --delay 1sec { fetch https://www.nushell.sh }",
+                result: None,
+            },
+            Example {
+                description: "Retry with a delay that doubles after every failure",
+                example: "retry --delay 100ms --backoff 2 { open flaky.json }",
+                result: None,
+            },
+        ]
+    }
+}
+
+// Streams are collected so that errors raised while streaming also count as a failed attempt
+fn collect_output(output: PipelineData, span: Span) -> Result<Value, ShellError> {
+    match output.into_value(span) {
+        Value::Error { error } => Err(*error),
+        Value::List { vals, span } => {
+            if let Some(Value::Error { error }) =
+                vals.iter().find(|val| matches!(val, Value::Error { .. }))
+            {
+                return Err(*error.clone());
+            }
+
+            Ok(Value::List { vals, span })
+        }
+        value => Ok(value),
+    }
+}
+
+// Multiply the delay by the backoff factor without overflowing
+fn next_delay(delay: Duration, backoff: f64) -> Duration {
+    let secs = delay.as_secs_f64() * backoff;
+
+    if secs.is_nan() {
+        // A zero delay multiplied by an infinite factor
+        delay
+    } else if secs >= MAX_DELAY.as_secs_f64() {
+        MAX_DELAY
+    } else {
+        Duration::from_secs_f64(secs)
+    }
+}
+
+// Sleep for the delay, returning false if ctrl-c was pressed in the meantime
+fn wait(engine_state: &EngineState, delay: Duration) -> bool {
+    let start = Instant::now();
+
+    while start.elapsed() < delay {
+        if let Some(ctrlc) = &engine_state.ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                return false;
+            }
+        }
+
+        thread::sleep(CTRL_C_CHECK_INTERVAL.min(delay.saturating_sub(start.elapsed())));
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn delay_grows_by_backoff() {
+        assert_eq!(
+            next_delay(Duration::from_millis(100), 2.0),
+            Duration::from_millis(200)
+        );
+        assert_eq!(next_delay(Duration::ZERO, f64::INFINITY), Duration::ZERO);
+    }
+
+    #[test]
+    fn delay_stops_at_max() {
+        assert_eq!(next_delay(Duration::from_secs(1), 1e300), MAX_DELAY);
+        assert_eq!(next_delay(MAX_DELAY, 2.0), MAX_DELAY);
+        assert_eq!(next_delay(Duration::from_secs(1), f64::INFINITY), MAX_DELAY);
+    }
+}
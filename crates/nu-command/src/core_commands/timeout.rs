@@ -0,0 +1,131 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    format_duration, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Timeout;
+
+impl Command for Timeout {
+    fn name(&self) -> &str {
+        "timeout"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block, failing if it takes longer than the given duration."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The block runs on its own thread, so changes it makes to the environment are not
+kept. A block that times out can't be stopped and keeps running in the background
+until it finishes, but its output is discarded."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("timeout")
+            .required(
+                "duration",
+                SyntaxShape::Duration,
+                "the time the block is allowed to run",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to run",
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let duration: Spanned<i64> = call.req(engine_state, stack, 0)?;
+        let block: Value = call.req(engine_state, stack, 1)?;
+        let block_id = block.as_block()?;
+
+        if duration.item < 0 {
+            return Err(ShellError::UnsupportedInput(
+                "the timeout can't be negative".into(),
+                duration.span,
+            ));
+        }
+
+        let head = call.head;
+        let engine_state = engine_state.clone();
+        let block = engine_state.get_block(block_id).clone();
+        let mut stack = stack.collect_captures(&block.captures);
+        let input = input.into_value(head);
+
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let result = eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                input.into_pipeline_data(),
+            )
+            .map(|output| output.into_value(head));
+
+            // The receiver is gone if the block timed out, in which case the result is dropped
+            let _ = sender.send(result);
+        });
+
+        match receiver.recv_timeout(Duration::from_nanos(duration.item as u64)) {
+            Ok(result) => result.map(|value| value.into_pipeline_data()),
+            Err(_) => Err(ShellError::SpannedLabeledError(
+                "Block timed out".into(),
+                format!(
+                    "the block didn't finish within {}",
+                    format_duration(duration.item)
+                ),
+                block_span(call),
+            )),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give up on a request after 30 seconds",
+                example: "timeout 30sec { fetch https://www.nushell.sh }",
+                result: None,
+            },
+            Example {
+                description: "Run a block that finishes in time",
+                example: "timeout 1sec { 1 + 2 }",
+                result: Some(Value::test_int(3)),
+            },
+        ]
+    }
+}
+
+fn block_span(call: &Call) -> nu_protocol::Span {
+    call.positional
+        .get(1)
+        .map(|expr| expr.span)
+        .unwrap_or(call.head)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Timeout {})
+    }
+}
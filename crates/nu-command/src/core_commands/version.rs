@@ -1,7 +1,9 @@
 use indexmap::IndexMap;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+};
 
 pub mod shadow {
     include!(concat!(env!("OUT_DIR"), "/shadow.rs"));
@@ -16,7 +18,7 @@ impl Command for Version {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("version")
+        Signature::build("version").category(Category::Core)
     }
 
     fn usage(&self) -> &str {
@@ -34,11 +36,18 @@ impl Command for Version {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Display Nu version",
-            example: "version",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Display Nu version",
+                example: "version",
+                result: None,
+            },
+            Example {
+                description: "Check if nushell was built with dataframe support",
+                example: "(version).features | any? $it == dataframe",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -182,9 +191,12 @@ pub fn version(
 
     indexmap.insert(
         "features".to_string(),
-        Value::String {
-            val: features_enabled().join(", "),
-            span: Span::unknown(),
+        Value::List {
+            vals: features_enabled()
+                .into_iter()
+                .map(|feature| Value::string(feature, tag))
+                .collect(),
+            span: tag,
         },
     );
 
@@ -193,14 +205,14 @@ pub fn version(
         .plugin_decls()
         .into_iter()
         .filter(|x| x.is_plugin().is_some())
-        .map(|x| x.name())
-        .collect::<Vec<_>>();
+        .map(|x| Value::string(x.name(), tag))
+        .collect();
 
     indexmap.insert(
         "installed_plugins".to_string(),
-        Value::String {
-            val: installed_plugins.join(", "),
-            span: Span::unknown(),
+        Value::List {
+            vals: installed_plugins,
+            span: tag,
         },
     );
 
@@ -226,127 +238,51 @@ pub fn version(
     .into_pipeline_data())
 }
 
-fn features_enabled() -> Vec<String> {
-    let mut names = vec!["default".to_string()];
-
-    // NOTE: There should be another way to know
-    // features on.
-    #[cfg(feature = "ctrlc")]
-    {
-        names.push("ctrlc".to_string());
-    }
-
-    // #[cfg(feature = "rich-benchmark")]
-    // {
-    //     names.push("rich-benchmark".to_string());
-    // }
+/// The optional features of nu-command this binary was built with
+fn features_enabled() -> Vec<&'static str> {
+    let mut names = vec!["default"];
 
-    #[cfg(feature = "rustyline-support")]
+    #[cfg(feature = "plugin")]
     {
-        names.push("rustyline".to_string());
+        names.push("plugin");
     }
 
-    #[cfg(feature = "term")]
+    #[cfg(feature = "dataframe")]
     {
-        names.push("term".to_string());
+        names.push("dataframe");
     }
 
-    #[cfg(feature = "uuid_crate")]
+    #[cfg(feature = "network")]
     {
-        names.push("uuid".to_string());
+        names.push("network");
     }
 
-    #[cfg(feature = "which")]
+    #[cfg(feature = "sqlite")]
     {
-        names.push("which".to_string());
+        names.push("sqlite");
     }
 
     #[cfg(feature = "zip")]
     {
-        names.push("zip".to_string());
+        names.push("zip");
     }
 
     #[cfg(feature = "clipboard-cli")]
     {
-        names.push("clipboard-cli".to_string());
-    }
-
-    #[cfg(feature = "trash-support")]
-    {
-        names.push("trash".to_string());
+        names.push("clipboard-cli");
     }
 
-    #[cfg(feature = "dataframe")]
+    #[cfg(feature = "clipboard-wayland")]
     {
-        names.push("dataframe".to_string());
+        names.push("clipboard-wayland");
     }
 
-    #[cfg(feature = "table-pager")]
+    #[cfg(feature = "trash-support")]
     {
-        names.push("table-pager".to_string());
+        names.push("trash");
     }
 
-    // #[cfg(feature = "binaryview")]
-    // {
-    //     names.push("binaryview".to_string());
-    // }
-
-    // #[cfg(feature = "start")]
-    // {
-    //     names.push("start".to_string());
-    // }
-
-    // #[cfg(feature = "bson")]
-    // {
-    //     names.push("bson".to_string());
-    // }
-
-    // #[cfg(feature = "sqlite")]
-    // {
-    //     names.push("sqlite".to_string());
-    // }
-
-    // #[cfg(feature = "s3")]
-    // {
-    //     names.push("s3".to_string());
-    // }
-
-    // #[cfg(feature = "chart")]
-    // {
-    //     names.push("chart".to_string());
-    // }
-
-    // #[cfg(feature = "xpath")]
-    // {
-    //     names.push("xpath".to_string());
-    // }
-
-    // #[cfg(feature = "selector")]
-    // {
-    //     names.push("selector".to_string());
-    // }
-
-    // #[cfg(feature = "extra")]
-    // {
-    //     names.push("extra".to_string());
-    // }
-
-    // #[cfg(feature = "preserve_order")]
-    // {
-    //     names.push("preserve_order".to_string());
-    // }
-
-    // #[cfg(feature = "wee_alloc")]
-    // {
-    //     names.push("wee_alloc".to_string());
-    // }
-
-    // #[cfg(feature = "console_error_panic_hook")]
-    // {
-    //     names.push("console_error_panic_hook".to_string());
-    // }
-
-    names.sort();
+    names.sort_unstable();
 
     names
 }
@@ -3,10 +3,10 @@ use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, PipelineData, Signature, Span, Spanned, SyntaxShape, Value,
+    Category, Config, Example, PipelineData, Signature, Span, Spanned, SyntaxShape, Value,
 };
 
-use super::utils::{parse_date_from_string, unsupported_input_error};
+use super::utils::{format_date, parse_date_from_string, unsupported_input_error};
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -30,6 +30,11 @@ impl Command for SubCommand {
         "Format a given date using the given format string."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Month and day names, and the formats like %c and %x, follow the locale set by
+'locale' in the config, or by the LC_ALL environment variable."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -39,8 +44,9 @@ impl Command for SubCommand {
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let head = call.head;
         let formatter: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let config = stack.get_config().unwrap_or_default();
         input.map(
-            move |value| format_helper(value, &formatter, head),
+            move |value| format_helper(value, &formatter, head, &config),
             engine_state.ctrlc.clone(),
         )
     }
@@ -75,17 +81,17 @@ impl Command for SubCommand {
     }
 }
 
-fn format_helper(value: Value, formatter: &Spanned<String>, span: Span) -> Value {
+fn format_helper(value: Value, formatter: &Spanned<String>, span: Span, config: &Config) -> Value {
     match value {
         Value::Date { val, span: _ } => Value::String {
-            val: val.format(formatter.item.as_str()).to_string(),
+            val: format_date(&val, &formatter.item, config),
             span,
         },
         Value::String { val, span: _ } => {
             let dt = parse_date_from_string(val);
             match dt {
                 Ok(x) => Value::String {
-                    val: x.format(formatter.item.as_str()).to_string(),
+                    val: format_date(&x, &formatter.item, config),
                     span,
                 },
                 Err(e) => e,
@@ -94,10 +100,7 @@ fn format_helper(value: Value, formatter: &Spanned<String>, span: Span) -> Value
         Value::Nothing { span: _ } => {
             let dt = Local::now();
             Value::String {
-                val: dt
-                    .with_timezone(dt.offset())
-                    .format(formatter.item.as_str())
-                    .to_string(),
+                val: format_date(&dt.with_timezone(dt.offset()), &formatter.item, config),
                 span,
             }
         }
@@ -74,10 +74,10 @@ fn helper(value: Value, head: Span) -> Value {
             span: head,
         },
         _ => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 String::from("Date cannot be parsed / date format is not supported"),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
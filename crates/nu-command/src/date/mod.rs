@@ -15,3 +15,4 @@ pub use list_timezone::SubCommand as DateListTimezones;
 pub use now::SubCommand as DateNow;
 pub use to_table::SubCommand as DateToTable;
 pub use to_timezone::SubCommand as DateToTimezone;
+pub(crate) use utils::format_date;
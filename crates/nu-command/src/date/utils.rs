@@ -1,9 +1,11 @@
-use chrono::{DateTime, FixedOffset};
-use nu_protocol::{ShellError, Span, Value};
+use std::convert::TryFrom;
+
+use chrono::{DateTime, FixedOffset, Locale};
+use nu_protocol::{get_config_locale, Config, ShellError, Span, Value};
 
 pub fn unsupported_input_error() -> Value {
     Value::Error {
-        error: ShellError::UnsupportedInput(
+        error: Box::new(ShellError::UnsupportedInput(
             String::from(
                 "Only dates with timezones are supported. The following formats are allowed \n 
             * %Y-%m-%d %H:%M:%S %z -- 2020-04-12 22:10:57 +02:00 \n 
@@ -12,7 +14,7 @@ pub fn unsupported_input_error() -> Value {
             * rfc2822 -- Tue, 1 Jul 2003 10:52:37 +0200",
             ),
             Span::unknown(),
-        ),
+        )),
     }
 }
 
@@ -41,3 +43,15 @@ pub fn parse_date_from_string(input: String) -> Result<DateTime<FixedOffset>, Va
         }
     }
 }
+
+/// Formats the date with the month and day names of the locale in the config, if there is one
+pub(crate) fn format_date(date: &DateTime<FixedOffset>, format: &str, config: &Config) -> String {
+    // The locales are named like de_DE, which is also how they are usually given
+    let locale = get_config_locale(config)
+        .and_then(|name| Locale::try_from(name.replace("-", "_").as_str()).ok());
+
+    match locale {
+        Some(locale) => date.format_localized(format, locale).to_string(),
+        None => date.format(format).to_string(),
+    }
+}
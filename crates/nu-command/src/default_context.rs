@@ -24,6 +24,7 @@ pub fn create_default_context() -> EngineState {
         // Core
         bind_command! {
             Alias,
+            Commandline,
             Debug,
             Def,
             Describe,
@@ -35,10 +36,15 @@ pub fn create_default_context() -> EngineState {
             For,
             Help,
             Hide,
+            History,
             If,
             Let,
+            Metadata,
+            MetadataSet,
             Module,
+            Retry,
             Source,
+            Timeout,
             Use,
             Version,
         };
@@ -56,14 +62,19 @@ pub fn create_default_context() -> EngineState {
             First,
             Flatten,
             Get,
+            Group,
+            GroupBy,
+            Join,
             Keep,
             KeepUntil,
             KeepWhile,
             Last,
             Length,
             Lines,
+            Merge,
             Nth,
             ParEach,
+            Partition,
             Prepend,
             Range,
             Reject,
@@ -73,7 +84,10 @@ pub fn create_default_context() -> EngineState {
             Skip,
             SkipUntil,
             SkipWhile,
+            Sort,
+            SortBy,
             Uniq,
+            UniqBy,
             Update,
             Where,
             Wrap,
@@ -97,9 +111,25 @@ pub fn create_default_context() -> EngineState {
         // System
         bind_command! {
             Benchmark,
+            Complete,
+            Exec,
             External,
+            IsAdmin,
+            Job,
+            JobKill,
+            JobList,
+            JobSpawn,
+            JobWait,
             Ps,
             Sys,
+            SysCapabilities,
+            SysDisks,
+            SysHost,
+            SysNet,
+            SysTemp,
+            SysUsers,
+            Ulimit,
+            Which,
         };
 
         // Strings
@@ -107,11 +137,14 @@ pub fn create_default_context() -> EngineState {
             BuildString,
             Char,
             Format,
+            FormatDate,
+            FormatFilesize,
             Parse,
             Size,
             Split,
             SplitChars,
             SplitColumn,
+            SplitList,
             SplitRow,
             Str,
             StrCamelCase,
@@ -120,12 +153,14 @@ pub fn create_default_context() -> EngineState {
             StrContains,
             StrDowncase,
             StrEndswith,
+            StrExpand,
             StrFindReplace,
             StrIndexOf,
             StrKebabCase,
             StrLength,
             StrLpad,
             StrPascalCase,
+            StrReplace,
             StrReverse,
             StrRpad,
             StrScreamingSnakeCase,
@@ -139,11 +174,17 @@ pub fn create_default_context() -> EngineState {
         // FileSystem
         bind_command! {
             Cd,
+            Chmod,
+            Chown,
             Cp,
+            Du,
             Ls,
             Mkdir,
             Mv,
+            Open,
             Rm,
+            Save,
+            Start,
             Touch,
         };
 
@@ -151,12 +192,35 @@ pub fn create_default_context() -> EngineState {
         bind_command! {
             Ansi,
             AnsiGradient,
+            AnsiLink,
             AnsiStrip,
             Clear,
+            Keybindings,
+            KeybindingsListen,
             Kill,
             Sleep,
         };
 
+        // Database
+        #[cfg(feature = "sqlite")]
+        bind_command! {
+            Stor,
+            StorCreate,
+            StorDelete,
+            StorInsert,
+            StorOpen,
+            StorQuery,
+            StorReset,
+            StorUpdate,
+        };
+
+        #[cfg(feature = "clipboard-cli")]
+        bind_command! {
+            Clip,
+            ClipCopy,
+            ClipPaste,
+        };
+
         // Date
         bind_command! {
             Date,
@@ -170,7 +234,14 @@ pub fn create_default_context() -> EngineState {
 
         // Shells
         bind_command! {
+            Dirs,
+            Enter,
             Exit,
+            NextShell,
+            Popd,
+            PrevShell,
+            Pushd,
+            Shells,
         };
 
         // Formats
@@ -210,6 +281,7 @@ pub fn create_default_context() -> EngineState {
 
         // Viewers
         bind_command! {
+            Explore,
             Griddle,
             Table,
         };
@@ -222,14 +294,20 @@ pub fn create_default_context() -> EngineState {
             IntoDecimal,
             IntoFilesize,
             IntoInt,
+            IntoRecord,
             IntoString,
         };
 
         // Env
         bind_command! {
             LetEnv,
+            LoadEnv,
             WithEnv,
             Env,
+            EnvPath,
+            EnvPathAdd,
+            EnvPathList,
+            EnvPathRemove,
         };
 
         // Math
@@ -252,7 +330,17 @@ pub fn create_default_context() -> EngineState {
             MathVariance,
         };
 
+        for command in MathIntArithmetic::all() {
+            working_set.add_decl(Box::new(command));
+        }
+
         // Network
+        #[cfg(feature = "network")]
+        bind_command! {
+            Fetch,
+            Post,
+        };
+
         bind_command! {
             Url,
             UrlHost,
@@ -264,10 +352,12 @@ pub fn create_default_context() -> EngineState {
         // Random
         bind_command! {
             Random,
+            RandomBinary,
             RandomBool,
             RandomChars,
             RandomDecimal,
             RandomDice,
+            RandomFloat,
             RandomInteger,
             RandomUuid,
         };
@@ -275,11 +365,16 @@ pub fn create_default_context() -> EngineState {
         // Generators
         bind_command! {
             Cal,
+            Every,
+            Generate,
         };
 
         // Hash
         bind_command! {
             Hash,
+            HashHmac,
+            HashHmacMd5::default(),
+            HashHmacSha256::default(),
             HashMd5::default(),
             HashSha256::default(),
         };
@@ -0,0 +1,91 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+
+use super::{expand_entry, read_path, write_path};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "env-path add"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env-path add")
+            .rest(
+                "paths",
+                SyntaxShape::Filepath,
+                "the directories to add to PATH",
+            )
+            .switch(
+                "append",
+                "Add the directories at the end of PATH instead of the front",
+                Some('a'),
+            )
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Add directories to the PATH environment variable."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Directories already in PATH are moved rather than added twice."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let paths: Vec<String> = call.rest(engine_state, stack, 0)?;
+        let append = call.has_flag("append");
+
+        let new_entries: Vec<String> = paths.iter().map(|path| expand_entry(path)).collect();
+
+        let mut path = read_path(stack)?;
+        path.entries.retain(|entry| !new_entries.contains(entry));
+
+        if append {
+            path.entries.extend(new_entries);
+        } else {
+            path.entries.splice(0..0, new_entries);
+        }
+
+        write_path(stack, path, call.head);
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Add a directory to the front of PATH",
+                example: "env-path add /usr/local/bin; $nu.env.PATH",
+                result: Some(Value::test_string("/usr/local/bin")),
+            },
+            Example {
+                description: "Add a directory to the end of PATH",
+                example: "env-path add ~/.cargo/bin --append",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -0,0 +1,47 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct EnvPathCommand;
+
+impl Command for EnvPathCommand {
+    fn name(&self) -> &str {
+        "env-path"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env-path").category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Inspect and modify the PATH environment variable."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The subcommands take care of the platform's path separator and keep PATH
+free of duplicates. PATH keeps the shape it had before: a separated string stays
+a string and a list stays a list."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, nu_protocol::ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &EnvPathCommand.signature(),
+                &EnvPathCommand.examples(),
+                engine_state,
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
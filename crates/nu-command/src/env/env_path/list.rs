@@ -0,0 +1,52 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+use super::read_path;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "env-path list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env-path list").category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "List the entries of the PATH environment variable."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let path = read_path(stack)?;
+
+        Ok(path
+            .entries
+            .into_iter()
+            .map(move |entry| Value::String {
+                val: entry,
+                span: head,
+            })
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "List the directories searched for executables",
+            example: "env-path list",
+            result: None,
+        }]
+    }
+}
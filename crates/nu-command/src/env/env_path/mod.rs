@@ -0,0 +1,103 @@
+mod add;
+pub mod command;
+mod list;
+mod remove;
+
+pub use add::SubCommand as EnvPathAdd;
+pub use command::EnvPathCommand as EnvPath;
+pub use list::SubCommand as EnvPathList;
+pub use remove::SubCommand as EnvPathRemove;
+
+use nu_protocol::engine::Stack;
+use nu_protocol::{ShellError, Span, Value};
+
+#[cfg(windows)]
+const ENV_SEP: char = ';';
+#[cfg(not(windows))]
+const ENV_SEP: char = ':';
+
+/// The entries of the PATH environment variable
+struct PathEntries {
+    /// Name of the variable, which is `Path` rather than `PATH` on some Windows setups
+    name: String,
+    entries: Vec<String>,
+    /// Whether the variable was stored as a list rather than a separated string
+    is_list: bool,
+}
+
+fn path_var_name(stack: &Stack) -> String {
+    stack
+        .get_env_vars()
+        .into_keys()
+        .find(|name| name.eq_ignore_ascii_case("path"))
+        .unwrap_or_else(|| "PATH".to_string())
+}
+
+fn read_path(stack: &Stack) -> Result<PathEntries, ShellError> {
+    let name = path_var_name(stack);
+
+    let (entries, is_list) = match stack.get_env_var(&name) {
+        Some(Value::List { vals, .. }) => (
+            vals.iter()
+                .map(|val| val.as_string())
+                .collect::<Result<Vec<String>, ShellError>>()?,
+            true,
+        ),
+        Some(Value::String { val, .. }) => (
+            val.split(ENV_SEP)
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| entry.to_string())
+                .collect(),
+            false,
+        ),
+        Some(other) => {
+            return Err(ShellError::UnsupportedInput(
+                format!("{} must be a string or a list of strings", name),
+                other.span()?,
+            ))
+        }
+        None => (vec![], false),
+    };
+
+    Ok(PathEntries {
+        name,
+        entries,
+        is_list,
+    })
+}
+
+/// The directories in PATH, in the order they're searched
+pub(crate) fn path_entries(stack: &Stack) -> Result<Vec<String>, ShellError> {
+    Ok(read_path(stack)?.entries)
+}
+
+/// Store the entries back in the same shape they were read in, without duplicates
+fn write_path(stack: &mut Stack, path: PathEntries, span: Span) {
+    let mut entries: Vec<String> = vec![];
+    for entry in path.entries {
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    let value = if path.is_list {
+        Value::List {
+            vals: entries
+                .into_iter()
+                .map(|entry| Value::String { val: entry, span })
+                .collect(),
+            span,
+        }
+    } else {
+        Value::String {
+            val: entries.join(&ENV_SEP.to_string()),
+            span,
+        }
+    };
+
+    stack.add_env_var(path.name, value);
+}
+
+fn expand_entry(entry: &str) -> String {
+    nu_path::expand_tilde(entry).to_string_lossy().to_string()
+}
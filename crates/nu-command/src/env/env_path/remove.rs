@@ -0,0 +1,55 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape};
+
+use super::{expand_entry, read_path, write_path};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "env-path remove"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env-path remove")
+            .rest(
+                "paths",
+                SyntaxShape::Filepath,
+                "the directories to remove from PATH",
+            )
+            .category(Category::Env)
+    }
+
+    fn usage(&self) -> &str {
+        "Remove directories from the PATH environment variable."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let paths: Vec<String> = call.rest(engine_state, stack, 0)?;
+        let removed: Vec<String> = paths.iter().map(|path| expand_entry(path)).collect();
+
+        let mut path = read_path(stack)?;
+        path.entries.retain(|entry| !removed.contains(entry));
+
+        write_path(stack, path, call.head);
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Remove a directory from PATH",
+            example: "env-path remove /usr/local/bin",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,110 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+
+#[derive(Clone)]
+pub struct LoadEnv;
+
+impl Command for LoadEnv {
+    fn name(&self) -> &str {
+        "load-env"
+    }
+
+    fn usage(&self) -> &str {
+        "Set environment variables from the columns of a record."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("load-env")
+            .optional(
+                "update",
+                SyntaxShape::Any,
+                "the record to load, instead of the input",
+            )
+            .named(
+                "prefix",
+                SyntaxShape::String,
+                "put this in front of the name of every variable",
+                Some('p'),
+            )
+            .category(Category::Env)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let update: Option<Value> = call.opt(engine_state, stack, 0)?;
+        let prefix: Option<String> = call.get_flag(engine_state, stack, "prefix")?;
+
+        let record = match update {
+            Some(record) => record,
+            None => input.into_value(call.head),
+        };
+
+        let (cols, vals) = match record {
+            Value::Record { cols, vals, .. } => (cols, vals),
+            other => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("load-env expects a record, not {}", other.get_type()),
+                    other.span().unwrap_or(call.head),
+                ))
+            }
+        };
+
+        let names: Vec<String> = cols
+            .into_iter()
+            .map(|name| match &prefix {
+                Some(prefix) => format!("{}{}", prefix, name),
+                None => name,
+            })
+            .collect();
+
+        // PWD has to match the directory of the process, which only cd keeps in step. It's
+        // looked for before anything is set, so that the record is loaded whole or not at all.
+        if let Some(idx) = names.iter().position(|name| name == "PWD") {
+            return Err(ShellError::SpannedLabeledError(
+                "Can't set PWD with load-env".into(),
+                "use cd to change the directory".into(),
+                vals[idx].span().unwrap_or(call.head),
+            ));
+        }
+
+        for (name, value) in names.into_iter().zip(vals) {
+            stack.add_env_var(name, value);
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Load variables from a record",
+                example: r#"load-env {NAME: "nu", EDITOR: "vim"}; $nu.env.NAME"#,
+                result: Some(Value::test_string("nu")),
+            },
+            Example {
+                description: "Load variables from a JSON file, with a prefix",
+                example: r#"open settings.json | load-env --prefix APP_"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(LoadEnv {})
+    }
+}
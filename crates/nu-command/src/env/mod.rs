@@ -1,7 +1,11 @@
 mod env_command;
+mod env_path;
 mod let_env;
+mod load_env;
 mod with_env;
 
 pub use env_command::Env;
+pub use env_path::*;
 pub use let_env::LetEnv;
+pub use load_env::LoadEnv;
 pub use with_env::WithEnv;
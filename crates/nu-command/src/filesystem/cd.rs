@@ -1,7 +1,11 @@
+use std::path::PathBuf;
+
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, PipelineData, Signature, SyntaxShape, Value};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
 
 #[derive(Clone)]
 pub struct Cd;
@@ -15,9 +19,23 @@ impl Command for Cd {
         "Change directory."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"The path is followed the way it is written, so after changing into a symlink to a
+directory, '..' goes back to where the symlink is rather than to the parent of the directory
+it points to. With --physical, symlinks are resolved first, and PWD is the real location.
+
+With 'auto_cd' turned on in the config, a directory name ending in a path separator, like
+'src/', can be typed on its own to change into it."#
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("cd")
             .optional("path", SyntaxShape::Filepath, "the path to change to")
+            .switch(
+                "physical",
+                "resolve symlinks in the path to the directories they point to",
+                Some('P'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -29,22 +47,69 @@ impl Command for Cd {
         _input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let path_val: Option<Value> = call.opt(engine_state, stack, 0)?;
+        let physical = call.has_flag("physical");
 
         let (path, span) = match path_val {
-            Some(v) => {
-                let path = nu_path::expand_path(v.as_string()?);
-                (path.to_string_lossy().to_string(), v.span()?)
-            }
-            None => {
-                let path = nu_path::expand_tilde("~");
-                (path.to_string_lossy().to_string(), call.head)
-            }
+            Some(v) => (v.as_string()?, v.span()?),
+            None => ("~".to_string(), call.head),
         };
-        let _ = std::env::set_current_dir(&path);
 
-        //FIXME: this only changes the current scope, but instead this environment variable
-        //should probably be a block that loads the information from the state in the overlay
-        stack.add_env_var("PWD".into(), Value::String { val: path, span });
+        change_directory(stack, &path, span, physical)?;
+
         Ok(PipelineData::new(call.head))
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Change to the home directory",
+                example: "cd",
+                result: None,
+            },
+            Example {
+                description: "Change to the directory a symlink points to",
+                example: "cd --physical /usr/local/lib/link",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Changes the current directory and PWD, after checking that the path is a directory.
+/// Relative paths are taken from PWD.
+pub(crate) fn change_directory(
+    stack: &mut Stack,
+    path: &str,
+    span: Span,
+    physical: bool,
+) -> Result<(), ShellError> {
+    let cwd = match stack.get_env_var("PWD") {
+        Some(pwd) => PathBuf::from(pwd.as_string()?),
+        None => std::env::current_dir()?,
+    };
+
+    let path = if physical {
+        nu_path::canonicalize_with(path, &cwd).map_err(|_| ShellError::DirectoryNotFound(span))?
+    } else {
+        nu_path::expand_path_with(path, &cwd)
+    };
+
+    if !path.is_dir() {
+        return Err(ShellError::DirectoryNotFound(span));
+    }
+
+    std::env::set_current_dir(&path)
+        .map_err(|err| ShellError::DirectoryNotFoundCustom(err.to_string(), span))?;
+
+    //FIXME: this only changes the current scope, but instead this environment variable
+    //should probably be a block that loads the information from the state in the overlay
+    stack.add_env_var(
+        "PWD".into(),
+        Value::String {
+            val: path.to_string_lossy().to_string(),
+            span,
+        },
+    );
+
+    Ok(())
 }
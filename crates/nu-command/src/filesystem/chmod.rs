@@ -0,0 +1,314 @@
+use std::path::Path;
+
+use super::util::target_paths;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Chmod;
+
+impl Command for Chmod {
+    fn name(&self) -> &str {
+        "chmod"
+    }
+
+    fn usage(&self) -> &str {
+        "Change the permissions of files and directories."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The mode is either octal, like 755, or symbolic, like u+x or go-w,a+r. Symbolic modes
+without u, g, o or a change the bits of everyone, but one that starts with a minus needs them,
+like a-w, so that it isn't taken for a flag. Without paths, the paths in the input are
+changed, which can be strings or the output of ls.
+
+On Windows, only whether a file can be written can be changed, so only the w bits count."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chmod")
+            .required("mode", SyntaxShape::String, "the octal or symbolic mode")
+            .rest(
+                "rest",
+                SyntaxShape::GlobPattern,
+                "the paths to change, instead of the input",
+            )
+            .switch(
+                "recursive",
+                "change everything inside the directories too",
+                Some('r'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let mode: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let mode = Mode::parse(&mode)?;
+        let recursive = call.has_flag("recursive");
+        let span = call.head;
+
+        let paths = target_paths(engine_state, stack, call, input, 1, recursive)?;
+
+        Ok(paths
+            .into_iter()
+            .map(move |path| match change_mode(&path, &mode) {
+                Ok(new_mode) => chmod_row(&path, Some(new_mode), None, span),
+                Err(err) => chmod_row(&path, None, Some(err.to_string()), span),
+            })
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Make a script executable",
+                example: "chmod +x build.sh",
+                result: None,
+            },
+            Example {
+                description: "Let only the owner read and write the files in a directory",
+                example: "chmod -r 600 secrets",
+                result: None,
+            },
+            Example {
+                description: "Take away write access to the files that ls lists",
+                example: "ls *.toml | chmod a-w",
+                result: None,
+            },
+        ]
+    }
+}
+
+// The bits of the mode each of u, g and o are about
+const USER_BITS: u32 = 0o4700;
+const GROUP_BITS: u32 = 0o2070;
+const OTHER_BITS: u32 = 0o1007;
+const ALL_BITS: u32 = 0o7777;
+
+enum Mode {
+    Octal(u32),
+    Symbolic(Vec<Clause>),
+}
+
+// A part of a symbolic mode, like go-w
+struct Clause {
+    who: u32,
+    actions: Vec<(char, String)>,
+}
+
+impl Mode {
+    fn parse(mode: &Spanned<String>) -> Result<Mode, ShellError> {
+        let text = mode.item.as_str();
+
+        if !text.is_empty() && text.len() <= 4 && text.chars().all(|c| ('0'..='7').contains(&c)) {
+            let bits = u32::from_str_radix(text, 8).map_err(|_| invalid_mode(mode.span))?;
+            return Ok(Mode::Octal(bits));
+        }
+
+        text.split(',')
+            .map(|clause| parse_clause(clause).ok_or_else(|| invalid_mode(mode.span)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Mode::Symbolic)
+    }
+
+    fn apply(&self, mode: u32, is_dir: bool) -> u32 {
+        let clauses = match self {
+            Mode::Octal(bits) => return *bits,
+            Mode::Symbolic(clauses) => clauses,
+        };
+
+        let mut mode = mode & ALL_BITS;
+        for clause in clauses {
+            for (op, perms) in &clause.actions {
+                let mut bits = 0;
+                for perm in perms.chars() {
+                    bits |= match perm {
+                        'r' => 0o444,
+                        'w' => 0o222,
+                        'x' => 0o111,
+                        // Execute only for directories and files someone can already execute
+                        'X' if is_dir || mode & 0o111 != 0 => 0o111,
+                        's' => 0o6000,
+                        't' => 0o1000,
+                        _ => 0,
+                    };
+                }
+                bits &= clause.who;
+
+                mode = match op {
+                    '+' => mode | bits,
+                    '-' => mode & !bits,
+                    _ => (mode & !clause.who) | bits,
+                };
+            }
+        }
+
+        mode
+    }
+}
+
+fn parse_clause(clause: &str) -> Option<Clause> {
+    let ops_start = clause.find(|c| matches!(c, '+' | '-' | '='))?;
+    let (who_text, mut rest) = clause.split_at(ops_start);
+
+    let mut who = 0;
+    for c in who_text.chars() {
+        who |= match c {
+            'u' => USER_BITS,
+            'g' => GROUP_BITS,
+            'o' => OTHER_BITS,
+            'a' => ALL_BITS,
+            _ => return None,
+        };
+    }
+    if who == 0 {
+        who = ALL_BITS;
+    }
+
+    let mut actions = vec![];
+    while let Some(op) = rest.chars().next() {
+        if !matches!(op, '+' | '-' | '=') {
+            return None;
+        }
+        rest = &rest[1..];
+
+        let perms_end = rest
+            .find(|c| matches!(c, '+' | '-' | '='))
+            .unwrap_or(rest.len());
+        let (perms, remaining) = rest.split_at(perms_end);
+
+        if !perms.chars().all(|c| "rwxXst".contains(c)) {
+            return None;
+        }
+
+        actions.push((op, perms.to_string()));
+        rest = remaining;
+    }
+
+    Some(Clause { who, actions })
+}
+
+fn invalid_mode(span: Span) -> ShellError {
+    ShellError::SpannedLabeledError(
+        "Invalid mode".into(),
+        "expected an octal mode like 644, or a symbolic one like u+x,go-w".into(),
+        span,
+    )
+}
+
+// Changes the permissions of the path, returning its new mode
+#[cfg(unix)]
+fn change_mode(path: &Path, mode: &Mode) -> std::io::Result<u32> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let metadata = std::fs::metadata(path)?;
+    let new_mode = mode.apply(metadata.permissions().mode(), metadata.is_dir());
+
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(new_mode))?;
+
+    Ok(new_mode)
+}
+
+// Windows only knows whether a file is read-only, which is what the w bits become
+#[cfg(not(unix))]
+fn change_mode(path: &Path, mode: &Mode) -> std::io::Result<u32> {
+    let metadata = std::fs::metadata(path)?;
+    let mut permissions = metadata.permissions();
+
+    let old_mode = if permissions.readonly() { 0o555 } else { 0o777 };
+    let new_mode = mode.apply(old_mode, metadata.is_dir());
+
+    permissions.set_readonly(new_mode & 0o222 == 0);
+    std::fs::set_permissions(path, permissions)?;
+
+    Ok(new_mode)
+}
+
+#[cfg(unix)]
+fn render_mode(mode: u32) -> String {
+    super::open::mode_string(mode)
+}
+
+#[cfg(not(unix))]
+fn render_mode(mode: u32) -> String {
+    if mode & 0o222 == 0 {
+        "readonly"
+    } else {
+        "writable"
+    }
+    .to_string()
+}
+
+fn chmod_row(path: &Path, mode: Option<u32>, error: Option<String>, span: Span) -> Value {
+    let (status, error) = match error {
+        Some(error) => ("error", Value::string(error, span)),
+        None => ("ok", Value::Nothing { span }),
+    };
+
+    Value::Record {
+        cols: vec![
+            "name".into(),
+            "mode".into(),
+            "status".into(),
+            "error".into(),
+        ],
+        vals: vec![
+            Value::string(path.to_string_lossy(), span),
+            match mode {
+                Some(mode) => Value::string(render_mode(mode), span),
+                None => Value::Nothing { span },
+            },
+            Value::string(status, span),
+            error,
+        ],
+        span,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn applied(mode: &str, old_mode: u32, is_dir: bool) -> u32 {
+        let mode = Mode::parse(&Spanned {
+            item: mode.to_string(),
+            span: Span::unknown(),
+        })
+        .expect("valid mode");
+
+        mode.apply(old_mode, is_dir)
+    }
+
+    #[test]
+    fn applies_modes() {
+        assert_eq!(applied("755", 0o644, false), 0o755);
+        assert_eq!(applied("+x", 0o644, false), 0o755);
+        assert_eq!(applied("go-w", 0o666, false), 0o644);
+        assert_eq!(applied("u=rw,go=r", 0o777, false), 0o644);
+        assert_eq!(applied("a+X", 0o644, true), 0o755);
+        assert_eq!(applied("a+X", 0o644, false), 0o644);
+        assert_eq!(applied("u+x-w", 0o644, false), 0o544);
+    }
+
+    #[test]
+    fn rejects_invalid_modes() {
+        for mode in ["", "999", "u+q", "z+x", "rw"] {
+            let parsed = Mode::parse(&Spanned {
+                item: mode.to_string(),
+                span: Span::unknown(),
+            });
+            assert!(parsed.is_err(), "{} should be invalid", mode);
+        }
+    }
+}
@@ -0,0 +1,314 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape};
+
+#[derive(Clone)]
+pub struct Chown;
+
+impl Command for Chown {
+    fn name(&self) -> &str {
+        "chown"
+    }
+
+    fn usage(&self) -> &str {
+        "Change the user and group that own files and directories."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"This command is only available on Unix. The owner is a user, user:group or :group, by
+name or by id. Without paths, the paths in the input are changed, which can be strings or the
+output of ls. Symlinks are followed."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("chown")
+            .required("owner", SyntaxShape::String, "the new user and/or group")
+            .rest(
+                "rest",
+                SyntaxShape::GlobPattern,
+                "the paths to change, instead of the input",
+            )
+            .switch(
+                "recursive",
+                "change everything inside the directories too",
+                Some('r'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        chown(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Give a file to another user",
+                example: "chown alice notes.txt",
+                result: None,
+            },
+            Example {
+                description: "Change the user and group of a directory and everything in it",
+                example: "chown -r www-data:www-data site",
+                result: None,
+            },
+            Example {
+                description: "Change only the group of the files that ls lists",
+                example: "ls *.log | chown :adm",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(unix)]
+fn chown(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    use super::util::target_paths;
+    use nu_engine::CallExt;
+    use nu_protocol::{IntoInterruptiblePipelineData, Spanned};
+
+    let owner: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let (user, group) = unix::parse_owner(&owner)?;
+    let recursive = call.has_flag("recursive");
+    let span = call.head;
+
+    let paths = target_paths(engine_state, stack, call, input, 1, recursive)?;
+
+    Ok(paths
+        .into_iter()
+        .map(move |path| {
+            let error = unix::change_owner(&path, user, group)
+                .err()
+                .map(|err| err.to_string());
+
+            unix::chown_row(&path, error, span)
+        })
+        .into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
+#[cfg(not(unix))]
+fn chown(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    call: &Call,
+    _input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::UnsupportedInput(
+        "chown is only supported on Unix".into(),
+        call.head,
+    ))
+}
+
+#[cfg(unix)]
+mod unix {
+    use std::ffi::{CStr, CString};
+    use std::mem::MaybeUninit;
+    use std::os::unix::ffi::OsStrExt;
+    use std::os::unix::fs::MetadataExt;
+    use std::path::Path;
+
+    use nu_protocol::{ShellError, Span, Spanned, Value};
+
+    /// Splits user:group into the ids to change to, None leaving that one as it is
+    pub(super) fn parse_owner(
+        owner: &Spanned<String>,
+    ) -> Result<(Option<libc::uid_t>, Option<libc::gid_t>), ShellError> {
+        let (user, group) = match owner.item.split_once(':') {
+            Some((user, group)) => (user, Some(group)),
+            None => (owner.item.as_str(), None),
+        };
+
+        let user = match user {
+            "" => None,
+            user => Some(user_id(user).ok_or_else(|| {
+                ShellError::SpannedLabeledError(
+                    "Unknown user".into(),
+                    format!("there is no user called {}", user),
+                    owner.span,
+                )
+            })?),
+        };
+        let group = match group {
+            None | Some("") => None,
+            Some(group) => Some(group_id(group).ok_or_else(|| {
+                ShellError::SpannedLabeledError(
+                    "Unknown group".into(),
+                    format!("there is no group called {}", group),
+                    owner.span,
+                )
+            })?),
+        };
+
+        if user.is_none() && group.is_none() {
+            return Err(ShellError::SpannedLabeledError(
+                "No owner".into(),
+                "expected a user, user:group or :group".into(),
+                owner.span,
+            ));
+        }
+
+        Ok((user, group))
+    }
+
+    // The lookups below use the reentrant functions with a buffer of their own, as paths are
+    // changed from par-each and the plain ones share their entry between threads
+    const LOOKUP_BUFFER: usize = 1024;
+    const MAX_LOOKUP_BUFFER: usize = 1024 * 1024;
+
+    /// Runs a getpwnam_r-style lookup, growing the buffer while it is too small, and reads the
+    /// entry it finds before the buffer is freed
+    fn lookup<T, R>(
+        get: impl Fn(*mut T, *mut libc::c_char, libc::size_t, *mut *mut T) -> libc::c_int,
+        read: impl FnOnce(&T) -> R,
+    ) -> Option<R> {
+        let mut buffer: Vec<libc::c_char> = vec![0; LOOKUP_BUFFER];
+
+        loop {
+            let mut entry = MaybeUninit::<T>::uninit();
+            let mut found = std::ptr::null_mut();
+
+            match get(
+                entry.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                &mut found,
+            ) {
+                libc::ERANGE if buffer.len() < MAX_LOOKUP_BUFFER => {
+                    buffer.resize(buffer.len() * 2, 0)
+                }
+                // Safety: on success, found points at the entry, whose strings are in the buffer
+                0 if !found.is_null() => return Some(read(unsafe { &*found })),
+                _ => return None,
+            }
+        }
+    }
+
+    fn user_id(name: &str) -> Option<libc::uid_t> {
+        if let Ok(id) = name.parse() {
+            return Some(id);
+        }
+
+        let name = CString::new(name).ok()?;
+        lookup(
+            // Safety: getpwnam_r gets a valid C string and the buffer with its length
+            |entry, buffer, len, found| unsafe {
+                libc::getpwnam_r(name.as_ptr(), entry, buffer, len, found)
+            },
+            |entry: &libc::passwd| entry.pw_uid,
+        )
+    }
+
+    fn group_id(name: &str) -> Option<libc::gid_t> {
+        if let Ok(id) = name.parse() {
+            return Some(id);
+        }
+
+        let name = CString::new(name).ok()?;
+        lookup(
+            // Safety: as with getpwnam_r above
+            |entry, buffer, len, found| unsafe {
+                libc::getgrnam_r(name.as_ptr(), entry, buffer, len, found)
+            },
+            |entry: &libc::group| entry.gr_gid,
+        )
+    }
+
+    pub(super) fn change_owner(
+        path: &Path,
+        user: Option<libc::uid_t>,
+        group: Option<libc::gid_t>,
+    ) -> std::io::Result<()> {
+        let path = CString::new(path.as_os_str().as_bytes())?;
+
+        // -1 leaves the user or group as it is
+        let user = user.unwrap_or(libc::uid_t::MAX);
+        let group = group.unwrap_or(libc::gid_t::MAX);
+
+        // Safety: chown gets a valid C string
+        if unsafe { libc::chown(path.as_ptr(), user, group) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        Ok(())
+    }
+
+    fn user_name(uid: libc::uid_t) -> Option<String> {
+        lookup(
+            // Safety: getpwuid_r gets the buffer with its length
+            |entry, buffer, len, found| unsafe { libc::getpwuid_r(uid, entry, buffer, len, found) },
+            // Safety: the name is a C string in the buffer, which is still alive here
+            |entry: &libc::passwd| {
+                unsafe { CStr::from_ptr(entry.pw_name) }
+                    .to_string_lossy()
+                    .to_string()
+            },
+        )
+    }
+
+    fn group_name(gid: libc::gid_t) -> Option<String> {
+        lookup(
+            // Safety: as with getpwuid_r above
+            |entry, buffer, len, found| unsafe { libc::getgrgid_r(gid, entry, buffer, len, found) },
+            // Safety: as with the user name above
+            |entry: &libc::group| {
+                unsafe { CStr::from_ptr(entry.gr_name) }
+                    .to_string_lossy()
+                    .to_string()
+            },
+        )
+    }
+
+    pub(super) fn chown_row(path: &Path, error: Option<String>, span: Span) -> Value {
+        let metadata = std::fs::metadata(path).ok();
+        let name_value = |name: Option<String>| match name {
+            Some(name) => Value::string(name, span),
+            None => Value::Nothing { span },
+        };
+
+        let (status, error) = match error {
+            Some(error) => ("error", Value::string(error, span)),
+            None => ("ok", Value::Nothing { span }),
+        };
+
+        Value::Record {
+            cols: vec![
+                "name".into(),
+                "user".into(),
+                "group".into(),
+                "status".into(),
+                "error".into(),
+            ],
+            vals: vec![
+                Value::string(path.to_string_lossy(), span),
+                name_value(metadata.as_ref().and_then(|m| user_name(m.uid()))),
+                name_value(metadata.as_ref().and_then(|m| group_name(m.gid()))),
+                Value::string(status, span),
+                error,
+            ],
+            span,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Chown {})
+    }
+}
@@ -1,12 +1,15 @@
 use std::env::current_dir;
 use std::path::PathBuf;
 
-use super::util::get_interactive_confirmation;
+use super::util::{get_interactive_confirmation, verbose_row};
 use nu_engine::CallExt;
 use nu_path::canonicalize_with;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, PipelineData, ShellError, Signature, SyntaxShape};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape,
+};
 
 use crate::filesystem::util::FileStructure;
 
@@ -34,6 +37,11 @@ impl Command for Cp {
             )
             .switch("force", "suppress error when no file", Some('f'))
             .switch("interactive", "ask user to confirm action", Some('i'))
+            .switch(
+                "verbose",
+                "return a table with the outcome for each copied file",
+                Some('v'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -48,6 +56,7 @@ impl Command for Cp {
         let destination: String = call.req(engine_state, stack, 1)?;
         let interactive = call.has_flag("interactive");
         let force = call.has_flag("force");
+        let verbose = call.has_flag("verbose");
 
         let path = current_dir()?;
         let source = path.join(source.as_str());
@@ -56,6 +65,9 @@ impl Command for Cp {
         let mut sources =
             glob::glob(&source.to_string_lossy()).map_or_else(|_| Vec::new(), Iterator::collect);
         if sources.is_empty() {
+            if force {
+                return Ok(PipelineData::new(call.head));
+            }
             return Err(ShellError::FileNotFound(call.positional[0].span));
         }
 
@@ -81,7 +93,7 @@ impl Command for Cp {
             let mut remove: Vec<usize> = vec![];
             for (index, file) in sources.iter().enumerate() {
                 let prompt = format!(
-                    "Are you shure that you want to copy {} to {}?",
+                    "Are you sure that you want to copy {} to {}?",
                     file.as_ref()
                         .map_err(|err| ShellError::SpannedLabeledError(
                             "Reference error".into(),
@@ -133,6 +145,8 @@ impl Command for Cp {
             }
         }
 
+        let mut results = vec![];
+
         for entry in sources.into_iter().flatten() {
             let mut sources = FileStructure::new();
             sources.walk_decorate(&entry)?;
@@ -152,7 +166,15 @@ impl Command for Cp {
 
                 for (src, dst) in sources {
                     if src.is_file() {
-                        std::fs::copy(&src, dst).map_err(|e| {
+                        let result = std::fs::copy(&src, &dst);
+
+                        if verbose {
+                            let error = result.err().map(|e| e.to_string());
+                            results.push(verbose_row(Some(&src), Some(&dst), error, call.head));
+                            continue;
+                        }
+
+                        result.map_err(|e| {
                             ShellError::MoveNotPossibleSingle(
                                 format!(
                                     "failed to move containing file \"{}\": {}",
@@ -214,7 +236,15 @@ impl Command for Cp {
                     }
 
                     if src.is_file() {
-                        std::fs::copy(&src, &dst).map_err(|e| {
+                        let result = std::fs::copy(&src, &dst);
+
+                        if verbose {
+                            let error = result.err().map(|e| e.to_string());
+                            results.push(verbose_row(Some(&src), Some(&dst), error, call.head));
+                            continue;
+                        }
+
+                        result.map_err(|e| {
                             ShellError::MoveNotPossibleSingle(
                                 format!(
                                     "failed to move containing file \"{}\": {}",
@@ -229,6 +259,32 @@ impl Command for Cp {
             }
         }
 
-        Ok(PipelineData::new(call.head))
+        if verbose {
+            Ok(results
+                .into_iter()
+                .into_pipeline_data(engine_state.ctrlc.clone()))
+        } else {
+            Ok(PipelineData::new(call.head))
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Copy a file",
+                example: "cp myfile dir_b",
+                result: None,
+            },
+            Example {
+                description: "Copy a directory and everything in it",
+                example: "cp -r dir_a dir_b",
+                result: None,
+            },
+            Example {
+                description: "Copy the files matching a glob, asking before each one",
+                example: "cp -i *.txt dir_b",
+                result: None,
+            },
+        ]
     }
 }
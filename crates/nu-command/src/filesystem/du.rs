@@ -0,0 +1,291 @@
+use std::env::current_dir;
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Du;
+
+struct DuOptions {
+    max_depth: Option<usize>,
+    exclude: Option<glob::Pattern>,
+    ctrlc: Option<Arc<AtomicBool>>,
+}
+
+// The disk usage of a directory, or of a single file when `directories` stays empty
+struct DirInfo {
+    path: PathBuf,
+    apparent: u64,
+    physical: u64,
+    directories: Vec<DirInfo>,
+}
+
+impl Command for Du {
+    fn name(&self) -> &str {
+        "du"
+    }
+
+    fn usage(&self) -> &str {
+        "Find disk usage sizes of specified items."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The apparent size is the number of bytes in the files, while the physical size is the
+space they take on disk. Sizes always include everything below a directory, --max-depth only
+limits how deep the directories are listed."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("du")
+            .optional("path", SyntaxShape::GlobPattern, "starting directory")
+            .named(
+                "max-depth",
+                SyntaxShape::Int,
+                "Directory recursion limit",
+                Some('d'),
+            )
+            .named(
+                "exclude",
+                SyntaxShape::GlobPattern,
+                "Exclude these file names",
+                Some('x'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let max_depth: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "max-depth")?;
+        let exclude: Option<Spanned<String>> = call.get_flag(engine_state, stack, "exclude")?;
+
+        let max_depth = match max_depth {
+            Some(Spanned { item, span }) if item < 0 => {
+                return Err(ShellError::UnsupportedInput(
+                    "max-depth can't be negative".into(),
+                    span,
+                ))
+            }
+            Some(Spanned { item, .. }) => Some(item as usize),
+            None => None,
+        };
+
+        let exclude = match exclude {
+            Some(pattern) => Some(glob::Pattern::new(&pattern.item).map_err(|err| {
+                ShellError::SpannedLabeledError(
+                    "Error extracting glob pattern".into(),
+                    err.to_string(),
+                    pattern.span,
+                )
+            })?),
+            None => None,
+        };
+
+        let options = DuOptions {
+            max_depth,
+            exclude,
+            ctrlc: engine_state.ctrlc.clone(),
+        };
+
+        let cwd = current_dir()?;
+        let path_given = path.is_some();
+        let (pattern, pattern_span) = match path {
+            Some(path) => (cwd.join(path.item), path.span),
+            None => (cwd, call.head),
+        };
+
+        let paths = glob::glob(&pattern.to_string_lossy()).map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Error extracting glob pattern".into(),
+                err.to_string(),
+                pattern_span,
+            )
+        })?;
+
+        let mut output = vec![];
+        for path in paths.flatten() {
+            if is_excluded(&path, &options) {
+                continue;
+            }
+
+            if let Some(info) = dir_info(path, 0, &options) {
+                output.push(info.into_value(call.head));
+            }
+
+            if interrupted(&options) {
+                return Ok(PipelineData::new(call.head));
+            }
+        }
+
+        if output.is_empty() && path_given {
+            return Err(ShellError::FileNotFound(pattern_span));
+        }
+
+        Ok(output
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Disk usage of the current directory",
+                example: "du",
+                result: None,
+            },
+            Example {
+                description: "Disk usage of a directory, listing one level of subdirectories",
+                example: "du ~/src --max-depth 1",
+                result: None,
+            },
+            Example {
+                description: "Disk usage ignoring build artifacts",
+                example: "du --exclude target",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn interrupted(options: &DuOptions) -> bool {
+    match &options.ctrlc {
+        Some(ctrlc) => ctrlc.load(Ordering::SeqCst),
+        None => false,
+    }
+}
+
+fn is_excluded(path: &Path, options: &DuOptions) -> bool {
+    match &options.exclude {
+        Some(pattern) => {
+            pattern.matches_path(path)
+                || path
+                    .file_name()
+                    .map(|name| pattern.matches(&name.to_string_lossy()))
+                    .unwrap_or(false)
+        }
+        None => false,
+    }
+}
+
+#[cfg(unix)]
+fn physical_size(metadata: &Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+
+    // st_blocks is always counted in 512 byte units
+    metadata.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn physical_size(metadata: &Metadata) -> u64 {
+    metadata.len()
+}
+
+/// The number of bytes in a file, or in everything below a directory
+pub(super) fn apparent_size(path: &Path, ctrlc: Option<Arc<AtomicBool>>) -> u64 {
+    let options = DuOptions {
+        max_depth: Some(0),
+        exclude: None,
+        ctrlc,
+    };
+
+    dir_info(path.to_path_buf(), 0, &options)
+        .map(|info| info.apparent)
+        .unwrap_or(0)
+}
+
+// Symlinks are measured themselves rather than followed, so a link cycle can't recurse forever.
+// Entries that can't be read are skipped instead of failing the whole traversal.
+fn dir_info(path: PathBuf, depth: usize, options: &DuOptions) -> Option<DirInfo> {
+    let metadata = std::fs::symlink_metadata(&path).ok()?;
+
+    let mut info = DirInfo {
+        apparent: metadata.len(),
+        physical: physical_size(&metadata),
+        path,
+        directories: vec![],
+    };
+
+    if !metadata.is_dir() {
+        return Some(info);
+    }
+
+    let list_children = match options.max_depth {
+        Some(max_depth) => depth < max_depth,
+        None => true,
+    };
+
+    if let Ok(entries) = std::fs::read_dir(&info.path) {
+        for entry in entries.flatten() {
+            if interrupted(options) {
+                break;
+            }
+
+            let entry_path = entry.path();
+            if is_excluded(&entry_path, options) {
+                continue;
+            }
+
+            if let Some(child) = dir_info(entry_path, depth + 1, options) {
+                info.apparent += child.apparent;
+                info.physical += child.physical;
+
+                let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
+                if is_dir && list_children {
+                    info.directories.push(child);
+                }
+            }
+        }
+    }
+
+    Some(info)
+}
+
+impl DirInfo {
+    fn into_value(self, span: Span) -> Value {
+        Value::Record {
+            cols: vec![
+                "path".into(),
+                "apparent".into(),
+                "physical".into(),
+                "directories".into(),
+            ],
+            vals: vec![
+                Value::String {
+                    val: self.path.to_string_lossy().to_string(),
+                    span,
+                },
+                Value::Filesize {
+                    val: self.apparent as i64,
+                    span,
+                },
+                Value::Filesize {
+                    val: self.physical as i64,
+                    span,
+                },
+                Value::List {
+                    vals: self
+                        .directories
+                        .into_iter()
+                        .map(|dir| dir.into_value(span))
+                        .collect(),
+                    span,
+                },
+            ],
+            span,
+        }
+    }
+}
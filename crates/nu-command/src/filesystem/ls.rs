@@ -1,16 +1,30 @@
+use super::du::apparent_size;
 use chrono::{DateTime, Utc};
 use nu_engine::eval_expression;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, DataSource, IntoInterruptiblePipelineData, PipelineData, PipelineMetadata, Signature,
-    SyntaxShape, Value,
+    Category, DataSource, Example, IntoInterruptiblePipelineData, PipelineData, PipelineMetadata,
+    ShellError, Signature, Span, SyntaxShape, Value,
 };
+use std::fs::Metadata;
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+/// Which columns `dir_entry_dict` fills in
+#[derive(Default)]
+pub(crate) struct EntryOptions {
+    /// Add the permission, ownership and timestamp columns
+    pub long: bool,
+    /// Measure directories by the size of everything below them
+    pub du: bool,
+    pub ctrlc: Option<Arc<AtomicBool>>,
+}
 
 #[derive(Clone)]
 pub struct Ls;
 
-//NOTE: this is not a real implementation :D. It's just a simple one to test with until we port the real one.
 impl Command for Ls {
     fn name(&self) -> &str {
         "ls"
@@ -27,6 +41,32 @@ impl Command for Ls {
                 SyntaxShape::GlobPattern,
                 "the glob pattern to use",
             )
+            .switch(
+                "all",
+                "Show hidden files, whose names start with a dot",
+                Some('a'),
+            )
+            .switch(
+                "long",
+                "Add permission, ownership and timestamp columns",
+                Some('l'),
+            )
+            .switch(
+                "du",
+                "Show the size of everything below a directory as its size",
+                Some('d'),
+            )
+            .switch("icons", "Show nerd font icons next to the file names", None)
+            .switch(
+                "no-color",
+                "Don't color the file names using LS_COLORS",
+                None,
+            )
+            .switch(
+                "ignore-errors",
+                "Skip entries that can't be read, reporting them as warnings",
+                Some('i'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -55,7 +95,20 @@ impl Command for Ls {
         };
 
         let call_span = call.head;
-        let glob = glob::glob(&pattern).map_err(|err| {
+        let icons = call.has_flag("icons");
+        let no_color = call.has_flag("no-color");
+        let ignore_errors = call.has_flag("ignore-errors");
+        let warnings = engine_state.warnings.clone();
+        let options = EntryOptions {
+            long: call.has_flag("long"),
+            du: call.has_flag("du"),
+            ctrlc: engine_state.ctrlc.clone(),
+        };
+        let match_options = glob::MatchOptions {
+            require_literal_leading_dot: !call.has_flag("all"),
+            ..glob::MatchOptions::new()
+        };
+        let glob = glob::glob_with(&pattern, match_options).map_err(|err| {
             nu_protocol::ShellError::SpannedLabeledError(
                 "Error extracting glob pattern".into(),
                 err.to_string(),
@@ -63,73 +116,181 @@ impl Command for Ls {
             )
         })?;
 
-        Ok(glob
-            .into_iter()
-            .map(move |x| match x {
-                Ok(path) => match std::fs::symlink_metadata(&path) {
-                    Ok(metadata) => {
-                        let is_symlink = metadata.file_type().is_symlink();
-                        let is_file = metadata.is_file();
-                        let is_dir = metadata.is_dir();
-                        let filesize = metadata.len();
-                        let mut cols = vec!["name".into(), "type".into(), "size".into()];
-
-                        let mut vals = vec![
-                            Value::String {
-                                val: path.to_string_lossy().to_string(),
-                                span: call_span,
-                            },
-                            if is_symlink {
-                                Value::string("symlink", call_span)
-                            } else if is_file {
-                                Value::string("file", call_span)
-                            } else if is_dir {
-                                Value::string("dir", call_span)
-                            } else {
-                                Value::Nothing { span: call_span }
-                            },
-                            Value::Filesize {
-                                val: filesize as i64,
-                                span: call_span,
-                            },
-                        ];
-
-                        if let Ok(date) = metadata.modified() {
-                            let utc: DateTime<Utc> = date.into();
-
-                            cols.push("modified".into());
-                            vals.push(Value::Date {
-                                val: utc.into(),
-                                span: call_span,
-                            });
-                        }
-
-                        Value::Record {
-                            cols,
-                            vals,
-                            span: call_span,
-                        }
-                    }
-                    Err(_) => Value::Record {
-                        cols: vec!["name".into(), "type".into(), "size".into()],
-                        vals: vec![
-                            Value::String {
-                                val: path.to_string_lossy().to_string(),
-                                span: call_span,
-                            },
-                            Value::Nothing { span: call_span },
-                            Value::Nothing { span: call_span },
-                        ],
-                        span: call_span,
-                    },
-                },
-                _ => Value::Nothing { span: call_span },
-            })
-            .into_pipeline_data_with_metadata(
-                PipelineMetadata {
-                    data_source: DataSource::Ls,
+        let output = glob.into_iter().filter_map(move |x| match x {
+            Ok(path) => Some(dir_entry_dict(&path, &options, call_span)),
+            Err(err) => {
+                let error = ShellError::SpannedLabeledError(
+                    format!("Could not read {}", err.path().to_string_lossy()),
+                    err.error().to_string(),
+                    call_span,
+                );
+
+                if ignore_errors {
+                    warnings.push(error);
+                    None
+                } else {
+                    Some(Value::Error {
+                        error: Box::new(error),
+                    })
+                }
+            }
+        });
+
+        Ok(output.into_pipeline_data_with_metadata(
+            PipelineMetadata {
+                data_source: DataSource::Ls {
+                    colors: !no_color,
+                    icons,
                 },
-                engine_state.ctrlc.clone(),
-            ))
+                content_type: None,
+            },
+            engine_state.ctrlc.clone(),
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the files in the current directory, including hidden ones",
+                example: "ls -a",
+                result: None,
+            },
+            Example {
+                description: "List the Rust files below the current directory",
+                example: "ls **/*.rs",
+                result: None,
+            },
+            Example {
+                description: "Show permissions, owners and timestamps",
+                example: "ls -l",
+                result: None,
+            },
+            Example {
+                description: "Find the biggest directories",
+                example: "ls --du | where type == dir | sort-by size --reverse",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// The row describing a single file in the output of `ls`
+pub(crate) fn dir_entry_dict(path: &Path, options: &EntryOptions, span: Span) -> Value {
+    let name = Value::String {
+        val: path.to_string_lossy().to_string(),
+        span,
+    };
+
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => {
+            return Value::Record {
+                cols: vec!["name".into(), "type".into(), "size".into()],
+                vals: vec![name, Value::Nothing { span }, Value::Nothing { span }],
+                span,
+            }
+        }
+    };
+
+    let file_type = metadata.file_type();
+    let mut cols = vec!["name".into(), "type".into()];
+    let mut vals = vec![
+        name,
+        if file_type.is_symlink() {
+            Value::string("symlink", span)
+        } else if file_type.is_file() {
+            Value::string("file", span)
+        } else if file_type.is_dir() {
+            Value::string("dir", span)
+        } else {
+            Value::Nothing { span }
+        },
+    ];
+
+    if options.long {
+        cols.push("target".into());
+        vals.push(match std::fs::read_link(path) {
+            Ok(target) => Value::String {
+                val: target.to_string_lossy().to_string(),
+                span,
+            },
+            Err(_) => Value::Nothing { span },
+        });
+
+        cols.push("readonly".into());
+        vals.push(Value::Bool {
+            val: metadata.permissions().readonly(),
+            span,
+        });
+
+        unix_columns(&metadata, &mut cols, &mut vals, span);
+    }
+
+    let size = if options.du && file_type.is_dir() {
+        apparent_size(path, options.ctrlc.clone())
+    } else {
+        metadata.len()
+    };
+    cols.push("size".into());
+    vals.push(Value::Filesize {
+        val: size as i64,
+        span,
+    });
+
+    if options.long {
+        cols.push("created".into());
+        vals.push(date_value(metadata.created(), span));
+
+        cols.push("accessed".into());
+        vals.push(date_value(metadata.accessed(), span));
+    }
+
+    if let Ok(date) = metadata.modified() {
+        cols.push("modified".into());
+        vals.push(date_value(Ok(date), span));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+fn date_value(time: std::io::Result<std::time::SystemTime>, span: Span) -> Value {
+    match time {
+        Ok(time) => {
+            let utc: DateTime<Utc> = time.into();
+
+            Value::Date {
+                val: utc.into(),
+                span,
+            }
+        }
+        Err(_) => Value::Nothing { span },
     }
 }
+
+#[cfg(unix)]
+fn unix_columns(metadata: &Metadata, cols: &mut Vec<String>, vals: &mut Vec<Value>, span: Span) {
+    use super::open::mode_string;
+    use std::os::unix::fs::MetadataExt;
+
+    cols.push("mode".into());
+    vals.push(Value::String {
+        val: mode_string(metadata.mode()),
+        span,
+    });
+
+    for (col, val) in [
+        ("num_links", metadata.nlink()),
+        ("inode", metadata.ino()),
+        ("uid", metadata.uid() as u64),
+        ("gid", metadata.gid() as u64),
+    ] {
+        cols.push(col.into());
+        vals.push(Value::Int {
+            val: val as i64,
+            span,
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn unix_columns(_: &Metadata, _: &mut Vec<String>, _: &mut Vec<Value>, _: Span) {}
@@ -1,6 +1,7 @@
 use std::collections::VecDeque;
 use std::env::current_dir;
 
+use super::util::verbose_row;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -25,6 +26,11 @@ impl Command for Mkdir {
                 "the name(s) of the path(s) to create",
             )
             .switch("show-created-paths", "show the path(s) created.", Some('s'))
+            .switch(
+                "verbose",
+                "return a table with the outcome for each path instead of failing on the first error",
+                Some('v'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -47,6 +53,7 @@ impl Command for Mkdir {
             .peekable();
 
         let show_created_paths = call.has_flag("show-created-paths");
+        let verbose = call.has_flag("verbose");
         let mut stream: VecDeque<Value> = VecDeque::new();
 
         if directories.peek().is_none() {
@@ -60,10 +67,16 @@ impl Command for Mkdir {
             let span = call.positional[i].span;
             let dir_res = std::fs::create_dir_all(&dir);
 
+            if verbose {
+                let error = dir_res.err().map(|reason| reason.to_string());
+                stream.push_back(verbose_row(None, Some(&dir), error, span));
+                continue;
+            }
+
             if let Err(reason) = dir_res {
                 return Err(ShellError::CreateNotPossible(
                     format!("failed to create directory: {}", reason),
-                    call.positional[i].span,
+                    span,
                 ));
             }
 
@@ -1,16 +1,30 @@
 mod cd;
+mod chmod;
+mod chown;
 mod cp;
+mod du;
 mod ls;
 mod mkdir;
 mod mv;
+mod open;
 mod rm;
+mod save;
+mod start;
 mod touch;
 mod util;
 
+pub(crate) use cd::change_directory;
 pub use cd::Cd;
+pub use chmod::Chmod;
+pub use chown::Chown;
 pub use cp::Cp;
+pub use du::Du;
 pub use ls::Ls;
 pub use mkdir::Mkdir;
 pub use mv::Mv;
+pub use open::Open;
 pub use rm::Rm;
+pub(crate) use save::write_atomically;
+pub use save::Save;
+pub use start::Start;
 pub use touch::Touch;
@@ -1,11 +1,14 @@
 use std::env::current_dir;
 use std::path::{Path, PathBuf};
 
-use super::util::get_interactive_confirmation;
+use super::util::{get_interactive_confirmation, verbose_row};
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Spanned,
+    SyntaxShape,
+};
 
 #[derive(Clone)]
 pub struct Mv;
@@ -34,6 +37,11 @@ impl Command for Mv {
             )
             .switch("interactive", "ask user to confirm action", Some('i'))
             .switch("force", "suppress error when no file", Some('f'))
+            .switch(
+                "verbose",
+                "return a table with the outcome for each moved path",
+                Some('v'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -49,6 +57,7 @@ impl Command for Mv {
         let destination: String = call.req(engine_state, stack, 1)?;
         let interactive = call.has_flag("interactive");
         let force = call.has_flag("force");
+        let verbose = call.has_flag("verbose");
 
         let path: PathBuf = current_dir()?;
         let source = path.join(spanned_source.item.as_str());
@@ -58,6 +67,9 @@ impl Command for Mv {
             glob::glob(&source.to_string_lossy()).map_or_else(|_| Vec::new(), Iterator::collect);
 
         if sources.is_empty() {
+            if force {
+                return Ok(PipelineData::new(call.head));
+            }
             return Err(ShellError::FileNotFound(spanned_source.span));
         }
 
@@ -65,7 +77,7 @@ impl Command for Mv {
             let mut remove: Vec<usize> = vec![];
             for (index, file) in sources.iter().enumerate() {
                 let prompt = format!(
-                    "Are you shure that you want to move {} to {}?",
+                    "Are you sure that you want to move {} to {}?",
                     file.as_ref()
                         .map_err(|err| ShellError::SpannedLabeledError(
                             "Reference error".into(),
@@ -149,15 +161,56 @@ impl Command for Mv {
                 .collect();
         }
 
+        if verbose {
+            let results: Vec<_> = sources
+                .into_iter()
+                .flatten()
+                .map(|entry| match move_file(call, &entry, &destination) {
+                    Ok(to) => verbose_row(Some(&entry), Some(&to), None, call.head),
+                    Err(error) => verbose_row(
+                        Some(&entry),
+                        Some(&destination),
+                        Some(error.to_string()),
+                        call.head,
+                    ),
+                })
+                .collect();
+
+            return Ok(results
+                .into_iter()
+                .into_pipeline_data(engine_state.ctrlc.clone()));
+        }
+
         for entry in sources.into_iter().flatten() {
-            move_file(call, &entry, &destination)?
+            move_file(call, &entry, &destination)?;
         }
 
         Ok(PipelineData::new(call.head))
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Rename a file",
+                example: "mv before.txt after.txt",
+                result: None,
+            },
+            Example {
+                description: "Move a file into a directory",
+                example: "mv test.txt my/subdirectory",
+                result: None,
+            },
+            Example {
+                description: "Move the files matching a glob into a directory",
+                example: "mv *.txt my/subdirectory",
+                result: None,
+            },
+        ]
+    }
 }
 
-fn move_file(call: &Call, from: &Path, to: &Path) -> Result<(), ShellError> {
+/// Move `from` to `to`, or into it if it's a directory, returning where it ended up
+fn move_file(call: &Call, from: &Path, to: &Path) -> Result<PathBuf, ShellError> {
     if to.exists() && from.is_dir() && to.is_file() {
         return Err(ShellError::MoveNotPossible {
             source_message: "Can't move a directory".to_string(),
@@ -187,16 +240,202 @@ fn move_file(call: &Call, from: &Path, to: &Path) -> Result<(), ShellError> {
         to.push(from_file_name);
     }
 
-    move_item(call, from, &to)
+    move_item(call, from, &to)?;
+
+    Ok(to)
 }
 
 fn move_item(call: &Call, from: &Path, to: &Path) -> Result<(), ShellError> {
-    // We first try a rename, which is a quick operation. If that doesn't work, we'll try a copy
-    // and remove the old file/folder. This is necessary if we're moving across filesystems or devices.
-    std::fs::rename(&from, &to).map_err(|_| ShellError::MoveNotPossible {
-        source_message: "failed to move".to_string(),
+    let move_error = |e: std::io::Error| ShellError::MoveNotPossible {
+        source_message: format!("failed to move: {}", e),
         source_span: call.positional[0].span,
         destination_message: "into".to_string(),
         destination_span: call.positional[1].span,
-    })
+    };
+
+    // We first try a rename, which is a quick operation. Only when the destination is on another
+    // file system or device do we copy and remove the old file/folder instead.
+    match std::fs::rename(&from, &to) {
+        Ok(()) => return Ok(()),
+        Err(e) if !crosses_devices(&e) => return Err(move_error(e)),
+        Err(_) => {}
+    }
+
+    copy_and_remove(from, to).map_err(move_error)
+}
+
+// What a move does when it can't rename: copy the item, then remove the original
+fn copy_and_remove(from: &Path, to: &Path) -> std::io::Result<()> {
+    let existed = to.symlink_metadata().is_ok();
+    if let Err(e) = copy_item(from, to) {
+        // Don't leave a partial copy behind, unless something was already there
+        if !existed {
+            let _ = remove_item(to);
+        }
+        return Err(e);
+    }
+
+    remove_item(from)
+}
+
+// Renaming fails with this error when the destination is on another file system
+#[cfg(unix)]
+fn crosses_devices(e: &std::io::Error) -> bool {
+    e.raw_os_error() == Some(libc::EXDEV)
+}
+
+#[cfg(windows)]
+fn crosses_devices(e: &std::io::Error) -> bool {
+    // ERROR_NOT_SAME_DEVICE
+    e.raw_os_error() == Some(17)
+}
+
+#[cfg(not(any(unix, windows)))]
+fn crosses_devices(_e: &std::io::Error) -> bool {
+    false
+}
+
+// Symlinks are looked at rather than followed, so a link to a folder is removed as a link
+fn remove_item(path: &Path) -> std::io::Result<()> {
+    if path.symlink_metadata()?.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
+// Symlinks are copied as links, which also keeps a link loop from being followed forever
+fn copy_item(from: &Path, to: &Path) -> std::io::Result<()> {
+    let file_type = from.symlink_metadata()?.file_type();
+
+    if file_type.is_symlink() {
+        return copy_symlink(from, to);
+    }
+    if !file_type.is_dir() {
+        return std::fs::copy(from, to).map(|_| ());
+    }
+
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        copy_item(&entry.path(), &to.join(entry.file_name()))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(std::fs::read_link(from)?, to)
+}
+
+#[cfg(windows)]
+fn copy_symlink(from: &Path, to: &Path) -> std::io::Result<()> {
+    let target = std::fs::read_link(from)?;
+    // Windows tells links to folders from links to files, so ask what the link points at
+    if from.is_dir() {
+        std::os::windows::fs::symlink_dir(target, to)
+    } else {
+        std::os::windows::fs::symlink_file(target, to)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn copy_symlink(from: &Path, to: &Path) -> std::io::Result<()> {
+    std::fs::copy(from, to).map(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn copy_and_remove_moves_a_tree() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let from = dir.path().join("from");
+        let to = dir.path().join("to");
+        std::fs::create_dir_all(from.join("inner"))?;
+        std::fs::write(from.join("inner").join("a.txt"), "a")?;
+
+        copy_and_remove(&from, &to)?;
+
+        assert!(!from.exists());
+        assert_eq!(
+            std::fs::read_to_string(to.join("inner").join("a.txt"))?,
+            "a"
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_item_keeps_symlinks_as_links() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let from = dir.path().join("from");
+        let to = dir.path().join("to");
+        std::fs::create_dir(&from)?;
+        std::fs::write(from.join("a.txt"), "a")?;
+        std::os::unix::fs::symlink("a.txt", from.join("link"))?;
+        // A link to its own folder, which would never end if it were followed
+        std::os::unix::fs::symlink(".", from.join("loop"))?;
+
+        copy_item(&from, &to)?;
+
+        assert!(to.join("link").symlink_metadata()?.file_type().is_symlink());
+        assert_eq!(std::fs::read_link(to.join("link"))?, Path::new("a.txt"));
+        assert_eq!(std::fs::read_link(to.join("loop"))?, Path::new("."));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn remove_item_removes_the_link_and_not_its_target() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let target = dir.path().join("target");
+        let link = dir.path().join("link");
+        std::fs::create_dir(&target)?;
+        std::fs::write(target.join("a.txt"), "a")?;
+        std::os::unix::fs::symlink(&target, &link)?;
+
+        remove_item(&link)?;
+
+        assert!(link.symlink_metadata().is_err());
+        assert!(target.join("a.txt").exists());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_and_remove_cleans_up_a_failed_copy() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let from = dir.path().join("from");
+        let to = dir.path().join("to");
+        std::fs::create_dir(&from)?;
+        std::fs::write(from.join("a.txt"), "a")?;
+        // Sockets can't be opened, so copying the folder fails on this one
+        let _socket = std::os::unix::net::UnixListener::bind(from.join("socket"))?;
+
+        assert!(copy_and_remove(&from, &to).is_err());
+
+        assert!(to.symlink_metadata().is_err());
+        assert!(from.join("a.txt").exists());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn copy_and_remove_keeps_what_was_already_there() -> std::io::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let from = dir.path().join("from");
+        let to = dir.path().join("to");
+        std::fs::create_dir(&from)?;
+        let _socket = std::os::unix::net::UnixListener::bind(from.join("socket"))?;
+        std::fs::create_dir(&to)?;
+        std::fs::write(to.join("b.txt"), "b")?;
+
+        assert!(copy_and_remove(&from, &to).is_err());
+
+        assert!(to.join("b.txt").exists());
+        Ok(())
+    }
 }
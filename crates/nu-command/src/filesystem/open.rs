@@ -0,0 +1,358 @@
+use std::env::current_dir;
+use std::fs::Metadata;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, DataSource, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
+    PipelineMetadata, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+
+use super::ls::{dir_entry_dict, EntryOptions};
+
+#[derive(Clone)]
+pub struct Open;
+
+impl Command for Open {
+    fn name(&self) -> &str {
+        "open"
+    }
+
+    fn usage(&self) -> &str {
+        "Opens a file or directory."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Files with an extension that has a matching 'from' command, like 'from csv',
+are parsed into structured data. Other files are loaded as a string, or as binary
+if they aren't valid UTF-8. With --raw, the bytes of the file are loaded as binary
+whatever they hold, which 'into string --decode' turns into text again. Directories
+are listed like 'ls' does.
+
+With --sniff, a file without an extension is recognized by its contents instead: JSON
+and TOML are parsed, UTF-16 text is decoded, and anything else is loaded as text or
+binary. The format that was detected is attached to the output as metadata."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("open")
+            .required("filename", SyntaxShape::Filepath, "the filename to use")
+            .switch(
+                "raw",
+                "open the file as binary, without decoding or parsing it",
+                Some('r'),
+            )
+            .switch(
+                "metadata",
+                "return the metadata of the file or directory instead of its contents",
+                Some('m'),
+            )
+            .switch(
+                "sniff",
+                "detect the format of a file without an extension from its contents",
+                Some('s'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let raw = call.has_flag("raw");
+        let filename: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let head = call.head;
+
+        let path = current_dir()?.join(&filename.item);
+        let metadata =
+            std::fs::metadata(&path).map_err(|_| ShellError::FileNotFound(filename.span))?;
+
+        if call.has_flag("metadata") {
+            return Ok(metadata_dict(&filename.item, &metadata, head).into_pipeline_data());
+        }
+
+        if metadata.is_dir() {
+            return open_dir(engine_state, &filename, head);
+        }
+
+        let bytes = std::fs::read(&path).map_err(|err| {
+            ShellError::SpannedLabeledError(
+                "Could not open file".into(),
+                err.to_string(),
+                filename.span,
+            )
+        })?;
+
+        if raw {
+            return Ok(Value::Binary {
+                val: bytes,
+                span: head,
+            }
+            .into_pipeline_data());
+        }
+
+        let (contents, format) = match path.extension() {
+            None if call.has_flag("sniff") => sniff(bytes, head),
+            ext => (
+                bytes_to_value(bytes, head),
+                ext.map(|ext| ext.to_string_lossy().to_lowercase()),
+            ),
+        };
+
+        let converter = match (&contents, &format) {
+            (Value::String { .. }, Some(format)) => {
+                engine_state.find_decl(format!("from {}", format).as_bytes())
+            }
+            _ => None,
+        };
+
+        let output = match converter {
+            Some(decl_id) => {
+                let mut from_call = Call::new();
+                from_call.decl_id = decl_id;
+                from_call.head = head;
+
+                engine_state.get_decl(decl_id).run(
+                    engine_state,
+                    stack,
+                    &from_call,
+                    contents.into_pipeline_data(),
+                )?
+            }
+            None => contents.into_pipeline_data(),
+        };
+
+        // Only a sniffed format is reported, an extension already says what the file holds
+        Ok(match format {
+            Some(format) if path.extension().is_none() => {
+                output.set_metadata(Some(PipelineMetadata {
+                    data_source: DataSource::Open { format },
+                    content_type: None,
+                }))
+            }
+            _ => output,
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Open a file, with structured data if it has a known extension",
+                example: "open myfile.json",
+                result: None,
+            },
+            Example {
+                description: "Open a file as binary, to hash it or look at its bytes",
+                example: "open myfile.json --raw | hash sha256",
+                result: None,
+            },
+            Example {
+                description: "List the contents of a directory",
+                example: "open src",
+                result: None,
+            },
+            Example {
+                description: "Open a file without an extension, detecting its format",
+                example: "open config --sniff",
+                result: None,
+            },
+            Example {
+                description: "Show the permissions and timestamps of a file",
+                example: "open myfile.json --metadata",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn bytes_to_value(bytes: Vec<u8>, span: Span) -> Value {
+    match String::from_utf8(bytes) {
+        Ok(val) => Value::String { val, span },
+        Err(err) => Value::Binary {
+            val: err.into_bytes(),
+            span,
+        },
+    }
+}
+
+// Guesses the format of a file from its contents, returning them decoded as far as the
+// format allows together with the name of the format
+fn sniff(bytes: Vec<u8>, span: Span) -> (Value, Option<String>) {
+    let utf16 = match bytes.get(..2) {
+        Some([0xff, 0xfe]) => Some(u16::from_le_bytes as fn([u8; 2]) -> u16),
+        Some([0xfe, 0xff]) => Some(u16::from_be_bytes as fn([u8; 2]) -> u16),
+        _ => None,
+    };
+
+    if let Some(decode) = utf16 {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|pair| decode([pair[0], pair[1]]))
+            .collect();
+
+        if let Ok(val) = String::from_utf16(&units) {
+            return (Value::String { val, span }, Some("utf-16".into()));
+        }
+    }
+
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(err) => {
+            return (
+                Value::Binary {
+                    val: err.into_bytes(),
+                    span,
+                },
+                Some("binary".into()),
+            )
+        }
+    };
+
+    let trimmed = text.trim_start();
+    let format = if (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && nu_json::from_str::<nu_json::Value>(&text).is_ok()
+    {
+        "json"
+    } else if !trimmed.is_empty() && toml::from_str::<toml::Value>(&text).is_ok() {
+        "toml"
+    } else {
+        "text"
+    };
+
+    (Value::String { val: text, span }, Some(format.into()))
+}
+
+fn open_dir(
+    engine_state: &EngineState,
+    dirname: &Spanned<String>,
+    head: Span,
+) -> Result<PipelineData, ShellError> {
+    let entries = std::fs::read_dir(current_dir()?.join(&dirname.item)).map_err(|err| {
+        ShellError::SpannedLabeledError(
+            "Could not open directory".into(),
+            err.to_string(),
+            dirname.span,
+        )
+    })?;
+
+    // Entries are named relative to the given directory, the same way `ls <dir>` names them
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|entry| Path::new(&dirname.item).join(entry.file_name()))
+        .collect();
+    paths.sort();
+
+    let options = EntryOptions::default();
+
+    Ok(paths
+        .into_iter()
+        .map(move |path| dir_entry_dict(&path, &options, head))
+        .into_pipeline_data_with_metadata(
+            PipelineMetadata {
+                data_source: DataSource::Ls {
+                    colors: true,
+                    icons: false,
+                },
+                content_type: None,
+            },
+            engine_state.ctrlc.clone(),
+        ))
+}
+
+fn metadata_dict(name: &str, metadata: &Metadata, span: Span) -> Value {
+    let mut cols = vec![
+        "name".to_string(),
+        "type".to_string(),
+        "size".to_string(),
+        "readonly".to_string(),
+    ];
+    let mut vals = vec![
+        Value::String {
+            val: name.to_string(),
+            span,
+        },
+        Value::string(if metadata.is_dir() { "dir" } else { "file" }, span),
+        Value::Filesize {
+            val: metadata.len() as i64,
+            span,
+        },
+        Value::Bool {
+            val: metadata.permissions().readonly(),
+            span,
+        },
+    ];
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        cols.push("permissions".to_string());
+        vals.push(Value::String {
+            val: mode_string(metadata.permissions().mode()),
+            span,
+        });
+    }
+
+    let timestamps = [
+        ("created", metadata.created()),
+        ("accessed", metadata.accessed()),
+        ("modified", metadata.modified()),
+    ];
+
+    for (col, time) in timestamps {
+        cols.push(col.to_string());
+        vals.push(match time {
+            Ok(time) => {
+                let utc: DateTime<Utc> = time.into();
+                Value::Date {
+                    val: utc.into(),
+                    span,
+                }
+            }
+            Err(_) => Value::Nothing { span },
+        });
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+// Renders unix permission bits the way `ls -l` does, eg: rwxr-xr-x
+#[cfg(unix)]
+pub(super) fn mode_string(mode: u32) -> String {
+    let flags = ['r', 'w', 'x'];
+
+    (0..9)
+        .map(|bit| {
+            if mode & (0o400 >> bit) != 0 {
+                flags[bit % 3]
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sniffed(bytes: &[u8]) -> String {
+        sniff(bytes.to_vec(), Span::unknown()).1.unwrap_or_default()
+    }
+
+    #[test]
+    fn sniffs_formats() {
+        assert_eq!(sniffed(b"{\"a\": 1}"), "json");
+        assert_eq!(sniffed(b"[package]\nname = \"x\"\n"), "toml");
+        assert_eq!(sniffed(b"hello\n"), "text");
+        assert_eq!(sniffed(b""), "text");
+        assert_eq!(sniffed(&[0xff, 0xfe, b'h', 0, b'i', 0]), "utf-16");
+        assert_eq!(sniffed(&[0, 0xff, 1]), "binary");
+    }
+}
@@ -3,14 +3,14 @@ use std::env::current_dir;
 use std::os::unix::prelude::FileTypeExt;
 use std::path::PathBuf;
 
-use super::util::get_interactive_confirmation;
+use super::util::{get_interactive_confirmation, verbose_row};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, SyntaxShape,
-    Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -25,6 +25,7 @@ struct RmArgs {
     trash: bool,
     permanent: bool,
     force: bool,
+    verbose: bool,
 }
 
 impl Command for Rm {
@@ -51,6 +52,11 @@ impl Command for Rm {
             .switch("recursive", "delete subdirectories recursively", Some('r'))
             .switch("force", "suppress error when no file", Some('f'))
             .switch("interactive", "ask user to confirm action", Some('i'))
+            .switch(
+                "verbose",
+                "return a table with the outcome for each removed path",
+                Some('v'),
+            )
             .rest(
                 "rest",
                 SyntaxShape::GlobPattern,
@@ -68,6 +74,31 @@ impl Command for Rm {
     ) -> Result<PipelineData, ShellError> {
         rm(engine_state, stack, call)
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Delete a file",
+                example: "rm file.txt",
+                result: None,
+            },
+            Example {
+                description: "Delete a directory and everything in it",
+                example: "rm -r dir",
+                result: None,
+            },
+            Example {
+                description: "Move the files matching a glob to the recycle bin",
+                example: "rm --trash *.log",
+                result: None,
+            },
+            Example {
+                description: "Delete a file that may not exist, without failing",
+                example: "rm -f maybe.txt",
+                result: None,
+            },
+        ]
+    }
 }
 
 fn rm(
@@ -94,7 +125,10 @@ fn rm(
         .peekable();
 
     if paths.peek().is_none() {
-        return Err(ShellError::FileNotFound(call.positional[0].span));
+        return Err(ShellError::MissingParameter(
+            "the file path(s) to remove".into(),
+            call.head,
+        ));
     }
 
     // Expand and flatten files
@@ -105,25 +139,26 @@ fn rm(
         )
     };
 
+    let recursive = call.has_flag("recursive");
+    let force = call.has_flag("force");
+    let verbose = call.has_flag("verbose");
+
     let mut targets: Vec<Target> = vec![];
     for (i, path) in paths.enumerate() {
         let mut paths: Vec<Target> = resolve_path(i, path);
 
-        if paths.is_empty() {
+        if paths.is_empty() && !force {
             return Err(ShellError::FileNotFound(call.positional[i].span));
         }
 
         targets.append(paths.as_mut());
     }
 
-    let recursive = call.has_flag("recursive");
-    let force = call.has_flag("force");
-
     if interactive && !force {
         let mut remove: Vec<usize> = vec![];
         for (index, file) in targets.iter().enumerate() {
             let prompt: String = format!(
-                "Are you sure that you what to delete {}?",
+                "Are you sure that you want to delete {}?",
                 file.1
                     .file_name()
                     .ok_or_else(|| ShellError::SpannedLabeledError(
@@ -163,6 +198,7 @@ fn rm(
         trash,
         permanent,
         force,
+        verbose,
     };
     let response = rm_helper(call, args);
 
@@ -176,12 +212,13 @@ fn rm(
 }
 
 fn rm_helper(call: &Call, args: RmArgs) -> Vec<Value> {
-    let (targets, recursive, trash, _permanent, force) = (
+    let (targets, recursive, trash, _permanent, force, verbose) = (
         args.targets,
         args.recursive,
         args.trash,
         args.permanent,
         args.force,
+        args.verbose,
     );
 
     #[cfg(not(feature = "trash-support"))]
@@ -198,13 +235,15 @@ fn rm_helper(call: &Call, args: RmArgs) -> Vec<Value> {
                 Err(err) => err,
             };
 
-            return vec![Value::Error { error }];
+            return vec![Value::Error {
+                error: Box::new(error),
+            }];
         }
     }
 
     if targets.is_empty() && !force {
         return vec![Value::Error {
-            error: ShellError::FileNotFound(call.head),
+            error: Box::new(ShellError::FileNotFound(call.head)),
         }];
     }
 
@@ -212,13 +251,14 @@ fn rm_helper(call: &Call, args: RmArgs) -> Vec<Value> {
         .into_iter()
         .map(move |target| {
             let (i, f) = (target.0, target.1);
+            let span = call.positional[i].span;
 
             let is_empty = || match f.read_dir() {
                 Ok(mut p) => p.next().is_none(),
                 Err(_) => false,
             };
 
-            if let Ok(metadata) = f.symlink_metadata() {
+            let result = if let Ok(metadata) = f.symlink_metadata() {
                 #[cfg(unix)]
                 let is_socket = metadata.file_type().is_socket();
                 #[cfg(unix)]
@@ -236,58 +276,49 @@ fn rm_helper(call: &Call, args: RmArgs) -> Vec<Value> {
                     || is_fifo
                     || is_empty()
                 {
-                    let result;
-                    #[cfg(feature = "trash-support")]
-                    {
-                        use std::io::Error;
-                        result = if trash {
-                            trash::delete(&f).map_err(|e: trash::Error| {
-                                use std::io::ErrorKind;
-                                Error::new(ErrorKind::Other, format!("{:?}", e))
-                            })
-                        } else if metadata.is_file() {
-                            std::fs::remove_file(&f)
-                        } else {
-                            std::fs::remove_dir_all(&f)
-                        };
-                    }
-                    #[cfg(not(feature = "trash-support"))]
-                    {
-                        result = if metadata.is_file() || is_socket || is_fifo {
+                    let remove = || {
+                        if metadata.is_file() || is_socket || is_fifo {
                             std::fs::remove_file(&f)
                         } else {
                             std::fs::remove_dir_all(&f)
-                        };
-                    }
-
-                    if let Err(e) = result {
-                        Value::Error {
-                            error: ShellError::RemoveNotPossible(
-                                format!("Could not delete because: {:}\nTry '--trash' flag", e),
-                                call.head,
-                            ),
                         }
+                    };
+
+                    #[cfg(feature = "trash-support")]
+                    let result = if trash {
+                        trash::delete(&f).map_err(|e: trash::Error| {
+                            use std::io::{Error, ErrorKind};
+                            Error::new(ErrorKind::Other, format!("{:?}", e))
+                        })
                     } else {
-                        Value::String {
-                            val: format!("deleted {:}", f.to_string_lossy()),
-                            span: call.positional[i].span,
-                        }
-                    }
+                        remove()
+                    };
+                    #[cfg(not(feature = "trash-support"))]
+                    let result = remove();
+
+                    result.map_err(|e| {
+                        (
+                            format!("Could not delete because: {:}\nTry '--trash' flag", e),
+                            call.head,
+                        )
+                    })
                 } else {
-                    Value::Error {
-                        error: ShellError::RemoveNotPossible(
-                            "Cannot remove. try --recursive".to_string(),
-                            call.positional[i].span,
-                        ),
-                    }
+                    Err(("Cannot remove. try --recursive".to_string(), span))
                 }
             } else {
-                Value::Error {
-                    error: ShellError::RemoveNotPossible(
-                        "no such file or directory".to_string(),
-                        call.positional[i].span,
-                    ),
-                }
+                Err(("no such file or directory".to_string(), span))
+            };
+
+            match result {
+                Ok(()) if verbose => verbose_row(Some(&f), None, None, span),
+                Ok(()) => Value::String {
+                    val: format!("deleted {:}", f.to_string_lossy()),
+                    span,
+                },
+                Err((message, _)) if verbose => verbose_row(Some(&f), None, Some(message), span),
+                Err((message, span)) => Value::Error {
+                    error: Box::new(ShellError::RemoveNotPossible(message, span)),
+                },
             }
         })
         .collect()
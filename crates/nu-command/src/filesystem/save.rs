@@ -0,0 +1,327 @@
+use std::env::current_dir;
+use std::fs::{File, Metadata, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use crate::formats::format_of_content_type;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Save;
+
+impl Command for Save {
+    fn name(&self) -> &str {
+        "save"
+    }
+
+    fn usage(&self) -> &str {
+        "Save the input to a file."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Files with an extension that has a matching 'to' command, like 'to csv', are
+written with that command, the same way 'open' reads them. Otherwise the input is
+saved as is, which requires it to be text or binary data rather than a table.
+
+The content type of the input, set by 'fetch' or 'metadata set', is taken into account:
+text that is already in the format of the file is saved as is, and a table is written in
+the format of its content type when the extension has no 'to' command.
+
+The input is first written to a temporary file next to the target, which then takes the
+place of the target. If the pipeline fails halfway, the file keeps its previous contents
+instead of being left truncated."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("save")
+            .required("filename", SyntaxShape::Filepath, "the filename to use")
+            .switch(
+                "raw",
+                "save the input as is, whatever the file extension",
+                Some('r'),
+            )
+            .switch(
+                "append",
+                "add to the end of the file instead of replacing it",
+                Some('a'),
+            )
+            .switch(
+                "force",
+                "overwrite the file even if it is read-only",
+                Some('f'),
+            )
+            .named(
+                "backup",
+                SyntaxShape::String,
+                "keep the previous version of the file, with this suffix added to its name",
+                Some('b'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let raw = call.has_flag("raw");
+        let append = call.has_flag("append");
+        let force = call.has_flag("force");
+        let backup: Option<Spanned<String>> = call.get_flag(engine_state, stack, "backup")?;
+        let filename: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let head = call.head;
+
+        let path = current_dir()?.join(&filename.item);
+
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase());
+        let content_format = input
+            .metadata()
+            .and_then(|metadata| metadata.content_type.as_deref())
+            .and_then(format_of_content_type);
+        let structured = matches!(
+            input,
+            PipelineData::Value(Value::Record { .. } | Value::List { .. }, ..)
+        );
+        let find_converter =
+            |format: &str| engine_state.find_decl(format!("to {}", format).as_bytes());
+
+        let converter = match extension.as_deref() {
+            _ if raw => None,
+            // Text that is already in the format of the file doesn't need converting again
+            Some(ext) if !structured && content_format == Some(ext) => None,
+            Some(ext) => find_converter(ext),
+            None => None,
+        };
+        let converter = match converter {
+            None if structured && !raw => content_format.and_then(find_converter),
+            converter => converter,
+        };
+
+        let output = match converter {
+            Some(decl_id) => {
+                let mut to_call = Call::new();
+                to_call.decl_id = decl_id;
+                to_call.head = head;
+
+                engine_state
+                    .get_decl(decl_id)
+                    .run(engine_state, stack, &to_call, input)?
+            }
+            None => input,
+        };
+
+        let config = stack.get_config().unwrap_or_default();
+
+        // Streams, like the chunks of a download, are written as they arrive, and single values
+        // are checked before the file is touched
+        let chunks: Box<dyn Iterator<Item = Result<Vec<u8>, ShellError>>> = match output {
+            PipelineData::Stream(stream, ..) => {
+                Box::new(stream.map(move |value| value_to_bytes(value, head, &config)))
+            }
+            PipelineData::Value(value, ..) => {
+                Box::new(std::iter::once(Ok(value_to_bytes(value, head, &config)?)))
+            }
+        };
+
+        // Write through a symlink to the file it points to, rather than replacing the link
+        let path = match std::fs::symlink_metadata(&path) {
+            Ok(metadata) if metadata.file_type().is_symlink() => {
+                std::fs::canonicalize(&path).unwrap_or(path)
+            }
+            _ => path,
+        };
+
+        let existing = std::fs::metadata(&path).ok();
+
+        if let Some(metadata) = &existing {
+            if metadata.permissions().readonly() && !force {
+                return Err(ShellError::SpannedLabeledError(
+                    "Could not save file".into(),
+                    "the file is read-only, use --force to overwrite it".into(),
+                    filename.span,
+                ));
+            }
+
+            if let Some(suffix) = &backup {
+                let mut backup_path = path.clone().into_os_string();
+                backup_path.push(&suffix.item);
+
+                std::fs::copy(&path, &backup_path).map_err(|err| save_error(err, filename.span))?;
+            }
+        }
+
+        if append {
+            if let Some(metadata) = &existing {
+                make_writable(&path, metadata).map_err(|err| save_error(err, filename.span))?;
+            }
+
+            let result = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .map_err(|err| save_error(err, filename.span))
+                .and_then(|mut file| write_chunks(&mut file, chunks, filename.span));
+
+            if let Some(metadata) = &existing {
+                let _ = std::fs::set_permissions(&path, metadata.permissions());
+            }
+
+            result?;
+        } else {
+            write_atomically(&path, existing, chunks, filename.span)?;
+        }
+
+        Ok(PipelineData::new(head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Save a table as JSON, picking the format from the extension",
+                example: "ls | save files.json",
+                result: None,
+            },
+            Example {
+                description: "Save a string as is, even though it has a known extension",
+                example: "'a,b' | save --raw data.csv",
+                result: None,
+            },
+            Example {
+                description: "Download a file, writing it as it arrives",
+                example: "fetch https://example.com/image.png | save image.png",
+                result: None,
+            },
+            Example {
+                description: "Save a table as CSV, following the content type it was tagged with",
+                example: "[[a b]; [1 2]] | metadata set --content-type text/csv | save data",
+                result: None,
+            },
+            Example {
+                description: "Add a line to the end of a log file",
+                example: r#""finished\n" | save --append log.txt"#,
+                result: None,
+            },
+            Example {
+                description: "Save a file, keeping its previous version as config.toml.bak",
+                example: "open config.toml | upsert theme dark | save --backup .bak config.toml",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn save_error(err: std::io::Error, span: Span) -> ShellError {
+    ShellError::SpannedLabeledError("Could not save file".into(), err.to_string(), span)
+}
+
+fn write_chunks(
+    file: &mut File,
+    mut chunks: impl Iterator<Item = Result<Vec<u8>, ShellError>>,
+    span: Span,
+) -> Result<(), ShellError> {
+    chunks.try_for_each(|bytes| file.write_all(&bytes?).map_err(|err| save_error(err, span)))
+}
+
+/// Writes the chunks to a temporary file in the same directory, so that it is on the same file
+/// system, and renames it over the target once everything is written
+pub(crate) fn write_atomically(
+    path: &Path,
+    existing: Option<Metadata>,
+    chunks: impl Iterator<Item = Result<Vec<u8>, ShellError>>,
+    span: Span,
+) -> Result<(), ShellError> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = File::create(&temp_path)
+        .map_err(|err| save_error(err, span))
+        .and_then(|mut file| {
+            write_chunks(&mut file, chunks, span)?;
+            file.sync_all().map_err(|err| save_error(err, span))?;
+
+            if let Some(metadata) = &existing {
+                // Only Windows refuses to rename over a read-only file
+                if cfg!(windows) {
+                    make_writable(path, metadata).map_err(|err| save_error(err, span))?;
+                }
+                std::fs::set_permissions(&temp_path, metadata.permissions())
+                    .map_err(|err| save_error(err, span))?;
+            }
+
+            std::fs::rename(&temp_path, path).map_err(|err| save_error(err, span))
+        });
+
+    if let (Err(_), Some(metadata)) = (&result, &existing) {
+        let _ = std::fs::set_permissions(path, metadata.permissions());
+    }
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+// A read-only file can't be opened for writing, nor replaced on Windows. On Unix only the owner
+// is given write permission, as set_readonly(false) would let everyone write to the file.
+fn make_writable(path: &Path, metadata: &Metadata) -> std::io::Result<()> {
+    let mut permissions = metadata.permissions();
+
+    if permissions.readonly() {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            permissions.set_mode(permissions.mode() | 0o200);
+        }
+        #[cfg(not(unix))]
+        permissions.set_readonly(false);
+
+        std::fs::set_permissions(path, permissions)?;
+    }
+
+    Ok(())
+}
+
+fn value_to_bytes(value: Value, head: Span, config: &Config) -> Result<Vec<u8>, ShellError> {
+    match value {
+        Value::String { val, .. } => Ok(val.into_bytes()),
+        Value::Binary { val, .. } => Ok(val),
+        Value::Nothing { .. } => Ok(vec![]),
+        Value::Error { error } => Err(*error),
+        Value::Record { .. } | Value::List { .. } | Value::Block { .. } => {
+            Err(ShellError::UnsupportedInput(
+                format!(
+                    "can't save {} as is, convert it to text first",
+                    value.get_type()
+                ),
+                head,
+            ))
+        }
+        other => Ok(other.into_string("", config).into_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Save {})
+    }
+}
@@ -0,0 +1,172 @@
+use std::env::current_dir;
+use std::process::{Command as CommandSys, Stdio};
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+
+#[derive(Clone)]
+pub struct Start;
+
+impl Command for Start {
+    fn name(&self) -> &str {
+        "start"
+    }
+
+    fn usage(&self) -> &str {
+        "Open a file or URL with the default application for it."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without a path, the paths or URLs in the input are opened, one by one. The application
+runs on its own, so the pipeline doesn't wait for it to be closed.
+
+The default application is the one xdg-open picks on Linux and the BSDs, 'open' on macOS
+and the file association on Windows."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("start")
+            .optional("path", SyntaxShape::String, "the file or URL to open")
+            .named(
+                "application",
+                SyntaxShape::String,
+                "the application to open it with, instead of the default one",
+                Some('a'),
+            )
+            .category(Category::FileSystem)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let application: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "application")?;
+
+        let targets = match path {
+            Some(path) => vec![path],
+            None => input
+                .into_iter()
+                .map(|value| {
+                    let span = value.span()?;
+                    Ok(Spanned {
+                        item: value.as_string()?,
+                        span,
+                    })
+                })
+                .collect::<Result<Vec<_>, ShellError>>()?,
+        };
+
+        if targets.is_empty() {
+            return Err(ShellError::MissingParameter("path".into(), call.head));
+        }
+
+        for target in targets {
+            let target = resolve_target(target)?;
+            open_with(&target, application.as_ref())?;
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Open a file with the default application",
+                example: "start report.pdf",
+                result: None,
+            },
+            Example {
+                description: "Open a URL in the default browser",
+                example: "start https://www.nushell.sh",
+                result: None,
+            },
+            Example {
+                description: "Open the largest image in the directory with a given application",
+                example: "ls *.png | sort-by size | last | get name | start --application gimp",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn is_url(target: &str) -> bool {
+    target.contains("://") || target.starts_with("mailto:")
+}
+
+// Paths are checked here, as the default application may fail without saying why
+fn resolve_target(target: Spanned<String>) -> Result<Spanned<String>, ShellError> {
+    if is_url(&target.item) {
+        return Ok(target);
+    }
+
+    let path = current_dir()?.join(nu_path::expand_path(&target.item));
+
+    if !path.exists() {
+        return Err(ShellError::FileNotFound(target.span));
+    }
+
+    Ok(Spanned {
+        item: path.to_string_lossy().to_string(),
+        span: target.span,
+    })
+}
+
+fn open_with(
+    target: &Spanned<String>,
+    application: Option<&Spanned<String>>,
+) -> Result<(), ShellError> {
+    // A launcher hands the target over and exits, so it is waited for to report its failures.
+    // An application given with --application keeps running until it is closed.
+    let (mut cmd, launcher) = match application {
+        Some(application) if cfg!(target_os = "macos") => {
+            let mut cmd = CommandSys::new("open");
+            cmd.arg("-a").arg(&application.item);
+            (cmd, true)
+        }
+        Some(application) => (CommandSys::new(&application.item), false),
+        // Unlike 'cmd /c start', this doesn't read the target as a command line, so characters
+        // like '&' in a URL can't start other commands
+        None if cfg!(windows) => {
+            let mut cmd = CommandSys::new("rundll32");
+            cmd.arg("url.dll,FileProtocolHandler");
+            (cmd, true)
+        }
+        None if cfg!(target_os = "macos") => (CommandSys::new("open"), true),
+        None => (CommandSys::new("xdg-open"), true),
+    };
+
+    cmd.arg(&target.item)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    let span = application.map_or(target.span, |application| application.span);
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|err| ShellError::ExternalCommand(err.to_string(), span))?;
+
+    if launcher {
+        let status = child
+            .wait()
+            .map_err(|err| ShellError::ExternalCommand(err.to_string(), span))?;
+        if !status.success() {
+            return Err(ShellError::ExternalCommand(
+                format!("could not open {}", target.item),
+                target.span,
+            ));
+        }
+    } else {
+        // Reap the application when it exits, so it isn't left behind as a zombie
+        std::thread::spawn(move || child.wait());
+    }
+
+    Ok(())
+}
@@ -1,7 +1,10 @@
 use std::path::{Path, PathBuf};
 
+use nu_engine::CallExt;
 use nu_path::canonicalize_with;
-use nu_protocol::ShellError;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{PipelineData, ShellError, Span, Spanned, Value};
 
 use dialoguer::Input;
 use std::error::Error;
@@ -106,3 +109,103 @@ pub fn get_interactive_confirmation(prompt: String) -> Result<bool, Box<dyn Erro
         Ok(false)
     }
 }
+
+/// A row of the table that `cp`, `mv`, `rm` and `mkdir` return with `--verbose`, describing
+/// what happened to a single path
+pub fn verbose_row(
+    source: Option<&Path>,
+    destination: Option<&Path>,
+    error: Option<String>,
+    span: Span,
+) -> Value {
+    let path_value = |path: Option<&Path>| match path {
+        Some(path) => Value::string(path.to_string_lossy(), span),
+        None => Value::Nothing { span },
+    };
+
+    let (status, error) = match error {
+        Some(error) => ("error", Value::string(error, span)),
+        None => ("ok", Value::Nothing { span }),
+    };
+
+    Value::Record {
+        cols: vec![
+            "source".into(),
+            "destination".into(),
+            "status".into(),
+            "error".into(),
+        ],
+        vals: vec![
+            path_value(source),
+            path_value(destination),
+            Value::string(status, span),
+            error,
+        ],
+        span,
+    }
+}
+
+/// The paths that `chmod` and `chown` change: the glob patterns given from the positional at
+/// `index` on, or else the paths in the input, either as strings or in the `name` column that
+/// `ls` gives. With `recursive`, everything inside a directory follows it.
+pub fn target_paths(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+    index: usize,
+    recursive: bool,
+) -> Result<Vec<PathBuf>, ShellError> {
+    let current_path = std::env::current_dir()?;
+    let patterns: Vec<Spanned<String>> = call.rest(engine_state, stack, index)?;
+
+    let mut paths = vec![];
+    if patterns.is_empty() {
+        for value in input {
+            let path = match &value {
+                Value::Record { .. } => {
+                    let span = value.span().unwrap_or(call.head);
+                    value
+                        .get_data_by_key("name")
+                        .ok_or(ShellError::CantFindColumn(call.head, span))?
+                }
+                _ => value,
+            };
+
+            paths.push(current_path.join(path.as_string()?));
+        }
+    } else {
+        for pattern in patterns {
+            let pattern_path = current_path.join(&pattern.item);
+            let matches = glob::glob(&pattern_path.to_string_lossy())
+                .map(|matches| matches.flatten().collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            if matches.is_empty() {
+                return Err(ShellError::FileNotFound(pattern.span));
+            }
+            paths.extend(matches);
+        }
+    }
+
+    if paths.is_empty() {
+        return Err(ShellError::MissingParameter("path".into(), call.head));
+    }
+
+    if recursive {
+        let mut all_paths = vec![];
+        for path in paths {
+            let is_dir = path.is_dir();
+            all_paths.push(path.clone());
+
+            if is_dir {
+                let mut structure = FileStructure::new();
+                structure.walk_decorate(&path)?;
+                all_paths.extend(structure.resources.into_iter().map(|r| r.location));
+            }
+        }
+        paths = all_paths;
+    }
+
+    Ok(paths)
+}
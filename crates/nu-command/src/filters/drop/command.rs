@@ -1,10 +1,12 @@
+use std::collections::VecDeque;
+
 use nu_engine::CallExt;
 
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
-    SyntaxShape, Value,
+    Spanned, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -70,28 +72,34 @@ impl Command for Drop {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let rows: Option<i64> = call.opt(engine_state, stack, 0)?;
-        let v: Vec<_> = input.into_iter().collect();
-        let vlen: i64 = v.len() as i64;
+        let rows: Option<Spanned<i64>> = call.opt(engine_state, stack, 0)?;
 
-        let rows_to_drop = if let Some(quantity) = rows {
-            quantity
-        } else {
-            1
+        let rows_to_drop = match rows {
+            Some(Spanned { item, span }) if item < 0 => {
+                return Err(ShellError::UnsupportedInput(
+                    "can't drop a negative number of rows".into(),
+                    span,
+                ))
+            }
+            Some(Spanned { item, .. }) => item as usize,
+            None => 1,
         };
 
-        if rows_to_drop == 0 {
-            Ok(v.into_iter().into_pipeline_data(engine_state.ctrlc.clone()))
-        } else {
-            let k = if vlen < rows_to_drop {
-                0
-            } else {
-                vlen - rows_to_drop
-            };
+        // Rows are held back until enough rows follow them to know they aren't among the last
+        let mut input = input.into_iter();
+        let mut held_back = VecDeque::with_capacity(rows_to_drop.min(1024) + 1);
+        let iter = std::iter::from_fn(move || {
+            for value in input.by_ref() {
+                held_back.push_back(value);
+                if held_back.len() > rows_to_drop {
+                    return held_back.pop_front();
+                }
+            }
+
+            None
+        });
 
-            let iter = v.into_iter().take(k as usize);
-            Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
-        }
+        Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
     }
 }
 
@@ -1,5 +1,5 @@
 use nu_engine::CallExt;
-use nu_protocol::ast::Call;
+use nu_protocol::ast::{Call, RangeInclusion};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoInterruptiblePipelineData, PipelineData, PipelineIterator, ShellError,
@@ -16,7 +16,11 @@ impl Command for DropNth {
 
     fn signature(&self) -> Signature {
         Signature::build("drop nth")
-            .rest("rest", SyntaxShape::Int, "the number of the row to drop")
+            .rest(
+                "rest",
+                SyntaxShape::Any,
+                "the number of the row to drop, or a range of rows to drop",
+            )
             .category(Category::Filters)
     }
 
@@ -58,6 +62,22 @@ impl Command for DropNth {
                     span: Span::unknown(),
                 }),
             },
+            Example {
+                example: "[0,1,2,3,4,5] | drop nth 0..2 5",
+                description: "Drop the first three rows and row 5",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(3), Value::test_int(4)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "[0,1,2,3,4,5] | drop nth 2..",
+                description: "Drop everything from row 2 on",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(1)],
+                    span: Span::unknown(),
+                }),
+            },
         ]
     }
 
@@ -68,8 +88,11 @@ impl Command for DropNth {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        let mut rows: Vec<usize> = call.rest(engine_state, stack, 0)?;
-        rows.sort_unstable();
+        let rows = call
+            .rest::<Value>(engine_state, stack, 0)?
+            .iter()
+            .map(RowSelection::from_value)
+            .collect::<Result<Vec<_>, ShellError>>()?;
         let pipeline_iter: PipelineIterator = input.into_iter();
 
         Ok(DropNthIterator {
@@ -81,9 +104,84 @@ impl Command for DropNth {
     }
 }
 
+// The rows from `start` to `end` (inclusive), every `step` rows
+struct RowSelection {
+    start: usize,
+    end: usize,
+    step: usize,
+}
+
+impl RowSelection {
+    fn from_value(value: &Value) -> Result<Self, ShellError> {
+        match value {
+            Value::Int { val, span } => {
+                let row = row_number(*val, *span)?;
+                Ok(RowSelection {
+                    start: row,
+                    end: row,
+                    step: 1,
+                })
+            }
+            Value::Range { val, span } => {
+                let start = row_number(val.from.as_integer()?, *span)?;
+                let step = match val.incr.as_integer()? {
+                    step if step > 0 => step as usize,
+                    _ => {
+                        return Err(ShellError::UnsupportedInput(
+                            "ranges of rows to drop must count up".into(),
+                            *span,
+                        ))
+                    }
+                };
+                let end = match &val.to {
+                    // Open ranges like 2.. drop everything from their start on
+                    Value::Nothing { .. } => usize::MAX,
+                    to => match (to.as_integer()?, &val.inclusion) {
+                        (to, _) if to < 0 => return Err(negative_row_error(*span)),
+                        (to, RangeInclusion::Inclusive) => to as usize,
+                        (0, RangeInclusion::RightExclusive) => {
+                            return Ok(RowSelection {
+                                start: 1,
+                                end: 0,
+                                step,
+                            })
+                        }
+                        (to, RangeInclusion::RightExclusive) => to as usize - 1,
+                    },
+                };
+
+                Ok(RowSelection { start, end, step })
+            }
+            other => Err(ShellError::UnsupportedInput(
+                format!(
+                    "expected a row number or a range, found {}",
+                    other.get_type()
+                ),
+                other.span()?,
+            )),
+        }
+    }
+
+    fn contains(&self, row: usize) -> bool {
+        row >= self.start && row <= self.end && (row - self.start) % self.step == 0
+    }
+}
+
+fn row_number(val: i64, span: Span) -> Result<usize, ShellError> {
+    if val < 0 {
+        Err(negative_row_error(span))
+    } else {
+        Ok(val as usize)
+    }
+}
+
+fn negative_row_error(span: Span) -> ShellError {
+    ShellError::UnsupportedInput("row numbers can't be negative".into(), span)
+}
+
 struct DropNthIterator {
     input: PipelineIterator,
-    rows: Vec<usize>,
+    rows: Vec<RowSelection>,
     current: usize,
 }
 
@@ -92,18 +190,12 @@ impl Iterator for DropNthIterator {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if let Some(row) = self.rows.get(0) {
-                if self.current == *row {
-                    self.rows.remove(0);
-                    self.current += 1;
-                    let _ = self.input.next();
-                    continue;
-                } else {
-                    self.current += 1;
-                    return self.input.next();
-                }
-            } else {
-                return self.input.next();
+            let value = self.input.next()?;
+            let row = self.current;
+            self.current += 1;
+
+            if !self.rows.iter().any(|selection| selection.contains(row)) {
+                return Some(value);
             }
         }
     }
@@ -2,10 +2,58 @@ use nu_engine::eval_block;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, Signature,
-    Span, SyntaxShape, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, SyntaxShape, Value,
 };
 
+/// What `each` and `par-each` do with rows that are errors, either because they came in as
+/// errors or because the block failed on them
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum ErrorPolicy {
+    /// Run the block on incoming errors too, and put a failing block's error in the output
+    Propagate,
+    /// Pass incoming errors through without running the block on them
+    Keep,
+    /// Leave erroneous rows out of the output
+    Skip,
+}
+
+impl ErrorPolicy {
+    pub(super) fn from_call(call: &Call) -> Result<Self, ShellError> {
+        match (call.has_flag("keep-errors"), call.has_flag("skip-errors")) {
+            (true, true) => Err(ShellError::IncompatibleParametersSingle(
+                "--keep-errors and --skip-errors can't be used together".into(),
+                call.head,
+            )),
+            (true, false) => Ok(ErrorPolicy::Keep),
+            (false, true) => Ok(ErrorPolicy::Skip),
+            (false, false) => Ok(ErrorPolicy::Propagate),
+        }
+    }
+
+    /// Whether the block should be skipped for this row, which then goes to the output as is
+    pub(super) fn passes_through(self, value: &Value) -> bool {
+        self != ErrorPolicy::Propagate && matches!(value, Value::Error { .. })
+    }
+
+    /// Whether this row belongs in the output
+    pub(super) fn keeps(self, value: &Value) -> bool {
+        self != ErrorPolicy::Skip || !matches!(value, Value::Error { .. })
+    }
+
+    /// The value a failing block leaves in a record or in place of a single value, None when
+    /// it is skipped. Without either flag the error is returned, as nothing is streamed there.
+    pub(super) fn block_failed(self, error: ShellError) -> Result<Option<Value>, ShellError> {
+        match self {
+            ErrorPolicy::Propagate => Err(error),
+            ErrorPolicy::Keep => Ok(Some(Value::Error {
+                error: Box::new(error),
+            })),
+            ErrorPolicy::Skip => Ok(None),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct Each;
 
@@ -18,6 +66,12 @@ impl Command for Each {
         "Run a block on each element of input"
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"When the block fails on an element, its error takes the element's place in the output.
+With --keep-errors, elements that already are errors skip the block and are passed along
+unchanged, and with --skip-errors, erroneous elements are dropped from the output."#
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("each")
             .required(
@@ -26,6 +80,12 @@ impl Command for Each {
                 "the block to run",
             )
             .switch("numbered", "iterate with an index", Some('n'))
+            .switch(
+                "keep-errors",
+                "pass errors along without running the block on them",
+                Some('k'),
+            )
+            .switch("skip-errors", "leave errors out of the output", Some('s'))
             .category(Category::Filters)
     }
 
@@ -45,14 +105,24 @@ impl Command for Each {
             },
         ];
 
-        vec![Example {
-            example: "[1 2 3] | each { 2 * $it }",
-            description: "Multiplies elements in list",
-            result: Some(Value::List {
-                vals: stream_test_1,
-                span: Span::unknown(),
-            }),
-        }]
+        vec![
+            Example {
+                example: "[1 2 3] | each { 2 * $it }",
+                description: "Multiplies elements in list",
+                result: Some(Value::List {
+                    vals: stream_test_1,
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: r#"[1 "two" 3] | each --skip-errors { $it + 1 }"#,
+                description: "Increments the elements it can, dropping the rest",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(2), Value::test_int(4)],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
     }
 
     fn run(
@@ -67,6 +137,7 @@ impl Command for Each {
             .expect("internal error: expected block");
 
         let numbered = call.has_flag("numbered");
+        let on_error = ErrorPolicy::from_call(call)?;
         let ctrlc = engine_state.ctrlc.clone();
         let engine_state = engine_state.clone();
         let block = engine_state.get_block(block_id).clone();
@@ -80,6 +151,10 @@ impl Command for Each {
                 .into_iter()
                 .enumerate()
                 .map(move |(idx, x)| {
+                    if on_error.passes_through(&x) {
+                        return x;
+                    }
+
                     if let Some(var) = block.signature.get_positional(0) {
                         if let Some(var_id) = &var.var_id {
                             if numbered {
@@ -105,15 +180,26 @@ impl Command for Each {
 
                     match eval_block(&engine_state, &mut stack, &block, PipelineData::new(span)) {
                         Ok(v) => v.into_value(span),
-                        Err(error) => Value::Error { error },
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        },
                     }
                 })
+                .filter(move |x| on_error.keeps(x))
                 .into_pipeline_data(ctrlc)),
             PipelineData::Value(Value::Record { cols, vals, .. }, ..) => {
                 let mut output_cols = vec![];
                 let mut output_vals = vec![];
 
                 for (col, val) in cols.into_iter().zip(vals.into_iter()) {
+                    if on_error.passes_through(&val) {
+                        if on_error.keeps(&val) {
+                            output_cols.push(col);
+                            output_vals.push(val);
+                        }
+                        continue;
+                    }
+
                     let block = engine_state.get_block(block_id);
 
                     let mut stack = stack.clone();
@@ -137,7 +223,20 @@ impl Command for Each {
                         }
                     }
 
-                    match eval_block(&engine_state, &mut stack, block, PipelineData::new(span))? {
+                    let output =
+                        match eval_block(&engine_state, &mut stack, block, PipelineData::new(span))
+                        {
+                            Ok(output) => output,
+                            Err(error) => {
+                                if let Some(error) = on_error.block_failed(error)? {
+                                    output_cols.push(col);
+                                    output_vals.push(error);
+                                }
+                                continue;
+                            }
+                        };
+
+                    match output {
                         PipelineData::Value(
                             Value::Record {
                                 mut cols, mut vals, ..
@@ -163,6 +262,14 @@ impl Command for Each {
                 .into_pipeline_data())
             }
             PipelineData::Value(x, ..) => {
+                if on_error.passes_through(&x) {
+                    return Ok(if on_error.keeps(&x) {
+                        x.into_pipeline_data()
+                    } else {
+                        PipelineData::new(span)
+                    });
+                }
+
                 let block = engine_state.get_block(block_id);
 
                 if let Some(var) = block.signature.get_positional(0) {
@@ -171,7 +278,13 @@ impl Command for Each {
                     }
                 }
 
-                eval_block(&engine_state, &mut stack, block, PipelineData::new(span))
+                match eval_block(&engine_state, &mut stack, block, PipelineData::new(span)) {
+                    Ok(output) => Ok(output),
+                    Err(error) => Ok(match on_error.block_failed(error)? {
+                        Some(error) => error.into_pipeline_data(),
+                        None => PipelineData::new(span),
+                    }),
+                }
             }
         }
     }
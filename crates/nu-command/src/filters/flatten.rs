@@ -94,7 +94,7 @@ fn is_table(value: &Value) -> bool {
 fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span) -> Vec<Value> {
     let tag = match item.span() {
         Ok(x) => x,
-        Err(e) => return vec![Value::Error { error: e }],
+        Err(e) => return vec![Value::Error { error: Box::new(e) }],
     };
 
     let res = {
@@ -111,17 +111,17 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span) -> Vec<Value>
                 } => (cols, vals),
                 x => {
                     return vec![Value::Error {
-                        error: ShellError::UnsupportedInput(
+                        error: Box::new(ShellError::UnsupportedInput(
                             format!("This should be a record, but instead got {}", x.get_type()),
                             tag,
-                        ),
+                        )),
                     }]
                 }
             };
 
             let s = match item.span() {
                 Ok(x) => x,
-                Err(e) => return vec![Value::Error { error: e }],
+                Err(e) => return vec![Value::Error { error: Box::new(e) }],
             };
 
             for (column, value) in records.0.iter().zip(records.1.iter()) {
@@ -167,10 +167,10 @@ fn flat_value(columns: &[CellPath], item: &Value, _name_tag: Span) -> Vec<Value>
                         };
 
                         if tables_explicitly_flattened >= 1 && column_requested.is_some() {
-                            return vec![Value::Error{ error: ShellError::UnsupportedInput(
+                            return vec![Value::Error{ error: Box::new(ShellError::UnsupportedInput(
                                     "can only flatten one inner table at the same time. tried flattening more than one column with inner tables... but is flattened already".to_string(),
                                     s
-                                )}
+                                ))}
                             ];
                         }
 
@@ -0,0 +1,113 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Group;
+
+impl Command for Group {
+    fn name(&self) -> &str {
+        "group"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("group")
+            .required("group_size", SyntaxShape::Int, "the size of each group")
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Groups input into lists of a fixed size."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Group a list in pairs",
+            example: "[1 2 3 4 5] | group 2",
+            result: Some(Value::List {
+                vals: vec![
+                    Value::List {
+                        vals: vec![Value::test_int(1), Value::test_int(2)],
+                        span: Span::unknown(),
+                    },
+                    Value::List {
+                        vals: vec![Value::test_int(3), Value::test_int(4)],
+                        span: Span::unknown(),
+                    },
+                    Value::List {
+                        vals: vec![Value::test_int(5)],
+                        span: Span::unknown(),
+                    },
+                ],
+                span: Span::unknown(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let group_size: Spanned<i64> = call.req(engine_state, stack, 0)?;
+        let span = call.head;
+
+        if group_size.item <= 0 {
+            return Err(ShellError::UnsupportedInput(
+                "group size must be greater than zero".into(),
+                group_size.span,
+            ));
+        }
+
+        let groups = EachGroup {
+            input: input.into_iter(),
+            group_size: group_size.item as usize,
+            span,
+        };
+
+        Ok(groups.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+// Collects the next `group_size` values of the input at a time, so the input is never
+// collected as a whole
+struct EachGroup<I> {
+    input: I,
+    group_size: usize,
+    span: Span,
+}
+
+impl<I: Iterator<Item = Value>> Iterator for EachGroup<I> {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        let vals: Vec<Value> = self.input.by_ref().take(self.group_size).collect();
+
+        if vals.is_empty() {
+            None
+        } else {
+            Some(Value::List {
+                vals,
+                span: self.span,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Group {})
+    }
+}
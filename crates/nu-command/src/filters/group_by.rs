@@ -0,0 +1,235 @@
+use indexmap::IndexMap;
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::{
+    ast::{Call, PathMember},
+    engine::{Command, EngineState, Stack},
+    BlockId, Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct GroupBy;
+
+impl Command for GroupBy {
+    fn name(&self) -> &str {
+        "group-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "grouper",
+                SyntaxShape::Any,
+                "the column to group by, or a block that computes the group of each row",
+            )
+            .switch(
+                "to-table",
+                "return a table with group and items columns instead of a record",
+                Some('t'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Split the rows of a table into groups by the value of a column or the result of a block."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A block gets each row as its parameter, like {|row| $row.size > 1kb }. The groups keep
+the order in which they first appear in the input. Group names are the values of the column
+or of the block, converted to text."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let row = |lang: &str, name: &str| Value::Record {
+            cols: vec!["lang".into(), "name".into()],
+            vals: vec![Value::test_string(lang), Value::test_string(name)],
+            span: Span::unknown(),
+        };
+
+        vec![
+            Example {
+                description: "Group a table by a column",
+                example: "[[lang name]; [rs nu] [go hugo] [rs fd]] | group-by lang",
+                result: Some(Value::Record {
+                    cols: vec!["rs".into(), "go".into()],
+                    vals: vec![
+                        Value::List {
+                            vals: vec![row("rs", "nu"), row("rs", "fd")],
+                            span: Span::unknown(),
+                        },
+                        Value::List {
+                            vals: vec![row("go", "hugo")],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Group numbers by the result of a block, as a table",
+                example: "[1 2 3 4 5] | group-by {|n| $n mod 2 } --to-table",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["group".into(), "items".into()],
+                            vals: vec![
+                                Value::test_string("1"),
+                                Value::List {
+                                    vals: vec![
+                                        Value::test_int(1),
+                                        Value::test_int(3),
+                                        Value::test_int(5),
+                                    ],
+                                    span: Span::unknown(),
+                                },
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["group".into(), "items".into()],
+                            vals: vec![
+                                Value::test_string("0"),
+                                Value::List {
+                                    vals: vec![Value::test_int(2), Value::test_int(4)],
+                                    span: Span::unknown(),
+                                },
+                            ],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let grouper: Value = call.req(engine_state, stack, 0)?;
+        let to_table = call.has_flag("to-table");
+        let span = call.head;
+        let config = stack.get_config().unwrap_or_default();
+
+        let mut grouper = Grouper::new(grouper, engine_state, stack)?;
+        let mut groups: IndexMap<String, Value> = IndexMap::new();
+
+        for value in input.into_interruptible_iter(engine_state.ctrlc.clone()) {
+            let group = grouper.group_of(&value, span)?.into_string(", ", &config);
+
+            match groups.get_mut(&group) {
+                Some(Value::List { vals, .. }) => vals.push(value),
+                _ => {
+                    groups.insert(
+                        group,
+                        Value::List {
+                            vals: vec![value],
+                            span,
+                        },
+                    );
+                }
+            }
+        }
+
+        if to_table {
+            let vals = groups
+                .into_iter()
+                .map(|(group, items)| Value::Record {
+                    cols: vec!["group".into(), "items".into()],
+                    vals: vec![Value::String { val: group, span }, items],
+                    span,
+                })
+                .collect();
+
+            Ok(Value::List { vals, span }.into_pipeline_data())
+        } else {
+            Ok(Value::from(Spanned { item: groups, span }).into_pipeline_data())
+        }
+    }
+}
+
+// How the group of a row is found: by following a column, or by running a block on it
+enum Grouper<'a> {
+    Column(Vec<PathMember>),
+    Block {
+        engine_state: &'a EngineState,
+        stack: Stack,
+        block_id: BlockId,
+    },
+}
+
+impl<'a> Grouper<'a> {
+    fn new(
+        grouper: Value,
+        engine_state: &'a EngineState,
+        stack: &Stack,
+    ) -> Result<Self, ShellError> {
+        match grouper {
+            Value::Block { val, .. } => {
+                let block = engine_state.get_block(val);
+
+                Ok(Grouper::Block {
+                    engine_state,
+                    stack: stack.collect_captures(&block.captures),
+                    block_id: val,
+                })
+            }
+            Value::String { val, span } => Ok(Grouper::Column(
+                val.split('.')
+                    .map(|column| PathMember::String {
+                        val: column.to_string(),
+                        span,
+                    })
+                    .collect(),
+            )),
+            other => Err(ShellError::UnsupportedInput(
+                format!(
+                    "expected a column name or a block, found {}",
+                    other.get_type()
+                ),
+                other.span()?,
+            )),
+        }
+    }
+
+    fn group_of(&mut self, value: &Value, span: Span) -> Result<Value, ShellError> {
+        match self {
+            Grouper::Column(members) => value.clone().follow_cell_path(members),
+            Grouper::Block {
+                engine_state,
+                stack,
+                block_id,
+            } => {
+                let block = engine_state.get_block(*block_id);
+                if let Some(var_id) = block.signature.get_positional(0).and_then(|v| v.var_id) {
+                    stack.add_var(var_id, value.clone());
+                }
+
+                Ok(eval_block(
+                    engine_state,
+                    stack,
+                    block,
+                    value.clone().into_pipeline_data(),
+                )?
+                .into_value(span))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(GroupBy)
+    }
+}
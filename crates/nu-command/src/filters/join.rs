@@ -0,0 +1,332 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Join;
+
+#[derive(Clone, Copy, PartialEq)]
+enum JoinType {
+    Inner,
+    Left,
+    Right,
+    Outer,
+}
+
+impl Command for Join {
+    fn name(&self) -> &str {
+        "join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("join")
+            .required("right-table", SyntaxShape::Any, "the table to join with")
+            .required(
+                "left-on",
+                SyntaxShape::String,
+                "the column of the input table to join on",
+            )
+            .optional(
+                "right-on",
+                SyntaxShape::String,
+                "the column of the right table to join on (defaults to left-on)",
+            )
+            .switch("inner", "Inner join (default)", Some('i'))
+            .switch("left", "Left-outer join", Some('l'))
+            .switch("right", "Right-outer join", Some('r'))
+            .switch("outer", "Outer join", Some('o'))
+            .named(
+                "suffix",
+                SyntaxShape::String,
+                "Suffix for right table columns that clash with the input table (default: _)",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Join two tables on the values of key columns."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let right: Value = call.req(engine_state, stack, 0)?;
+        let left_on: Spanned<String> = call.req(engine_state, stack, 1)?;
+        let right_on: Option<Spanned<String>> = call.opt(engine_state, stack, 2)?;
+        let suffix: Option<Spanned<String>> = call.get_flag(engine_state, stack, "suffix")?;
+
+        let join_type = join_type(call)?;
+        let right_on = right_on.unwrap_or_else(|| left_on.clone());
+        let suffix = match suffix {
+            Some(Spanned { item, span }) if item.is_empty() => {
+                return Err(ShellError::UnsupportedInput(
+                    "the suffix can't be empty".into(),
+                    span,
+                ))
+            }
+            Some(Spanned { item, .. }) => item,
+            None => "_".to_string(),
+        };
+
+        let left_rows: Vec<Value> = input.into_iter().collect();
+        let right_rows = match right {
+            Value::List { vals, .. } => vals,
+            Value::Record { .. } => vec![right],
+            x => {
+                return Err(ShellError::UnsupportedInput(
+                    format!("expected a table to join with, got {}", x.get_type()),
+                    x.span()?,
+                ))
+            }
+        };
+
+        let output = join(
+            &left_rows,
+            &right_rows,
+            &left_on.item,
+            &right_on.item,
+            join_type,
+            &suffix,
+            call.head,
+        );
+
+        Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Join two tables on a shared column",
+                example:
+                    "[[id name]; [1 alice] [2 bob]] | join [[id item]; [1 book] [1 pen] [3 cup]] id",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["id".into(), "name".into(), "item".into()],
+                            vals: vec![
+                                Value::test_int(1),
+                                Value::test_string("alice"),
+                                Value::test_string("book"),
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["id".into(), "name".into(), "item".into()],
+                            vals: vec![
+                                Value::test_int(1),
+                                Value::test_string("alice"),
+                                Value::test_string("pen"),
+                            ],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Keep the rows of the input table that have no match",
+                example:
+                    "[[id name]; [1 alice] [2 bob]] | join [[user name]; [1 book]] id user --left",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["id".into(), "name".into(), "user".into(), "name_".into()],
+                            vals: vec![
+                                Value::test_int(1),
+                                Value::test_string("alice"),
+                                Value::test_int(1),
+                                Value::test_string("book"),
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["id".into(), "name".into(), "user".into(), "name_".into()],
+                            vals: vec![
+                                Value::test_int(2),
+                                Value::test_string("bob"),
+                                Value::nothing(Span::unknown()),
+                                Value::nothing(Span::unknown()),
+                            ],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+}
+
+fn join_type(call: &Call) -> Result<JoinType, ShellError> {
+    let flags = [
+        ("inner", JoinType::Inner),
+        ("left", JoinType::Left),
+        ("right", JoinType::Right),
+        ("outer", JoinType::Outer),
+    ];
+
+    let mut selected = flags.iter().filter(|(name, _)| call.has_flag(name));
+
+    match (selected.next(), selected.next()) {
+        (None, _) => Ok(JoinType::Inner),
+        (Some((_, join_type)), None) => Ok(*join_type),
+        (Some(_), Some((name, _))) => Err(ShellError::IncompatibleParametersSingle(
+            "only one of --inner, --left, --right and --outer can be used".into(),
+            call.get_named_arg(name)
+                .map(|arg| arg.span)
+                .unwrap_or(call.head),
+        )),
+    }
+}
+
+// All the columns found in a table, in the order they first appear
+fn table_columns(rows: &[Value]) -> Vec<String> {
+    let mut columns: Vec<String> = vec![];
+    for row in rows {
+        for column in row.columns() {
+            if !columns.contains(&column) {
+                columns.push(column);
+            }
+        }
+    }
+    columns
+}
+
+fn join(
+    left_rows: &[Value],
+    right_rows: &[Value],
+    left_on: &str,
+    right_on: &str,
+    join_type: JoinType,
+    suffix: &str,
+    span: Span,
+) -> Vec<Value> {
+    let left_columns = table_columns(left_rows);
+    let right_columns: Vec<String> = table_columns(right_rows)
+        .into_iter()
+        // A key column shared by name is only output once
+        .filter(|column| !(left_on == right_on && column == right_on))
+        .collect();
+
+    // A suffixed name can itself be taken, by either table, so the suffix is added again until
+    // the name is free
+    let mut taken: Vec<String> = left_columns.iter().chain(&right_columns).cloned().collect();
+    let mut output_columns = left_columns.clone();
+    for column in &right_columns {
+        if left_columns.contains(column) {
+            let mut name = format!("{}{}", column, suffix);
+            while taken.contains(&name) {
+                name.push_str(suffix);
+            }
+
+            taken.push(name.clone());
+            output_columns.push(name);
+        } else {
+            output_columns.push(column.clone());
+        }
+    }
+
+    let merge = |left: Option<&Value>, right: Option<&Value>| -> Value {
+        let mut vals = vec![];
+
+        for column in &left_columns {
+            let value = match (left, right) {
+                (Some(left), _) => left.get_data_by_key(column),
+                // Rows only found in the right table still carry the shared key
+                (None, Some(right)) if column == left_on => right.get_data_by_key(right_on),
+                (None, _) => None,
+            };
+            vals.push(value.unwrap_or_else(|| Value::nothing(span)));
+        }
+
+        for column in &right_columns {
+            let value = right.and_then(|right| right.get_data_by_key(column));
+            vals.push(value.unwrap_or_else(|| Value::nothing(span)));
+        }
+
+        Value::Record {
+            cols: output_columns.clone(),
+            vals,
+            span,
+        }
+    };
+
+    let mut output = vec![];
+    let mut right_matched = vec![false; right_rows.len()];
+
+    for left in left_rows {
+        let left_key = left.get_data_by_key(left_on);
+        let mut matched = false;
+
+        if let Some(left_key) = &left_key {
+            for (idx, right) in right_rows.iter().enumerate() {
+                if right.get_data_by_key(right_on).as_ref() == Some(left_key) {
+                    output.push(merge(Some(left), Some(right)));
+                    right_matched[idx] = true;
+                    matched = true;
+                }
+            }
+        }
+
+        if !matched && matches!(join_type, JoinType::Left | JoinType::Outer) {
+            output.push(merge(Some(left), None));
+        }
+    }
+
+    if matches!(join_type, JoinType::Right | JoinType::Outer) {
+        for (right, matched) in right_rows.iter().zip(right_matched) {
+            if !matched {
+                output.push(merge(None, Some(right)));
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Join {})
+    }
+
+    #[test]
+    fn suffixed_names_that_are_taken_get_the_suffix_again() {
+        let row = |cols: &[&str]| Value::Record {
+            cols: cols.iter().map(|col| col.to_string()).collect(),
+            vals: cols.iter().map(|_| Value::test_int(1)).collect(),
+            span: Span::unknown(),
+        };
+
+        let output = join(
+            &[row(&["id", "a", "a_"])],
+            &[row(&["id", "a", "a__"])],
+            "id",
+            "id",
+            JoinType::Inner,
+            "_",
+            Span::unknown(),
+        );
+
+        match &output[..] {
+            [Value::Record { cols, .. }] => {
+                assert_eq!(cols, &["id", "a", "a_", "a___", "a__"])
+            }
+            _ => panic!("expected one row"),
+        }
+    }
+}
@@ -1,7 +1,12 @@
+use std::collections::VecDeque;
+
+use encoding_rs::{Decoder, Encoding};
+use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Value,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -18,65 +23,56 @@ impl Command for Lines {
         "Converts input to lines"
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"Binary input is decoded with the given encoding, or as UTF-8 when there is none, where
+bytes that can't be decoded are replaced. A byte order mark at the start of the input takes
+precedence over the encoding, so UTF-16 files written on Windows are read correctly."#
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
-        Signature::build("lines").category(Category::Filters)
+        Signature::build("lines")
+            .switch("skip-empty", "skip empty lines", Some('s'))
+            .named(
+                "encoding",
+                SyntaxShape::String,
+                "the encoding of binary input, like utf-16le or latin1 (utf-8 by default)",
+                Some('e'),
+            )
+            .category(Category::Filters)
     }
 
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
-        match input {
-            #[allow(clippy::needless_collect)]
-            // Collect is needed because the string may not live long enough for
-            // the Rc structure to continue using it. If split could take ownership
-            // of the split values, then this wouldn't be needed
-            PipelineData::Value(Value::String { val, span }, ..) => {
-                let lines = val
-                    .split(SPLIT_CHAR)
-                    .map(|s| s.to_string())
-                    .collect::<Vec<String>>();
-
-                let iter = lines.into_iter().filter_map(move |s| {
-                    if !s.is_empty() {
-                        Some(Value::string(s, span))
-                    } else {
-                        None
-                    }
-                });
+        let skip_empty = call.has_flag("skip-empty");
+        let encoding: Option<Spanned<String>> = call.get_flag(engine_state, stack, "encoding")?;
+
+        let encoding = match encoding {
+            Some(label) => Encoding::for_label(label.item.as_bytes()).ok_or_else(|| {
+                ShellError::SpannedLabeledError(
+                    "Unknown encoding".into(),
+                    format!("'{}' is not an encoding lines knows about", label.item),
+                    label.span,
+                )
+            })?,
+            None => encoding_rs::UTF_8,
+        };
 
-                Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
+        match input {
+            PipelineData::Value(value @ Value::String { .. }, ..)
+            | PipelineData::Value(value @ Value::Binary { .. }, ..) => {
+                Ok(
+                    LinesIterator::new(std::iter::once(value), encoding, skip_empty, call.head)
+                        .into_pipeline_data(engine_state.ctrlc.clone()),
+                )
             }
             PipelineData::Stream(stream, ..) => {
-                let iter = stream
-                    .into_iter()
-                    .filter_map(|value| {
-                        if let Value::String { val, span } = value {
-                            let inner = val
-                                .split(SPLIT_CHAR)
-                                .filter_map(|s| {
-                                    if !s.is_empty() {
-                                        Some(Value::String {
-                                            val: s.into(),
-                                            span,
-                                        })
-                                    } else {
-                                        None
-                                    }
-                                })
-                                .collect::<Vec<Value>>();
-
-                            Some(inner)
-                        } else {
-                            None
-                        }
-                    })
-                    .flatten();
-
-                Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
+                Ok(LinesIterator::new(stream, encoding, skip_empty, call.head)
+                    .into_pipeline_data(engine_state.ctrlc.clone()))
             }
             PipelineData::Value(val, ..) => Err(ShellError::UnsupportedInput(
                 format!("Not supported input: {}", val.as_string()?),
@@ -84,4 +80,138 @@ impl Command for Lines {
             )),
         }
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Split a string into lines",
+                example: "echo $\"two(char nl)lines\" | lines",
+                result: None,
+            },
+            Example {
+                description: "Split a string into lines, leaving out the empty ones",
+                example: "echo $\"two(char nl)(char nl)lines\" | lines --skip-empty",
+                result: None,
+            },
+            Example {
+                description: "Read the lines of a UTF-16 log file without a byte order mark",
+                example: "open --raw service.log | lines --encoding utf-16le",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Splits strings and binary data into lines as they come in, so that a line broken across
+/// two chunks of a stream is still returned whole
+struct LinesIterator<I> {
+    input: I,
+    decoder: Decoder,
+    skip_empty: bool,
+    // The text after the last newline, which is not a complete line yet
+    buffer: String,
+    lines: VecDeque<String>,
+    started: bool,
+    done: bool,
+    span: Span,
+}
+
+impl<I: Iterator<Item = Value>> LinesIterator<I> {
+    fn new(input: I, encoding: &'static Encoding, skip_empty: bool, span: Span) -> Self {
+        LinesIterator {
+            input,
+            // The decoder removes a byte order mark, and switches to the encoding it names
+            decoder: encoding.new_decoder(),
+            skip_empty,
+            buffer: String::new(),
+            lines: VecDeque::new(),
+            started: false,
+            done: false,
+            span,
+        }
+    }
+
+    fn push_str(&mut self, text: &str) {
+        let text = if self.started {
+            text
+        } else {
+            self.started = !text.is_empty();
+            text.trim_start_matches('\u{feff}')
+        };
+
+        self.buffer.push_str(text);
+
+        while let Some(index) = self.buffer.find(SPLIT_CHAR) {
+            let line: String = self.buffer.drain(..=index).collect();
+            self.push_line(line);
+        }
+    }
+
+    fn push_bytes(&mut self, bytes: &[u8], last: bool) {
+        let mut text = String::with_capacity(
+            self.decoder
+                .max_utf8_buffer_length(bytes.len())
+                .unwrap_or(bytes.len()),
+        );
+        let _ = self.decoder.decode_to_string(bytes, &mut text, last);
+
+        self.started = true;
+        self.push_str(&text);
+    }
+
+    fn push_line(&mut self, line: String) {
+        let line = line.trim_end_matches(SPLIT_CHAR);
+        let line = line.strip_suffix('\r').unwrap_or(line);
+
+        if !(self.skip_empty && line.is_empty()) {
+            self.lines.push_back(line.to_string());
+        }
+    }
+}
+
+impl<I: Iterator<Item = Value>> Iterator for LinesIterator<I> {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.lines.pop_front() {
+                return Some(Value::string(line, self.span));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            match self.input.next() {
+                Some(Value::String { val, span }) => {
+                    self.span = span;
+                    self.push_str(&val);
+                }
+                Some(Value::Binary { val, span }) => {
+                    self.span = span;
+                    self.push_bytes(&val, false);
+                }
+                // Errors go on to the next command, and other values become errors there
+                Some(Value::Error { error }) => return Some(Value::Error { error }),
+                Some(value) => {
+                    return Some(Value::Error {
+                        error: Box::new(ShellError::UnsupportedInput(
+                            format!("lines can't split a {}", value.get_type()),
+                            value.span().unwrap_or(self.span),
+                        )),
+                    })
+                }
+                None => {
+                    self.done = true;
+                    self.push_bytes(&[], true);
+
+                    // A trailing newline ends the last line rather than starting an empty one
+                    if !self.buffer.is_empty() {
+                        let line = std::mem::take(&mut self.buffer);
+                        self.push_line(line);
+                    }
+                }
+            }
+        }
+    }
 }
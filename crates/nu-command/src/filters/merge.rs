@@ -0,0 +1,177 @@
+use indexmap::IndexMap;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Merge;
+
+impl Command for Merge {
+    fn name(&self) -> &str {
+        "merge"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("merge")
+            .required(
+                "record",
+                SyntaxShape::Any,
+                "the record to merge into the input",
+            )
+            .named(
+                "strategy",
+                SyntaxShape::String,
+                "how lists found in both records are merged: 'replace' (default) or 'append'",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Deep merge a record into the input record, or into each row of the input table."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Nested records are merged column by column. Any other value in the given record
+replaces the value of the same column in the input."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let span = Span::unknown();
+
+        vec![
+            Example {
+                example: "{a: 1, b: {c: 2, d: 3}} | merge {b: {d: 4}, e: 5}",
+                description: "Merge nested records, with the given record taking precedence",
+                result: Some(Value::Record {
+                    cols: vec!["a".into(), "b".into(), "e".into()],
+                    vals: vec![
+                        Value::test_int(1),
+                        Value::Record {
+                            cols: vec!["c".into(), "d".into()],
+                            vals: vec![Value::test_int(2), Value::test_int(4)],
+                            span,
+                        },
+                        Value::test_int(5),
+                    ],
+                    span,
+                }),
+            },
+            Example {
+                example: "{plugins: [a]} | merge {plugins: [b]} --strategy append",
+                description: "Append lists instead of replacing them",
+                result: Some(Value::Record {
+                    cols: vec!["plugins".into()],
+                    vals: vec![Value::List {
+                        vals: vec![Value::test_string("a"), Value::test_string("b")],
+                        span,
+                    }],
+                    span,
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let other: Value = call.req(engine_state, stack, 0)?;
+        let strategy: Option<Spanned<String>> = call.get_flag(engine_state, stack, "strategy")?;
+
+        let strategy = match strategy {
+            None => ListStrategy::Replace,
+            Some(Spanned { item, span }) => match item.as_str() {
+                "replace" => ListStrategy::Replace,
+                "append" => ListStrategy::Append,
+                _ => {
+                    return Err(ShellError::UnsupportedInput(
+                        "expected 'replace' or 'append'".into(),
+                        span,
+                    ))
+                }
+            },
+        };
+
+        if !matches!(other, Value::Record { .. }) {
+            return Err(ShellError::UnsupportedInput(
+                format!("expected a record, found {}", other.get_type()),
+                call.positional[0].span,
+            ));
+        }
+
+        input.map(
+            move |row| match row {
+                Value::Record { .. } => merge_values(row, other.clone(), strategy),
+                Value::Error { .. } => row,
+                _ => Value::Error {
+                    error: Box::new(ShellError::UnsupportedInput(
+                        format!("expected a record, found {}", row.get_type()),
+                        row.span().unwrap_or_else(|_| Span::unknown()),
+                    )),
+                },
+            },
+            engine_state.ctrlc.clone(),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ListStrategy {
+    Replace,
+    Append,
+}
+
+fn merge_values(left: Value, right: Value, strategy: ListStrategy) -> Value {
+    match (left, right) {
+        (
+            Value::Record { cols, vals, span },
+            Value::Record {
+                cols: right_cols,
+                vals: right_vals,
+                ..
+            },
+        ) => {
+            let mut record: IndexMap<String, Value> = cols.into_iter().zip(vals).collect();
+
+            for (col, right) in right_cols.into_iter().zip(right_vals) {
+                match record.get_mut(&col) {
+                    Some(left) => {
+                        let old = std::mem::replace(left, Value::Nothing { span });
+                        *left = merge_values(old, right, strategy);
+                    }
+                    None => {
+                        record.insert(col, right);
+                    }
+                }
+            }
+
+            Value::from(Spanned { item: record, span })
+        }
+        (Value::List { mut vals, span }, Value::List { vals: right, .. })
+            if strategy == ListStrategy::Append =>
+        {
+            vals.extend(right);
+            Value::List { vals, span }
+        }
+        (_, right) => right,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Merge {})
+    }
+}
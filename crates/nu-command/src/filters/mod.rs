@@ -7,12 +7,17 @@ mod each;
 mod first;
 mod flatten;
 mod get;
+mod group;
+mod group_by;
+mod join;
 mod keep;
 mod last;
 mod length;
 mod lines;
+mod merge;
 mod nth;
 mod par_each;
+mod partition;
 mod prepend;
 mod range;
 mod reject;
@@ -20,7 +25,11 @@ mod reverse;
 mod select;
 mod shuffle;
 mod skip;
+mod sort;
+mod sort_by;
+mod sort_utils;
 mod uniq;
+mod uniq_by;
 mod update;
 mod where_;
 mod wrap;
@@ -35,12 +44,17 @@ pub use each::Each;
 pub use first::First;
 pub use flatten::Flatten;
 pub use get::Get;
+pub use group::Group;
+pub use group_by::GroupBy;
+pub use join::Join;
 pub use keep::*;
 pub use last::Last;
 pub use length::Length;
 pub use lines::Lines;
+pub use merge::Merge;
 pub use nth::Nth;
 pub use par_each::ParEach;
+pub use partition::Partition;
 pub use prepend::Prepend;
 pub use range::Range;
 pub use reject::Reject;
@@ -48,7 +62,10 @@ pub use reverse::Reverse;
 pub use select::Select;
 pub use shuffle::Shuffle;
 pub use skip::*;
+pub use sort::Sort;
+pub use sort_by::SortBy;
 pub use uniq::*;
+pub use uniq_by::UniqBy;
 pub use update::Update;
 pub use where_::Where;
 pub use wrap::Wrap;
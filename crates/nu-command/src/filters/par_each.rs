@@ -7,6 +7,8 @@ use nu_protocol::{
 };
 use rayon::prelude::*;
 
+use super::each::ErrorPolicy;
+
 #[derive(Clone)]
 pub struct ParEach;
 
@@ -27,15 +29,28 @@ impl Command for ParEach {
                 "the block to run",
             )
             .switch("numbered", "iterate with an index", Some('n'))
+            .switch(
+                "keep-errors",
+                "pass errors along without running the block on them",
+                Some('k'),
+            )
+            .switch("skip-errors", "leave errors out of the output", Some('s'))
             .category(Category::Filters)
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            example: "[1 2 3] | par-each { 2 * $it }",
-            description: "Multiplies elements in list",
-            result: None,
-        }]
+        vec![
+            Example {
+                example: "[1 2 3] | par-each { 2 * $it }",
+                description: "Multiplies elements in list",
+                result: None,
+            },
+            Example {
+                example: r#"[1 "two" 3] | par-each --skip-errors { $it + 1 }"#,
+                description: "Increments the elements it can, dropping the rest",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -50,6 +65,7 @@ impl Command for ParEach {
             .expect("internal error: expected block");
 
         let numbered = call.has_flag("numbered");
+        let on_error = ErrorPolicy::from_call(call)?;
         let ctrlc = engine_state.ctrlc.clone();
         let engine_state = engine_state.clone();
         let block = engine_state.get_block(block_id);
@@ -62,6 +78,10 @@ impl Command for ParEach {
                 .enumerate()
                 .par_bridge()
                 .map(move |(idx, x)| {
+                    if on_error.passes_through(&x) {
+                        return x.into_pipeline_data();
+                    }
+
                     let block = engine_state.get_block(block_id);
 
                     let mut stack = stack.clone();
@@ -91,18 +111,26 @@ impl Command for ParEach {
 
                     match eval_block(&engine_state, &mut stack, block, PipelineData::new(span)) {
                         Ok(v) => v,
-                        Err(error) => Value::Error { error }.into_pipeline_data(),
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        }
+                        .into_pipeline_data(),
                     }
                 })
                 .collect::<Vec<_>>()
                 .into_iter()
                 .flatten()
+                .filter(move |x| on_error.keeps(x))
                 .into_pipeline_data(ctrlc)),
             PipelineData::Value(Value::List { vals: val, .. }, ..) => Ok(val
                 .into_iter()
                 .enumerate()
                 .par_bridge()
                 .map(move |(idx, x)| {
+                    if on_error.passes_through(&x) {
+                        return x.into_pipeline_data();
+                    }
+
                     let block = engine_state.get_block(block_id);
 
                     let mut stack = stack.clone();
@@ -132,17 +160,25 @@ impl Command for ParEach {
 
                     match eval_block(&engine_state, &mut stack, block, PipelineData::new(span)) {
                         Ok(v) => v,
-                        Err(error) => Value::Error { error }.into_pipeline_data(),
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        }
+                        .into_pipeline_data(),
                     }
                 })
                 .collect::<Vec<_>>()
                 .into_iter()
                 .flatten()
+                .filter(move |x| on_error.keeps(x))
                 .into_pipeline_data(ctrlc)),
             PipelineData::Stream(stream, ..) => Ok(stream
                 .enumerate()
                 .par_bridge()
                 .map(move |(idx, x)| {
+                    if on_error.passes_through(&x) {
+                        return x.into_pipeline_data();
+                    }
+
                     let block = engine_state.get_block(block_id);
 
                     let mut stack = stack.clone();
@@ -172,18 +208,30 @@ impl Command for ParEach {
 
                     match eval_block(&engine_state, &mut stack, block, PipelineData::new(span)) {
                         Ok(v) => v,
-                        Err(error) => Value::Error { error }.into_pipeline_data(),
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        }
+                        .into_pipeline_data(),
                     }
                 })
                 .collect::<Vec<_>>()
                 .into_iter()
                 .flatten()
+                .filter(move |x| on_error.keeps(x))
                 .into_pipeline_data(ctrlc)),
             PipelineData::Value(Value::Record { cols, vals, .. }, ..) => {
                 let mut output_cols = vec![];
                 let mut output_vals = vec![];
 
                 for (col, val) in cols.into_iter().zip(vals.into_iter()) {
+                    if on_error.passes_through(&val) {
+                        if on_error.keeps(&val) {
+                            output_cols.push(col);
+                            output_vals.push(val);
+                        }
+                        continue;
+                    }
+
                     let block = engine_state.get_block(block_id);
 
                     let mut stack = stack.clone();
@@ -207,7 +255,20 @@ impl Command for ParEach {
                         }
                     }
 
-                    match eval_block(&engine_state, &mut stack, block, PipelineData::new(span))? {
+                    let output =
+                        match eval_block(&engine_state, &mut stack, block, PipelineData::new(span))
+                        {
+                            Ok(output) => output,
+                            Err(error) => {
+                                if let Some(error) = on_error.block_failed(error)? {
+                                    output_cols.push(col);
+                                    output_vals.push(error);
+                                }
+                                continue;
+                            }
+                        };
+
+                    match output {
                         PipelineData::Value(
                             Value::Record {
                                 mut cols, mut vals, ..
@@ -233,6 +294,14 @@ impl Command for ParEach {
                 .into_pipeline_data())
             }
             PipelineData::Value(x, ..) => {
+                if on_error.passes_through(&x) {
+                    return Ok(if on_error.keeps(&x) {
+                        x.into_pipeline_data()
+                    } else {
+                        PipelineData::new(span)
+                    });
+                }
+
                 let block = engine_state.get_block(block_id);
 
                 if let Some(var) = block.signature.get_positional(0) {
@@ -241,7 +310,13 @@ impl Command for ParEach {
                     }
                 }
 
-                eval_block(&engine_state, &mut stack, block, PipelineData::new(span))
+                match eval_block(&engine_state, &mut stack, block, PipelineData::new(span)) {
+                    Ok(output) => Ok(output),
+                    Err(error) => Ok(match on_error.block_failed(error)? {
+                        Some(error) => error.into_pipeline_data(),
+                        None => PipelineData::new(span),
+                    }),
+                }
             }
         }
     }
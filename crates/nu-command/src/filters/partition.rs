@@ -0,0 +1,122 @@
+use nu_engine::eval_block;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Partition;
+
+impl Command for Partition {
+    fn name(&self) -> &str {
+        "partition"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required(
+                "predicate",
+                SyntaxShape::RowCondition,
+                "the predicate that splits the input",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Split the input in the elements that match a predicate and the rest."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The predicate runs once for each element, so this is cheaper than filtering the
+input twice with 'where'. Elements for which the predicate fails go to 'rest'."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Split a list in even and odd numbers",
+            example: "[1 2 3 4 5] | partition ($it mod 2) == 0",
+            result: Some(Value::Record {
+                cols: vec!["matched".into(), "rest".into()],
+                vals: vec![
+                    Value::List {
+                        vals: vec![Value::test_int(2), Value::test_int(4)],
+                        span: Span::unknown(),
+                    },
+                    Value::List {
+                        vals: vec![Value::test_int(1), Value::test_int(3), Value::test_int(5)],
+                        span: Span::unknown(),
+                    },
+                ],
+                span: Span::unknown(),
+            }),
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let predicate = &call.positional[0];
+        let span = call.head;
+
+        let block_id = predicate
+            .as_row_condition_block()
+            .ok_or_else(|| ShellError::TypeMismatch("expected row condition".to_owned(), span))?;
+
+        let block = engine_state.get_block(block_id);
+        let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+        let mut stack = stack.collect_captures(&block.captures);
+
+        let ctrlc = engine_state.ctrlc.clone();
+
+        let mut matched = vec![];
+        let mut rest = vec![];
+
+        for value in input.into_interruptible_iter(ctrlc) {
+            if let Some(var_id) = var_id {
+                stack.add_var(var_id, value.clone());
+            }
+
+            let is_match = eval_block(engine_state, &mut stack, block, PipelineData::new(span))
+                .map_or(false, |pipeline_data| {
+                    pipeline_data.into_value(span).is_true()
+                });
+
+            if is_match {
+                matched.push(value);
+            } else {
+                rest.push(value);
+            }
+        }
+
+        Ok(Value::Record {
+            cols: vec!["matched".into(), "rest".into()],
+            vals: vec![
+                Value::List {
+                    vals: matched,
+                    span,
+                },
+                Value::List { vals: rest, span },
+            ],
+            span,
+        }
+        .into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Partition)
+    }
+}
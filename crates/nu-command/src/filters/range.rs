@@ -20,7 +20,7 @@ impl Command for Range {
             .optional(
                 "rows",
                 SyntaxShape::Range,
-                "range of rows to return: Eg) 4..7 (=> from 4 to 7)",
+                "range of rows to return: Eg) 4..7 (=> from 4 to 7), 0..2..8 (=> every other row)",
             )
             .category(Category::Filters)
     }
@@ -55,6 +55,22 @@ impl Command for Range {
                     span: Span::unknown(),
                 }),
             },
+            Example {
+                example: "[0,1,2,3,4,5] | range 0..2..5",
+                description: "Get every other item",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(2), Value::test_int(4)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "[0,1,2,3,4,5] | range 3..",
+                description: "Get the items from the fourth one on",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(3), Value::test_int(4), Value::test_int(5)],
+                    span: Span::unknown(),
+                }),
+            },
         ]
     }
 
@@ -67,58 +83,75 @@ impl Command for Range {
     ) -> Result<PipelineData, ShellError> {
         let rows: nu_protocol::Range = call.req(engine_state, stack, 0)?;
 
-        let rows_from = get_range_val(rows.from);
-        let rows_to = get_range_val(rows.to);
+        let rows_from = get_range_val(&rows.from).unwrap_or(0);
+        let rows_to = get_range_val(&rows.to);
+        // Stepped ranges like 0..2..10 pick every other row
+        let step = match get_range_val(&rows.incr) {
+            Some(step) if step > 0 => step as usize,
+            _ => 1,
+        };
 
         // only collect the input if we have any negative indices
-        if rows_from < 0 || rows_to < 0 {
+        if rows_from < 0 || matches!(rows_to, Some(to) if to < 0) {
             let v: Vec<_> = input.into_iter().collect();
             let vlen: i64 = v.len() as i64;
 
             let from = if rows_from < 0 {
-                (vlen + rows_from) as usize
+                (vlen + rows_from).max(0) as usize
             } else {
                 rows_from as usize
             };
 
-            let to = if rows_to < 0 {
-                (vlen + rows_to) as usize
-            } else if rows_to > v.len() as i64 {
-                v.len()
-            } else {
-                rows_to as usize
+            let to = match rows_to {
+                Some(to) if to < 0 => vlen + to,
+                Some(to) if to > vlen => vlen,
+                Some(to) => to,
+                None => vlen - 1,
             };
 
-            if from > to {
+            if (from as i64) > to {
                 Ok(PipelineData::Value(
                     Value::Nothing { span: call.head },
                     None,
                 ))
             } else {
-                let iter = v.into_iter().skip(from).take(to - from + 1);
+                let iter = v
+                    .into_iter()
+                    .skip(from)
+                    .take(to as usize - from + 1)
+                    .step_by(step);
                 Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
             }
         } else {
             let from = rows_from as usize;
-            let to = rows_to as usize;
 
-            if from > to {
-                Ok(PipelineData::Value(
+            match rows_to {
+                Some(to) if from > to as usize => Ok(PipelineData::Value(
                     Value::Nothing { span: call.head },
                     None,
-                ))
-            } else {
-                let iter = input.into_iter().skip(from).take(to - from + 1);
-                Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
+                )),
+                Some(to) => {
+                    let iter = input
+                        .into_iter()
+                        .skip(from)
+                        .take(to as usize - from + 1)
+                        .step_by(step);
+                    Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
+                }
+                // Open ranges like 2.. keep streaming the rest of the input
+                None => {
+                    let iter = input.into_iter().skip(from).step_by(step);
+                    Ok(iter.into_pipeline_data(engine_state.ctrlc.clone()))
+                }
             }
         }
     }
 }
 
-fn get_range_val(rows_val: Value) -> i64 {
+fn get_range_val(rows_val: &Value) -> Option<i64> {
     match rows_val {
-        Value::Int { val: x, .. } => x,
-        _ => 0,
+        Value::Int { val: x, .. } => Some(*x),
+        _ => None,
     }
 }
 
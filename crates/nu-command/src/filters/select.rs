@@ -108,7 +108,9 @@ fn select(
                         }
                         Err(error) => {
                             cols.push(path.into_string());
-                            vals.push(Value::Error { error });
+                            vals.push(Value::Error {
+                                error: Box::new(error),
+                            });
                         }
                     }
                 }
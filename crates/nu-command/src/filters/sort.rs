@@ -0,0 +1,141 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Value,
+};
+
+use super::sort_utils::{compare_values, SortOptions};
+
+#[derive(Clone)]
+pub struct Sort;
+
+impl Command for Sort {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("sort")
+            .switch("reverse", "Sort in reverse order", Some('r'))
+            .switch(
+                "insensitive",
+                "Sort string-based values case-insensitively",
+                Some('i'),
+            )
+            .switch(
+                "natural",
+                "Sort strings with numbers in numeric order (file2 before file10)",
+                Some('n'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Sort a list of values."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Values of different types are grouped by type: booleans, numbers, filesizes, durations, dates, ranges, strings, binary, lists, records, and finally nothing."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[2 0 1] | sort",
+                description: "Sort a list of numbers",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(0), Value::test_int(1), Value::test_int(2)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "[2 0 1] | sort -r",
+                description: "Sort a list of numbers in reverse order",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(2), Value::test_int(1), Value::test_int(0)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "['b' 'A' 'c'] | sort -i",
+                description: "Sort strings case-insensitively",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("A"),
+                        Value::test_string("b"),
+                        Value::test_string("c"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "['file10' 'file2' 'file1'] | sort -n",
+                description: "Sort strings containing numbers in numeric order",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("file1"),
+                        Value::test_string("file2"),
+                        Value::test_string("file10"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "['a' 2 1.5] | sort",
+                description: "Sort a list of mixed types",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_float(1.5),
+                        Value::test_int(2),
+                        Value::test_string("a"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let reverse = call.has_flag("reverse");
+        let options = SortOptions {
+            insensitive: call.has_flag("insensitive"),
+            natural: call.has_flag("natural"),
+            collation: None,
+        };
+
+        // Reversing the comparison rather than the sorted values keeps equal values in the
+        // order they came in
+        let mut values: Vec<Value> = input.into_iter().collect();
+        values.sort_by(|a, b| {
+            let ordering = compare_values(a, b, options);
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        Ok(values
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Sort {})
+    }
+}
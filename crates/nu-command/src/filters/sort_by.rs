@@ -0,0 +1,198 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+use super::sort_utils::{compare_values, Collation, SortOptions};
+use std::cmp::Ordering;
+
+#[derive(Clone)]
+pub struct SortBy;
+
+impl Command for SortBy {
+    fn name(&self) -> &str {
+        "sort-by"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("sort-by")
+            .rest("columns", SyntaxShape::CellPath, "the column(s) to sort by")
+            .switch("reverse", "Sort in reverse order", Some('r'))
+            .switch(
+                "natural",
+                "Sort strings with numbers in numeric order (file2 before file10)",
+                Some('n'),
+            )
+            .named(
+                "locale",
+                SyntaxShape::String,
+                "Collate strings following the rules of a locale, eg: sv-SE",
+                Some('l'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Sort by the given columns, in increasing order."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                example: "[[name]; [file10] [file2]] | sort-by name -n",
+                description: "Sort a table by a column with numbers in numeric order",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["name".to_string()],
+                            vals: vec![Value::test_string("file2")],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["name".to_string()],
+                            vals: vec![Value::test_string("file10")],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "[[kind size]; [b 1] [a 2] [a 1]] | sort-by kind size --reverse",
+                description: "Sort a table by several columns, in decreasing order",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["kind".to_string(), "size".to_string()],
+                            vals: vec![Value::test_string("b"), Value::test_int(1)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["kind".to_string(), "size".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_int(2)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["kind".to_string(), "size".to_string()],
+                            vals: vec![Value::test_string("a"), Value::test_int(1)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                example: "[[name]; [ö] [z]] | sort-by name --locale sv-SE",
+                description: "Sort a table using Swedish collation rules",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["name".to_string()],
+                            vals: vec![Value::test_string("z")],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["name".to_string()],
+                            vals: vec![Value::test_string("ö")],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let columns: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let locale: Option<Spanned<String>> = call.get_flag(engine_state, stack, "locale")?;
+        let reverse = call.has_flag("reverse");
+
+        let collation = match locale {
+            Some(locale) => Some(Collation::from_locale(&locale.item).ok_or_else(|| {
+                ShellError::UnsupportedConfigValue(
+                    "a locale like de-DE, es-ES, sv-SE, da-DK or en-US".into(),
+                    locale.item.clone(),
+                    locale.span,
+                )
+            })?),
+            None => None,
+        };
+
+        let options = SortOptions {
+            insensitive: false,
+            natural: call.has_flag("natural"),
+            collation,
+        };
+
+        // Reversing the comparison rather than the sorted rows keeps rows with equal keys in the
+        // order they came in
+        let order = |ordering: Ordering| {
+            if reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        };
+
+        let mut values: Vec<Value> = input.into_iter().collect();
+
+        if columns.is_empty() {
+            values.sort_by(|a, b| order(compare_values(a, b, options)));
+        } else {
+            // Look up the sort keys once per row instead of on every comparison
+            let mut keyed: Vec<(Vec<Value>, Value)> = values
+                .into_iter()
+                .map(|value| {
+                    let keys = columns
+                        .iter()
+                        .map(|column| {
+                            value
+                                .clone()
+                                .follow_cell_path(&column.members)
+                                .unwrap_or_else(|_| Value::nothing(call.head))
+                        })
+                        .collect();
+                    (keys, value)
+                })
+                .collect();
+
+            keyed.sort_by(|(lhs, _), (rhs, _)| {
+                order(
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| compare_values(lhs, rhs, options))
+                        .find(|ordering| ordering.is_ne())
+                        .unwrap_or(Ordering::Equal),
+                )
+            });
+
+            values = keyed.into_iter().map(|(_, value)| value).collect();
+        }
+
+        Ok(values
+            .into_iter()
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SortBy {})
+    }
+}
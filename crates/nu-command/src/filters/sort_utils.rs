@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+
+use nu_protocol::Value;
+
+/// Options shared by the sorting commands to decide how two values are ordered
+#[derive(Clone, Copy, Default)]
+pub struct SortOptions {
+    pub insensitive: bool,
+    pub natural: bool,
+    pub collation: Option<Collation>,
+}
+
+/// Locale-specific rules for ordering letters outside of plain ASCII
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Collation {
+    /// Accented letters sort next to their base letter
+    Generic,
+    /// German: umlauts sort as their base vowel, ß as "ss"
+    German,
+    /// Spanish: ñ is a letter of its own between n and o
+    Spanish,
+    /// Swedish and Finnish: å, ä and ö follow z
+    Swedish,
+    /// Danish and Norwegian: æ, ø and å follow z
+    Danish,
+}
+
+impl Collation {
+    /// Pick the collation rules from a locale tag such as `sv`, `de-DE` or `es_ES`, None if
+    /// the language isn't one these rules are known to fit
+    pub fn from_locale(tag: &str) -> Option<Collation> {
+        let language = tag
+            .split(&['-', '_', '.'][..])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match language.as_str() {
+            "c" | "posix" | "en" | "fr" | "it" | "pt" | "nl" | "ca" => Some(Collation::Generic),
+            "de" => Some(Collation::German),
+            "es" => Some(Collation::Spanish),
+            "sv" | "fi" => Some(Collation::Swedish),
+            "da" | "nb" | "nn" | "no" => Some(Collation::Danish),
+            _ => None,
+        }
+    }
+
+    // Primary collation weights for a lowercase character. Letters that a locale treats as
+    // their own letter get the base they follow plus a rank, anything else folds to its base
+    // letter(s) so accents only matter as a tie-breaker.
+    fn weights(self, c: char) -> Vec<(char, u8)> {
+        match (self, c) {
+            (Collation::Spanish, 'ñ') => vec![('n', 1)],
+            (Collation::Swedish, 'å') => vec![('z', 1)],
+            (Collation::Swedish, 'ä') | (Collation::Swedish, 'æ') => vec![('z', 2)],
+            (Collation::Swedish, 'ö') | (Collation::Swedish, 'ø') => vec![('z', 3)],
+            (Collation::Danish, 'æ') | (Collation::Danish, 'ä') => vec![('z', 1)],
+            (Collation::Danish, 'ø') | (Collation::Danish, 'ö') => vec![('z', 2)],
+            (Collation::Danish, 'å') => vec![('z', 3)],
+            _ => match fold_accents(c) {
+                Some(folded) => folded.chars().map(|c| (c, 0)).collect(),
+                None => vec![(c, 0)],
+            },
+        }
+    }
+
+    /// Compare two strings using the rules of this collation
+    pub fn compare(self, lhs: &str, rhs: &str) -> Ordering {
+        let lhs_lower = lhs.to_lowercase();
+        let rhs_lower = rhs.to_lowercase();
+
+        let lhs_key = lhs_lower.chars().flat_map(|c| self.weights(c));
+        let rhs_key = rhs_lower.chars().flat_map(|c| self.weights(c));
+
+        lhs_key
+            .cmp(rhs_key)
+            // Accents break ties, then lowercase sorts before uppercase
+            .then_with(|| lhs_lower.cmp(&rhs_lower))
+            .then_with(|| rhs.cmp(lhs))
+    }
+}
+
+fn fold_accents(c: char) -> Option<&'static str> {
+    let folded = match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => "e",
+        'ğ' => "g",
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => "i",
+        'ł' | 'ľ' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' | 'ş' => "s",
+        'ß' => "ss",
+        'ť' | 'ţ' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+
+    Some(folded)
+}
+
+// Values of different types are ordered by type first, so that a mixed list still sorts
+// deterministically. Ints and floats share a rank so they are compared numerically.
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Bool { .. } => 0,
+        Value::Int { .. } | Value::Float { .. } => 1,
+        Value::Filesize { .. } => 2,
+        Value::Duration { .. } => 3,
+        Value::Date { .. } => 4,
+        Value::Range { .. } => 5,
+        Value::String { .. } => 6,
+        Value::Binary { .. } => 7,
+        Value::List { .. } => 8,
+        Value::Record { .. } => 9,
+        Value::Block { .. } => 10,
+        Value::CellPath { .. } => 11,
+        Value::CustomValue { .. } => 12,
+        Value::Error { .. } => 13,
+        Value::Nothing { .. } => 14,
+    }
+}
+
+/// Compare two values using the ordering rules of `sort`
+pub fn compare_values(lhs: &Value, rhs: &Value, options: SortOptions) -> Ordering {
+    match (lhs, rhs) {
+        (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => {
+            compare_strings(lhs, rhs, options)
+        }
+        _ => match type_rank(lhs).cmp(&type_rank(rhs)) {
+            Ordering::Equal => lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal),
+            ordering => ordering,
+        },
+    }
+}
+
+/// Compare two strings, optionally ignoring case, treating runs of digits as numbers and
+/// following the collation rules of a locale
+pub fn compare_strings(lhs: &str, rhs: &str, options: SortOptions) -> Ordering {
+    let (lhs, rhs) = if options.insensitive {
+        (lhs.to_lowercase(), rhs.to_lowercase())
+    } else {
+        (lhs.to_string(), rhs.to_string())
+    };
+
+    let text_cmp = |lhs: &str, rhs: &str| match options.collation {
+        Some(collation) => collation.compare(lhs, rhs),
+        None => lhs.cmp(rhs),
+    };
+
+    if options.natural {
+        natural_cmp_by(&lhs, &rhs, text_cmp)
+    } else {
+        text_cmp(&lhs, &rhs)
+    }
+}
+
+// Numeric-aware string ordering, so that "file2" comes before "file10". Both strings are split
+// into runs of digits and runs of other characters. Digit runs are compared by their numeric
+// value, everything else with `text_cmp`.
+fn natural_cmp_by(lhs: &str, rhs: &str, text_cmp: impl Fn(&str, &str) -> Ordering) -> Ordering {
+    let mut lhs = Chunks(lhs);
+    let mut rhs = Chunks(rhs);
+
+    loop {
+        let ordering = match (lhs.next(), rhs.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some((true, l)), Some((true, r))) => {
+                let l_trimmed = l.trim_start_matches('0');
+                let r_trimmed = r.trim_start_matches('0');
+
+                l_trimmed
+                    .len()
+                    .cmp(&r_trimmed.len())
+                    .then_with(|| l_trimmed.cmp(r_trimmed))
+                    .then_with(|| l.len().cmp(&r.len()))
+            }
+            (Some((_, l)), Some((_, r))) => text_cmp(l, r),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+// Iterator over the digit and non-digit runs of a string
+struct Chunks<'a>(&'a str);
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = (bool, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.0.chars().next()?;
+        let is_digit = first.is_ascii_digit();
+
+        let end = self
+            .0
+            .find(|c: char| c.is_ascii_digit() != is_digit)
+            .unwrap_or(self.0.len());
+
+        let (chunk, rest) = self.0.split_at(end);
+        self.0 = rest;
+
+        Some((is_digit, chunk))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn natural_cmp(lhs: &str, rhs: &str) -> Ordering {
+        natural_cmp_by(lhs, rhs, |lhs, rhs| lhs.cmp(rhs))
+    }
+
+    #[test]
+    fn natural_ordering() {
+        assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("file10", "file10"), Ordering::Equal);
+        assert_eq!(natural_cmp("file010", "file10"), Ordering::Greater);
+        assert_eq!(natural_cmp("a", "file1"), Ordering::Less);
+        assert_eq!(natural_cmp("file", "file1"), Ordering::Less);
+    }
+
+    #[test]
+    fn locale_collation() {
+        let swedish = Collation::from_locale("sv_SE").unwrap();
+        assert_eq!(swedish, Collation::Swedish);
+        assert_eq!(swedish.compare("ö", "z"), Ordering::Greater);
+        assert_eq!(swedish.compare("å", "ä"), Ordering::Less);
+
+        let german = Collation::from_locale("de-DE").unwrap();
+        assert_eq!(german.compare("ö", "z"), Ordering::Less);
+        assert_eq!(german.compare("Äpfel", "Birnen"), Ordering::Less);
+        assert_eq!(german.compare("straße", "strasse"), Ordering::Greater);
+
+        let spanish = Collation::from_locale("es").unwrap();
+        assert_eq!(spanish.compare("ñu", "nz"), Ordering::Greater);
+        assert_eq!(spanish.compare("ñu", "oa"), Ordering::Less);
+
+        assert_eq!(
+            Collation::from_locale("fr").unwrap().compare("é", "f"),
+            Ordering::Less
+        );
+
+        assert_eq!(Collation::from_locale("xx-YY"), None);
+    }
+
+    #[test]
+    fn natural_locale_ordering() {
+        let options = SortOptions {
+            natural: true,
+            collation: Some(Collation::Swedish),
+            ..Default::default()
+        };
+
+        assert_eq!(compare_strings("ö2", "ö10", options), Ordering::Less);
+        assert_eq!(compare_strings("ö2", "z10", options), Ordering::Greater);
+    }
+}
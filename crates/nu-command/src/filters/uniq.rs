@@ -3,7 +3,8 @@ use std::collections::VecDeque;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Value,
 };
 
 #[derive(Clone)]
@@ -19,7 +20,7 @@ impl Command for Uniq {
             .switch("count", "Count the unique rows", Some('c'))
             .switch(
                 "repeated",
-                "Count the rows that has more than one value",
+                "Only return the rows that appear more than once",
                 Some('d'),
             )
             .switch(
@@ -67,9 +68,9 @@ impl Command for Uniq {
             },
             Example {
                 description: "Ignore differences in case when comparing",
-                example: "['hello' 'goodbye' 'Hello'] | uniq -i",
+                example: "['Hello' 'goodbye' 'hello'] | uniq -i",
                 result: Some(Value::List {
-                    vals: vec![Value::test_string("hello"), Value::test_string("goodbye")],
+                    vals: vec![Value::test_string("Hello"), Value::test_string("goodbye")],
                     span: Span::unknown(),
                 }),
             },
@@ -96,18 +97,19 @@ impl Command for Uniq {
     }
 }
 
-fn to_lowercase(value: nu_protocol::Value) -> nu_protocol::Value {
+// The value that rows are compared by
+fn comparison_key(value: &Value, ignore_case: bool) -> Value {
     match value {
-        Value::String { val: s, span } => Value::String {
-            val: s.to_lowercase(),
-            span,
+        Value::String { val, span } if ignore_case => Value::String {
+            val: val.to_lowercase(),
+            span: *span,
         },
-        other => other,
+        other => other.clone(),
     }
 }
 
 fn uniq(
-    _engine_state: &EngineState,
+    engine_state: &EngineState,
     _stack: &mut Stack,
     call: &Call,
     input: PipelineData,
@@ -118,37 +120,49 @@ fn uniq(
     let ignore_case = call.has_flag("ignore-case");
     let only_uniques = call.has_flag("unique");
 
-    let uniq_values = {
-        let counter = &mut Vec::new();
-        for line in input.into_iter() {
-            let item = if ignore_case {
-                to_lowercase(line)
-            } else {
-                line
-            };
-
-            if counter.is_empty() {
-                counter.push((item, 1));
-            } else {
-                // check if the value item already exists in our collection. if it does, increase counter, otherwise add it to the collection
-                match counter.iter_mut().find(|x| x.0 == item) {
-                    Some(x) => x.1 += 1,
-                    None => counter.push((item, 1)),
-                }
-            }
+    // Without counting, a row can go out as soon as it is first seen
+    if let PipelineData::Stream(..) = input {
+        if !should_show_count && !show_repeated && !only_uniques {
+            let mut seen = Vec::new();
+
+            return Ok(input
+                .into_iter()
+                .filter(move |value| {
+                    let key = comparison_key(value, ignore_case);
+                    if seen.contains(&key) {
+                        false
+                    } else {
+                        seen.push(key);
+                        true
+                    }
+                })
+                .into_pipeline_data(engine_state.ctrlc.clone()));
         }
-        counter.to_vec()
-    };
+    }
 
-    let uv = uniq_values.to_vec();
-    let mut values = if show_repeated {
-        uv.into_iter().filter(|i| i.1 > 1).collect()
-    } else {
-        uv
-    };
+    // The first occurrence of each row, with the number of times it was seen
+    let mut counter: Vec<(Value, Value, i64)> = Vec::new();
+    for line in input.into_iter() {
+        let key = comparison_key(&line, ignore_case);
+
+        // check if the value item already exists in our collection. if it does, increase counter, otherwise add it to the collection
+        match counter.iter_mut().find(|x| x.0 == key) {
+            Some(x) => x.2 += 1,
+            None => counter.push((key, line, 1)),
+        }
+    }
+
+    let mut values: Vec<(Value, i64)> = counter
+        .into_iter()
+        .map(|(_, value, count)| (value, count))
+        .collect();
+
+    if show_repeated {
+        values.retain(|i| i.1 > 1);
+    }
 
     if only_uniques {
-        values = values.into_iter().filter(|i| i.1 == 1).collect::<_>()
+        values.retain(|i| i.1 == 1);
     }
 
     let mut values_vec_deque = VecDeque::new();
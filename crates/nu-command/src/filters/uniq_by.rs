@@ -0,0 +1,198 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct UniqBy;
+
+impl Command for UniqBy {
+    fn name(&self) -> &str {
+        "uniq-by"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("uniq-by")
+            .rest(
+                "columns",
+                SyntaxShape::String,
+                "the column(s) to compare rows by",
+            )
+            .switch(
+                "last",
+                "Keep the last occurrence of each row instead of the first",
+                Some('l'),
+            )
+            .switch(
+                "count",
+                "Count the rows sharing the same columns",
+                Some('c'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Return the rows that are unique in the given columns."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let columns: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
+
+        if columns.is_empty() {
+            return Err(ShellError::MissingParameter("columns".into(), call.head));
+        }
+
+        uniq_by(engine_state, call, columns, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the first row for every fruit",
+                example: "[[fruit count]; [apple 9] [apple 2] [pear 3]] | uniq-by fruit",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["fruit".to_string(), "count".to_string()],
+                            vals: vec![Value::test_string("apple"), Value::test_int(9)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["fruit".to_string(), "count".to_string()],
+                            vals: vec![Value::test_string("pear"), Value::test_int(3)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Keep the last row for every fruit",
+                example: "[[fruit count]; [apple 9] [apple 2] [pear 3]] | uniq-by fruit --last",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["fruit".to_string(), "count".to_string()],
+                            vals: vec![Value::test_string("apple"), Value::test_int(2)],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["fruit".to_string(), "count".to_string()],
+                            vals: vec![Value::test_string("pear"), Value::test_int(3)],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Count the rows for every fruit",
+                example: "[[fruit]; [apple] [apple] [pear]] | uniq-by fruit -c",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::Record {
+                            cols: vec!["value".to_string(), "count".to_string()],
+                            vals: vec![
+                                Value::Record {
+                                    cols: vec!["fruit".to_string()],
+                                    vals: vec![Value::test_string("apple")],
+                                    span: Span::unknown(),
+                                },
+                                Value::test_int(2),
+                            ],
+                            span: Span::unknown(),
+                        },
+                        Value::Record {
+                            cols: vec!["value".to_string(), "count".to_string()],
+                            vals: vec![
+                                Value::Record {
+                                    cols: vec!["fruit".to_string()],
+                                    vals: vec![Value::test_string("pear")],
+                                    span: Span::unknown(),
+                                },
+                                Value::test_int(1),
+                            ],
+                            span: Span::unknown(),
+                        },
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+}
+
+fn uniq_by(
+    engine_state: &EngineState,
+    call: &Call,
+    columns: Vec<Spanned<String>>,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let keep_last = call.has_flag("last");
+    let should_show_count = call.has_flag("count");
+
+    // Each entry holds the key columns, the row we keep, and how many rows shared the key
+    let mut groups: Vec<(Vec<Value>, Value, i64)> = Vec::new();
+
+    for row in input.into_iter() {
+        let key = columns
+            .iter()
+            .map(|column| match row.get_data_by_key(&column.item) {
+                Some(value) => Ok(value),
+                None => Err(ShellError::CantFindColumn(column.span, row.span()?)),
+            })
+            .collect::<Result<Vec<Value>, ShellError>>()?;
+
+        match groups.iter_mut().find(|group| group.0 == key) {
+            Some(group) => {
+                group.2 += 1;
+                if keep_last {
+                    group.1 = row;
+                }
+            }
+            None => groups.push((key, row, 1)),
+        }
+    }
+
+    let output = groups.into_iter().map(move |(_, row, count)| {
+        if should_show_count {
+            Value::Record {
+                cols: vec!["value".to_string(), "count".to_string()],
+                vals: vec![
+                    row,
+                    Value::Int {
+                        val: count,
+                        span: head,
+                    },
+                ],
+                span: head,
+            }
+        } else {
+            row
+        }
+    });
+
+    Ok(output.into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(UniqBy {})
+    }
+}
@@ -95,12 +95,12 @@ fn update(
                         if let Err(e) =
                             input.replace_data_at_cell_path(&cell_path.members, pd.into_value(span))
                         {
-                            return Value::Error { error: e };
+                            return Value::Error { error: Box::new(e) };
                         }
 
                         input
                     }
-                    Err(e) => Value::Error { error: e },
+                    Err(e) => Value::Error { error: Box::new(e) },
                 }
             },
             ctrlc,
@@ -111,7 +111,7 @@ fn update(
                 let replacement = replacement.clone();
 
                 if let Err(e) = input.replace_data_at_cell_path(&cell_path.members, replacement) {
-                    return Value::Error { error: e };
+                    return Value::Error { error: Box::new(e) };
                 }
 
                 input
@@ -1,7 +1,9 @@
 use nu_engine::eval_block;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, PipelineData, ShellError, Signature, SyntaxShape};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
 
 #[derive(Clone)]
 pub struct Where;
@@ -15,9 +17,18 @@ impl Command for Where {
         "Filter values based on a condition."
     }
 
+    fn extra_usage(&self) -> &str {
+        "Conditions can be combined with 'and' and 'or', and negated with 'not'. 'not' applies to the comparison that follows it, and 'and' binds tighter than 'or'."
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("where")
             .required("cond", SyntaxShape::RowCondition, "condition")
+            .switch(
+                "not",
+                "keep the values that don't match the condition",
+                Some('n'),
+            )
             .category(Category::Filters)
     }
 
@@ -30,6 +41,7 @@ impl Command for Where {
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let cond = &call.positional[0];
         let span = call.head;
+        let invert = call.has_flag("not");
 
         let block_id = cond
             .as_row_condition_block()
@@ -51,11 +63,59 @@ impl Command for Where {
                 let result = eval_block(&engine_state, &mut stack, &block, PipelineData::new(span));
 
                 match result {
-                    Ok(result) => result.into_value(span).is_true(),
+                    Ok(result) => result.into_value(span).is_true() != invert,
                     _ => false,
                 }
             },
             ctrlc,
         )
     }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Keep the rows that match several conditions",
+                example: "[[name size]; [a.rs 10] [b.rs 2000] [c.md 3000]] | where size > 1000 and name =~ '.rs'",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["name".into(), "size".into()],
+                        vals: vec![
+                            Value::test_string("b.rs"),
+                            Value::test_int(2000),
+                        ],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Drop the values that match a condition",
+                example: "[1 2 3 4] | where --not $it > 2",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Negate one of the conditions",
+                example: "[1 2 3 4] | where not $it == 2 and $it < 4",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(3)],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Where {})
+    }
 }
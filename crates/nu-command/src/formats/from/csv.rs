@@ -1,9 +1,9 @@
-use super::delimited::from_delimited_data;
+use super::delimited::{from_delimited_data, DelimitedOptions};
 
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
 
 #[derive(Clone)]
 pub struct FromCsv;
@@ -26,6 +26,22 @@ impl Command for FromCsv {
                 "don't treat the first row as column names",
                 Some('n'),
             )
+            .named(
+                "quote",
+                SyntaxShape::String,
+                "a character to quote fields with, defaults to '\"'",
+                Some('q'),
+            )
+            .switch(
+                "no-quoting",
+                "keep quote characters as part of the fields",
+                None,
+            )
+            .switch(
+                "flexible",
+                "allow rows with missing or extra fields, padding them with nothing and putting extras in an 'extra' column (or 'extra_1' and so on if the header has one)",
+                None,
+            )
             .category(Category::Formats)
     }
 
@@ -65,6 +81,16 @@ impl Command for FromCsv {
                 example: "open data.txt | from csv --separator ';'",
                 result: None,
             },
+            Example {
+                description: "Convert data where some rows have missing or extra fields",
+                example: "open data.txt | from csv --flexible",
+                result: None,
+            },
+            Example {
+                description: "Convert data with fields quoted by single quotes to a table",
+                example: r#"open data.txt | from csv --quote "'""#,
+                result: None,
+            },
         ]
     }
 }
@@ -78,28 +104,46 @@ fn from_csv(
     let name = call.head;
 
     let noheaders = call.has_flag("noheaders");
-    let separator: Option<Value> = call.get_flag(engine_state, stack, "separator")?;
+    let separator: Option<Spanned<String>> = call.get_flag(engine_state, stack, "separator")?;
+    let quote: Option<Spanned<String>> = call.get_flag(engine_state, stack, "quote")?;
     let config = stack.get_config().unwrap_or_default();
 
-    let sep = match separator {
-        Some(Value::String { val: s, span }) => {
-            if s == r"\t" {
-                '\t'
-            } else {
-                let vec_s: Vec<char> = s.chars().collect();
-                if vec_s.len() != 1 {
-                    return Err(ShellError::MissingParameter(
-                        "single character separator".into(),
-                        span,
-                    ));
-                };
-                vec_s[0]
-            }
-        }
-        _ => ',',
+    let options = DelimitedOptions {
+        noheaders,
+        separator: single_char(separator, ',', "separator")?,
+        quote: if call.has_flag("no-quoting") {
+            None
+        } else {
+            Some(single_char(quote, '"', "quote")?)
+        },
+        flexible: call.has_flag("flexible"),
     };
 
-    from_delimited_data(noheaders, sep, input, name, &config)
+    from_delimited_data(options, input, name, &config, engine_state.ctrlc.clone())
+}
+
+fn single_char(
+    flag: Option<Spanned<String>>,
+    default: char,
+    what: &str,
+) -> Result<char, ShellError> {
+    match flag {
+        Some(Spanned { item, span }) => {
+            if item == r"\t" {
+                return Ok('\t');
+            }
+
+            let mut chars = item.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii() => Ok(c),
+                _ => Err(ShellError::MissingParameter(
+                    format!("single character {}", what),
+                    span,
+                )),
+            }
+        }
+        None => Ok(default),
+    }
 }
 
 #[cfg(test)]
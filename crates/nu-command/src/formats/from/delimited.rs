@@ -1,62 +1,169 @@
-use csv::ReaderBuilder;
-use nu_protocol::{Config, IntoPipelineData, PipelineData, ShellError, Span, Value};
-
-fn from_delimited_string_to_value(
-    s: String,
-    noheaders: bool,
-    separator: char,
-    span: Span,
-) -> Result<Value, csv::Error> {
-    let mut reader = ReaderBuilder::new()
-        .has_headers(!noheaders)
-        .delimiter(separator as u8)
-        .from_reader(s.as_bytes());
+use csv::{ErrorKind, ReaderBuilder};
+use nu_protocol::{
+    Config, IntoInterruptiblePipelineData, PipelineData, PipelineIterator, ShellError, Span, Value,
+};
+use std::io::{self, Read};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
-    let headers = if noheaders {
-        (1..=reader.headers()?.len())
-            .map(|i| format!("Column{}", i))
-            .collect::<Vec<String>>()
-    } else {
-        reader.headers()?.iter().map(String::from).collect()
-    };
+/// How the fields of delimited text are split
+pub struct DelimitedOptions {
+    pub noheaders: bool,
+    pub separator: char,
+    /// None turns quoting off, so quote characters are kept as part of the fields
+    pub quote: Option<char>,
+    /// Accept rows with more or fewer fields than the header
+    pub flexible: bool,
+}
 
-    let mut rows = vec![];
-    for row in reader.records() {
-        let mut output_row = vec![];
-        for value in row?.iter() {
-            if let Ok(i) = value.parse::<i64>() {
-                output_row.push(Value::Int { val: i, span });
-            } else if let Ok(f) = value.parse::<f64>() {
-                output_row.push(Value::Float { val: f, span });
-            } else {
-                output_row.push(Value::String {
-                    val: value.into(),
-                    span,
-                });
-            }
+/// Column that holds the fields of a flexible row beyond the ones of the header. If the header
+/// already has it, a number is added to the name until it is unique.
+const EXTRA_COLUMN: &str = "extra";
+
+// Hands the text of the input to the csv reader one value at a time, so a large input never
+// has to be collected into a single string
+struct InputReader {
+    input: PipelineIterator,
+    chunk: Vec<u8>,
+    position: usize,
+    config: Config,
+}
+
+impl Read for InputReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position == self.chunk.len() {
+            self.chunk = match self.input.next() {
+                Some(Value::String { val, .. }) => val.into_bytes(),
+                Some(Value::Binary { val, .. }) => val,
+                Some(Value::Error { error }) => {
+                    return Err(io::Error::new(io::ErrorKind::Other, error.to_string()))
+                }
+                Some(value) => value.into_string("", &self.config).into_bytes(),
+                None => return Ok(0),
+            };
+            self.position = 0;
         }
-        rows.push(Value::Record {
-            cols: headers.clone(),
-            vals: output_row,
+
+        let len = buf.len().min(self.chunk.len() - self.position);
+        buf[..len].copy_from_slice(&self.chunk[self.position..self.position + len]);
+        self.position += len;
+
+        Ok(len)
+    }
+}
+
+fn field_to_value(field: &str, span: Span) -> Value {
+    if let Ok(val) = field.parse::<i64>() {
+        Value::Int { val, span }
+    } else if let Ok(val) = field.parse::<f64>() {
+        Value::Float { val, span }
+    } else {
+        Value::String {
+            val: field.into(),
             span,
-        });
+        }
     }
+}
+
+// The name, or the first of name_1, name_2 and so on that isn't one of the columns
+fn unique_column_name(name: &str, columns: &[String]) -> String {
+    let taken = |candidate: &str| columns.iter().any(|column| column == candidate);
 
-    Ok(Value::List { vals: rows, span })
+    if !taken(name) {
+        return name.into();
+    }
+
+    (1..)
+        .map(|n| format!("{}_{}", name, n))
+        .find(|candidate| !taken(candidate))
+        .expect("internal error: ran out of column names")
 }
 
 pub fn from_delimited_data(
-    noheaders: bool,
-    sep: char,
+    options: DelimitedOptions,
     input: PipelineData,
     name: Span,
     config: &Config,
+    ctrlc: Option<Arc<AtomicBool>>,
 ) -> Result<PipelineData, ShellError> {
-    let concat_string = input.collect_string("", config);
+    let mut reader = ReaderBuilder::new()
+        .has_headers(!options.noheaders)
+        .delimiter(options.separator as u8)
+        .quoting(options.quote.is_some())
+        .quote(options.quote.unwrap_or('"') as u8)
+        .flexible(options.flexible)
+        .from_reader(InputReader {
+            input: input.into_iter(),
+            chunk: vec![],
+            position: 0,
+            config: config.clone(),
+        });
+
+    // Without headers, this reads the first row without consuming it, to count the columns
+    let headers = reader
+        .headers()
+        .map_err(|x| ShellError::DelimiterError(x.to_string(), name))?;
+    let headers = if options.noheaders {
+        (1..=headers.len())
+            .map(|i| format!("Column{}", i))
+            .collect::<Vec<String>>()
+    } else {
+        headers.iter().map(String::from).collect()
+    };
+    let extra_column = unique_column_name(EXTRA_COLUMN, &headers);
+
+    let rows = reader
+        .into_records()
+        .enumerate()
+        .map(move |(idx, row)| match row {
+            Ok(row) => {
+                let mut cols = headers.clone();
+                let mut vals: Vec<Value> = row
+                    .iter()
+                    .map(|field| field_to_value(field, name))
+                    .collect();
+
+                // Only flexible readers let ragged rows through
+                if vals.len() < cols.len() {
+                    vals.resize(cols.len(), Value::nothing(name));
+                } else if vals.len() > cols.len() {
+                    let extra = vals.split_off(cols.len());
+                    cols.push(extra_column.clone());
+                    vals.push(Value::List {
+                        vals: extra,
+                        span: name,
+                    });
+                }
+
+                Value::Record {
+                    cols,
+                    vals,
+                    span: name,
+                }
+            }
+            Err(x) => {
+                let message = match x.kind() {
+                    ErrorKind::UnequalLengths {
+                        pos,
+                        expected_len,
+                        len,
+                    } => format!(
+                        "row {}{} has {} fields but the header has {}, use --flexible to allow this",
+                        idx + 1,
+                        pos.as_ref()
+                            .map(|pos| format!(" (line {})", pos.line()))
+                            .unwrap_or_default(),
+                        len,
+                        expected_len
+                    ),
+                    _ => x.to_string(),
+                };
+
+                Value::Error {
+                    error: Box::new(ShellError::DelimiterError(message, name)),
+                }
+            }
+        });
 
-    Ok(
-        from_delimited_string_to_value(concat_string, noheaders, sep, name)
-            .map_err(|x| ShellError::DelimiterError(x.to_string(), name))?
-            .into_pipeline_data(),
-    )
+    Ok(rows.into_pipeline_data(ctrlc))
 }
@@ -104,10 +104,10 @@ fn from_ics(input: PipelineData, head: Span, config: &Config) -> Result<Pipeline
         match calendar {
             Ok(c) => output.push(calendar_to_value(c, head)),
             Err(_) => output.push(Value::Error {
-                error: ShellError::UnsupportedInput(
+                error: Box::new(ShellError::UnsupportedInput(
                     "input cannot be parsed as .ics".to_string(),
                     head,
-                ),
+                )),
             }),
         }
     }
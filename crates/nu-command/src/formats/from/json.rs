@@ -1,3 +1,6 @@
+use std::collections::VecDeque;
+
+use crate::formats::to::{JSON_BINARY_TAG, JSON_FLOAT_TAG};
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
@@ -20,6 +23,11 @@ impl Command for FromJson {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("from json")
             .switch("objects", "treat each line as a separate value", Some('o'))
+            .switch(
+                "tagged",
+                "read the tagged binary and float objects written by `to json --tagged`",
+                Some('t'),
+            )
             .category(Category::Formats)
     }
 
@@ -64,6 +72,14 @@ impl Command for FromJson {
                     span: Span::unknown(),
                 }),
             },
+            Example {
+                example: "'{ \"$binary\": \"AQL/\" }' | from json --tagged",
+                description: "Reads back tagged binary values written by `to json`",
+                result: Some(Value::Binary {
+                    val: vec![1, 2, 255],
+                    span: Span::unknown(),
+                }),
+            },
         ]
     }
 
@@ -76,35 +92,118 @@ impl Command for FromJson {
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let span = call.head;
         let config = stack.get_config().unwrap_or_default();
-        let mut string_input = input.collect_string("", &config);
-        string_input.push('\n');
+        let tagged = call.has_flag("tagged");
 
         // TODO: turn this into a structured underline of the nu_json error
         if call.has_flag("objects") {
-            #[allow(clippy::needless_collect)]
-            let lines: Vec<String> = string_input.lines().map(|x| x.to_string()).collect();
-            Ok(lines
-                .into_iter()
-                .map(move |mut x| {
-                    x.push('\n');
-                    match convert_string_to_value(x, span) {
-                        Ok(v) => v,
-                        Err(error) => Value::Error { error },
-                    }
-                })
-                .into_pipeline_data(engine_state.ctrlc.clone()))
+            // Streams are parsed as their chunks arrive, so that the whole input never has to
+            // be held in memory at once
+            let ctrlc = engine_state.ctrlc.clone();
+            match input {
+                PipelineData::Stream(stream, ..) => {
+                    Ok(JsonLines::new(stream, span, tagged).into_pipeline_data(ctrlc))
+                }
+                input => {
+                    let string_input = Value::string(input.collect_string("", &config), span);
+                    Ok(JsonLines::new(std::iter::once(string_input), span, tagged)
+                        .into_pipeline_data(ctrlc))
+                }
+            }
         } else {
-            Ok(convert_string_to_value(string_input, span)?.into_pipeline_data())
+            let mut string_input = input.collect_string("", &config);
+            string_input.push('\n');
+
+            Ok(convert_string_to_value(string_input, span, tagged)?.into_pipeline_data())
+        }
+    }
+}
+
+/// Parses one json value per line out of string chunks, as the chunks come in
+struct JsonLines<I> {
+    chunks: I,
+    // The start of a line whose end hasn't arrived yet, kept as bytes so that a character split
+    // between binary chunks is only decoded once it is whole
+    partial: Vec<u8>,
+    lines: VecDeque<String>,
+    span: Span,
+    tagged: bool,
+}
+
+impl<I: Iterator<Item = Value>> JsonLines<I> {
+    fn new(chunks: I, span: Span, tagged: bool) -> Self {
+        JsonLines {
+            chunks,
+            partial: Vec::new(),
+            lines: VecDeque::new(),
+            span,
+            tagged,
+        }
+    }
+
+    fn push_chunk(&mut self, chunk: &[u8]) {
+        self.partial.extend_from_slice(chunk);
+
+        if let Some(end) = self.partial.iter().rposition(|&b| b == b'\n') {
+            let rest = self.partial.split_off(end + 1);
+            let complete = std::mem::replace(&mut self.partial, rest);
+            self.lines
+                .extend(String::from_utf8_lossy(&complete).lines().map(String::from));
         }
     }
 }
 
-fn convert_nujson_to_value(value: &nu_json::Value, span: Span) -> Value {
+impl<I: Iterator<Item = Value>> Iterator for JsonLines<I> {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(mut line) = self.lines.pop_front() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                line.push('\n');
+                return Some(
+                    match convert_string_to_value(line, self.span, self.tagged) {
+                        Ok(v) => v,
+                        Err(error) => Value::Error {
+                            error: Box::new(error),
+                        },
+                    },
+                );
+            }
+
+            match self.chunks.next() {
+                Some(Value::String { val, .. }) => self.push_chunk(val.as_bytes()),
+                Some(Value::Binary { val, .. }) => self.push_chunk(&val),
+                Some(error @ Value::Error { .. }) => return Some(error),
+                Some(other) => {
+                    return Some(Value::Error {
+                        error: Box::new(ShellError::UnsupportedInput(
+                            format!("Expected string input, found {}", other.get_type()),
+                            other.span().unwrap_or(self.span),
+                        )),
+                    })
+                }
+                None if self.partial.is_empty() => return None,
+                None => {
+                    let last = std::mem::take(&mut self.partial);
+                    self.lines
+                        .push_back(String::from_utf8_lossy(&last).into_owned());
+                }
+            }
+        }
+    }
+}
+
+// Single-entry objects with a tag are only decoded when `tagged` is set, as they may just as well
+// be ordinary data
+fn convert_nujson_to_value(value: &nu_json::Value, span: Span, tagged: bool) -> Value {
     match value {
         nu_json::Value::Array(array) => {
             let v: Vec<Value> = array
                 .iter()
-                .map(|x| convert_nujson_to_value(x, span))
+                .map(|x| convert_nujson_to_value(x, span, tagged))
                 .collect();
 
             Value::List { vals: v, span }
@@ -113,25 +212,42 @@ fn convert_nujson_to_value(value: &nu_json::Value, span: Span) -> Value {
         nu_json::Value::F64(f) => Value::Float { val: *f, span },
         nu_json::Value::I64(i) => Value::Int { val: *i, span },
         nu_json::Value::Null => Value::Nothing { span },
-        nu_json::Value::Object(k) => {
-            let mut cols = vec![];
-            let mut vals = vec![];
-
-            for item in k {
-                cols.push(item.0.clone());
-                vals.push(convert_nujson_to_value(item.1, span));
+        nu_json::Value::Object(k) if tagged && k.len() == 1 => match k.iter().next() {
+            Some((tag, nu_json::Value::String(s))) if tag == JSON_BINARY_TAG => {
+                match base64::decode(s) {
+                    Ok(val) => Value::Binary { val, span },
+                    Err(_) => Value::Error {
+                        error: Box::new(ShellError::CantConvert(
+                            "binary".into(),
+                            "base64".into(),
+                            span,
+                        )),
+                    },
+                }
             }
-
-            Value::Record { cols, vals, span }
-        }
+            Some((tag, nu_json::Value::String(s))) if tag == JSON_FLOAT_TAG => {
+                match s.parse::<f64>() {
+                    Ok(val) => Value::Float { val, span },
+                    Err(_) => Value::Error {
+                        error: Box::new(ShellError::CantConvert(
+                            "float".into(),
+                            "string".into(),
+                            span,
+                        )),
+                    },
+                }
+            }
+            _ => convert_nujson_object(k, span, tagged),
+        },
+        nu_json::Value::Object(k) => convert_nujson_object(k, span, tagged),
         nu_json::Value::U64(u) => {
             if *u > i64::MAX as u64 {
                 Value::Error {
-                    error: ShellError::CantConvert(
+                    error: Box::new(ShellError::CantConvert(
                         "i64 sized integer".into(),
                         "larger than i64".into(),
                         span,
-                    ),
+                    )),
                 }
             } else {
                 Value::Int {
@@ -147,10 +263,30 @@ fn convert_nujson_to_value(value: &nu_json::Value, span: Span) -> Value {
     }
 }
 
-fn convert_string_to_value(string_input: String, span: Span) -> Result<Value, ShellError> {
+fn convert_nujson_object(
+    object: &nu_json::Map<String, nu_json::Value>,
+    span: Span,
+    tagged: bool,
+) -> Value {
+    let mut cols = vec![];
+    let mut vals = vec![];
+
+    for item in object {
+        cols.push(item.0.clone());
+        vals.push(convert_nujson_to_value(item.1, span, tagged));
+    }
+
+    Value::Record { cols, vals, span }
+}
+
+fn convert_string_to_value(
+    string_input: String,
+    span: Span,
+    tagged: bool,
+) -> Result<Value, ShellError> {
     let result: Result<nu_json::Value, nu_json::Error> = nu_json::from_str(&string_input);
     match result {
-        Ok(value) => Ok(convert_nujson_to_value(&value, span)),
+        Ok(value) => Ok(convert_nujson_to_value(&value, span, tagged)),
 
         Err(_x) => Err(ShellError::CantConvert(
             "structured data from json".into(),
@@ -170,4 +306,50 @@ mod test {
 
         test_examples(FromJson {})
     }
+
+    #[test]
+    fn objects_split_across_chunks() {
+        let span = Span::unknown();
+        let chunks = vec![
+            Value::string("{\"a\": 1}\n{\"a\"", span),
+            Value::string(": 2}\n\n", span),
+            Value::string("{\"a\": 3}", span),
+        ];
+
+        let values: Vec<Value> = JsonLines::new(chunks.into_iter(), span, false)
+            .map(|v| v.get_data_by_key("a").expect("column a"))
+            .collect();
+
+        assert_eq!(
+            values,
+            vec![
+                Value::Int { val: 1, span },
+                Value::Int { val: 2, span },
+                Value::Int { val: 3, span }
+            ]
+        );
+    }
+
+    #[test]
+    fn character_split_across_binary_chunks() {
+        let span = Span::unknown();
+        let bytes = "{\"a\": \"é\"}\n".as_bytes();
+        // The two bytes of é end up in different chunks
+        let chunks = vec![
+            Value::Binary {
+                val: bytes[..8].to_vec(),
+                span,
+            },
+            Value::Binary {
+                val: bytes[8..].to_vec(),
+                span,
+            },
+        ];
+
+        let values: Vec<Value> = JsonLines::new(chunks.into_iter(), span, false)
+            .map(|v| v.get_data_by_key("a").expect("column a"))
+            .collect();
+
+        assert_eq!(values, vec![Value::string("é", span)]);
+    }
 }
@@ -1,8 +1,8 @@
-use super::delimited::from_delimited_data;
+use super::delimited::{from_delimited_data, DelimitedOptions};
 
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Config, PipelineData, ShellError, Signature};
+use nu_protocol::{Category, Config, Example, PipelineData, ShellError, Signature};
 
 #[derive(Clone)]
 pub struct FromTsv;
@@ -13,37 +13,81 @@ impl Command for FromTsv {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from csv")
+        Signature::build("from tsv")
             .switch(
                 "noheaders",
                 "don't treat the first row as column names",
                 Some('n'),
             )
+            .switch(
+                "no-quoting",
+                "keep quote characters as part of the fields",
+                None,
+            )
+            .switch(
+                "flexible",
+                "allow rows with missing or extra fields, padding them with nothing and putting extras in an 'extra' column (or 'extra_1' and so on if the header has one)",
+                None,
+            )
             .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
-        "Parse text as .csv and create table."
+        "Parse text as .tsv and create table."
     }
 
     fn run(
         &self,
-        _engine_state: &EngineState,
+        engine_state: &EngineState,
         stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let config = stack.get_config().unwrap_or_default();
-        from_tsv(call, input, &config)
+        from_tsv(engine_state, call, input, &config)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Convert tab-separated data to a table",
+                example: "open data.txt | from tsv",
+                result: None,
+            },
+            Example {
+                description: "Convert tab-separated data to a table, ignoring headers",
+                example: "open data.txt | from tsv --noheaders",
+                result: None,
+            },
+            Example {
+                description: "Convert data where quotes are ordinary characters",
+                example: "open data.txt | from tsv --no-quoting",
+                result: None,
+            },
+        ]
     }
 }
 
-fn from_tsv(call: &Call, input: PipelineData, config: &Config) -> Result<PipelineData, ShellError> {
+fn from_tsv(
+    engine_state: &EngineState,
+    call: &Call,
+    input: PipelineData,
+    config: &Config,
+) -> Result<PipelineData, ShellError> {
     let name = call.head;
 
-    let noheaders = call.has_flag("noheaders");
+    let options = DelimitedOptions {
+        noheaders: call.has_flag("noheaders"),
+        separator: '\t',
+        quote: if call.has_flag("no-quoting") {
+            None
+        } else {
+            Some('"')
+        },
+        flexible: call.has_flag("flexible"),
+    };
 
-    from_delimited_data(noheaders, '\t', input, name, config)
+    from_delimited_data(options, input, name, config, engine_state.ctrlc.clone())
 }
 
 #[cfg(test)]
@@ -132,7 +132,10 @@ fn from_vcf(input: PipelineData, head: Span, config: &Config) -> Result<Pipeline
     let iter = parser.map(move |contact| match contact {
         Ok(c) => contact_to_value(c, head),
         Err(_) => Value::Error {
-            error: ShellError::UnsupportedInput("input cannot be parsed as .vcf".to_string(), head),
+            error: Box::new(ShellError::UnsupportedInput(
+                "input cannot be parsed as .vcf".to_string(),
+                head,
+            )),
         },
     });
 
@@ -1,3 +1,4 @@
+use indexmap::IndexMap;
 use itertools::Itertools;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
@@ -6,7 +7,6 @@ use nu_protocol::{
     Spanned, Value,
 };
 use serde::de::Deserialize;
-use std::collections::HashMap;
 
 #[derive(Clone)]
 pub struct FromYaml;
@@ -17,7 +17,13 @@ impl Command for FromYaml {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from yaml").category(Category::Formats)
+        Signature::build("from yaml")
+            .switch(
+                "multi",
+                "always return a list of the documents, even when there is only one",
+                Some('m'),
+            )
+            .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
@@ -60,6 +66,18 @@ impl Command for FromYaml {
                     span: Span::unknown(),
                 }),
             },
+            Example {
+                example: "'a: 1' | from yaml --multi",
+                description: "Converts a yaml stream to a list with one entry per document",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["a".to_string()],
+                        vals: vec![Value::test_int(1)],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
         ]
     }
 
@@ -72,7 +90,7 @@ impl Command for FromYaml {
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let head = call.head;
         let config = stack.get_config().unwrap_or_default();
-        from_yaml(input, head, &config)
+        from_yaml(input, head, &config, call.has_flag("multi"))
     }
 }
 
@@ -85,7 +103,13 @@ impl Command for FromYml {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("from yml").category(Category::Formats)
+        Signature::build("from yml")
+            .switch(
+                "multi",
+                "always return a list of the documents, even when there is only one",
+                Some('m'),
+            )
+            .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
@@ -101,7 +125,7 @@ impl Command for FromYml {
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let head = call.head;
         let config = stack.get_config().unwrap_or_default();
-        from_yaml(input, head, &config)
+        from_yaml(input, head, &config, call.has_flag("multi"))
     }
 }
 
@@ -133,10 +157,7 @@ fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, span: Span) -> Result<V
             }
         }
         serde_yaml::Value::Mapping(t) => {
-            let mut collected = Spanned {
-                item: HashMap::new(),
-                span,
-            };
+            let mut collected: IndexMap<String, Value> = IndexMap::with_capacity(t.len());
 
             for (k, v) in t {
                 // A ShellError that we re-use multiple times in the Mapping scenario
@@ -146,9 +167,14 @@ fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, span: Span) -> Result<V
                 );
                 match (k, v) {
                     (serde_yaml::Value::String(k), _) => {
-                        collected
-                            .item
-                            .insert(k.clone(), convert_yaml_value_to_nu_value(v, span)?);
+                        collected.insert(k.clone(), convert_yaml_value_to_nu_value(v, span)?);
+                    }
+                    // Scalar keys like `1: one` or `true: yes` become columns named after them
+                    (serde_yaml::Value::Number(n), _) => {
+                        collected.insert(n.to_string(), convert_yaml_value_to_nu_value(v, span)?);
+                    }
+                    (serde_yaml::Value::Bool(b), _) => {
+                        collected.insert(b.to_string(), convert_yaml_value_to_nu_value(v, span)?);
                     }
                     // Hard-code fix for cases where "v" is a string without quotations with double curly braces
                     // e.g. k = value
@@ -178,22 +204,24 @@ fn convert_yaml_value_to_nu_value(v: &serde_yaml::Value, span: Span) -> Result<V
                 }
             }
 
-            Value::from(collected)
+            Value::from(Spanned {
+                item: collected,
+                span,
+            })
         }
         serde_yaml::Value::Null => Value::nothing(span),
-        x => unimplemented!("Unsupported yaml case: {:?}", x),
+        // Numbers that are neither an i64 nor an f64 are integers above i64::MAX
+        serde_yaml::Value::Number(n) => {
+            return Err(ShellError::UnsupportedInput(
+                format!("the number {} is too large for an int", n),
+                span,
+            ))
+        }
     })
 }
 
 pub fn from_yaml_string_to_value(s: String, span: Span) -> Result<Value, ShellError> {
-    let mut documents = vec![];
-
-    for document in serde_yaml::Deserializer::from_str(&s) {
-        let v: serde_yaml::Value = serde_yaml::Value::deserialize(document).map_err(|x| {
-            ShellError::UnsupportedInput(format!("Could not load yaml: {}", x), span)
-        })?;
-        documents.push(convert_yaml_value_to_nu_value(&v, span)?);
-    }
+    let mut documents = from_yaml_string_to_documents(s, span)?;
 
     match documents.len() {
         0 => Ok(Value::nothing(span)),
@@ -205,12 +233,37 @@ pub fn from_yaml_string_to_value(s: String, span: Span) -> Result<Value, ShellEr
     }
 }
 
-fn from_yaml(input: PipelineData, head: Span, config: &Config) -> Result<PipelineData, ShellError> {
+/// Every document of a yaml stream, in order
+pub fn from_yaml_string_to_documents(s: String, span: Span) -> Result<Vec<Value>, ShellError> {
+    let mut documents = vec![];
+
+    for document in serde_yaml::Deserializer::from_str(&s) {
+        let v: serde_yaml::Value = serde_yaml::Value::deserialize(document).map_err(|x| {
+            ShellError::UnsupportedInput(format!("Could not load yaml: {}", x), span)
+        })?;
+        documents.push(convert_yaml_value_to_nu_value(&v, span)?);
+    }
+
+    Ok(documents)
+}
+
+fn from_yaml(
+    input: PipelineData,
+    head: Span,
+    config: &Config,
+    multi: bool,
+) -> Result<PipelineData, ShellError> {
     let concat_string = input.collect_string("", config);
 
-    match from_yaml_string_to_value(concat_string, head) {
-        Ok(x) => Ok(x.into_pipeline_data()),
-        Err(other) => Err(other),
+    if multi {
+        let documents = from_yaml_string_to_documents(concat_string, head)?;
+        Ok(Value::List {
+            vals: documents,
+            span: head,
+        }
+        .into_pipeline_data())
+    } else {
+        from_yaml_string_to_value(concat_string, head).map(|x| x.into_pipeline_data())
     }
 }
 
@@ -270,6 +323,17 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_mapping_keeps_order_and_scalar_keys() {
+        let actual = from_yaml_string_to_value("z: 1\n2: b\ntrue: c".into(), Span::unknown())
+            .expect("yaml should parse");
+
+        match actual {
+            Value::Record { cols, .. } => assert_eq!(cols, vec!["z", "2", "true"]),
+            other => panic!("expected a record, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_examples() {
         use crate::test_examples;
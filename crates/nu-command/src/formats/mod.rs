@@ -3,3 +3,18 @@ mod to;
 
 pub use from::*;
 pub use to::*;
+
+/// The format of a media type, which names its `from` and `to` subcommands, if there is one
+pub(crate) fn format_of_content_type(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "application/json" => Some("json"),
+        "text/csv" => Some("csv"),
+        "text/tab-separated-values" => Some("tsv"),
+        "application/xml" | "text/xml" => Some("xml"),
+        "application/yaml" | "application/x-yaml" | "text/yaml" | "text/x-yaml" => Some("yaml"),
+        "application/toml" => Some("toml"),
+        _ if content_type.ends_with("+json") => Some("json"),
+        _ if content_type.ends_with("+xml") => Some("xml"),
+        _ => None,
+    }
+}
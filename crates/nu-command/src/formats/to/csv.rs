@@ -1,10 +1,12 @@
 use crate::formats::to::delimited::to_delimited_data;
+use crate::formats::to::nested::{nested_flags, NestedPolicy};
+use csv::QuoteStyle;
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
-    Value,
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -16,7 +18,7 @@ impl Command for ToCsv {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to csv")
+        nested_flags(Signature::build("to csv")
             .named(
                 "separator",
                 SyntaxShape::String,
@@ -28,6 +30,12 @@ impl Command for ToCsv {
                 "do not output the columns names as the first row",
                 Some('n'),
             )
+            .named(
+                "quote-style",
+                SyntaxShape::String,
+                "which fields are quoted: 'necessary' (default), 'always', 'non-numeric' or 'never'",
+                None,
+            ))
             .category(Category::Formats)
     }
 
@@ -43,6 +51,16 @@ impl Command for ToCsv {
                 example: "[[foo bar]; [1 2]] | to csv -s ';' ",
                 result: Some(Value::test_string("foo;bar\n1;2\n")),
             },
+            Example {
+                description: "Quote every field that isn't a number",
+                example: "[[foo bar]; [a 2]] | to csv --quote-style non-numeric",
+                result: Some(Value::test_string("\"foo\",\"bar\"\n\"a\",2\n")),
+            },
+            Example {
+                description: "Write nested records as columns of their own",
+                example: "[[name meta]; [a {size: 1}]] | to csv --flatten-nested",
+                result: Some(Value::test_string("name,meta.size\na,1\n")),
+            },
         ]
     }
 
@@ -60,8 +78,12 @@ impl Command for ToCsv {
         let head = call.head;
         let noheaders = call.has_flag("noheaders");
         let separator: Option<Spanned<String>> = call.get_flag(engine_state, stack, "separator")?;
+        let quote_style: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "quote-style")?;
+        let policy = NestedPolicy::from_call(engine_state, stack, call)?;
         let config = stack.get_config().unwrap_or_default();
-        to_csv(input, noheaders, separator, head, config)
+        let input = policy.apply(input.into_value(head))?.into_pipeline_data();
+        to_csv(input, noheaders, separator, quote_style, head, config)
     }
 }
 
@@ -69,6 +91,7 @@ fn to_csv(
     input: PipelineData,
     noheaders: bool,
     separator: Option<Spanned<String>>,
+    quote_style: Option<Spanned<String>>,
     head: Span,
     config: Config,
 ) -> Result<PipelineData, ShellError> {
@@ -90,7 +113,23 @@ fn to_csv(
         _ => ',',
     };
 
-    to_delimited_data(noheaders, sep, "CSV", input, head, config)
+    let quote_style = match quote_style {
+        Some(Spanned { item, span }) => match item.as_str() {
+            "necessary" => QuoteStyle::Necessary,
+            "always" => QuoteStyle::Always,
+            "non-numeric" => QuoteStyle::NonNumeric,
+            "never" => QuoteStyle::Never,
+            _ => {
+                return Err(ShellError::UnsupportedInput(
+                    "expected 'necessary', 'always', 'non-numeric' or 'never'".into(),
+                    span,
+                ))
+            }
+        },
+        None => QuoteStyle::Necessary,
+    };
+
+    to_delimited_data(noheaders, sep, quote_style, "CSV", input, head, config)
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use csv::WriterBuilder;
+use csv::{QuoteStyle, WriterBuilder};
 use indexmap::{indexset, IndexSet};
 use nu_protocol::{Config, IntoPipelineData, PipelineData, ShellError, Span, Value};
 use std::collections::VecDeque;
@@ -6,12 +6,14 @@ use std::collections::VecDeque;
 fn from_value_to_delimited_string(
     value: &Value,
     separator: char,
+    quote_style: QuoteStyle,
     config: &Config,
 ) -> Result<String, ShellError> {
     match value {
         Value::Record { cols, vals, span } => {
             let mut wtr = WriterBuilder::new()
                 .delimiter(separator as u8)
+                .quote_style(quote_style)
                 .from_writer(vec![]);
             let mut fields: VecDeque<String> = VecDeque::new();
             let mut values: VecDeque<String> = VecDeque::new();
@@ -36,6 +38,7 @@ fn from_value_to_delimited_string(
         Value::List { vals, span } => {
             let mut wtr = WriterBuilder::new()
                 .delimiter(separator as u8)
+                .quote_style(quote_style)
                 .from_writer(vec![]);
 
             let merged_descriptors = merge_descriptors(vals);
@@ -120,13 +123,14 @@ pub fn merge_descriptors(values: &[Value]) -> Vec<String> {
 pub fn to_delimited_data(
     noheaders: bool,
     sep: char,
+    quote_style: QuoteStyle,
     format_name: &'static str,
     input: PipelineData,
     span: Span,
     config: Config,
 ) -> Result<PipelineData, ShellError> {
     let value = input.into_value(span);
-    let output = match from_value_to_delimited_string(&value, sep, &config) {
+    let output = match from_value_to_delimited_string(&value, sep, quote_style, &config) {
         Ok(mut x) => {
             if noheaders {
                 if let Some(second_line) = x.find('\n') {
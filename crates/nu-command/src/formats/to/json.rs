@@ -1,9 +1,15 @@
+use super::nested::{nested_flags, NestedPolicy};
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
 };
 
+/// Key of the single-entry object that carries a base64 encoded binary value
+pub(crate) const JSON_BINARY_TAG: &str = "$binary";
+/// Key of the single-entry object that carries a NaN or infinite float
+pub(crate) const JSON_FLOAT_TAG: &str = "$float";
+
 #[derive(Clone)]
 pub struct ToJson;
 
@@ -13,7 +19,12 @@ impl Command for ToJson {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to json").category(Category::Formats)
+        nested_flags(Signature::build("to json").switch(
+            "tagged",
+            "encode binary, NaN and infinity as tagged objects that `from json --tagged` reads back",
+            Some('t'),
+        ))
+        .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
@@ -22,31 +33,62 @@ impl Command for ToJson {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
-        to_json(call, input)
+        let policy = NestedPolicy::from_call(engine_state, stack, call)?;
+        to_json(call, input, &policy)
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description:
-                "Outputs an unformatted JSON string representing the contents of this table",
-            example: "[1 2 3] | to json",
-            result: Some(Value::test_string("[\n  1,\n  2,\n  3\n]")),
-        }]
+        vec![
+            Example {
+                description:
+                    "Outputs an unformatted JSON string representing the contents of this table",
+                example: "[1 2 3] | to json",
+                result: Some(Value::test_string("[\n  1,\n  2,\n  3\n]")),
+            },
+            Example {
+                description: "Encode binary as base64 with a type tag, to read it back as binary",
+                example: "'hello' | into binary | to json --tagged",
+                result: None,
+            },
+            Example {
+                description: "Encode infinity, which plain JSON has no number for",
+                example: "'inf' | into decimal | to json --tagged",
+                result: None,
+            },
+            Example {
+                description: "Write nested records as flat keys",
+                example: "{a: {b: 1}} | to json --flatten-nested",
+                result: Some(Value::test_string("{\n  \"a.b\": 1\n}")),
+            },
+        ]
     }
 }
 
-pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
+pub fn value_to_json_value(v: &Value, tagged: bool) -> Result<nu_json::Value, ShellError> {
     Ok(match v {
         Value::Bool { val, .. } => nu_json::Value::Bool(*val),
         Value::Filesize { val, .. } => nu_json::Value::I64(*val),
         Value::Duration { val, .. } => nu_json::Value::I64(*val),
         Value::Date { val, .. } => nu_json::Value::String(val.to_string()),
-        Value::Float { val, .. } => nu_json::Value::F64(*val),
+        Value::Float { val, .. } if val.is_finite() => nu_json::Value::F64(*val),
+        Value::Float { val, span } => {
+            if !tagged {
+                return Err(ShellError::UnsupportedInput(
+                    format!(
+                        "{} can't be represented in JSON, use --tagged to encode it",
+                        val
+                    ),
+                    *span,
+                ));
+            }
+
+            tag(JSON_FLOAT_TAG, val.to_string())
+        }
         Value::Int { val, .. } => nu_json::Value::I64(*val),
         Value::Nothing { .. } => nu_json::Value::Null,
         Value::String { val, .. } => nu_json::Value::String(val.to_string()),
@@ -60,16 +102,17 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
                 .collect::<Result<Vec<nu_json::Value>, ShellError>>()?,
         ),
 
-        Value::List { vals, .. } => nu_json::Value::Array(json_list(vals)?),
-        Value::Error { error } => return Err(error.clone()),
+        Value::List { vals, .. } => nu_json::Value::Array(json_list(vals, tagged)?),
+        Value::Error { error } => return Err(*error.clone()),
         Value::Block { .. } | Value::Range { .. } => nu_json::Value::Null,
+        Value::Binary { val, .. } if tagged => tag(JSON_BINARY_TAG, base64::encode(val)),
         Value::Binary { val, .. } => {
             nu_json::Value::Array(val.iter().map(|x| nu_json::Value::U64(*x as u64)).collect())
         }
         Value::Record { cols, vals, .. } => {
             let mut m = nu_json::Map::new();
             for (k, v) in cols.iter().zip(vals) {
-                m.insert(k.clone(), value_to_json_value(v)?);
+                m.insert(k.clone(), value_to_json_value(v, tagged)?);
             }
             nu_json::Value::Object(m)
         }
@@ -77,22 +120,33 @@ pub fn value_to_json_value(v: &Value) -> Result<nu_json::Value, ShellError> {
     })
 }
 
-fn json_list(input: &[Value]) -> Result<Vec<nu_json::Value>, ShellError> {
+fn json_list(input: &[Value], tagged: bool) -> Result<Vec<nu_json::Value>, ShellError> {
     let mut out = vec![];
 
     for value in input {
-        out.push(value_to_json_value(value)?);
+        out.push(value_to_json_value(value, tagged)?);
     }
 
     Ok(out)
 }
 
-fn to_json(call: &Call, input: PipelineData) -> Result<PipelineData, ShellError> {
+fn tag(name: &str, val: String) -> nu_json::Value {
+    let mut m = nu_json::Map::new();
+    m.insert(name.to_string(), nu_json::Value::String(val));
+    nu_json::Value::Object(m)
+}
+
+fn to_json(
+    call: &Call,
+    input: PipelineData,
+    policy: &NestedPolicy,
+) -> Result<PipelineData, ShellError> {
     let span = call.head;
 
-    let value = input.into_value(span);
+    let tagged = call.has_flag("tagged");
+    let value = policy.apply(input.into_value(span))?;
 
-    let json_value = value_to_json_value(&value)?;
+    let json_value = value_to_json_value(&value, tagged)?;
     match nu_json::to_string(&json_value) {
         Ok(serde_json_string) => Ok(Value::String {
             val: serde_json_string,
@@ -100,7 +154,11 @@ fn to_json(call: &Call, input: PipelineData) -> Result<PipelineData, ShellError>
         }
         .into_pipeline_data()),
         _ => Ok(Value::Error {
-            error: ShellError::CantConvert("JSON".into(), value.get_type().to_string(), span),
+            error: Box::new(ShellError::CantConvert(
+                "JSON".into(),
+                value.get_type().to_string(),
+                span,
+            )),
         }
         .into_pipeline_data()),
     }
@@ -4,6 +4,7 @@ mod delimited;
 mod html;
 mod json;
 mod md;
+mod nested;
 mod toml;
 mod tsv;
 mod url;
@@ -16,6 +17,7 @@ pub use self::url::ToUrl;
 pub use command::To;
 pub use html::ToHtml;
 pub use json::ToJson;
+pub(crate) use json::{value_to_json_value, JSON_BINARY_TAG, JSON_FLOAT_TAG};
 pub use md::ToMd;
 pub use tsv::ToTsv;
 pub use xml::ToXml;
@@ -0,0 +1,179 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{ShellError, Signature, Span, SyntaxShape, Value};
+
+/// Adds the flags that decide how the `to` commands write nothing and nested values
+pub(super) fn nested_flags(signature: Signature) -> Signature {
+    signature
+        .named(
+            "null-value",
+            SyntaxShape::String,
+            "the text to write in place of nothing values",
+            None,
+        )
+        .switch(
+            "flatten-nested",
+            "turn nested records and lists into columns named like 'parent.child' and 'list.0'",
+            None,
+        )
+        .switch(
+            "error-on-nested",
+            "fail when a row holds a nested record or list",
+            None,
+        )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Nested {
+    Keep,
+    Flatten,
+    Error,
+}
+
+/// The values of the flags added by [`nested_flags`]
+pub(super) struct NestedPolicy {
+    null_value: Option<String>,
+    nested: Nested,
+}
+
+impl NestedPolicy {
+    pub(super) fn from_call(
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+    ) -> Result<Self, ShellError> {
+        let nested = match (
+            call.has_flag("flatten-nested"),
+            call.has_flag("error-on-nested"),
+        ) {
+            (true, true) => {
+                return Err(ShellError::IncompatibleParametersSingle(
+                    "--flatten-nested and --error-on-nested can't be used together".into(),
+                    call.head,
+                ))
+            }
+            (true, false) => Nested::Flatten,
+            (false, true) => Nested::Error,
+            (false, false) => Nested::Keep,
+        };
+
+        Ok(NestedPolicy {
+            null_value: call.get_flag(engine_state, stack, "null-value")?,
+            nested,
+        })
+    }
+
+    /// Rewrites the input of a `to` command: the rows of a table, or a single record, get their
+    /// nested values flattened or rejected, then nothing values are replaced everywhere
+    pub(super) fn apply(&self, value: Value) -> Result<Value, ShellError> {
+        let value = match value {
+            Value::List { vals, span } => Value::List {
+                vals: vals
+                    .into_iter()
+                    .map(|row| self.apply_to_row(row))
+                    .collect::<Result<_, _>>()?,
+                span,
+            },
+            other => self.apply_to_row(other)?,
+        };
+
+        Ok(self.replace_nulls(value))
+    }
+
+    fn apply_to_row(&self, row: Value) -> Result<Value, ShellError> {
+        match (self.nested, row) {
+            (Nested::Keep, row) => Ok(row),
+            (Nested::Flatten, Value::Record { cols, vals, span }) => {
+                let mut flat_cols = vec![];
+                let mut flat_vals = vec![];
+                for (col, val) in cols.into_iter().zip(vals) {
+                    flatten_into(col, val, &mut flat_cols, &mut flat_vals);
+                }
+
+                Ok(Value::Record {
+                    cols: flat_cols,
+                    vals: flat_vals,
+                    span,
+                })
+            }
+            (Nested::Flatten, row) => Ok(row),
+            (Nested::Error, Value::Record { cols, vals, span }) => {
+                if let Some((col, val)) = cols
+                    .iter()
+                    .zip(&vals)
+                    .find(|(_, val)| matches!(val, Value::Record { .. } | Value::List { .. }))
+                {
+                    return Err(nested_error(
+                        &format!("column '{}'", col),
+                        val.span().unwrap_or(span),
+                    ));
+                }
+
+                Ok(Value::Record { cols, vals, span })
+            }
+            (Nested::Error, Value::List { span, .. }) => Err(nested_error("a row", span)),
+            (Nested::Error, row) => Ok(row),
+        }
+    }
+
+    fn replace_nulls(&self, value: Value) -> Value {
+        let null_value = match &self.null_value {
+            Some(null_value) => null_value,
+            None => return value,
+        };
+
+        match value {
+            Value::Nothing { span } => Value::String {
+                val: null_value.clone(),
+                span,
+            },
+            Value::List { vals, span } => Value::List {
+                vals: vals.into_iter().map(|v| self.replace_nulls(v)).collect(),
+                span,
+            },
+            Value::Record { cols, vals, span } => Value::Record {
+                cols,
+                vals: vals.into_iter().map(|v| self.replace_nulls(v)).collect(),
+                span,
+            },
+            other => other,
+        }
+    }
+}
+
+// Pushes a cell, or the cells nested inside it with their dotted paths as column names
+fn flatten_into(col: String, val: Value, cols: &mut Vec<String>, vals: &mut Vec<Value>) {
+    match val {
+        Value::Record {
+            cols: inner_cols,
+            vals: inner_vals,
+            ..
+        } => {
+            for (inner_col, inner_val) in inner_cols.into_iter().zip(inner_vals) {
+                flatten_into(format!("{}.{}", col, inner_col), inner_val, cols, vals);
+            }
+        }
+        Value::List {
+            vals: inner_vals, ..
+        } => {
+            for (idx, inner_val) in inner_vals.into_iter().enumerate() {
+                flatten_into(format!("{}.{}", col, idx), inner_val, cols, vals);
+            }
+        }
+        val => {
+            cols.push(col);
+            vals.push(val);
+        }
+    }
+}
+
+fn nested_error(location: &str, span: Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        format!(
+            "{} holds a nested value, use --flatten-nested to write it as columns",
+            location
+        ),
+        span,
+    )
+}
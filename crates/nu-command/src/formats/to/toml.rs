@@ -62,7 +62,7 @@ fn helper(v: &Value) -> Result<toml::Value, ShellError> {
         Value::List { vals, .. } => toml::Value::Array(toml_list(vals)?),
         Value::Block { .. } => toml::Value::String("<Block>".to_string()),
         Value::Nothing { .. } => toml::Value::String("<Nothing>".to_string()),
-        Value::Error { error } => return Err(error.clone()),
+        Value::Error { error } => return Err(*error.clone()),
         Value::Binary { val, .. } => toml::Value::Array(
             val.iter()
                 .map(|x| toml::Value::Integer(*x as i64))
@@ -103,7 +103,11 @@ fn toml_into_pipeline_data(
         }
         .into_pipeline_data()),
         _ => Ok(Value::Error {
-            error: ShellError::CantConvert("TOML".into(), value_type.to_string(), span),
+            error: Box::new(ShellError::CantConvert(
+                "TOML".into(),
+                value_type.to_string(),
+                span,
+            )),
         }
         .into_pipeline_data()),
     }
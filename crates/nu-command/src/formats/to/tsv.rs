@@ -1,7 +1,11 @@
 use crate::formats::to::delimited::to_delimited_data;
+use crate::formats::to::nested::{nested_flags, NestedPolicy};
+use csv::QuoteStyle;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Config, Example, PipelineData, ShellError, Signature, Span, Value};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+};
 
 #[derive(Clone)]
 pub struct ToTsv;
@@ -12,13 +16,12 @@ impl Command for ToTsv {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to tsv")
-            .switch(
-                "noheaders",
-                "do not output the column names as the first row",
-                Some('n'),
-            )
-            .category(Category::Formats)
+        nested_flags(Signature::build("to tsv").switch(
+            "noheaders",
+            "do not output the column names as the first row",
+            Some('n'),
+        ))
+        .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
@@ -35,14 +38,16 @@ impl Command for ToTsv {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
+        engine_state: &EngineState,
         stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let head = call.head;
         let noheaders = call.has_flag("noheaders");
+        let policy = NestedPolicy::from_call(engine_state, stack, call)?;
         let config = stack.get_config().unwrap_or_default();
+        let input = policy.apply(input.into_value(head))?.into_pipeline_data();
         to_tsv(input, noheaders, head, config)
     }
 }
@@ -53,7 +58,15 @@ fn to_tsv(
     head: Span,
     config: Config,
 ) -> Result<PipelineData, ShellError> {
-    to_delimited_data(noheaders, '\t', "TSV", input, head, config)
+    to_delimited_data(
+        noheaders,
+        '\t',
+        QuoteStyle::Necessary,
+        "TSV",
+        input,
+        head,
+        config,
+    )
 }
 
 #[cfg(test)]
@@ -130,7 +130,7 @@ pub fn write_xml_events<W: Write>(
                 }
                 writer
                     .write_event(Event::Start(e))
-                    .expect("Couldn't open XML node");
+                    .map_err(|_| write_error("Couldn't open XML node", span))?;
                 let c = get_children(v);
                 if let Some(c) = c {
                     for v in c {
@@ -139,7 +139,7 @@ pub fn write_xml_events<W: Write>(
                 }
                 writer
                     .write_event(Event::End(BytesEnd::borrowed(k.as_bytes())))
-                    .expect("Couldn't close XML node");
+                    .map_err(|_| write_error("Couldn't close XML node", span))?;
             }
         }
         Value::List { vals, .. } => {
@@ -148,21 +148,35 @@ pub fn write_xml_events<W: Write>(
             }
         }
         _ => {
-            let s = current.clone().into_abbreviated_string(config);
+            let span = current.span()?;
+            let s = current.into_abbreviated_string(config);
             writer
                 .write_event(Event::Text(BytesText::from_plain_str(s.as_str())))
-                .expect("Couldn't write XML text");
+                .map_err(|_| write_error("Couldn't write XML text", span))?;
         }
     }
     Ok(())
 }
 
+fn write_error(msg: &str, span: Span) -> ShellError {
+    ShellError::SpannedLabeledError(msg.into(), "while writing this value".into(), span)
+}
+
 fn to_xml(
     input: PipelineData,
     head: Span,
     pretty: Option<Spanned<i64>>,
     config: &Config,
 ) -> Result<PipelineData, ShellError> {
+    if let Some(Spanned { item, span }) = pretty {
+        if item < 0 {
+            return Err(ShellError::UnsupportedInput(
+                "the indentation can't be negative".into(),
+                span,
+            ));
+        }
+    }
+
     let mut w = pretty.as_ref().map_or_else(
         || quick_xml::Writer::new(Cursor::new(Vec::new())),
         |p| quick_xml::Writer::new_with_indent(Cursor::new(Vec::new()), b' ', p.item as usize),
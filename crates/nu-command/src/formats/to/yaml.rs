@@ -13,7 +13,13 @@ impl Command for ToYaml {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("to yaml").category(Category::Formats)
+        Signature::build("to yaml")
+            .switch(
+                "multi",
+                "write each item of a list as its own document",
+                Some('m'),
+            )
+            .category(Category::Formats)
     }
 
     fn usage(&self) -> &str {
@@ -21,11 +27,18 @@ impl Command for ToYaml {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Outputs an YAML string representing the contents of this table",
-            example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
-            result: Some(Value::test_string("---\n- foo: \"1\"\n  bar: \"2\"\n")),
-        }]
+        vec![
+            Example {
+                description: "Outputs an YAML string representing the contents of this table",
+                example: r#"[[foo bar]; ["1" "2"]] | to yaml"#,
+                result: Some(Value::test_string("---\n- foo: \"1\"\n  bar: \"2\"\n")),
+            },
+            Example {
+                description: "Outputs a YAML stream with one document per row",
+                example: r#"[[foo]; [1] [2]] | to yaml --multi"#,
+                result: Some(Value::test_string("---\nfoo: 1\n---\nfoo: 2\n")),
+            },
+        ]
     }
 
     fn run(
@@ -36,7 +49,7 @@ impl Command for ToYaml {
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, ShellError> {
         let head = call.head;
-        to_yaml(input, head)
+        to_yaml(input, head, call.has_flag("multi"))
     }
 }
 
@@ -71,7 +84,7 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         }
         Value::Block { .. } => serde_yaml::Value::Null,
         Value::Nothing { .. } => serde_yaml::Value::Null,
-        Value::Error { error } => return Err(error.clone()),
+        Value::Error { error } => return Err(*error.clone()),
         Value::Binary { val, .. } => serde_yaml::Value::Sequence(
             val.iter()
                 .map(|x| serde_yaml::Value::Number(serde_yaml::Number::from(*x)))
@@ -92,21 +105,41 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
     })
 }
 
-fn to_yaml(input: PipelineData, head: Span) -> Result<PipelineData, ShellError> {
+fn to_yaml(input: PipelineData, head: Span, multi: bool) -> Result<PipelineData, ShellError> {
     let value = input.into_value(head);
 
-    let yaml_value = value_to_yaml_value(&value)?;
-    match serde_yaml::to_string(&yaml_value) {
-        Ok(serde_yaml_string) => Ok(Value::String {
-            val: serde_yaml_string,
-            span: head,
-        }
-        .into_pipeline_data()),
-        _ => Ok(Value::Error {
-            error: ShellError::CantConvert("YAML".into(), value.get_type().to_string(), head),
+    let documents = match &value {
+        Value::List { vals, .. } if multi => vals.iter().collect(),
+        value => vec![value],
+    };
+
+    // Every document serde_yaml writes starts with its own `---` marker, so they can be joined
+    // as they are
+    let mut yaml = String::new();
+    for document in documents {
+        let yaml_value = value_to_yaml_value(document)?;
+        match serde_yaml::to_string(&yaml_value) {
+            Ok(serde_yaml_string) => yaml.push_str(&serde_yaml_string),
+            _ => return Ok(cant_convert(&value, head)),
         }
-        .into_pipeline_data()),
     }
+
+    Ok(Value::String {
+        val: yaml,
+        span: head,
+    }
+    .into_pipeline_data())
+}
+
+fn cant_convert(value: &Value, head: Span) -> PipelineData {
+    Value::Error {
+        error: Box::new(ShellError::CantConvert(
+            "YAML".into(),
+            value.get_type().to_string(),
+            head,
+        )),
+    }
+    .into_pipeline_data()
 }
 
 #[cfg(test)]
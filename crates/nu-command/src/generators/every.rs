@@ -0,0 +1,207 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::{Block, Call};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone)]
+pub struct Every;
+
+impl Command for Every {
+    fn name(&self) -> &str {
+        "every"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("every")
+            .required(
+                "interval",
+                SyntaxShape::Duration,
+                "time between the start of each run",
+            )
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to run",
+            )
+            .named(
+                "times",
+                SyntaxShape::Int,
+                "stop after running the block this many times",
+                Some('t'),
+            )
+            .category(Category::Generators)
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block repeatedly at an interval, streaming the output of each run."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The block is first run right away, then once every interval until ctrl-c is pressed
+or the --times limit is reached. If a run takes longer than the interval, the next
+run starts as soon as it finishes."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Print the current time every second",
+                example: "every 1sec { date now }",
+                result: None,
+            },
+            Example {
+                description: "Check the size of a directory 10 times, a minute apart",
+                example: "every 1min --times 10 { du target | get apparent }",
+                result: None,
+            },
+            Example {
+                description: "Run a block three times",
+                example: "every 1ms --times 3 { 'tick' }",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("tick"),
+                        Value::test_string("tick"),
+                        Value::test_string("tick"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let interval: Spanned<i64> = call.req(engine_state, stack, 0)?;
+        let block_id = call.positional[1]
+            .as_block()
+            .expect("internal error: expected block");
+        let times: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "times")?;
+
+        if interval.item <= 0 {
+            return Err(ShellError::UnsupportedInput(
+                "the interval must be positive".into(),
+                interval.span,
+            ));
+        }
+
+        let remaining = match times {
+            Some(Spanned { item, span }) if item < 0 => {
+                return Err(ShellError::UnsupportedInput(
+                    "the number of runs can't be negative".into(),
+                    span,
+                ))
+            }
+            Some(Spanned { item, .. }) => Some(item as usize),
+            None => None,
+        };
+
+        let block = engine_state.get_block(block_id).clone();
+        let stack = stack.collect_captures(&block.captures);
+
+        let scheduler = Scheduler {
+            interval: Duration::from_nanos(interval.item as u64),
+            next_run: None,
+            remaining,
+            ctrlc: engine_state.ctrlc.clone(),
+            engine_state: engine_state.clone(),
+            stack,
+            block,
+            span: call.head,
+        };
+
+        Ok(scheduler.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+struct Scheduler {
+    interval: Duration,
+    next_run: Option<Instant>,
+    remaining: Option<usize>,
+    ctrlc: Option<Arc<AtomicBool>>,
+    engine_state: EngineState,
+    stack: Stack,
+    block: Block,
+    span: Span,
+}
+
+impl Scheduler {
+    // Sleep until the next run is due, returning false if ctrl-c was pressed in the meantime
+    fn wait(&self, until: Instant) -> bool {
+        loop {
+            if let Some(ctrlc) = &self.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    return false;
+                }
+            }
+
+            let now = Instant::now();
+            if now >= until {
+                return true;
+            }
+
+            thread::sleep(CTRL_C_CHECK_INTERVAL.min(until - now));
+        }
+    }
+}
+
+impl Iterator for Scheduler {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(remaining) = &mut self.remaining {
+            if *remaining == 0 {
+                return None;
+            }
+            *remaining -= 1;
+        }
+
+        if let Some(next_run) = self.next_run {
+            if !self.wait(next_run) {
+                return None;
+            }
+        }
+
+        let start = Instant::now();
+        self.next_run = Some(start + self.interval);
+
+        let mut stack = self.stack.clone();
+        match eval_block(
+            &self.engine_state,
+            &mut stack,
+            &self.block,
+            PipelineData::new(self.span),
+        ) {
+            Ok(output) => Some(output.into_value(self.span)),
+            Err(error) => Some(Value::Error {
+                error: Box::new(error),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Every {})
+    }
+}
@@ -0,0 +1,195 @@
+use std::sync::atomic::Ordering;
+
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::{Block, Call};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct Generate;
+
+impl Command for Generate {
+    fn name(&self) -> &str {
+        "generate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("generate")
+            .required("initial", SyntaxShape::Any, "initial state for the block")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![SyntaxShape::Any])),
+                "block that turns the current state into an output and the next state",
+            )
+            .category(Category::Generators)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a stream of values by repeatedly running a block on a state."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The block receives the current state and returns a record with an optional 'out'
+field, which is added to the stream, and an optional 'next' field, which becomes the
+state for the following run. The stream ends when the block returns nothing or a
+record without 'next'. Values are only generated as they are consumed, so the
+stream can be infinite."#
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate the numbers from 0 to 10 in steps of 2",
+                example: "generate 0 {|i| if $i <= 10 { {out: $i, next: ($i + 2)} } }",
+                result: None,
+            },
+            Example {
+                description: "Generate the first five Fibonacci numbers",
+                example: "generate [0 1] {|fib| {out: $fib.0, next: [$fib.1, ($fib.0 + $fib.1)]} } | first 5",
+                result: None,
+            },
+            Example {
+                description: "Emit a single value when the block gives no next state",
+                example: "generate 1 {|i| {out: $i} }",
+                result: Some(Value::List {
+                    vals: vec![Value::test_int(1)],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let initial: Value = call.req(engine_state, stack, 0)?;
+        let block_id = call.positional[1]
+            .as_block()
+            .expect("internal error: expected block");
+
+        let block = engine_state.get_block(block_id).clone();
+        let stack = stack.collect_captures(&block.captures);
+
+        let generator = Generator {
+            state: Some(initial),
+            engine_state: engine_state.clone(),
+            stack,
+            block,
+            span: call.head,
+        };
+
+        Ok(generator.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+}
+
+struct Generator {
+    state: Option<Value>,
+    engine_state: EngineState,
+    stack: Stack,
+    block: Block,
+    span: Span,
+}
+
+impl Generator {
+    // Runs the block once, returning the output (if any) and the next state (if any)
+    fn step(&mut self, state: Value) -> Result<(Option<Value>, Option<Value>), ShellError> {
+        if let Some(var) = self.block.signature.get_positional(0) {
+            if let Some(var_id) = &var.var_id {
+                self.stack.add_var(*var_id, state);
+            }
+        }
+
+        let result = eval_block(
+            &self.engine_state,
+            &mut self.stack,
+            &self.block,
+            PipelineData::new(self.span),
+        )?
+        .into_value(self.span);
+
+        match result {
+            Value::Nothing { .. } => Ok((None, None)),
+            Value::Record { cols, vals, span } => {
+                let mut out = None;
+                let mut next = None;
+
+                for (col, val) in cols.into_iter().zip(vals) {
+                    match col.as_str() {
+                        "out" => out = Some(val),
+                        "next" => next = Some(val),
+                        _ => {
+                            return Err(ShellError::UnsupportedInput(
+                                format!(
+                                    "the generate block returned an unexpected field '{}', expected 'out' and 'next'",
+                                    col
+                                ),
+                                span,
+                            ))
+                        }
+                    }
+                }
+
+                Ok((out, next))
+            }
+            other => Err(ShellError::UnsupportedInput(
+                format!(
+                    "the generate block must return a record with 'out' and 'next' fields or nothing, got {}",
+                    other.get_type()
+                ),
+                other.span().unwrap_or(self.span),
+            )),
+        }
+    }
+}
+
+impl Iterator for Generator {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Runs that only produce a next state don't add to the stream, so keep going until
+        // there is an output or the states run out. The stream only checks for ctrl-c between
+        // outputs, so it is checked here too.
+        while let Some(state) = self.state.take() {
+            if let Some(ctrlc) = &self.engine_state.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    return None;
+                }
+            }
+
+            match self.step(state) {
+                Ok((out, next)) => {
+                    self.state = next;
+                    if out.is_some() {
+                        return out;
+                    }
+                }
+                Err(error) => {
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    })
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Generate {})
+    }
+}
@@ -0,0 +1,5 @@
+mod every;
+mod generate;
+
+pub use every::Every;
+pub use generate::Generate;
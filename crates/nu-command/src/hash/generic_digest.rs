@@ -67,10 +67,11 @@ where
                 } else {
                     let mut v = v;
                     for path in &cell_paths {
-                        let ret = v
-                            .update_cell_path(&path.members, Box::new(move |old| action::<D>(old)));
+                        let ret = v.update_cell_path(&path.members, &|old| action::<D>(old));
                         if let Err(error) = ret {
-                            return Value::Error { error };
+                            return Value::Error {
+                                error: Box::new(error),
+                            };
                         }
                     }
                     v
@@ -92,18 +93,22 @@ where
         other => {
             let span = match input.span() {
                 Ok(span) => span,
-                Err(error) => return Value::Error { error },
+                Err(error) => {
+                    return Value::Error {
+                        error: Box::new(error),
+                    }
+                }
             };
 
             return Value::Error {
-                error: ShellError::UnsupportedInput(
+                error: Box::new(ShellError::UnsupportedInput(
                     format!(
                         "Type `{}` is not supported for {} hashing input",
                         other.get_type(),
                         D::name()
                     ),
                     span,
-                ),
+                )),
             };
         }
     };
@@ -0,0 +1,224 @@
+use super::generic_digest::HashDigest;
+use digest::core_api::BlockSizeUser;
+use hmac::{Mac, SimpleHmac};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
+use std::marker::PhantomData;
+
+#[derive(Clone)]
+pub struct GenericHmac<D: HashDigest> {
+    name: String,
+    usage: String,
+    phantom: PhantomData<D>,
+}
+
+impl<D: HashDigest> Default for GenericHmac<D> {
+    fn default() -> Self {
+        Self {
+            name: format!("hash hmac {}", D::name()),
+            usage: format!(
+                "compute an HMAC of a value using the {} hash algorithm",
+                D::name()
+            ),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<D> Command for GenericHmac<D>
+where
+    D: HashDigest + BlockSizeUser + Send + Sync + 'static,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required_named(
+                "key",
+                SyntaxShape::Any,
+                "the secret key, as a string or binary",
+                Some('k'),
+            )
+            .switch(
+                "binary",
+                "output the HMAC as binary instead of a hex string",
+                Some('b'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                format!(
+                    "optionally compute the {} HMAC of data by cell path",
+                    D::name()
+                ),
+            )
+    }
+
+    fn usage(&self) -> &str {
+        &self.usage
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Sign a message with a secret key",
+                example: "'message' | hash hmac sha256 --key 'secret'",
+                result: None,
+            },
+            Example {
+                description: "Check the signature of a webhook payload",
+                example: "(open payload.json --raw | hash hmac sha256 -k $nu.env.WEBHOOK_SECRET) == $signature",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let key: Option<Value> = call.get_flag(engine_state, stack, "key")?;
+        let binary = call.has_flag("binary");
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+
+        let key = match key {
+            Some(Value::String { val, .. }) => val.into_bytes(),
+            Some(Value::Binary { val, .. }) => val,
+            Some(other) => {
+                return Err(ShellError::UnsupportedInput(
+                    format!(
+                        "the key must be a string or binary, got {}",
+                        other.get_type()
+                    ),
+                    other.span()?,
+                ))
+            }
+            None => return Err(ShellError::MissingParameter("key".into(), call.head)),
+        };
+
+        input.map(
+            move |v| {
+                if cell_paths.is_empty() {
+                    action::<D>(&v, &key, binary)
+                } else {
+                    let mut v = v;
+                    for path in &cell_paths {
+                        let ret = v
+                            .update_cell_path(&path.members, &|old| action::<D>(old, &key, binary));
+                        if let Err(error) = ret {
+                            return Value::Error {
+                                error: Box::new(error),
+                            };
+                        }
+                    }
+                    v
+                }
+            },
+            engine_state.ctrlc.clone(),
+        )
+    }
+}
+
+pub fn action<D>(input: &Value, key: &[u8], binary: bool) -> Value
+where
+    D: HashDigest + BlockSizeUser,
+{
+    let (bytes, span) = match input {
+        Value::String { val, span } => (val.as_bytes(), *span),
+        Value::Binary { val, span } => (val.as_slice(), *span),
+        other => {
+            let span = match input.span() {
+                Ok(span) => span,
+                Err(error) => {
+                    return Value::Error {
+                        error: Box::new(error),
+                    }
+                }
+            };
+
+            return Value::Error {
+                error: Box::new(ShellError::UnsupportedInput(
+                    format!(
+                        "Type `{}` is not supported for {} HMAC input",
+                        other.get_type(),
+                        D::name()
+                    ),
+                    span,
+                )),
+            };
+        }
+    };
+
+    let mac = match SimpleHmac::<D>::new_from_slice(key) {
+        Ok(mac) => mac,
+        Err(e) => return invalid_key(e, span),
+    };
+    let output = mac.chain_update(bytes).finalize().into_bytes();
+
+    if binary {
+        Value::Binary {
+            val: output.to_vec(),
+            span,
+        }
+    } else {
+        let val = output.iter().map(|byte| format!("{:02x}", byte)).collect();
+        Value::String { val, span }
+    }
+}
+
+// HMAC accepts keys of any length, so this is not expected to happen
+fn invalid_key(error: digest::InvalidLength, span: Span) -> Value {
+    Value::Error {
+        error: Box::new(ShellError::UnsupportedInput(
+            format!("invalid HMAC key: {}", error),
+            span,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::md5::Md5;
+    use ::sha2::Sha256;
+
+    // Test case 2 of RFC 2104 and RFC 4231
+    const KEY: &[u8] = b"Jefe";
+    const DATA: &str = "what do ya want for nothing?";
+
+    #[test]
+    fn hmac_sha256() {
+        let input = Value::String {
+            val: DATA.to_owned(),
+            span: Span::unknown(),
+        };
+        let expected = Value::String {
+            val: "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843".to_owned(),
+            span: Span::unknown(),
+        };
+        assert_eq!(action::<Sha256>(&input, KEY, false), expected);
+    }
+
+    #[test]
+    fn hmac_md5_binary() {
+        let input = Value::Binary {
+            val: DATA.as_bytes().to_vec(),
+            span: Span::unknown(),
+        };
+        let expected = Value::Binary {
+            val: vec![
+                0x75, 0x0c, 0x78, 0x3e, 0x6a, 0xb0, 0xb5, 0x03, 0xea, 0xa8, 0x6e, 0x31, 0x0a, 0x5d,
+                0xb7, 0x38,
+            ],
+            span: Span::unknown(),
+        };
+        assert_eq!(action::<Md5>(&input, KEY, true), expected);
+    }
+}
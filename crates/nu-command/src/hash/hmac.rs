@@ -0,0 +1,41 @@
+use super::generic_hmac::GenericHmac;
+use ::md5::Md5;
+use ::sha2::Sha256;
+use nu_engine::get_full_help;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, IntoPipelineData, PipelineData, ShellError, Signature, Value};
+
+pub type HashHmacMd5 = GenericHmac<Md5>;
+pub type HashHmacSha256 = GenericHmac<Sha256>;
+
+#[derive(Clone)]
+pub struct HashHmac;
+
+impl Command for HashHmac {
+    fn name(&self) -> &str {
+        "hash hmac"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("hash hmac").category(Category::Hash)
+    }
+
+    fn usage(&self) -> &str {
+        "Compute keyed-hash message authentication codes."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(&Self.signature(), &Self.examples(), engine_state),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
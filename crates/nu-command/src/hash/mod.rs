@@ -1,8 +1,11 @@
 mod command;
 mod generic_digest;
+mod generic_hmac;
+mod hmac;
 mod md5;
 mod sha256;
 
 pub use self::command::Hash;
+pub use self::hmac::{HashHmac, HashHmacMd5, HashHmacSha256};
 pub use self::md5::HashMd5;
 pub use self::sha256::HashSha256;
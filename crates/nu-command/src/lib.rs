@@ -9,6 +9,7 @@ mod experimental;
 mod filesystem;
 mod filters;
 mod formats;
+mod generators;
 mod hash;
 mod math;
 mod network;
@@ -31,6 +32,7 @@ pub use experimental::*;
 pub use filesystem::*;
 pub use filters::*;
 pub use formats::*;
+pub use generators::*;
 pub use hash::*;
 pub use math::*;
 pub use network::*;
@@ -47,3 +49,9 @@ mod dataframe;
 
 #[cfg(feature = "dataframe")]
 pub use dataframe::*;
+
+#[cfg(feature = "sqlite")]
+mod stor;
+
+#[cfg(feature = "sqlite")]
+pub use stor::*;
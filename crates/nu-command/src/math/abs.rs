@@ -63,10 +63,10 @@ fn abs_helper(val: Value, head: Span) -> Value {
             span,
         },
         _ => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 String::from("Only numerical values are supported"),
                 head,
-            ),
+            )),
         },
     }
 }
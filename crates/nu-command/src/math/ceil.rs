@@ -52,10 +52,10 @@ fn operate(value: Value, head: Span) -> Value {
             span,
         },
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 String::from("Only numerical values are supported"),
                 other.span().unwrap_or(head),
-            ),
+            )),
         },
     }
 }
@@ -73,18 +73,18 @@ pub fn eval(
                     match parse(&string, &val.span().unwrap_or_else(|_| Span::unknown())) {
                         Ok(value) => value,
                         Err(err) => Value::Error {
-                            error: ShellError::UnsupportedInput(
+                            error: Box::new(ShellError::UnsupportedInput(
                                 format!("Math evaluation error: {}", err),
                                 val.span().unwrap_or_else(|_| Span::unknown()),
-                            ),
+                            )),
                         },
                     }
                 } else {
                     Value::Error {
-                        error: ShellError::UnsupportedInput(
+                        error: Box::new(ShellError::UnsupportedInput(
                             "Expected a string from pipeline".to_string(),
                             val.span().unwrap_or_else(|_| Span::unknown()),
-                        ),
+                        )),
                     }
                 }
             },
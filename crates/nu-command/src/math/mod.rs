@@ -8,6 +8,7 @@ mod max;
 mod median;
 mod min;
 mod mode;
+mod overflow;
 mod product;
 mod reducers;
 mod round;
@@ -27,6 +28,7 @@ pub use max::SubCommand as MathMax;
 pub use median::SubCommand as MathMedian;
 pub use min::SubCommand as MathMin;
 pub use mode::SubCommand as MathMode;
+pub use overflow::IntArithmetic as MathIntArithmetic;
 pub use product::SubCommand as MathProduct;
 pub use round::SubCommand as MathRound;
 pub use sqrt::SubCommand as MathSqrt;
@@ -0,0 +1,173 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntOverflow, PipelineData, ShellError, Signature, SyntaxShape, Value,
+};
+
+#[derive(Clone, Copy)]
+enum IntOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// `math checked-add` and friends, which pick how integer overflow is handled for one operation
+/// instead of following the `int_overflow` setting of the config
+#[derive(Clone)]
+pub struct IntArithmetic {
+    op: IntOp,
+    overflow: IntOverflow,
+}
+
+impl IntArithmetic {
+    pub fn all() -> Vec<IntArithmetic> {
+        let mut commands = vec![];
+
+        for overflow in [
+            IntOverflow::Checked,
+            IntOverflow::Wrapping,
+            IntOverflow::Saturating,
+        ] {
+            for op in [IntOp::Add, IntOp::Sub, IntOp::Mul] {
+                commands.push(IntArithmetic { op, overflow });
+            }
+        }
+
+        commands
+    }
+
+    fn apply(&self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self.op {
+            IntOp::Add => self.overflow.add(lhs, rhs),
+            IntOp::Sub => self.overflow.sub(lhs, rhs),
+            IntOp::Mul => self.overflow.mul(lhs, rhs),
+        }
+    }
+}
+
+impl Command for IntArithmetic {
+    fn name(&self) -> &str {
+        match (self.overflow, self.op) {
+            (IntOverflow::Checked, IntOp::Add) => "math checked-add",
+            (IntOverflow::Checked, IntOp::Sub) => "math checked-sub",
+            (IntOverflow::Checked, IntOp::Mul) => "math checked-mul",
+            (IntOverflow::Wrapping, IntOp::Add) => "math wrapping-add",
+            (IntOverflow::Wrapping, IntOp::Sub) => "math wrapping-sub",
+            (IntOverflow::Wrapping, IntOp::Mul) => "math wrapping-mul",
+            (IntOverflow::Saturating, IntOp::Add) => "math saturating-add",
+            (IntOverflow::Saturating, IntOp::Sub) => "math saturating-sub",
+            (IntOverflow::Saturating, IntOp::Mul) => "math saturating-mul",
+        }
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .required("operand", SyntaxShape::Int, "the right-hand side integer")
+            .category(Category::Math)
+    }
+
+    fn usage(&self) -> &str {
+        match self.overflow {
+            IntOverflow::Checked => "Integer arithmetic that fails on overflow",
+            IntOverflow::Wrapping => "Integer arithmetic that wraps around on overflow",
+            IntOverflow::Saturating => "Integer arithmetic that stops at the limits on overflow",
+        }
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let operand: i64 = call.req(engine_state, stack, 0)?;
+        let command = self.clone();
+
+        input.map(
+            move |value| match value {
+                Value::Int { val, span } => match command.apply(val, operand) {
+                    Some(val) => Value::Int { val, span },
+                    None => Value::Error {
+                        error: Box::new(ShellError::OperatorOverflow(
+                            format!("{} overflowed", command.name()),
+                            span,
+                        )),
+                    },
+                },
+                Value::Error { .. } => value,
+                other => Value::Error {
+                    error: Box::new(ShellError::UnsupportedInput(
+                        format!("expected an integer, found {}", other.get_type()),
+                        other.span().unwrap_or(head),
+                    )),
+                },
+            },
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let (description, example, result) = match (self.overflow, self.op) {
+            (IntOverflow::Checked, IntOp::Add) => ("Add two integers", "3 | math checked-add 4", 7),
+            (IntOverflow::Checked, IntOp::Sub) => {
+                ("Subtract two integers", "3 | math checked-sub 4", -1)
+            }
+            (IntOverflow::Checked, IntOp::Mul) => {
+                ("Multiply two integers", "3 | math checked-mul 4", 12)
+            }
+            (IntOverflow::Wrapping, IntOp::Add) => (
+                "Wrap around to the smallest integer",
+                "9223372036854775807 | math wrapping-add 1",
+                i64::MIN,
+            ),
+            (IntOverflow::Wrapping, IntOp::Sub) => (
+                "Wrap around to the largest integer",
+                "-9223372036854775807 | math wrapping-sub 2",
+                i64::MAX,
+            ),
+            (IntOverflow::Wrapping, IntOp::Mul) => (
+                "Wrap around to the smallest integer",
+                "4611686018427387904 | math wrapping-mul 2",
+                i64::MIN,
+            ),
+            (IntOverflow::Saturating, IntOp::Add) => (
+                "Stop at the largest integer",
+                "9223372036854775807 | math saturating-add 1",
+                i64::MAX,
+            ),
+            (IntOverflow::Saturating, IntOp::Sub) => (
+                "Stop at the smallest integer",
+                "-9223372036854775807 | math saturating-sub 10",
+                i64::MIN,
+            ),
+            (IntOverflow::Saturating, IntOp::Mul) => (
+                "Stop at the largest integer",
+                "4611686018427387904 | math saturating-mul 4",
+                i64::MAX,
+            ),
+        };
+
+        vec![Example {
+            description,
+            example,
+            result: Some(Value::test_int(result)),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        for command in IntArithmetic::all() {
+            test_examples(command)
+        }
+    }
+}
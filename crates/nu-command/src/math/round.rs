@@ -93,10 +93,10 @@ fn operate(value: Value, head: Span, precision: Option<i64>) -> Value {
         },
         Value::Int { .. } => value,
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 String::from("Only numerical values are supported"),
                 other.span().unwrap_or(head),
-            ),
+            )),
         },
     }
 }
@@ -61,20 +61,20 @@ fn operate(value: Value, head: Span) -> Value {
             Value::Float { val: squared, span }
         }
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 String::from("Only numerical values are supported"),
                 other.span().unwrap_or(head),
-            ),
+            )),
         },
     }
 }
 
 fn error_negative_sqrt(span: Span) -> Value {
     Value::Error {
-        error: ShellError::UnsupportedInput(
+        error: Box::new(ShellError::UnsupportedInput(
             String::from("Can't square root a negative number"),
             span,
-        ),
+        )),
     }
 }
 
@@ -0,0 +1,238 @@
+use std::env::current_dir;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{ShellError, Signature, Span, Spanned, SyntaxShape, Value};
+use reqwest::blocking::ClientBuilder;
+use reqwest::{Certificate, Identity, Proxy, Url};
+
+/// Adds the flags for the TLS settings of the HTTP client
+pub(super) fn client_flags(signature: Signature) -> Signature {
+    signature
+        .switch(
+            "insecure",
+            "don't check the certificate of the server",
+            Some('k'),
+        )
+        .named(
+            "certificate",
+            SyntaxShape::Filepath,
+            "a PEM file of certificate authorities to trust besides the system ones",
+            None,
+        )
+        .named(
+            "identity",
+            SyntaxShape::Filepath,
+            "a PKCS #12 file with the client certificate and key to present",
+            None,
+        )
+        .named(
+            "identity-password",
+            SyntaxShape::String,
+            "the password of the --identity file",
+            None,
+        )
+}
+
+/// The values of the flags added by [`client_flags`], with the proxies set in the environment
+pub(super) struct ClientOptions {
+    insecure: bool,
+    certificate: Option<Spanned<String>>,
+    identity: Option<Spanned<String>>,
+    identity_password: Option<String>,
+    proxies: Proxies,
+}
+
+impl ClientOptions {
+    pub(super) fn from_call(
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+    ) -> Result<Self, ShellError> {
+        Ok(ClientOptions {
+            insecure: call.has_flag("insecure"),
+            certificate: call.get_flag(engine_state, stack, "certificate")?,
+            identity: call.get_flag(engine_state, stack, "identity")?,
+            identity_password: call.get_flag(engine_state, stack, "identity-password")?,
+            proxies: Proxies::from_env(stack, call.head)?,
+        })
+    }
+
+    /// Sets up the certificates and the proxies of a client
+    pub(super) fn apply(&self, mut client: ClientBuilder) -> Result<ClientBuilder, ShellError> {
+        if self.insecure {
+            client = client.danger_accept_invalid_certs(true);
+        }
+        if let Some(path) = &self.certificate {
+            for certificate in read_certificates(path)? {
+                client = client.add_root_certificate(certificate);
+            }
+        }
+        if let Some(path) = &self.identity {
+            let der = read_file(path)?;
+            let password = self.identity_password.as_deref().unwrap_or_default();
+            let identity = Identity::from_pkcs12_der(&der, password).map_err(|err| {
+                ShellError::SpannedLabeledError(
+                    "Could not load the identity".into(),
+                    err.to_string(),
+                    path.span,
+                )
+            })?;
+            client = client.identity(identity);
+        }
+
+        // The environment of the shell decides, not the one nu was started with
+        Ok(if self.proxies.is_empty() {
+            client.no_proxy()
+        } else {
+            let proxies = self.proxies.clone();
+            client.proxy(Proxy::custom(move |url| proxies.for_url(url)))
+        })
+    }
+}
+
+/// The proxies set in the environment, in the variables curl and most other tools read
+#[derive(Clone)]
+struct Proxies {
+    http: Option<Url>,
+    https: Option<Url>,
+    all: Option<Url>,
+    no_proxy: Vec<String>,
+}
+
+impl Proxies {
+    fn from_env(stack: &Stack, head: Span) -> Result<Self, ShellError> {
+        let proxy = |names: &[&str]| match env_string(stack, names) {
+            // A proxy without a scheme is an HTTP one, as in curl
+            Some(proxy) if !proxy.contains("://") => {
+                parse_proxy(&format!("http://{}", proxy), head)
+            }
+            Some(proxy) => parse_proxy(&proxy, head),
+            None => Ok(None),
+        };
+
+        Ok(Proxies {
+            http: proxy(&["http_proxy", "HTTP_PROXY"])?,
+            https: proxy(&["https_proxy", "HTTPS_PROXY"])?,
+            all: proxy(&["all_proxy", "ALL_PROXY"])?,
+            no_proxy: env_string(stack, &["no_proxy", "NO_PROXY"])
+                .map(|hosts| {
+                    hosts
+                        .split(',')
+                        .map(|host| host.trim().trim_start_matches('.').to_lowercase())
+                        .filter(|host| !host.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.http.is_none() && self.https.is_none() && self.all.is_none()
+    }
+
+    /// The proxy to send a request to `url` through, if any
+    fn for_url(&self, url: &Url) -> Option<Url> {
+        let host = url.host_str()?.to_lowercase();
+        let bypassed = self.no_proxy.iter().any(|pattern| {
+            pattern == "*"
+                || host == *pattern
+                || host
+                    .strip_suffix(pattern.as_str())
+                    .map_or(false, |sub| sub.ends_with('.'))
+        });
+        if bypassed {
+            return None;
+        }
+
+        match url.scheme() {
+            "http" => self.http.as_ref(),
+            "https" => self.https.as_ref(),
+            _ => None,
+        }
+        .or_else(|| self.all.as_ref())
+        .cloned()
+    }
+}
+
+// The first of the environment variables that is set to a non-empty string
+fn env_string(stack: &Stack, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| match stack.get_env_var(name) {
+        Some(Value::String { val, .. }) if !val.is_empty() => Some(val),
+        _ => None,
+    })
+}
+
+fn parse_proxy(proxy: &str, head: Span) -> Result<Option<Url>, ShellError> {
+    Url::parse(proxy).map(Some).map_err(|err| {
+        ShellError::SpannedLabeledError(format!("Invalid proxy '{}'", proxy), err.to_string(), head)
+    })
+}
+
+fn read_file(path: &Spanned<String>) -> Result<Vec<u8>, ShellError> {
+    std::fs::read(current_dir()?.join(&path.item)).map_err(|err| {
+        ShellError::SpannedLabeledError("Could not open file".into(), err.to_string(), path.span)
+    })
+}
+
+// Every certificate of a PEM bundle, as a single PEM block only holds one
+fn read_certificates(path: &Spanned<String>) -> Result<Vec<Certificate>, ShellError> {
+    let pem = read_file(path)?;
+    let certificates = String::from_utf8_lossy(&pem)
+        .split_inclusive("-----END CERTIFICATE-----")
+        .filter(|block| block.contains("-----BEGIN CERTIFICATE-----"))
+        .map(|block| {
+            Certificate::from_pem(block.as_bytes()).map_err(|err| {
+                ShellError::SpannedLabeledError(
+                    "Could not load a certificate".into(),
+                    err.to_string(),
+                    path.span,
+                )
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if certificates.is_empty() {
+        return Err(ShellError::SpannedLabeledError(
+            "Could not load a certificate".into(),
+            "no PEM certificates in this file".into(),
+            path.span,
+        ));
+    }
+
+    Ok(certificates)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn proxy_for_url() {
+        let proxy = |url: &str| Some(Url::parse(url).unwrap());
+        let proxies = Proxies {
+            http: proxy("http://plain:3128"),
+            https: None,
+            all: proxy("http://fallback:3128"),
+            no_proxy: vec!["example.com".into()],
+        };
+
+        assert_eq!(
+            proxies.for_url(&Url::parse("http://nushell.sh").unwrap()),
+            proxy("http://plain:3128")
+        );
+        assert_eq!(
+            proxies.for_url(&Url::parse("https://nushell.sh").unwrap()),
+            proxy("http://fallback:3128")
+        );
+        assert_eq!(
+            proxies.for_url(&Url::parse("https://api.example.com").unwrap()),
+            None
+        );
+        assert_eq!(
+            proxies.for_url(&Url::parse("https://notexample.com").unwrap()),
+            proxy("http://fallback:3128")
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use std::env::current_dir;
+use std::io::{ErrorKind, Read};
+use std::sync::atomic::Ordering;
+
+use crate::filesystem::write_atomically;
+use crate::platform::ProgressBar;
+use nu_protocol::engine::EngineState;
+use nu_protocol::{ShellError, Spanned};
+use reqwest::blocking::Response;
+
+// Size of the chunks a body is read in
+pub(super) const CHUNK_SIZE: usize = 8192;
+
+/// Writes a body to a file while it is being downloaded, so it is never held in memory
+///
+/// A progress bar is drawn while the body arrives. The body goes to a temporary file that only
+/// replaces the target once it is complete.
+pub(super) fn download(
+    mut response: Response,
+    output: &Spanned<String>,
+    engine_state: &EngineState,
+    url: &Spanned<String>,
+) -> Result<(), ShellError> {
+    let path = current_dir()?.join(&output.item);
+    let existing = std::fs::metadata(&path).ok();
+
+    let mut progress = ProgressBar::new(response.content_length());
+    let mut buf = vec![0; CHUNK_SIZE];
+    let chunks = std::iter::from_fn(|| loop {
+        if let Some(ctrlc) = &engine_state.ctrlc {
+            if ctrlc.load(Ordering::SeqCst) {
+                return Some(Err(ShellError::Interrupted(url.span)));
+            }
+        }
+
+        match response.read(&mut buf) {
+            Ok(0) => return None,
+            Ok(len) => {
+                progress.advance(len as u64);
+                return Some(Ok(buf[..len].to_vec()));
+            }
+            Err(err) if err.kind() == ErrorKind::Interrupted => {}
+            Err(err) => return Some(Err(ShellError::NetworkFailure(err.to_string(), url.span))),
+        }
+    });
+
+    write_atomically(&path, existing, chunks, output.span)
+}
@@ -0,0 +1,474 @@
+use std::io::{ErrorKind, Read};
+use std::time::Duration;
+
+use crate::formats::format_of_content_type;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, DataSource, DeclId, Example, IntoInterruptiblePipelineData, IntoPipelineData,
+    PipelineData, PipelineMetadata, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::CONTENT_TYPE;
+
+use super::client::{client_flags, ClientOptions};
+use super::download::{download, CHUNK_SIZE};
+
+#[derive(Clone)]
+pub struct Fetch;
+
+impl Command for Fetch {
+    fn name(&self) -> &str {
+        "fetch"
+    }
+
+    fn usage(&self) -> &str {
+        "Fetch the contents from a URL (HTTP GET operation)."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Bodies in a format that has a matching 'from' command, like JSON, are parsed into
+structured data. The format is picked from the Content-Type of the response, or else from
+the extension of the URL. Other text is returned as a string, and binary bodies are
+streamed as they arrive.
+
+With --output, the body is written to a file as it arrives instead, with a progress bar
+on the terminal. A file that is already there is only replaced once the download is
+complete.
+
+Requests go through the proxies set in HTTP_PROXY, HTTPS_PROXY and ALL_PROXY, except for
+the hosts listed in NO_PROXY. The lowercase names are read too."#
+    }
+
+    fn signature(&self) -> Signature {
+        request_flags(
+            Signature::build("fetch")
+                .required(
+                    "url",
+                    SyntaxShape::String,
+                    "the URL to fetch the contents from",
+                )
+                .named(
+                    "output",
+                    SyntaxShape::Filepath,
+                    "download the body to this file instead of returning it",
+                    Some('o'),
+                ),
+        )
+        .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let url: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let output: Option<Spanned<String>> = call.get_flag(engine_state, stack, "output")?;
+        let options = RequestOptions::from_call(engine_state, stack, call)?;
+        let head = call.head;
+
+        let request = options.client(head)?.get(&url.item);
+        let response = options
+            .apply(request, stack, head)?
+            .send()
+            .map_err(|err| ShellError::NetworkFailure(err.to_string(), url.span))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ShellError::NetworkFailure(
+                format!("the server responded with {}", status),
+                url.span,
+            ));
+        }
+
+        if let Some(output) = output {
+            download(response, &output, engine_state, &url)?;
+            return Ok(PipelineData::new(head));
+        }
+
+        let body = ResponseBody::new(&response, engine_state);
+        if !body.is_text() {
+            let chunks = BodyChunks {
+                response,
+                span: head,
+                done: false,
+            };
+
+            return Ok(chunks
+                .into_pipeline_data(engine_state.ctrlc.clone())
+                .set_metadata(body.metadata()));
+        }
+
+        let bytes = response
+            .bytes()
+            .map_err(|err| ShellError::NetworkFailure(err.to_string(), url.span))?;
+
+        body.parse(bytes.to_vec(), options.raw, engine_state, stack, head)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Fetch JSON and get a field of it",
+                example:
+                    "fetch https://api.github.com/repos/nushell/nushell | get stargazers_count",
+                result: None,
+            },
+            Example {
+                description: "Fetch a page with a custom header, giving up after five seconds",
+                example: "fetch -H [Accept text/plain] -t 5sec https://example.com",
+                result: None,
+            },
+            Example {
+                description: "Fetch a file that needs basic authentication",
+                example: "fetch -u myuser -p mypass https://example.com/data.csv",
+                result: None,
+            },
+            Example {
+                description: "Download a large file to disk, showing the progress",
+                example: "fetch -o nu.tar.gz https://example.com/nu.tar.gz",
+                result: None,
+            },
+            Example {
+                description: "Fetch from a server whose certificate is signed by a company CA",
+                example: "fetch --certificate company-ca.pem https://intranet.example.com",
+                result: None,
+            },
+            Example {
+                description: "Fetch through a proxy",
+                example:
+                    "let-env HTTPS_PROXY = http://proxy.example.com:3128; fetch https://example.com",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Adds the flags shared by the commands that make HTTP requests
+pub(super) fn request_flags(signature: Signature) -> Signature {
+    client_flags(
+        signature
+            .named(
+                "user",
+                SyntaxShape::String,
+                "the username when authenticating",
+                Some('u'),
+            )
+            .named(
+                "password",
+                SyntaxShape::String,
+                "the password when authenticating",
+                Some('p'),
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "how long to wait for a response",
+                Some('t'),
+            )
+            .named(
+                "headers",
+                SyntaxShape::Any,
+                "custom headers to send, as a record or a list of names and values",
+                Some('H'),
+            )
+            .switch(
+                "raw",
+                "return the body as text or binary, without parsing it",
+                Some('r'),
+            ),
+    )
+}
+
+/// The values of the flags added by [`request_flags`]
+pub(super) struct RequestOptions {
+    user: Option<String>,
+    password: Option<Spanned<String>>,
+    timeout: Option<Spanned<i64>>,
+    headers: Option<Value>,
+    pub(super) raw: bool,
+    client_options: ClientOptions,
+}
+
+impl RequestOptions {
+    pub(super) fn from_call(
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+    ) -> Result<Self, ShellError> {
+        Ok(RequestOptions {
+            user: call.get_flag(engine_state, stack, "user")?,
+            password: call.get_flag(engine_state, stack, "password")?,
+            timeout: call.get_flag(engine_state, stack, "timeout")?,
+            headers: call.get_flag(engine_state, stack, "headers")?,
+            raw: call.has_flag("raw"),
+            client_options: ClientOptions::from_call(engine_state, stack, call)?,
+        })
+    }
+
+    pub(super) fn client(&self, head: Span) -> Result<Client, ShellError> {
+        let mut client = Client::builder();
+        if let Some(timeout) = &self.timeout {
+            if timeout.item <= 0 {
+                return Err(ShellError::UnsupportedInput(
+                    "the timeout must be positive".into(),
+                    timeout.span,
+                ));
+            }
+            client = client.timeout(Duration::from_nanos(timeout.item as u64));
+        }
+
+        self.client_options
+            .apply(client)?
+            .build()
+            .map_err(|err| ShellError::NetworkFailure(err.to_string(), head))
+    }
+
+    /// Adds the authentication and the custom headers to a request
+    pub(super) fn apply(
+        &self,
+        mut request: RequestBuilder,
+        stack: &Stack,
+        head: Span,
+    ) -> Result<RequestBuilder, ShellError> {
+        match (&self.user, &self.password) {
+            (Some(user), password) => {
+                request = request.basic_auth(user, password.as_ref().map(|password| &password.item))
+            }
+            (None, Some(password)) => {
+                return Err(ShellError::MissingParameter(
+                    "--user is needed with --password".into(),
+                    password.span,
+                ))
+            }
+            (None, None) => {}
+        }
+
+        if let Some(headers) = &self.headers {
+            let config = stack.get_config().unwrap_or_default();
+            for (name, value) in header_pairs(headers.clone(), &config, head)? {
+                request = request.header(name, value);
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+/// How the body of a response is turned into values, decided from its headers and URL
+pub(super) struct ResponseBody {
+    content_type: Option<String>,
+    converter: Option<DeclId>,
+}
+
+impl ResponseBody {
+    pub(super) fn new(response: &Response, engine_state: &EngineState) -> Self {
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map(mime_essence);
+
+        let format = content_type
+            .as_deref()
+            .and_then(format_of_content_type)
+            .map(String::from)
+            .or_else(|| {
+                let path = response.url().path();
+                let file_name = path.rsplit('/').next().unwrap_or(path);
+                file_name
+                    .rsplit_once('.')
+                    .map(|(_, ext)| ext.to_lowercase())
+            });
+
+        let converter =
+            format.and_then(|format| engine_state.find_decl(format!("from {}", format).as_bytes()));
+
+        ResponseBody {
+            content_type,
+            converter,
+        }
+    }
+
+    pub(super) fn is_text(&self) -> bool {
+        self.converter.is_some()
+            || matches!(&self.content_type, Some(content_type) if is_text_content_type(content_type))
+    }
+
+    /// Parses a downloaded body with its matching `from` command, unless `raw` is set
+    pub(super) fn parse(
+        &self,
+        bytes: Vec<u8>,
+        raw: bool,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        head: Span,
+    ) -> Result<PipelineData, ShellError> {
+        let contents = match String::from_utf8(bytes) {
+            Ok(val) if self.is_text() => Value::String { val, span: head },
+            Ok(val) => Value::Binary {
+                val: val.into_bytes(),
+                span: head,
+            },
+            Err(err) => Value::Binary {
+                val: err.into_bytes(),
+                span: head,
+            },
+        };
+
+        match self.converter {
+            Some(decl_id) if !raw && matches!(contents, Value::String { .. }) => {
+                let mut from_call = Call::new();
+                from_call.decl_id = decl_id;
+                from_call.head = head;
+
+                engine_state.get_decl(decl_id).run(
+                    engine_state,
+                    stack,
+                    &from_call,
+                    contents.into_pipeline_data(),
+                )
+            }
+            _ => Ok(contents.into_pipeline_data().set_metadata(self.metadata())),
+        }
+    }
+
+    /// Tags a body that wasn't parsed with its media type, so `save` can tell what it holds
+    pub(super) fn metadata(&self) -> Option<PipelineMetadata> {
+        self.content_type
+            .as_ref()
+            .map(|content_type| PipelineMetadata {
+                data_source: DataSource::None,
+                content_type: Some(content_type.clone()),
+            })
+    }
+}
+
+// The headers to send, from either a record or a flat list of names and values
+fn header_pairs(
+    headers: Value,
+    config: &Config,
+    head: Span,
+) -> Result<Vec<(String, String)>, ShellError> {
+    match headers {
+        Value::Record { cols, vals, .. } => Ok(cols
+            .into_iter()
+            .zip(vals)
+            .map(|(name, value)| (name, value.into_string("", config)))
+            .collect()),
+        Value::List { vals, span } => {
+            if vals.len() % 2 != 0 {
+                return Err(ShellError::UnsupportedInput(
+                    "headers need a value for each name".into(),
+                    span,
+                ));
+            }
+
+            Ok(vals
+                .chunks(2)
+                .map(|pair| {
+                    (
+                        pair[0].clone().into_string("", config),
+                        pair[1].clone().into_string("", config),
+                    )
+                })
+                .collect())
+        }
+        other => Err(ShellError::UnsupportedInput(
+            format!(
+                "headers must be a record or a list, found {}",
+                other.get_type()
+            ),
+            other.span().unwrap_or(head),
+        )),
+    }
+}
+
+// The media type of a Content-Type header, without parameters like the charset
+pub(super) fn mime_essence(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+fn is_text_content_type(content_type: &str) -> bool {
+    content_type.starts_with("text/")
+        || matches!(
+            content_type,
+            "application/javascript" | "application/x-www-form-urlencoded"
+        )
+}
+
+// Streams a body as binary chunks while it is being downloaded
+struct BodyChunks {
+    response: Response,
+    span: Span,
+    done: bool,
+}
+
+impl Iterator for BodyChunks {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        if self.done {
+            return None;
+        }
+
+        let mut buf = vec![0; CHUNK_SIZE];
+        loop {
+            match self.response.read(&mut buf) {
+                Ok(0) => return None,
+                Ok(len) => {
+                    buf.truncate(len);
+                    return Some(Value::Binary {
+                        val: buf,
+                        span: self.span,
+                    });
+                }
+                // A read that a signal interrupted is tried again
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => {
+                    self.done = true;
+                    return Some(Value::Error {
+                        error: Box::new(ShellError::NetworkFailure(err.to_string(), self.span)),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn content_type_formats() {
+        assert_eq!(
+            format_of_content_type(&mime_essence("application/json; charset=utf-8")),
+            Some("json")
+        );
+        assert_eq!(
+            format_of_content_type("application/vnd.github+json"),
+            Some("json")
+        );
+        assert_eq!(format_of_content_type("image/png"), None);
+        assert!(is_text_content_type(&mime_essence(
+            "text/html; charset=UTF-8"
+        )));
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Fetch {})
+    }
+}
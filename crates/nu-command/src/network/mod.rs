@@ -1,3 +1,15 @@
+#[cfg(feature = "network")]
+mod client;
+#[cfg(feature = "network")]
+mod download;
+#[cfg(feature = "network")]
+mod fetch;
+#[cfg(feature = "network")]
+mod post;
 mod url;
 
 pub use self::url::*;
+#[cfg(feature = "network")]
+pub use fetch::Fetch;
+#[cfg(feature = "network")]
+pub use post::Post;
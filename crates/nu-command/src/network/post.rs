@@ -0,0 +1,238 @@
+use indexmap::IndexMap;
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
+};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+
+use super::fetch::{mime_essence, request_flags, RequestOptions, ResponseBody};
+use crate::formats::value_to_json_value;
+
+const JSON: &str = "application/json";
+const FORM: &str = "application/x-www-form-urlencoded";
+
+#[derive(Clone)]
+pub struct Post;
+
+impl Command for Post {
+    fn name(&self) -> &str {
+        "post"
+    }
+
+    fn usage(&self) -> &str {
+        "Send the input to a URL (HTTP POST operation)."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Records and lists are sent as JSON, or form-encoded when the content type is
+'application/x-www-form-urlencoded'. Strings and binary data are sent as they are.
+
+The output is a record with the status, the headers and the body of the response. Bodies
+in a format that has a matching 'from' command are parsed, like with 'fetch'."#
+    }
+
+    fn signature(&self) -> Signature {
+        request_flags(
+            Signature::build("post")
+                .required("url", SyntaxShape::String, "the URL to send the input to")
+                .named(
+                    "content-type",
+                    SyntaxShape::String,
+                    "the media type of the body, which also picks how records are encoded",
+                    Some('c'),
+                )
+                .named(
+                    "method",
+                    SyntaxShape::String,
+                    "the HTTP method to use instead of POST, like PUT or PATCH",
+                    Some('m'),
+                ),
+        )
+        .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let url: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let content_type: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "content-type")?;
+        let method: Option<Spanned<String>> = call.get_flag(engine_state, stack, "method")?;
+        let options = RequestOptions::from_call(engine_state, stack, call)?;
+        let head = call.head;
+        let config = stack.get_config().unwrap_or_default();
+
+        let method = match method {
+            Some(method) => {
+                Method::from_bytes(method.item.to_uppercase().as_bytes()).map_err(|_| {
+                    ShellError::UnsupportedInput("not a valid HTTP method".into(), method.span)
+                })?
+            }
+            None => Method::POST,
+        };
+
+        let (content_type, body) = encode_body(input.into_value(head), content_type, &config)?;
+
+        let mut request = options.client(head)?.request(method, &url.item);
+        if let Some(content_type) = content_type {
+            request = request.header(CONTENT_TYPE, content_type);
+        }
+        let response = options
+            .apply(request.body(body), stack, head)?
+            .send()
+            .map_err(|err| ShellError::NetworkFailure(err.to_string(), url.span))?;
+
+        let status = Value::Int {
+            val: response.status().as_u16() as i64,
+            span: head,
+        };
+        // A header that is sent several times, like set-cookie, has its values joined
+        let mut headers: IndexMap<String, Value> = IndexMap::new();
+        for (name, value) in response.headers() {
+            let value = String::from_utf8_lossy(value.as_bytes()).to_string();
+            let value = match headers.get(name.as_str()) {
+                Some(Value::String { val, .. }) => format!("{}, {}", val, value),
+                _ => value,
+            };
+            headers.insert(
+                name.as_str().to_string(),
+                Value::String {
+                    val: value,
+                    span: head,
+                },
+            );
+        }
+        let headers = Value::from(Spanned {
+            item: headers,
+            span: head,
+        });
+
+        let body_kind = ResponseBody::new(&response, engine_state);
+        let bytes = response
+            .bytes()
+            .map_err(|err| ShellError::NetworkFailure(err.to_string(), url.span))?;
+        let body = body_kind
+            .parse(bytes.to_vec(), options.raw, engine_state, stack, head)?
+            .into_value(head);
+
+        Ok(Value::Record {
+            cols: vec!["status".into(), "headers".into(), "body".into()],
+            vals: vec![status, headers, body],
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Send a record as JSON and get the parsed response",
+                example: "{name: nu} | post https://httpbin.org/post | get body",
+                result: None,
+            },
+            Example {
+                description: "Submit a form",
+                example: "{q: nushell} | post -c application/x-www-form-urlencoded https://example.com/search",
+                result: None,
+            },
+            Example {
+                description: "Replace a resource with the contents of a file",
+                example: "open --raw data.csv | post -m put -c text/csv https://example.com/data",
+                result: None,
+            },
+        ]
+    }
+}
+
+// The content type to send and the encoded body of the input
+fn encode_body(
+    value: Value,
+    content_type: Option<Spanned<String>>,
+    config: &Config,
+) -> Result<(Option<String>, Vec<u8>), ShellError> {
+    let span = value.span().unwrap_or_else(|_| Span::unknown());
+    let requested = content_type
+        .as_ref()
+        .map(|content_type| mime_essence(&content_type.item));
+
+    match value {
+        Value::Record { cols, vals, .. } if requested.as_deref() == Some(FORM) => {
+            let pairs: Vec<(String, String)> = cols
+                .into_iter()
+                .zip(vals)
+                .map(|(col, val)| (col, val.into_string("", config)))
+                .collect();
+            let body = serde_urlencoded::to_string(pairs)
+                .map_err(|_| ShellError::CantConvert("form data".into(), "record".into(), span))?;
+
+            Ok((content_type.map(|c| c.item), body.into_bytes()))
+        }
+        Value::Record { .. } | Value::List { .. } => match requested.as_deref() {
+            None | Some(JSON) => {
+                let json = value_to_json_value(&value, false)?;
+                // nu_json writes Hjson, which quotes some strings in ways JSON doesn't allow
+                let body = serde_json::to_string(&json).map_err(|_| {
+                    ShellError::CantConvert("JSON".into(), value.get_type().to_string(), span)
+                })?;
+
+                Ok((
+                    Some(content_type.map_or_else(|| JSON.into(), |c| c.item)),
+                    body.into_bytes(),
+                ))
+            }
+            Some(_) => Err(ShellError::UnsupportedInput(
+                format!(
+                    "can't send {} as {}, convert it to text first",
+                    value.get_type(),
+                    content_type.map(|c| c.item).unwrap_or_default()
+                ),
+                span,
+            )),
+        },
+        Value::Binary { val, .. } => Ok((
+            Some(content_type.map_or_else(|| "application/octet-stream".into(), |c| c.item)),
+            val,
+        )),
+        Value::Nothing { .. } => Ok((content_type.map(|c| c.item), vec![])),
+        Value::Error { error } => Err(*error),
+        other => Ok((
+            Some(content_type.map_or_else(|| "text/plain; charset=utf-8".into(), |c| c.item)),
+            other.into_string("", config).into_bytes(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn records_are_sent_as_json() {
+        let record = Value::Record {
+            cols: vec!["a".into()],
+            vals: vec![Value::test_string("say \"hi\"\\\n")],
+            span: Span::unknown(),
+        };
+
+        let (content_type, body) = encode_body(record, None, &Config::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(content_type.as_deref(), Some(JSON));
+        assert_eq!(json["a"], "say \"hi\"\\\n");
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Post {})
+    }
+}
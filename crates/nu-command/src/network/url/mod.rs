@@ -45,10 +45,10 @@ where
                 Ok(s) => {
                     let got = format!("Expected a string, got {} instead", other.get_type());
                     Value::Error {
-                        error: ShellError::UnsupportedInput(got, s),
+                        error: Box::new(ShellError::UnsupportedInput(got, s)),
                     }
                 }
-                Err(e) => Value::Error { error: e },
+                Err(e) => Value::Error { error: Box::new(e) },
             }
         }
     }
@@ -75,12 +75,12 @@ where
                 let mut ret = v;
 
                 for path in &column_paths {
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| handle_value(&action, old, span)),
-                    );
+                    let r = ret
+                        .update_cell_path(&path.members, &|old| handle_value(&action, old, span));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
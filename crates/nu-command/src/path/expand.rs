@@ -111,12 +111,12 @@ fn expand(path: &Path, span: Span, args: &Arguments) -> Value {
         Value::string(p.to_string_lossy(), span)
     } else if args.strict {
         Value::Error {
-            error: ShellError::LabeledError(
+            error: Box::new(ShellError::LabeledError(
                 "Could not expand path".into(),
                 "could not be expanded (path might not exist, non-final \
                     component is not a directory, or other cause)"
                     .into(),
-            ),
+            )),
         }
     } else {
         Value::string(expand_path(path).to_string_lossy(), span)
@@ -177,7 +177,11 @@ fn join_list(parts: &[Value], span: Span, args: &Arguments) -> Value {
                     Value::List { vals, span }
                 }
                 Err(_) => Value::Error {
-                    error: ShellError::PipelineMismatch("string or record".into(), span, span),
+                    error: Box::new(ShellError::PipelineMismatch(
+                        "string or record".into(),
+                        span,
+                        span,
+                    )),
                 },
             }
         }
@@ -199,7 +203,9 @@ fn join_record(cols: &[String], vals: &[Value], span: Span, args: &Arguments) ->
     } else {
         match merge_record(cols, vals, span) {
             Ok(p) => join_single(p.as_path(), span, args),
-            Err(error) => Value::Error { error },
+            Err(error) => Value::Error {
+                error: Box::new(error),
+            },
         }
     }
 }
@@ -122,12 +122,13 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, fgs_hex, fge_hex, bgs_hex, bge_hex, &head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| {
+                        action(old, fgs_hex, fge_hex, bgs_hex, bge_hex, &head)
+                    });
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -151,10 +152,10 @@ fn action(
                 (None, None, None, None) => {
                     // Error - no colors
                     Value::Error {
-                        error: ShellError::MissingParameter(
+                        error: Box::new(ShellError::MissingParameter(
                             "please supply foreground and/or background color parameters".into(),
                             *command_span,
-                        ),
+                        )),
                     }
                 }
                 (None, None, None, Some(bg_end)) => {
@@ -278,7 +279,10 @@ fn action(
             let got = format!("value is {}, not string", other.get_type().to_string());
 
             Value::Error {
-                error: ShellError::TypeMismatch(got, other.span().unwrap_or(*command_span)),
+                error: Box::new(ShellError::TypeMismatch(
+                    got,
+                    other.span().unwrap_or(*command_span),
+                )),
             }
         }
     }
@@ -0,0 +1,118 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call, engine::Command, engine::EngineState, engine::Stack, Category, Example,
+    IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "ansi link"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ansi link")
+            .optional(
+                "url",
+                SyntaxShape::String,
+                "the URL to link to, instead of the strings in the input",
+            )
+            .named(
+                "text",
+                SyntaxShape::String,
+                "the text to show for the link, instead of the URL",
+                Some('t'),
+            )
+            .category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "add a link (using OSC 8 escape sequence) to the given string"
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Terminals without support for OSC 8 show the text without the link."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, ShellError> {
+        let url: Option<String> = call.opt(engine_state, stack, 0)?;
+        let text: Option<String> = call.get_flag(engine_state, stack, "text")?;
+        let head = call.head;
+
+        if let Some(url) = url {
+            let text = text.as_deref().unwrap_or(&url);
+            return Ok(Value::string(hyperlink(&url, text), head).into_pipeline_data());
+        }
+
+        input.map(
+            move |v| action(&v, text.as_deref(), head),
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Create a link to a website",
+                example: r#"ansi link --text "Nushell" https://www.nushell.sh"#,
+                result: Some(Value::test_string(
+                    "\u{1b}]8;;https://www.nushell.sh\u{1b}\\Nushell\u{1b}]8;;\u{1b}\\",
+                )),
+            },
+            Example {
+                description: "Turn the URLs in a list into links to themselves",
+                example: r#"echo [https://www.nushell.sh] | ansi link"#,
+                result: Some(Value::List {
+                    vals: vec![Value::test_string(
+                        "\u{1b}]8;;https://www.nushell.sh\u{1b}\\https://www.nushell.sh\u{1b}]8;;\u{1b}\\",
+                    )],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, text: Option<&str>, command_span: Span) -> Value {
+    match input {
+        Value::String { val, span } => Value::String {
+            val: hyperlink(val, text.unwrap_or(val)),
+            span: *span,
+        },
+        other => {
+            let got = format!("value is {}, not string", other.get_type());
+
+            Value::Error {
+                error: Box::new(ShellError::TypeMismatch(
+                    got,
+                    other.span().unwrap_or(command_span),
+                )),
+            }
+        }
+    }
+}
+
+/// Wraps the text in the OSC 8 sequences that make it a link to the URL
+pub(crate) fn hyperlink(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SubCommand;
+
+    #[test]
+    fn examples_work_as_expected() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
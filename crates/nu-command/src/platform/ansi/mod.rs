@@ -1,7 +1,10 @@
 mod command;
 mod gradient;
+mod link;
 mod strip;
 
 pub use command::AnsiCommand as Ansi;
 pub use gradient::SubCommand as AnsiGradient;
+pub(crate) use link::hyperlink;
+pub use link::SubCommand as AnsiLink;
 pub use strip::SubCommand as AnsiStrip;
@@ -62,10 +62,11 @@ fn operate(
                 let mut ret = v;
 
                 for path in &column_paths {
-                    let r = ret
-                        .update_cell_path(&path.members, Box::new(move |old| action(old, &head)));
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, &head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
 
@@ -93,7 +94,10 @@ fn action(input: &Value, command_span: &Span) -> Value {
             let got = format!("value is {}, not string", other.get_type().to_string());
 
             Value::Error {
-                error: ShellError::TypeMismatch(got, other.span().unwrap_or(*command_span)),
+                error: Box::new(ShellError::TypeMismatch(
+                    got,
+                    other.span().unwrap_or(*command_span),
+                )),
             }
         }
     }
@@ -0,0 +1,43 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Clip;
+
+impl Command for Clip {
+    fn name(&self) -> &str {
+        "clip"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("clip").category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Copy to and paste from the system clipboard."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Clipboard support is enabled with the 'clipboard-cli' feature, which covers
+Windows, macOS and X11. Wayland compositors that implement the data-control
+protocol are supported with the 'clipboard-wayland' feature."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, nu_protocol::ShellError> {
+        Ok(Value::String {
+            val: get_full_help(&Clip.signature(), &Clip.examples(), engine_state),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
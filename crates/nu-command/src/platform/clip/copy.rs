@@ -0,0 +1,65 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature,
+};
+
+use super::open_clipboard;
+
+#[derive(Clone)]
+pub struct ClipCopy;
+
+impl Command for ClipCopy {
+    fn name(&self) -> &str {
+        "clip copy"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("clip copy").category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Copy the input into the system clipboard as text."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Streams are collected first, with one line per item."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let config = stack.get_config().unwrap_or_default();
+        let text = input.collect_string("\n", &config);
+
+        let mut clipboard = open_clipboard(call.head)?;
+        clipboard.set_text(text).map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Could not copy to the clipboard".into(),
+                e.to_string(),
+                call.head,
+            )
+        })?;
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Copy a string to the clipboard",
+                example: "'hello' | clip copy",
+                result: None,
+            },
+            Example {
+                description: "Copy the names of the files in the current directory",
+                example: "ls | get name | clip copy",
+                result: None,
+            },
+        ]
+    }
+}
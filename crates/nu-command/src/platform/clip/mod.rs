@@ -0,0 +1,20 @@
+mod command;
+mod copy;
+mod paste;
+
+pub use command::Clip;
+pub use copy::ClipCopy;
+pub use paste::ClipPaste;
+
+use arboard::Clipboard;
+use nu_protocol::{ShellError, Span};
+
+fn open_clipboard(span: Span) -> Result<Clipboard, ShellError> {
+    Clipboard::new().map_err(|e| {
+        ShellError::SpannedLabeledError(
+            "Clipboard unavailable".into(),
+            format!("could not access the system clipboard: {}", e),
+            span,
+        )
+    })
+}
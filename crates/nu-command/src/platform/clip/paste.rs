@@ -0,0 +1,55 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+use super::open_clipboard;
+
+#[derive(Clone)]
+pub struct ClipPaste;
+
+impl Command for ClipPaste {
+    fn name(&self) -> &str {
+        "clip paste"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("clip paste").category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Output the text in the system clipboard."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let mut clipboard = open_clipboard(call.head)?;
+        let text = clipboard.get_text().map_err(|e| {
+            ShellError::SpannedLabeledError(
+                "Could not paste from the clipboard".into(),
+                e.to_string(),
+                call.head,
+            )
+        })?;
+
+        Ok(Value::String {
+            val: text,
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Split the text in the clipboard into lines",
+            example: "clip paste | lines",
+            result: None,
+        }]
+    }
+}
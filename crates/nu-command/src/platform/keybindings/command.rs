@@ -0,0 +1,57 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Keybindings;
+
+impl Command for Keybindings {
+    fn name(&self) -> &str {
+        "keybindings"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("keybindings").category(Category::Platform)
+    }
+
+    fn usage(&self) -> &str {
+        "Work with the keybindings of the line editor."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Keybindings are added to the default ones of the edit mode with the keybindings list
+of the config. Each is a record of the mode it's for (emacs, vi_insert or vi_normal), the
+modifier and the keycode of the key, as 'keybindings listen' shows them, and the event it
+sends, like:
+
+  {mode: emacs, modifier: control, keycode: char_l, event: {send: clearscreen}}
+  {mode: emacs, modifier: alt, keycode: char_u, event: {edit: uppercaseword}}
+  {mode: vi_insert, modifier: none, keycode: f1, event: {edit: insertstring, value: 'help'}}
+
+Control-x opens the history menu in the emacs and vi_insert modes. Send 'historymenu' to open it
+with another key, or 'completionmenu' for the completion menu, which is on tab by default.
+
+The edit mode itself is chosen with the edit_mode of the config, either emacs or vi."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, nu_protocol::ShellError> {
+        Ok(Value::String {
+            val: get_full_help(
+                &Keybindings.signature(),
+                &Keybindings.examples(),
+                engine_state,
+            ),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,148 @@
+use std::io::Write;
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    terminal,
+};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature,
+};
+
+#[derive(Clone)]
+pub struct KeybindingsListen;
+
+impl Command for KeybindingsListen {
+    fn name(&self) -> &str {
+        "keybindings listen"
+    }
+
+    fn usage(&self) -> &str {
+        "Show the keys that are pressed, the way the keybindings of the config name them."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each key pressed is printed as the modifier and keycode a keybinding needs to match it,
+which is useful to find out what the terminal sends for a key. Press Esc to stop."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("keybindings listen").category(Category::Platform)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let mut stdout = std::io::stdout();
+        writeln!(stdout, "Press keys to see their names, and Esc to stop")?;
+
+        let _raw_mode = RawMode::enter()?;
+
+        loop {
+            if let Event::Key(key) = event::read()? {
+                if key.code == KeyCode::Esc && key.modifiers == KeyModifiers::NONE {
+                    break;
+                }
+
+                // Raw mode doesn't turn a newline into a carriage return as well
+                write!(stdout, "{}\r\n", describe_key(key))?;
+                stdout.flush()?;
+            }
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Find out the keycode and modifier of a key",
+            example: "keybindings listen",
+            result: None,
+        }]
+    }
+}
+
+// Keeps the terminal in raw mode for as long as it is alive, so every key press is read
+struct RawMode;
+
+impl RawMode {
+    fn enter() -> Result<Self, ShellError> {
+        terminal::enable_raw_mode()?;
+
+        Ok(RawMode)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn describe_key(key: KeyEvent) -> String {
+    let mut modifiers = vec![];
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        modifiers.push("control");
+    }
+    if key.modifiers.contains(KeyModifiers::SHIFT) {
+        modifiers.push("shift");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        modifiers.push("alt");
+    }
+    if modifiers.is_empty() {
+        modifiers.push("none");
+    }
+
+    let keycode = match key.code {
+        KeyCode::Backspace => "backspace".into(),
+        KeyCode::Enter => "enter".into(),
+        KeyCode::Left => "left".into(),
+        KeyCode::Right => "right".into(),
+        KeyCode::Up => "up".into(),
+        KeyCode::Down => "down".into(),
+        KeyCode::Home => "home".into(),
+        KeyCode::End => "end".into(),
+        KeyCode::PageUp => "pageup".into(),
+        KeyCode::PageDown => "pagedown".into(),
+        KeyCode::Tab => "tab".into(),
+        KeyCode::BackTab => "backtab".into(),
+        KeyCode::Delete => "delete".into(),
+        KeyCode::Insert => "insert".into(),
+        KeyCode::Esc => "esc".into(),
+        KeyCode::F(number) => format!("f{}", number),
+        KeyCode::Char(c) => format!("char_{}", c),
+        KeyCode::Null => "null".into(),
+    };
+
+    format!(
+        "{{modifier: {}, keycode: {}}}",
+        modifiers.join("_"),
+        keycode
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn describes_keys_like_the_config() {
+        let key = KeyEvent::new(
+            KeyCode::Char('l'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        );
+        assert_eq!(
+            describe_key(key),
+            "{modifier: control_alt, keycode: char_l}"
+        );
+
+        let key = KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE);
+        assert_eq!(describe_key(key), "{modifier: none, keycode: f5}");
+    }
+}
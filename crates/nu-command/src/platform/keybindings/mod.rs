@@ -0,0 +1,5 @@
+mod command;
+mod listen;
+
+pub use command::Keybindings;
+pub use listen::KeybindingsListen;
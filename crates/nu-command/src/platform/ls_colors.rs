@@ -0,0 +1,57 @@
+use super::icons::icon_for_file;
+use lscolors::{LsColors, Style};
+use nu_engine::env_to_string;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::{Config, ShellError};
+use std::path::Path;
+
+/// Load the file coloring rules from the LS_COLORS environment variable, falling back to the
+/// default rules when it isn't set
+pub fn get_ls_colors(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    config: &Config,
+) -> Result<LsColors, ShellError> {
+    Ok(match stack.get_env_var("LS_COLORS") {
+        Some(v) => {
+            LsColors::from_string(&env_to_string("LS_COLORS", v, engine_state, stack, config)?)
+        }
+        None => LsColors::default(),
+    })
+}
+
+/// Render a file name for display, colored by its type and extension and optionally prefixed
+/// with its nerd font icon
+pub fn style_file_name(
+    ls_colors: &LsColors,
+    name: &str,
+    use_color: bool,
+    use_icons: bool,
+) -> Result<String, ShellError> {
+    let path = Path::new(name);
+
+    let style = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => ls_colors.style_for_path_with_metadata(path, Some(&metadata)),
+        Err(_) => ls_colors.style_for_path(path),
+    };
+    let ansi_style = style.map(Style::to_crossterm_style).unwrap_or_default();
+
+    let styled_name = if use_color {
+        ansi_style.apply(name).to_string()
+    } else {
+        name.to_string()
+    };
+
+    if use_icons {
+        let icon = icon_for_file(path)?;
+        let styled_icon = if use_color {
+            ansi_style.apply(icon).to_string()
+        } else {
+            icon.to_string()
+        };
+
+        Ok(format!("{} {}", styled_icon, styled_name))
+    } else {
+        Ok(styled_name)
+    }
+}
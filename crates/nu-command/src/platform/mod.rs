@@ -1,9 +1,23 @@
 mod ansi;
 mod clear;
+#[cfg(feature = "clipboard-cli")]
+mod clip;
+mod icons;
+mod keybindings;
 mod kill;
+mod ls_colors;
+#[cfg(feature = "network")]
+mod progress_bar;
 mod sleep;
 
-pub use ansi::{Ansi, AnsiGradient, AnsiStrip};
+pub(crate) use ansi::hyperlink;
+pub use ansi::{Ansi, AnsiGradient, AnsiLink, AnsiStrip};
 pub use clear::Clear;
+#[cfg(feature = "clipboard-cli")]
+pub use clip::{Clip, ClipCopy, ClipPaste};
+pub use keybindings::{Keybindings, KeybindingsListen};
 pub use kill::Kill;
+pub(crate) use ls_colors::{get_ls_colors, style_file_name};
+#[cfg(feature = "network")]
+pub(crate) use progress_bar::ProgressBar;
 pub use sleep::Sleep;
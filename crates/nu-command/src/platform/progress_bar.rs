@@ -0,0 +1,104 @@
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use bytesize::ByteSize;
+use crossterm::tty::IsTty;
+
+// How often the bar is redrawn at most, so fast transfers don't flood the terminal
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+const BAR_WIDTH: u64 = 30;
+
+/// The progress of a transfer, drawn on one line of stderr when it is a terminal
+///
+/// Without a known total, only the size so far and the speed are shown. The line is ended when
+/// the bar is dropped, whether or not the transfer finished.
+pub(crate) struct ProgressBar {
+    total: Option<u64>,
+    done: u64,
+    started: Instant,
+    last_drawn: Option<Instant>,
+    visible: bool,
+}
+
+impl ProgressBar {
+    pub(crate) fn new(total: Option<u64>) -> Self {
+        ProgressBar {
+            total,
+            done: 0,
+            started: Instant::now(),
+            last_drawn: None,
+            visible: std::io::stderr().is_tty(),
+        }
+    }
+
+    pub(crate) fn advance(&mut self, len: u64) {
+        self.done += len;
+
+        let due = self
+            .last_drawn
+            .map_or(true, |last_drawn| last_drawn.elapsed() >= REDRAW_INTERVAL);
+        if due {
+            self.draw();
+        }
+    }
+
+    fn draw(&mut self) {
+        if !self.visible {
+            return;
+        }
+        self.last_drawn = Some(Instant::now());
+
+        let elapsed = self.started.elapsed().as_secs_f64();
+        let speed = if elapsed > 0.0 {
+            (self.done as f64 / elapsed) as u64
+        } else {
+            0
+        };
+
+        let line = match self.total {
+            Some(total) if total > 0 => {
+                let done = self.done.min(total);
+                let filled = (BAR_WIDTH * done / total) as usize;
+                let arrow = if done < total { ">" } else { "" };
+                let eta = match speed {
+                    0 => "?".to_string(),
+                    speed => format_eta((total - done) / speed),
+                };
+
+                format!(
+                    "[{}{}{}] {} / {}  {}/s  ETA {}",
+                    "=".repeat(filled),
+                    arrow,
+                    " ".repeat(BAR_WIDTH as usize - filled - arrow.len()),
+                    ByteSize(done),
+                    ByteSize(total),
+                    ByteSize(speed),
+                    eta
+                )
+            }
+            _ => format!("{}  {}/s", ByteSize(self.done), ByteSize(speed)),
+        };
+
+        // Return to the start of the line and clear what the last draw left there
+        let mut stderr = std::io::stderr();
+        let _ = write!(stderr, "\r\x1b[2K{}", line);
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for ProgressBar {
+    fn drop(&mut self) {
+        if self.visible && self.last_drawn.is_some() {
+            self.draw();
+            eprintln!();
+        }
+    }
+}
+
+fn format_eta(seconds: u64) -> String {
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {:02}s", seconds / 60, seconds % 60),
+        _ => format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
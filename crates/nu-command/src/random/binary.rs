@@ -0,0 +1,107 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
+};
+use rand::RngCore;
+
+use super::random_generator;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "random binary"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random binary")
+            .required("length", SyntaxShape::Int, "Number of bytes to generate")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
+            .category(Category::Random)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate random bytes"
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The bytes come from a cryptographically secure generator, unless --seed is given."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        binary(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate 16 random bytes",
+                example: "random binary 16",
+                result: None,
+            },
+            Example {
+                description: "Generate the same 8 bytes on every run",
+                example: "random binary 8 --seed 42",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn binary(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let length: Spanned<i64> = call.req(engine_state, stack, 0)?;
+
+    if length.item < 0 {
+        return Err(ShellError::UnsupportedInput(
+            "the length can't be negative".into(),
+            length.span,
+        ));
+    }
+
+    // Reserving fallibly keeps a length larger than the memory from aborting the shell
+    let too_large =
+        || ShellError::UnsupportedInput("the length doesn't fit in memory".into(), length.span);
+    let size = usize::try_from(length.item).map_err(|_| too_large())?;
+    let mut bytes = Vec::new();
+    bytes.try_reserve_exact(size).map_err(|_| too_large())?;
+    bytes.resize(size, 0u8);
+
+    let mut rng = random_generator(engine_state, stack, call)?;
+    rng.fill_bytes(&mut bytes);
+
+    Ok(PipelineData::Value(
+        Value::Binary { val: bytes, span },
+        None,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -4,7 +4,9 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
 };
-use rand::prelude::{thread_rng, Rng};
+use rand::Rng;
+
+use super::random_generator;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -22,6 +24,12 @@ impl Command for SubCommand {
                 "Adjusts the probability of a \"true\" outcome",
                 Some('b'),
             )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
             .category(Category::Random)
     }
 
@@ -75,7 +83,7 @@ fn bool(
         }
     }
 
-    let mut rng = thread_rng();
+    let mut rng = random_generator(engine_state, stack, call)?;
     let bool_result: bool = rng.gen_bool(probability);
 
     Ok(PipelineData::Value(
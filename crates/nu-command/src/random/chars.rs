@@ -2,10 +2,9 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
-use rand::{
-    distributions::{Alphanumeric, Distribution},
-    thread_rng,
-};
+use rand::distributions::{Alphanumeric, Distribution};
+
+use super::random_generator;
 
 const DEFAULT_CHARS_LENGTH: usize = 25;
 
@@ -20,6 +19,12 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("random chars")
             .named("length", SyntaxShape::Int, "Number of chars", Some('l'))
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
             .category(Category::Random)
     }
 
@@ -62,7 +67,7 @@ fn chars(
     let length: Option<usize> = call.get_flag(engine_state, stack, "length")?;
 
     let chars_length = length.unwrap_or(DEFAULT_CHARS_LENGTH);
-    let mut rng = thread_rng();
+    let mut rng = random_generator(engine_state, stack, call)?;
 
     let random_string = Alphanumeric
         .sample_iter(&mut rng)
@@ -1,11 +1,8 @@
-use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{
-    Category, Example, PipelineData, Range, ShellError, Signature, Span, SyntaxShape, Value,
-};
-use rand::prelude::{thread_rng, Rng};
-use std::cmp::Ordering;
+use nu_protocol::{Category, Example, PipelineData, Signature, SyntaxShape};
+
+use super::float::float;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -18,6 +15,12 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("random decimal")
             .optional("range", SyntaxShape::Range, "Range of values")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
             .category(Category::Random)
     }
 
@@ -32,7 +35,7 @@ impl Command for SubCommand {
         call: &Call,
         _input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
-        decimal(engine_state, stack, call)
+        float(engine_state, stack, call)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -61,48 +64,6 @@ impl Command for SubCommand {
     }
 }
 
-fn decimal(
-    engine_state: &EngineState,
-    stack: &mut Stack,
-    call: &Call,
-) -> Result<PipelineData, ShellError> {
-    let span = call.head;
-    let range: Option<Range> = call.opt(engine_state, stack, 0)?;
-
-    let (min, max) = if let Some(r) = range {
-        (r.from.as_float()?, r.to.as_float()?)
-    } else {
-        (0.0, 1.0)
-    };
-
-    match min.partial_cmp(&max) {
-        Some(Ordering::Greater) => Err(ShellError::InvalidRange(
-            min.to_string(),
-            max.to_string(),
-            span,
-        )),
-        Some(Ordering::Equal) => Ok(PipelineData::Value(
-            Value::Float {
-                val: min,
-                span: Span::new(64, 64),
-            },
-            None,
-        )),
-        _ => {
-            let mut thread_rng = thread_rng();
-            let result: f64 = thread_rng.gen_range(min..max);
-
-            Ok(PipelineData::Value(
-                Value::Float {
-                    val: result,
-                    span: Span::new(64, 64),
-                },
-                None,
-            ))
-        }
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -4,7 +4,9 @@ use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value, ValueStream,
 };
-use rand::prelude::{thread_rng, Rng};
+use rand::Rng;
+
+use super::random_generator;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -28,6 +30,12 @@ impl Command for SubCommand {
                 "The amount of sides a die has",
                 Some('s'),
             )
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
             .category(Category::Random)
     }
 
@@ -71,12 +79,10 @@ fn dice(
     let dice: usize = call.get_flag(engine_state, stack, "dice")?.unwrap_or(1);
     let sides: usize = call.get_flag(engine_state, stack, "sides")?.unwrap_or(6);
 
-    let iter = (0..dice).map(move |_| {
-        let mut thread_rng = thread_rng();
-        Value::Int {
-            val: thread_rng.gen_range(1..sides + 1) as i64,
-            span,
-        }
+    let mut rng = random_generator(engine_state, stack, call)?;
+    let iter = (0..dice).map(move |_| Value::Int {
+        val: rng.gen_range(1..sides + 1) as i64,
+        span,
     });
 
     Ok(PipelineData::Stream(
@@ -0,0 +1,141 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, Range, ShellError, Signature, SyntaxShape, Value,
+};
+use rand::Rng;
+use std::cmp::Ordering;
+
+use super::random_generator;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "random float"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("random float")
+            .optional("range", SyntaxShape::Range, "Range of values")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
+            .category(Category::Random)
+    }
+
+    fn usage(&self) -> &str {
+        "Generate a random float within a range [min..max]"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        float(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Generate a float between 0 and 1",
+                example: "random float",
+                result: None,
+            },
+            Example {
+                description: "Generate a float between 1 and 10",
+                example: "random float 1..10",
+                result: None,
+            },
+            Example {
+                description: "Generate a float greater than or equal to 0.5",
+                example: "random float 0.5..",
+                result: None,
+            },
+            Example {
+                description: "Generate the same float on every run",
+                example: "random float 1..10 --seed 42",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(super) fn float(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+    let range: Option<Range> = call.opt(engine_state, stack, 0)?;
+
+    let (min, max) = if let Some(r) = range {
+        (range_bound(&r.from, 0.0)?, range_bound(&r.to, f64::MAX)?)
+    } else {
+        (0.0, 1.0)
+    };
+
+    // gen_range panics on bounds it can't sample between, so they are checked here
+    let range_span = call.positional.get(0).map_or(span, |range| range.span);
+    if !min.is_finite() || !max.is_finite() {
+        return Err(ShellError::SpannedLabeledError(
+            "Invalid range".into(),
+            "the bounds of the range must be finite".into(),
+            range_span,
+        ));
+    }
+    if !(max - min).is_finite() {
+        return Err(ShellError::SpannedLabeledError(
+            "Invalid range".into(),
+            "the range is too wide, its size must fit in a float".into(),
+            range_span,
+        ));
+    }
+
+    match min.partial_cmp(&max) {
+        Some(Ordering::Greater) => Err(ShellError::InvalidRange(
+            min.to_string(),
+            max.to_string(),
+            span,
+        )),
+        Some(Ordering::Equal) => Ok(PipelineData::Value(Value::Float { val: min, span }, None)),
+        _ => {
+            let mut rng = random_generator(engine_state, stack, call)?;
+            let result: f64 = rng.gen_range(min..max);
+
+            Ok(PipelineData::Value(
+                Value::Float { val: result, span },
+                None,
+            ))
+        }
+    }
+}
+
+// Open ends of a range are left as nothing
+fn range_bound(value: &Value, default: f64) -> Result<f64, ShellError> {
+    match value {
+        Value::Nothing { .. } => Ok(default),
+        Value::Int { val, .. } => Ok(*val as f64),
+        value => value.as_float(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,12 +1,14 @@
 use nu_engine::CallExt;
-use nu_protocol::ast::Call;
+use nu_protocol::ast::{Call, RangeInclusion};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
     Category, Example, PipelineData, Range, ShellError, Signature, SyntaxShape, Value,
 };
-use rand::prelude::{thread_rng, Rng};
+use rand::Rng;
 use std::cmp::Ordering;
 
+use super::random_generator;
+
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -18,6 +20,12 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("random integer")
             .optional("range", SyntaxShape::Range, "Range of values")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
             .category(Category::Random)
     }
 
@@ -25,6 +33,10 @@ impl Command for SubCommand {
         "Generate a random integer [min..max]"
     }
 
+    fn extra_usage(&self) -> &str {
+        "The range includes its end, like 1..10 does elsewhere. Use 1..<10 to leave 10 out."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -57,6 +69,16 @@ impl Command for SubCommand {
                 example: "random integer 1..10",
                 result: None,
             },
+            Example {
+                description: "Generate a random integer between 1 and 9",
+                example: "random integer 1..<10",
+                result: None,
+            },
+            Example {
+                description: "Generate the same integer on every run",
+                example: "random integer 1..10 --seed 42",
+                result: None,
+            },
         ]
     }
 }
@@ -70,7 +92,13 @@ fn integer(
     let range: Option<Range> = call.opt(engine_state, stack, 0)?;
 
     let (min, max) = if let Some(r) = range {
-        (r.from.as_integer()?, r.to.as_integer()?)
+        // Open ends of a range are left as nothing
+        let max = match (r.to, r.inclusion) {
+            (Value::Nothing { .. }, _) => i64::MAX,
+            (to, RangeInclusion::Inclusive) => to.as_integer()?,
+            (to, RangeInclusion::RightExclusive) => to.as_integer()?.saturating_sub(1),
+        };
+        (r.from.as_integer()?, max)
     } else {
         (0, i64::MAX)
     };
@@ -83,8 +111,8 @@ fn integer(
         )),
         Some(Ordering::Equal) => Ok(PipelineData::Value(Value::Int { val: min, span }, None)),
         _ => {
-            let mut thread_rng = thread_rng();
-            let result: i64 = thread_rng.gen_range(min..max);
+            let mut rng = random_generator(engine_state, stack, call)?;
+            let result: i64 = rng.gen_range(min..=max);
 
             Ok(PipelineData::Value(Value::Int { val: result, span }, None))
         }
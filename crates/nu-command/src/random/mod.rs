@@ -1,15 +1,80 @@
+mod binary;
 mod bool;
 mod chars;
 mod command;
 mod decimal;
 mod dice;
+mod float;
 mod integer;
 mod uuid;
 
+pub use self::binary::SubCommand as RandomBinary;
 pub use self::bool::SubCommand as RandomBool;
 pub use self::chars::SubCommand as RandomChars;
 pub use self::decimal::SubCommand as RandomDecimal;
 pub use self::dice::SubCommand as RandomDice;
+pub use self::float::SubCommand as RandomFloat;
 pub use self::integer::SubCommand as RandomInteger;
 pub use self::uuid::SubCommand as RandomUuid;
 pub use command::RandomCommand as Random;
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{EngineState, Stack};
+use nu_protocol::ShellError;
+use rand::{thread_rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+/// The generator of a `random` subcommand
+///
+/// With `--seed`, this is ChaCha12 seeded through `SeedableRng::seed_from_u64` with the bits of
+/// the seed, so that a seed gives the same values on every platform. It isn't fit for
+/// cryptographic use. Otherwise the values come from the thread's cryptographically secure
+/// generator.
+pub(super) enum RandomGenerator {
+    Seeded(ChaCha12Rng),
+    Thread,
+}
+
+impl RngCore for RandomGenerator {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            RandomGenerator::Seeded(rng) => rng.next_u32(),
+            RandomGenerator::Thread => thread_rng().next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            RandomGenerator::Seeded(rng) => rng.next_u64(),
+            RandomGenerator::Thread => thread_rng().next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            RandomGenerator::Seeded(rng) => rng.fill_bytes(dest),
+            RandomGenerator::Thread => thread_rng().fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            RandomGenerator::Seeded(rng) => rng.try_fill_bytes(dest),
+            RandomGenerator::Thread => thread_rng().try_fill_bytes(dest),
+        }
+    }
+}
+
+fn random_generator(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<RandomGenerator, ShellError> {
+    let seed: Option<i64> = call.get_flag(engine_state, stack, "seed")?;
+
+    Ok(match seed {
+        Some(seed) => RandomGenerator::Seeded(ChaCha12Rng::seed_from_u64(seed as u64)),
+        None => RandomGenerator::Thread,
+    })
+}
@@ -1,7 +1,10 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Value};
-use uuid::Uuid;
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+use rand::RngCore;
+use uuid::{Builder, Variant, Version};
+
+use super::random_generator;
 
 #[derive(Clone)]
 pub struct SubCommand;
@@ -12,7 +15,14 @@ impl Command for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("random uuid").category(Category::Random)
+        Signature::build("random uuid")
+            .named(
+                "seed",
+                SyntaxShape::Int,
+                "Seed for reproducible, non-cryptographic output",
+                None,
+            )
+            .category(Category::Random)
     }
 
     fn usage(&self) -> &str {
@@ -21,12 +31,12 @@ impl Command for SubCommand {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         _input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
-        uuid(call)
+        uuid(engine_state, stack, call)
     }
 
     fn examples(&self) -> Vec<Example> {
@@ -38,9 +48,22 @@ impl Command for SubCommand {
     }
 }
 
-fn uuid(call: &Call) -> Result<PipelineData, ShellError> {
+fn uuid(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
     let span = call.head;
-    let uuid_4 = Uuid::new_v4().to_hyphenated().to_string();
+
+    let mut bytes = [0u8; 16];
+    random_generator(engine_state, stack, call)?.fill_bytes(&mut bytes);
+
+    let uuid_4 = Builder::from_bytes(bytes)
+        .set_variant(Variant::RFC4122)
+        .set_version(Version::Random)
+        .build()
+        .to_hyphenated()
+        .to_string();
 
     Ok(PipelineData::Value(
         Value::String { val: uuid_4, span },
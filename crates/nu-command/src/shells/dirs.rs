@@ -0,0 +1,121 @@
+use std::sync::MutexGuard;
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Dirs;
+
+impl Command for Dirs {
+    fn name(&self) -> &str {
+        "dirs"
+    }
+
+    fn usage(&self) -> &str {
+        "List the directory stack, starting with the current directory."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Directories are put on the stack with 'pushd' and taken off with 'popd'. The index of
+each directory is the number to give 'pushd' to rotate the stack until it is at the top, or
+'popd' to remove it."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("dirs")
+            .switch("clear", "remove all directories from the stack", Some('c'))
+            .category(Category::Shells)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        if call.has_flag("clear") {
+            lock_dir_stack(engine_state).clear();
+            return Ok(PipelineData::new(span));
+        }
+
+        let rows = dir_list(engine_state, stack)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, path)| Value::Record {
+                cols: vec!["index".into(), "path".into()],
+                vals: vec![Value::int(index as i64, span), Value::string(path, span)],
+                span,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(rows.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the directory stack",
+                example: "dirs",
+                result: None,
+            },
+            Example {
+                description: "Forget the directories on the stack",
+                example: "dirs --clear",
+                result: None,
+            },
+        ]
+    }
+}
+
+pub(super) fn lock_dir_stack(engine_state: &EngineState) -> MutexGuard<Vec<String>> {
+    // The stack is only a list of paths, so it is still usable after a panic
+    match engine_state.dir_stack.lock() {
+        Ok(dirs) => dirs,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+pub(super) fn current_dir(stack: &Stack) -> Result<String, ShellError> {
+    match stack.get_env_var("PWD") {
+        Some(pwd) => pwd.as_string(),
+        None => Ok(std::env::current_dir()?.to_string_lossy().to_string()),
+    }
+}
+
+/// The current directory followed by the stack, as `dirs` shows them
+pub(super) fn dir_list(
+    engine_state: &EngineState,
+    stack: &Stack,
+) -> Result<Vec<String>, ShellError> {
+    let mut dirs = vec![current_dir(stack)?];
+    dirs.extend(lock_dir_stack(engine_state).iter().cloned());
+
+    Ok(dirs)
+}
+
+/// Turns an index into the list of `dirs` into a position, counting from the end if it is
+/// negative
+pub(super) fn dir_index(index: i64, len: usize, span: Span) -> Result<usize, ShellError> {
+    let position = if index < 0 { len as i64 + index } else { index };
+
+    if position < 0 || position as usize >= len {
+        Err(ShellError::AccessBeyondEnd(len - 1, span))
+    } else {
+        Ok(position as usize)
+    }
+}
+
+pub(super) fn empty_dir_stack(span: Span) -> ShellError {
+    ShellError::SpannedLabeledError(
+        "Directory stack is empty".into(),
+        "there is no directory to go back to".into(),
+        span,
+    )
+}
@@ -0,0 +1,69 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+
+use super::dirs::current_dir;
+use super::shells_::lock_shells;
+use crate::change_directory;
+
+#[derive(Clone)]
+pub struct Enter;
+
+impl Command for Enter {
+    fn name(&self) -> &str {
+        "enter"
+    }
+
+    fn usage(&self) -> &str {
+        "Open a new shell in the given directory and make it the active one."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The shell that was active keeps its directory, and can be switched back to with 'p'.
+'exit' closes the new shell again."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("enter")
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "the directory to open the shell in",
+            )
+            .category(Category::Shells)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let previous = current_dir(stack)?;
+
+        change_directory(stack, &path.item, path.span, false)?;
+
+        let mut ring = lock_shells(engine_state);
+        if ring.dirs.is_empty() {
+            ring.dirs.push(previous);
+        } else {
+            let current = ring.current;
+            ring.dirs[current] = previous;
+        }
+        ring.dirs.push(current_dir(stack)?);
+        ring.current = ring.dirs.len() - 1;
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Open a shell in the parent directory",
+            example: "enter ..",
+            result: None,
+        }]
+    }
+}
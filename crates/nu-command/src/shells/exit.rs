@@ -1,8 +1,10 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, PipelineData, ShellError, Signature};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature};
+
+use super::shells_::lock_shells;
+use crate::change_directory;
 
-/// Source a file for environment variables.
 #[derive(Clone)]
 pub struct Exit;
 
@@ -12,22 +14,68 @@ impl Command for Exit {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("exit").category(Category::Shells)
+        Signature::build("exit")
+            .switch(
+                "now",
+                "exit nushell, even when other shells are open",
+                Some('n'),
+            )
+            .category(Category::Shells)
     }
 
     fn usage(&self) -> &str {
-        "Runs a script file in the current context."
+        "Close the active shell, or exit nushell when it is the only one."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "After closing a shell opened with 'enter', the shell before it becomes the active one."
     }
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
-        _call: &Call,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        //TODO: add more shell support
+        if call.has_flag("now") {
+            std::process::exit(0);
+        }
+
+        let mut ring = lock_shells(engine_state);
+        if ring.dirs.len() < 2 {
+            std::process::exit(0);
+        }
+
+        let closed = ring.current;
+        ring.dirs.remove(closed);
+        ring.current = closed.saturating_sub(1);
+
+        let dir = ring.dirs[ring.current].clone();
+
+        // Back to the one shell, which uses PWD alone
+        if ring.dirs.len() == 1 {
+            ring.dirs.clear();
+            ring.current = 0;
+        }
+
+        change_directory(stack, &dir, call.head, false)?;
+
+        Ok(PipelineData::new(call.head))
+    }
 
-        std::process::exit(0);
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Close the active shell",
+                example: "exit",
+                result: None,
+            },
+            Example {
+                description: "Exit nushell with all of its shells",
+                example: "exit --now",
+                result: None,
+            },
+        ]
     }
 }
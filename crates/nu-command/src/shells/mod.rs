@@ -1,3 +1,17 @@
+mod dirs;
+mod enter;
 mod exit;
+mod next;
+mod popd;
+mod prev;
+mod pushd;
+mod shells_;
 
+pub use dirs::Dirs;
+pub use enter::Enter;
 pub use exit::Exit;
+pub use next::NextShell;
+pub use popd::Popd;
+pub use prev::PrevShell;
+pub use pushd::Pushd;
+pub use shells_::Shells;
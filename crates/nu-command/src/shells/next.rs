@@ -0,0 +1,44 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature};
+
+use super::shells_::switch_shell;
+
+#[derive(Clone)]
+pub struct NextShell;
+
+impl Command for NextShell {
+    fn name(&self) -> &str {
+        "n"
+    }
+
+    fn usage(&self) -> &str {
+        "Switch to the next shell, going back to the first one after the last."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("n").category(Category::Shells)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        switch_shell(engine_state, stack, call.head, |current, len| {
+            (current + 1) % len
+        })?;
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Switch to the next shell",
+            example: "n",
+            result: None,
+        }]
+    }
+}
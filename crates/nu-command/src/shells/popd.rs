@@ -0,0 +1,81 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+
+use super::dirs::{dir_index, empty_dir_stack, lock_dir_stack};
+use crate::change_directory;
+
+#[derive(Clone)]
+pub struct Popd;
+
+impl Command for Popd {
+    fn name(&self) -> &str {
+        "popd"
+    }
+
+    fn usage(&self) -> &str {
+        "Change to the directory at the top of the directory stack, removing it from the stack."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"With an index from 'dirs', that directory is removed from the stack instead, without
+changing directory. Negative numbers count from the bottom of the stack."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("popd")
+            .optional(
+                "index",
+                SyntaxShape::Int,
+                "the index in 'dirs' of the directory to remove",
+            )
+            .category(Category::Shells)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let index: Option<Spanned<i64>> = call.opt(engine_state, stack, 0)?;
+
+        if let Some(index) = index {
+            let mut dirs = lock_dir_stack(engine_state);
+
+            // The first entry of 'dirs' is the current directory, which isn't on the stack
+            let position = dir_index(index.item, dirs.len() + 1, index.span)?;
+            if position > 0 {
+                dirs.remove(position - 1);
+                return Ok(PipelineData::new(call.head));
+            }
+        }
+
+        let top = lock_dir_stack(engine_state)
+            .first()
+            .cloned()
+            .ok_or_else(|| empty_dir_stack(call.head))?;
+
+        change_directory(stack, &top, call.head, false)?;
+        lock_dir_stack(engine_state).remove(0);
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Go back to the directory saved by the last pushd",
+                example: "popd",
+                result: None,
+            },
+            Example {
+                description: "Remove the directory at the bottom of the stack",
+                example: "popd -1",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,96 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape, Value};
+
+use super::dirs::{current_dir, dir_index, dir_list, empty_dir_stack, lock_dir_stack};
+use crate::change_directory;
+
+#[derive(Clone)]
+pub struct Pushd;
+
+impl Command for Pushd {
+    fn name(&self) -> &str {
+        "pushd"
+    }
+
+    fn usage(&self) -> &str {
+        "Change directory, saving the current one on the directory stack."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Without a directory, the current directory and the one at the top of the stack trade
+places. With a number, the stack is rotated until the directory with that index in 'dirs' is
+the current one, where negative numbers count from the bottom of the stack. A directory with
+a number for a name can be given as a path, like './2'."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pushd")
+            .optional(
+                "target",
+                SyntaxShape::Any,
+                "the directory to change to, or the index of a directory in 'dirs'",
+            )
+            .category(Category::Shells)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let target: Option<Value> = call.opt(engine_state, stack, 0)?;
+        let cwd = current_dir(stack)?;
+
+        match target {
+            None => {
+                let top = lock_dir_stack(engine_state)
+                    .first()
+                    .cloned()
+                    .ok_or_else(|| empty_dir_stack(call.head))?;
+
+                change_directory(stack, &top, call.head, false)?;
+                lock_dir_stack(engine_state)[0] = cwd;
+            }
+            Some(Value::Int { val, span }) => {
+                let mut dirs = dir_list(engine_state, stack)?;
+                let index = dir_index(val, dirs.len(), span)?;
+                dirs.rotate_left(index);
+
+                change_directory(stack, &dirs[0], span, false)?;
+                *lock_dir_stack(engine_state) = dirs.split_off(1);
+            }
+            Some(target) => {
+                let span = target.span()?;
+                change_directory(stack, &target.as_string()?, span, false)?;
+                lock_dir_stack(engine_state).insert(0, cwd);
+            }
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Change to a directory, saving the current one",
+                example: "pushd ~/projects",
+                result: None,
+            },
+            Example {
+                description: "Go back and forth between the two most recent directories",
+                example: "pushd",
+                result: None,
+            },
+            Example {
+                description:
+                    "Rotate the stack until the third directory in 'dirs' is the current one",
+                example: "pushd 2",
+                result: None,
+            },
+        ]
+    }
+}
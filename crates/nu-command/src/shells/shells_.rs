@@ -0,0 +1,110 @@
+use std::sync::MutexGuard;
+
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, ShellRing, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Value,
+};
+
+use super::dirs::current_dir;
+use crate::change_directory;
+
+#[derive(Clone)]
+pub struct Shells;
+
+impl Command for Shells {
+    fn name(&self) -> &str {
+        "shells"
+    }
+
+    fn usage(&self) -> &str {
+        "List the shells opened with 'enter', and which one is active."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Each shell keeps its own working directory. 'n' and 'p' switch to the next and previous
+shell, and 'exit' closes the active one."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("shells").category(Category::Shells)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let pwd = current_dir(stack)?;
+        let ring = lock_shells(engine_state);
+
+        let dirs = if ring.dirs.is_empty() {
+            vec![pwd]
+        } else {
+            // The active shell may have changed directory since it was entered
+            let mut dirs = ring.dirs.clone();
+            dirs[ring.current] = pwd;
+            dirs
+        };
+
+        let rows = dirs
+            .into_iter()
+            .enumerate()
+            .map(|(index, path)| Value::Record {
+                cols: vec!["index".into(), "active".into(), "path".into()],
+                vals: vec![
+                    Value::int(index as i64, span),
+                    Value::boolean(index == ring.current, span),
+                    Value::string(path, span),
+                ],
+                span,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(rows.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the open shells",
+            example: "shells",
+            result: None,
+        }]
+    }
+}
+
+pub(super) fn lock_shells(engine_state: &EngineState) -> MutexGuard<ShellRing> {
+    // The ring is only a list of paths, so it is still usable after a panic
+    match engine_state.shells.lock() {
+        Ok(ring) => ring,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// Saves PWD as the directory of the active shell, then makes the shell `next` picks active and
+/// changes into its directory. Does nothing while there is only one shell.
+pub(super) fn switch_shell(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    span: Span,
+    next: impl FnOnce(usize, usize) -> usize,
+) -> Result<(), ShellError> {
+    let mut ring = lock_shells(engine_state);
+    if ring.dirs.len() < 2 {
+        return Ok(());
+    }
+
+    let current = ring.current;
+    ring.dirs[current] = current_dir(stack)?;
+
+    let next = next(current, ring.dirs.len());
+    let dir = ring.dirs[next].clone();
+    change_directory(stack, &dir, span, false)?;
+    ring.current = next;
+
+    Ok(())
+}
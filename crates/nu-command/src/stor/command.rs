@@ -0,0 +1,42 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Stor;
+
+impl Command for Stor {
+    fn name(&self) -> &str {
+        "stor"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor").category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Keep tables in an in-memory SQLite database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The database lasts for as long as the shell runs and is never written to disk.
+Every table gets an 'id' column holding an automatically assigned row id."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(&Stor.signature(), &Stor.examples(), engine_state),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,86 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
+};
+
+use super::{column_type, db_error, quote_identifier, with_db};
+
+#[derive(Clone)]
+pub struct StorCreate;
+
+impl Command for StorCreate {
+    fn name(&self) -> &str {
+        "stor create"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor create")
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the table to create",
+                Some('t'),
+            )
+            .required_named(
+                "columns",
+                SyntaxShape::Any,
+                "a record of column names and their types",
+                Some('c'),
+            )
+            .category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Create a table in the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Column types can be int, float, str, bool, datetime, filesize, duration or binary."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Create a table with a name and a size column",
+            example: "stor create --table-name files --columns {name: str, size: filesize}",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let table: Option<Spanned<String>> = call.get_flag(engine_state, stack, "table-name")?;
+        let columns: Option<Value> = call.get_flag(engine_state, stack, "columns")?;
+
+        let table = table.ok_or_else(|| ShellError::MissingParameter("table-name".into(), head))?;
+        let columns =
+            columns.ok_or_else(|| ShellError::MissingParameter("columns".into(), head))?;
+
+        let (names, types) = columns.as_record()?;
+        let mut definitions = vec!["id INTEGER PRIMARY KEY".to_string()];
+        for (name, ty) in names.iter().zip(types) {
+            definitions.push(format!(
+                "{} {}",
+                quote_identifier(name),
+                column_type(&ty.as_string()?, ty.span()?)?
+            ));
+        }
+
+        let sql = format!(
+            "CREATE TABLE {} ({})",
+            quote_identifier(&table.item),
+            definitions.join(", ")
+        );
+
+        with_db(|conn| conn.execute(&sql, []).map_err(|e| db_error(e, table.span)))?;
+
+        Ok(PipelineData::new(head))
+    }
+}
@@ -0,0 +1,84 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+};
+
+use super::{db_error, quote_identifier, with_db};
+
+#[derive(Clone)]
+pub struct StorDelete;
+
+impl Command for StorDelete {
+    fn name(&self) -> &str {
+        "stor delete"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor delete")
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the table to delete from",
+                Some('t'),
+            )
+            .named(
+                "where-clause",
+                SyntaxShape::String,
+                "an SQL condition selecting the rows to delete",
+                Some('w'),
+            )
+            .category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Delete rows or a whole table from the in-memory database."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "Without --where-clause, the table itself is dropped."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Delete the rows of small files",
+                example: "stor delete --table-name files --where-clause 'size < 1024'",
+                result: None,
+            },
+            Example {
+                description: "Drop a table",
+                example: "stor delete --table-name files",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let table: Option<Spanned<String>> = call.get_flag(engine_state, stack, "table-name")?;
+        let where_clause: Option<String> = call.get_flag(engine_state, stack, "where-clause")?;
+
+        let table = table.ok_or_else(|| ShellError::MissingParameter("table-name".into(), head))?;
+
+        let sql = match where_clause {
+            Some(where_clause) => format!(
+                "DELETE FROM {} WHERE {}",
+                quote_identifier(&table.item),
+                where_clause
+            ),
+            None => format!("DROP TABLE {}", quote_identifier(&table.item)),
+        };
+
+        with_db(|conn| conn.execute(&sql, []).map_err(|e| db_error(e, table.span)))?;
+
+        Ok(PipelineData::new(head))
+    }
+}
@@ -0,0 +1,102 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
+};
+use rusqlite::params_from_iter;
+
+use super::{db_error, quote_identifier, record_from, value_to_sql, with_db};
+
+#[derive(Clone)]
+pub struct StorInsert;
+
+impl Command for StorInsert {
+    fn name(&self) -> &str {
+        "stor insert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor insert")
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the table to insert into",
+                Some('t'),
+            )
+            .named(
+                "data-record",
+                SyntaxShape::Any,
+                "the record to insert, defaults to the input, which can also be a table",
+                Some('d'),
+            )
+            .category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Insert a row into a table of the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Insert a row",
+                example: "stor insert --table-name files --data-record {name: 'a.txt', size: 1kb}",
+                result: None,
+            },
+            Example {
+                description: "Insert the rows of a table from the pipeline",
+                example: "ls | select name size | stor insert --table-name files",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let table: Option<Spanned<String>> = call.get_flag(engine_state, stack, "table-name")?;
+        let record: Option<Value> = call.get_flag(engine_state, stack, "data-record")?;
+
+        let table = table.ok_or_else(|| ShellError::MissingParameter("table-name".into(), head))?;
+
+        // A table in the input inserts one row per record
+        let records = match (record, input.into_value(head)) {
+            (None, Value::List { vals, .. }) => vals,
+            (record, input) => vec![record.unwrap_or(input)],
+        };
+
+        with_db(|conn| {
+            for record in records {
+                let (cols, vals) = record_from(None, record, "data-record", head)?;
+
+                let params = vals
+                    .iter()
+                    .map(value_to_sql)
+                    .collect::<Result<Vec<_>, ShellError>>()?;
+
+                let sql = format!(
+                    "INSERT INTO {} ({}) VALUES ({})",
+                    quote_identifier(&table.item),
+                    cols.iter()
+                        .map(|col| quote_identifier(col))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    vec!["?"; cols.len()].join(", ")
+                );
+
+                conn.execute(&sql, params_from_iter(params))
+                    .map_err(|e| db_error(e, table.span))?;
+            }
+
+            Ok(())
+        })?;
+
+        Ok(PipelineData::new(head))
+    }
+}
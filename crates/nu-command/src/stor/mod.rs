@@ -0,0 +1,161 @@
+mod command;
+mod create;
+mod delete;
+mod insert;
+mod open;
+mod query;
+mod reset;
+mod update;
+
+pub use command::Stor;
+pub use create::StorCreate;
+pub use delete::StorDelete;
+pub use insert::StorInsert;
+pub use open::StorOpen;
+pub use query::StorQuery;
+pub use reset::StorReset;
+pub use update::StorUpdate;
+
+use lazy_static::lazy_static;
+use nu_protocol::{ShellError, Span, Value};
+use rusqlite::types::{Value as SqlValue, ValueRef};
+use rusqlite::{Connection, Row};
+use std::sync::Mutex;
+
+lazy_static! {
+    // The store lives for as long as the shell, and is never written to disk
+    static ref MEMORY_DB: Mutex<Connection> =
+        Mutex::new(Connection::open_in_memory().expect("could not open the in-memory database"));
+}
+
+/// Run a function on the in-memory database
+fn with_db<T>(f: impl FnOnce(&Connection) -> Result<T, ShellError>) -> Result<T, ShellError> {
+    let conn = MEMORY_DB
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    f(&conn)
+}
+
+fn db_error(error: rusqlite::Error, span: Span) -> ShellError {
+    ShellError::SpannedLabeledError("Database error".into(), error.to_string(), span)
+}
+
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn column_type(ty: &str, span: Span) -> Result<&'static str, ShellError> {
+    match ty {
+        "int" | "filesize" | "duration" => Ok("INTEGER"),
+        "float" => Ok("REAL"),
+        "str" | "string" => Ok("TEXT"),
+        "bool" => Ok("BOOLEAN"),
+        "date" | "datetime" => Ok("DATETIME"),
+        "binary" => Ok("BLOB"),
+        _ => Err(ShellError::UnsupportedInput(
+            format!(
+                "unsupported column type '{}', expected int, float, str, bool, datetime, filesize, duration or binary",
+                ty
+            ),
+            span,
+        )),
+    }
+}
+
+fn value_to_sql(value: &Value) -> Result<SqlValue, ShellError> {
+    Ok(match value {
+        Value::Bool { val, .. } => SqlValue::Integer(*val as i64),
+        Value::Int { val, .. } | Value::Filesize { val, .. } | Value::Duration { val, .. } => {
+            SqlValue::Integer(*val)
+        }
+        Value::Float { val, .. } => SqlValue::Real(*val),
+        Value::String { val, .. } => SqlValue::Text(val.clone()),
+        Value::Date { val, .. } => SqlValue::Text(val.to_rfc3339()),
+        Value::Binary { val, .. } => SqlValue::Blob(val.clone()),
+        Value::Nothing { .. } => SqlValue::Null,
+        other => {
+            return Err(ShellError::UnsupportedInput(
+                format!(
+                    "can't store a value of type {} in the database",
+                    other.get_type()
+                ),
+                other.span()?,
+            ))
+        }
+    })
+}
+
+fn sql_to_value(value: ValueRef, span: Span) -> Value {
+    match value {
+        ValueRef::Null => Value::Nothing { span },
+        ValueRef::Integer(val) => Value::Int { val, span },
+        ValueRef::Real(val) => Value::Float { val, span },
+        ValueRef::Text(val) => Value::String {
+            val: String::from_utf8_lossy(val).to_string(),
+            span,
+        },
+        ValueRef::Blob(val) => Value::Binary {
+            val: val.to_vec(),
+            span,
+        },
+    }
+}
+
+fn row_to_value(row: &Row, cols: &[String], span: Span) -> rusqlite::Result<Value> {
+    let mut vals = Vec::with_capacity(cols.len());
+    for idx in 0..cols.len() {
+        vals.push(sql_to_value(row.get_ref(idx)?, span));
+    }
+
+    Ok(Value::Record {
+        cols: cols.to_vec(),
+        vals,
+        span,
+    })
+}
+
+/// Run a query and collect its rows into a table
+fn query_table(conn: &Connection, sql: &str, span: Span) -> Result<Value, ShellError> {
+    let mut stmt = conn.prepare(sql).map_err(|e| db_error(e, span))?;
+    let cols: Vec<String> = stmt.column_names().iter().map(|c| c.to_string()).collect();
+
+    let rows = stmt
+        .query_map([], |row| row_to_value(row, &cols, span))
+        .map_err(|e| db_error(e, span))?
+        .collect::<rusqlite::Result<Vec<Value>>>()
+        .map_err(|e| db_error(e, span))?;
+
+    Ok(Value::List { vals: rows, span })
+}
+
+fn table_names(conn: &Connection, span: Span) -> Result<Vec<String>, ShellError> {
+    let mut stmt = conn
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name")
+        .map_err(|e| db_error(e, span))?;
+
+    let names = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| db_error(e, span))?
+        .collect::<rusqlite::Result<Vec<String>>>()
+        .map_err(|e| db_error(e, span))?;
+
+    Ok(names)
+}
+
+/// Take a record from a flag, or from the input when the flag isn't given
+fn record_from(
+    flag: Option<Value>,
+    input: Value,
+    name: &str,
+    span: Span,
+) -> Result<(Vec<String>, Vec<Value>), ShellError> {
+    match flag.unwrap_or(input) {
+        Value::Record { cols, vals, .. } => Ok((cols, vals)),
+        Value::Nothing { .. } => Err(ShellError::MissingParameter(name.into(), span)),
+        other => Err(ShellError::UnsupportedInput(
+            format!("expected a record, got {}", other.get_type()),
+            other.span()?,
+        )),
+    }
+}
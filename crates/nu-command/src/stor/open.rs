@@ -0,0 +1,65 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+use super::{query_table, quote_identifier, table_names, with_db};
+
+#[derive(Clone)]
+pub struct StorOpen;
+
+impl Command for StorOpen {
+    fn name(&self) -> &str {
+        "stor open"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor open").category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Get the contents of the in-memory database as a record of tables."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the rows of a table",
+            example: "stor open | get files",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let (cols, vals) = with_db(|conn| {
+            let tables = table_names(conn, head)?;
+            let contents = tables
+                .iter()
+                .map(|table| {
+                    query_table(
+                        conn,
+                        &format!("SELECT * FROM {}", quote_identifier(table)),
+                        head,
+                    )
+                })
+                .collect::<Result<Vec<Value>, ShellError>>()?;
+
+            Ok((tables, contents))
+        })?;
+
+        Ok(Value::Record {
+            cols,
+            vals,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+}
@@ -0,0 +1,49 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+};
+
+use super::{query_table, with_db};
+
+#[derive(Clone)]
+pub struct StorQuery;
+
+impl Command for StorQuery {
+    fn name(&self) -> &str {
+        "stor query"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor query")
+            .required("sql", SyntaxShape::String, "the SQL query to run")
+            .category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Run an SQL query on the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Get the total size of the stored files",
+            example: "stor query 'SELECT sum(size) AS total FROM files'",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let sql: Spanned<String> = call.req(engine_state, stack, 0)?;
+
+        let table = with_db(|conn| query_table(conn, &sql.item, sql.span))?;
+
+        Ok(table.into_pipeline_data())
+    }
+}
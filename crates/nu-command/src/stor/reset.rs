@@ -0,0 +1,53 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature,
+};
+
+use super::{db_error, quote_identifier, table_names, with_db};
+
+#[derive(Clone)]
+pub struct StorReset;
+
+impl Command for StorReset {
+    fn name(&self) -> &str {
+        "stor reset"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor reset").category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Drop every table of the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Start over with an empty database",
+            example: "stor reset",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        with_db(|conn| {
+            for table in table_names(conn, head)? {
+                conn.execute(&format!("DROP TABLE {}", quote_identifier(&table)), [])
+                    .map_err(|e| db_error(e, head))?;
+            }
+
+            Ok(())
+        })?;
+
+        Ok(PipelineData::new(head))
+    }
+}
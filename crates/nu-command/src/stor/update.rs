@@ -0,0 +1,94 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape, Value,
+};
+use rusqlite::params_from_iter;
+
+use super::{db_error, quote_identifier, record_from, value_to_sql, with_db};
+
+#[derive(Clone)]
+pub struct StorUpdate;
+
+impl Command for StorUpdate {
+    fn name(&self) -> &str {
+        "stor update"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("stor update")
+            .required_named(
+                "table-name",
+                SyntaxShape::String,
+                "name of the table to update",
+                Some('t'),
+            )
+            .named(
+                "update-record",
+                SyntaxShape::Any,
+                "a record of the columns to change and their new values, defaults to the input",
+                Some('u'),
+            )
+            .named(
+                "where-clause",
+                SyntaxShape::String,
+                "an SQL condition selecting the rows to update, all rows if not given",
+                Some('w'),
+            )
+            .category(Category::Database)
+    }
+
+    fn usage(&self) -> &str {
+        "Update rows of a table in the in-memory database."
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Change the size of a row",
+            example: "stor update --table-name files --update-record {size: 2kb} --where-clause \"name = 'a.txt'\"",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let table: Option<Spanned<String>> = call.get_flag(engine_state, stack, "table-name")?;
+        let record: Option<Value> = call.get_flag(engine_state, stack, "update-record")?;
+        let where_clause: Option<String> = call.get_flag(engine_state, stack, "where-clause")?;
+
+        let table = table.ok_or_else(|| ShellError::MissingParameter("table-name".into(), head))?;
+        let (cols, vals) = record_from(record, input.into_value(head), "update-record", head)?;
+
+        let params = vals
+            .iter()
+            .map(value_to_sql)
+            .collect::<Result<Vec<_>, ShellError>>()?;
+
+        let mut sql = format!(
+            "UPDATE {} SET {}",
+            quote_identifier(&table.item),
+            cols.iter()
+                .map(|col| format!("{} = ?", quote_identifier(col)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        if let Some(where_clause) = where_clause {
+            sql.push_str(" WHERE ");
+            sql.push_str(&where_clause);
+        }
+
+        with_db(|conn| {
+            conn.execute(&sql, params_from_iter(params))
+                .map_err(|e| db_error(e, table.span))
+        })?;
+
+        Ok(PipelineData::new(head))
+    }
+}
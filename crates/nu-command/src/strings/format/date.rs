@@ -0,0 +1,122 @@
+use chrono::format::{Item, StrftimeItems};
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct FormatDate;
+
+impl Command for FormatDate {
+    fn name(&self) -> &str {
+        "format date"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format date")
+            .required(
+                "format string",
+                SyntaxShape::String,
+                "the strftime format to show the dates with, like '%Y-%m-%d'",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "optionally format the dates in these columns",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Format dates with a strftime format string."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let format: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+        let head = call.head;
+
+        // Formatting a date with an invalid specifier panics, so check the format up front
+        if StrftimeItems::new(&format.item).any(|item| matches!(item, Item::Error)) {
+            return Err(ShellError::UnsupportedInput(
+                "invalid format string".into(),
+                format.span,
+            ));
+        }
+
+        input.map(
+            move |v| {
+                if column_paths.is_empty() {
+                    action(&v, &format.item, head)
+                } else {
+                    let mut ret = v;
+                    for path in &column_paths {
+                        let r = ret.update_cell_path(&path.members, &|old| {
+                            action(old, &format.item, head)
+                        });
+                        if let Err(error) = r {
+                            return Value::Error {
+                                error: Box::new(error),
+                            };
+                        }
+                    }
+                    ret
+                }
+            },
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show when files were modified as plain dates",
+                example: "ls | format date '%Y-%m-%d' modified",
+                result: None,
+            },
+            Example {
+                description: "Format a date",
+                example: "'2021-10-22 20:00:12 +01:00' | into datetime | format date '%d/%m/%Y'",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, format: &str, head: Span) -> Value {
+    match input {
+        Value::Date { val, .. } => Value::String {
+            val: val.format(format).to_string(),
+            span: head,
+        },
+        other => Value::Error {
+            error: Box::new(ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with dates.",
+                    other.get_type()
+                ),
+                other.span().unwrap_or(head),
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FormatDate {})
+    }
+}
@@ -0,0 +1,136 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::{Call, CellPath};
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Config, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape,
+    Value,
+};
+
+// The units that the `filesize_format` setting understands
+const UNITS: &[&str] = &[
+    "b", "kb", "kib", "mb", "mib", "gb", "gib", "tb", "tib", "pb", "pib", "eb", "eib", "zb", "zib",
+    "auto",
+];
+
+#[derive(Clone)]
+pub struct FormatFilesize;
+
+impl Command for FormatFilesize {
+    fn name(&self) -> &str {
+        "format filesize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("format filesize")
+            .required(
+                "unit",
+                SyntaxShape::String,
+                "the unit to show the sizes in, like kb, mib or auto",
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "optionally format the sizes in these columns",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "Format file sizes in the given unit."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "The units are the ones of the filesize_format setting, and follow filesize_metric the same way."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let unit: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
+        let head = call.head;
+
+        let unit_name = unit.item.to_lowercase();
+        if !UNITS.contains(&unit_name.as_str()) {
+            return Err(ShellError::UnsupportedInput(
+                format!("unknown unit, expected one of: {}", UNITS.join(", ")),
+                unit.span,
+            ));
+        }
+
+        let config = Config {
+            filesize_format: unit_name,
+            ..stack.get_config().unwrap_or_default()
+        };
+
+        input.map(
+            move |v| {
+                if column_paths.is_empty() {
+                    action(&v, &config, head)
+                } else {
+                    let mut ret = v;
+                    for path in &column_paths {
+                        let r =
+                            ret.update_cell_path(&path.members, &|old| action(old, &config, head));
+                        if let Err(error) = r {
+                            return Value::Error {
+                                error: Box::new(error),
+                            };
+                        }
+                    }
+                    ret
+                }
+            },
+            engine_state.ctrlc.clone(),
+        )
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the sizes of files in kilobytes",
+                example: "ls | format filesize kb size",
+                result: None,
+            },
+            Example {
+                description: "Format a file size in mebibytes",
+                example: "3mib | format filesize mib",
+                result: Some(Value::test_string("3.0 MiB")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, config: &Config, head: Span) -> Value {
+    match input {
+        Value::Filesize { .. } => Value::String {
+            val: input.clone().into_string("", config),
+            span: head,
+        },
+        other => Value::Error {
+            error: Box::new(ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with file sizes.",
+                    other.get_type()
+                ),
+                other.span().unwrap_or(head),
+            )),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(FormatFilesize {})
+    }
+}
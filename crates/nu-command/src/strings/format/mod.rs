@@ -1,3 +1,7 @@
 pub mod command;
+mod date;
+mod filesize;
 
 pub use command::Format;
+pub use date::FormatDate;
+pub use filesize::FormatFilesize;
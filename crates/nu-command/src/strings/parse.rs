@@ -2,8 +2,8 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, PipelineData, ShellError, Signature, Span, Spanned, SyntaxShape, Value,
-    ValueStream,
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Value,
 };
 use regex::Regex;
 
@@ -19,6 +19,13 @@ impl Command for Parse {
         "Parse columns from string data using a simple pattern."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"A column of a simple pattern can be given a modifier after a colon: `{size:int}`,
+`{ratio:decimal}`, `{size:filesize}` and `{flag:bool}` only match values of that type and
+put them in the column as such, while `{rest:greedy}` matches as much of the input as it can
+instead of as little. Columns without a modifier are strings."#
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("parse")
             .required(
@@ -27,6 +34,11 @@ impl Command for Parse {
                 "the pattern to match. Eg) \"{foo}: {bar}\"",
             )
             .switch("regex", "use full regex syntax for patterns", Some('r'))
+            .switch(
+                "greedy",
+                "make the columns without a modifier match as much as they can",
+                Some('g'),
+            )
             .category(Category::Strings)
     }
 
@@ -51,6 +63,30 @@ impl Command for Parse {
                 example: "echo \"hi there\" | parse -r \"(?P<foo>\\w+) (?P<bar>\\w+)\"",
                 result: Some(result),
             },
+            Example {
+                description: "Parse a number into an integer column",
+                example: "echo \"3 apples\" | parse \"{count:int} {fruit}\"",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["count".to_string(), "fruit".to_string()],
+                        vals: vec![Value::test_int(3), Value::test_string("apples")],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Let the first column take as much as it can",
+                example: "echo \"a,b,c\" | parse --greedy \"{first},{last}\"",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["first".to_string(), "last".to_string()],
+                        vals: vec![Value::test_string("a,b"), Value::test_string("c")],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
         ]
     }
 
@@ -74,66 +110,116 @@ fn operate(
     let head = call.head;
     let pattern: Spanned<String> = call.req(engine_state, stack, 0)?;
     let regex: bool = call.has_flag("regex");
+    let greedy: bool = call.has_flag("greedy");
     let ctrlc = engine_state.ctrlc.clone();
 
     let pattern_item = pattern.item;
     let pattern_span = pattern.span;
 
-    let item_to_parse = if regex {
-        pattern_item
+    let (item_to_parse, column_types) = if regex {
+        (pattern_item, vec![])
     } else {
-        build_regex(&pattern_item, pattern_span)?
+        build_regex(&pattern_item, pattern_span, greedy)?
     };
 
     let regex_pattern =
         Regex::new(&item_to_parse).map_err(|e| parse_regex_error(e, pattern_span))?;
 
     let columns = column_names(&regex_pattern);
-    let mut parsed: Vec<Value> = Vec::new();
 
-    for v in input {
-        match v.as_string() {
+    Ok(input
+        .into_iter()
+        .flat_map(move |v| match v.as_string() {
             Ok(s) => {
-                let results = regex_pattern.captures_iter(&s);
-
-                for c in results {
-                    let mut cols = Vec::with_capacity(columns.len());
-                    let mut vals = Vec::with_capacity(c.len());
-
-                    for (column_name, cap) in columns.iter().zip(c.iter().skip(1)) {
-                        let cap_string = cap.map(|v| v.as_str()).unwrap_or("").to_string();
-                        cols.push(column_name.clone());
-                        vals.push(Value::String {
-                            val: cap_string,
-                            span: v.span()?,
-                        });
-                    }
-
-                    parsed.push(Value::Record {
-                        cols,
-                        vals,
-                        span: head,
-                    });
-                }
+                let span = v.span().unwrap_or(head);
+
+                regex_pattern
+                    .captures_iter(&s)
+                    .map(|c| {
+                        let mut cols = Vec::with_capacity(columns.len());
+                        let mut vals = Vec::with_capacity(c.len());
+
+                        for (i, (column_name, cap)) in
+                            columns.iter().zip(c.iter().skip(1)).enumerate()
+                        {
+                            let cap_string = cap.map(|v| v.as_str()).unwrap_or("");
+                            let column_type =
+                                column_types.get(i).copied().unwrap_or(ColumnType::String);
+
+                            cols.push(column_name.clone());
+                            vals.push(column_type.to_value(cap_string, span));
+                        }
+
+                        Value::Record {
+                            cols,
+                            vals,
+                            span: head,
+                        }
+                    })
+                    .collect::<Vec<_>>()
             }
-            Err(_) => {
-                return Err(ShellError::PipelineMismatch(
+            Err(_) => vec![Value::Error {
+                error: Box::new(ShellError::PipelineMismatch(
                     "string".into(),
                     head,
-                    v.span()?,
-                ))
-            }
-        }
-    }
+                    v.span().unwrap_or(head),
+                )),
+            }],
+        })
+        .into_pipeline_data(ctrlc))
+}
+
+/// The type a column of a simple pattern is converted to, given with a modifier like `{size:int}`
+#[derive(Clone, Copy)]
+enum ColumnType {
+    String,
+    Int,
+    Decimal,
+    Filesize,
+    Bool,
+}
+
+impl ColumnType {
+    fn to_value(self, s: &str, span: Span) -> Value {
+        let value = match self {
+            ColumnType::String => Ok(Value::String {
+                val: s.to_string(),
+                span,
+            }),
+            ColumnType::Int => s
+                .parse::<i64>()
+                .map(|val| Value::Int { val, span })
+                .map_err(|_| ShellError::CantConvert("int".into(), "string".into(), span)),
+            ColumnType::Decimal => s
+                .parse::<f64>()
+                .map(|val| Value::Float { val, span })
+                .map_err(|_| ShellError::CantConvert("decimal".into(), "string".into(), span)),
+            ColumnType::Filesize => s
+                .parse::<bytesize::ByteSize>()
+                .map(|size| Value::Filesize {
+                    val: size.0 as i64,
+                    span,
+                })
+                .map_err(|_| ShellError::CantConvert("filesize".into(), "string".into(), span)),
+            ColumnType::Bool => Ok(Value::Bool {
+                val: s == "true",
+                span,
+            }),
+        };
 
-    Ok(PipelineData::Stream(
-        ValueStream::from_stream(parsed.into_iter(), ctrlc),
-        None,
-    ))
+        value.unwrap_or_else(|error| Value::Error {
+            error: Box::new(error),
+        })
+    }
 }
 
-fn build_regex(input: &str, span: Span) -> Result<String, ShellError> {
+fn build_regex(
+    input: &str,
+    span: Span,
+    greedy: bool,
+) -> Result<(String, Vec<ColumnType>), ShellError> {
     let mut output = "(?s)\\A".to_string();
+    let mut column_types = vec![];
 
     //let mut loop_input = input;
     let mut loop_input = input.chars().peekable();
@@ -172,9 +258,40 @@ fn build_regex(input: &str, span: Span) -> Result<String, ShellError> {
         }
 
         if !column.is_empty() {
+            let (name, modifier) = match column.split_once(':') {
+                Some((name, modifier)) => (name, Some(modifier.trim())),
+                None => (column.as_str(), None),
+            };
+
+            let (column_type, capture) = match modifier {
+                None | Some("string") if greedy => (ColumnType::String, ".*"),
+                None | Some("string") => (ColumnType::String, ".*?"),
+                Some("greedy") => (ColumnType::String, ".*"),
+                Some("int") => (ColumnType::Int, r"[-+]?\d+"),
+                Some("decimal") => (
+                    ColumnType::Decimal,
+                    r"[-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?",
+                ),
+                Some("filesize") => (ColumnType::Filesize, r"\d+(?:\.\d+)?\s?[a-zA-Z]*"),
+                Some("bool") => (ColumnType::Bool, "true|false"),
+                Some(modifier) => {
+                    return Err(ShellError::DelimiterError(
+                        format!(
+                            "Unknown column modifier `{}`, expected string, greedy, int, decimal, filesize or bool",
+                            modifier
+                        ),
+                        span,
+                    ))
+                }
+            };
+
             output.push_str("(?P<");
-            output.push_str(&column);
-            output.push_str(">.*?)");
+            output.push_str(name);
+            output.push('>');
+            output.push_str(capture);
+            output.push(')');
+
+            column_types.push(column_type);
         }
 
         if before.is_empty() && column.is_empty() {
@@ -183,7 +300,7 @@ fn build_regex(input: &str, span: Span) -> Result<String, ShellError> {
     }
 
     output.push_str("\\z");
-    Ok(output)
+    Ok((output, column_types))
 }
 
 fn column_names(regex: &Regex) -> Vec<String> {
@@ -110,7 +110,7 @@ fn size(
         move |v| match v.as_string() {
             Ok(s) => count(&s, span),
             Err(_) => Value::Error {
-                error: ShellError::PipelineMismatch("string".into(), span, span),
+                error: Box::new(ShellError::PipelineMismatch("string".into(), span, span)),
             },
         },
         engine_state.ctrlc.clone(),
@@ -72,11 +72,13 @@ fn split_chars_helper(v: &Value, name: Span) -> Vec<Value> {
                     .collect()
             } else {
                 vec![Value::Error {
-                    error: ShellError::PipelineMismatch("string".into(), name, v_span),
+                    error: Box::new(ShellError::PipelineMismatch("string".into(), name, v_span)),
                 }]
             }
         }
-        Err(error) => vec![Value::Error { error }],
+        Err(error) => vec![Value::Error {
+            error: Box::new(error),
+        }],
     }
 }
 
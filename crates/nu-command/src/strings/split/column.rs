@@ -107,9 +107,11 @@ fn split_column_helper(
     } else {
         match v.span() {
             Ok(span) => vec![Value::Error {
-                error: ShellError::PipelineMismatch("string".into(), head, span),
+                error: Box::new(ShellError::PipelineMismatch("string".into(), head, span)),
+            }],
+            Err(error) => vec![Value::Error {
+                error: Box::new(error),
             }],
-            Err(error) => vec![Value::Error { error }],
         }
     }
 }
@@ -0,0 +1,161 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Span,
+    SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "split list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("split list")
+            .required(
+                "separator",
+                SyntaxShape::Any,
+                "the value, or a block returning true for the values, that separates the sublists",
+            )
+            .category(Category::Filters)
+    }
+
+    fn usage(&self) -> &str {
+        "Split a list into multiple lists using a separator"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        split_list(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        let sublists = Some(Value::List {
+            vals: vec![
+                Value::List {
+                    vals: vec![Value::test_int(1), Value::test_int(2)],
+                    span: Span::unknown(),
+                },
+                Value::List {
+                    vals: vec![Value::test_int(3), Value::test_int(4)],
+                    span: Span::unknown(),
+                },
+            ],
+            span: Span::unknown(),
+        });
+
+        vec![
+            Example {
+                description: "Split a list of numbers on zero",
+                example: "[1 2 0 3 4] | split list 0",
+                result: sublists.clone(),
+            },
+            Example {
+                description: "Split a list on the values that match a block",
+                example: "[1 2 -1 3 4] | split list {|x| $x < 0 }",
+                result: sublists,
+            },
+        ]
+    }
+}
+
+fn split_list(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let span = call.head;
+
+    let mut is_separator: Box<dyn FnMut(&Value) -> Result<bool, ShellError> + Send> =
+        match call.positional[0].as_block() {
+            Some(block_id) => {
+                let engine_state = engine_state.clone();
+                let block = engine_state.get_block(block_id).clone();
+                let var_id = block.signature.get_positional(0).and_then(|arg| arg.var_id);
+                let mut stack = stack.collect_captures(&block.captures);
+
+                Box::new(move |value| {
+                    if let Some(var_id) = var_id {
+                        stack.add_var(var_id, value.clone());
+                    }
+
+                    match eval_block(&engine_state, &mut stack, &block, PipelineData::new(span))?
+                        .into_value(span)
+                    {
+                        Value::Error { error } => Err(*error),
+                        value => Ok(value.is_true()),
+                    }
+                })
+            }
+            None => {
+                let separator: Value = call.req(engine_state, stack, 0)?;
+
+                Box::new(move |value| Ok(value == &separator))
+            }
+        };
+
+    let mut input = input.into_iter();
+    let mut failed = false;
+
+    // Separators are dropped, and each one ends a sublist, even an empty one between separators
+    // that follow each other. Nothing is left after a trailing separator, and a failing block
+    // ends the output with its error.
+    let sublists = std::iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+
+        let mut sublist = vec![];
+
+        for value in input.by_ref() {
+            match is_separator(&value) {
+                Ok(false) => sublist.push(value),
+                Ok(true) => {
+                    return Some(Value::List {
+                        vals: sublist,
+                        span,
+                    })
+                }
+                Err(error) => {
+                    failed = true;
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    });
+                }
+            }
+        }
+
+        if sublist.is_empty() {
+            None
+        } else {
+            Some(Value::List {
+                vals: sublist,
+                span,
+            })
+        }
+    });
+
+    Ok(sublists.into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
@@ -1,9 +1,11 @@
 pub mod chars;
 pub mod column;
 pub mod command;
+pub mod list;
 pub mod row;
 
 pub use chars::SubCommand as SplitChars;
 pub use column::SubCommand as SplitColumn;
 pub use command::SplitCommand as Split;
+pub use list::SubCommand as SplitList;
 pub use row::SubCommand as SplitRow;
@@ -69,11 +69,13 @@ fn split_row_helper(v: &Value, separator: &Spanned<String>, name: Span) -> Vec<V
                     .collect()
             } else {
                 vec![Value::Error {
-                    error: ShellError::PipelineMismatch("string".into(), name, v_span),
+                    error: Box::new(ShellError::PipelineMismatch("string".into(), name, v_span)),
                 }]
             }
         }
-        Err(error) => vec![Value::Error { error }],
+        Err(error) => vec![Value::Error {
+            error: Box::new(error),
+        }],
     }
 }
 
@@ -38,12 +38,12 @@ where
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, case_operation, head)),
-                    );
+                    let r = ret
+                        .update_cell_path(&path.members, &|old| action(old, case_operation, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -63,13 +63,13 @@ where
             span: head,
         },
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
@@ -2,8 +2,8 @@ use nu_engine::CallExt;
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
-    Value,
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Spanned,
+    SyntaxShape, Value,
 };
 
 #[derive(Clone)]
@@ -21,6 +21,12 @@ impl Command for StrCollect {
                 SyntaxShape::String,
                 "optional separator to use when creating string",
             )
+            .named(
+                "column",
+                SyntaxShape::String,
+                "the column whose values are joined, when the input is a table",
+                Some('c'),
+            )
             .category(Category::Strings)
     }
 
@@ -28,6 +34,10 @@ impl Command for StrCollect {
         "creates a string from the input, optionally using a separator"
     }
 
+    fn extra_usage(&self) -> &str {
+        "Collecting a table without --column stringifies whole rows; this behavior is deprecated."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -36,16 +46,41 @@ impl Command for StrCollect {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let separator: Option<String> = call.opt(engine_state, stack, 0)?;
+        let column: Option<Spanned<String>> = call.get_flag(engine_state, stack, "column")?;
 
         let config = stack.get_config().unwrap_or_default();
 
+        let values: Vec<Value> = input.into_iter().collect();
+
         // Hmm, not sure what we actually want. If you don't use debug_string, Date comes out as human readable
         // which feels funny
-        #[allow(clippy::needless_collect)]
-        let strings: Vec<String> = input
-            .into_iter()
-            .map(|value| value.debug_string("\n", &config))
-            .collect();
+        let strings = match column {
+            Some(column) => values
+                .into_iter()
+                .map(|value| match value.get_data_by_key(&column.item) {
+                    Some(cell) => Ok(cell.debug_string("\n", &config)),
+                    None => Err(ShellError::CantFindColumn(column.span, value.span()?)),
+                })
+                .collect::<Result<Vec<String>, ShellError>>()?,
+            None => {
+                // Stringifying whole records is deprecated, a column should be passed instead
+                if values
+                    .iter()
+                    .any(|value| matches!(value, Value::Record { .. }))
+                {
+                    engine_state.warnings.push(ShellError::Deprecated(
+                        "str collect on a table".into(),
+                        "pass the column to join with --column".into(),
+                        call.head,
+                    ));
+                }
+
+                values
+                    .into_iter()
+                    .map(|value| value.debug_string("\n", &config))
+                    .collect()
+            }
+        };
 
         let output = if let Some(separator) = separator {
             strings.join(&separator)
@@ -78,6 +113,14 @@ impl Command for StrCollect {
                     span: Span::unknown(),
                 }),
             },
+            Example {
+                description: "Join the values of a table column with a separator",
+                example: "[[name]; [nu] [shell]] | str collect ', ' --column name",
+                result: Some(Value::String {
+                    val: "nu, shell".to_string(),
+                    span: Span::unknown(),
+                }),
+            },
         ]
     }
 }
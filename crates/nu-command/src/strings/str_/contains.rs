@@ -146,13 +146,13 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let p = pattern.item.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, case_insensitive, &p, head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| {
+                        action(old, case_insensitive, &pattern.item, head)
+                    });
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -172,13 +172,13 @@ fn action(input: &Value, case_insensitive: bool, pattern: &str, head: Span) -> V
             span: head,
         },
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
@@ -116,10 +116,11 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -136,13 +137,13 @@ fn action(input: &Value, head: Span) -> Value {
             span: head,
         },
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
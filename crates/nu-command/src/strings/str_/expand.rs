@@ -0,0 +1,393 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::ast::CellPath;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::Category;
+use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
+
+// Expansions with more strings than this are refused, as a range like {1..9223372036854775807}
+// couldn't be held in memory
+const MAX_EXPANSION: usize = 1_000_000;
+
+#[derive(Clone)]
+pub struct SubCommand;
+
+impl Command for SubCommand {
+    fn name(&self) -> &str {
+        "str expand"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str expand")
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "optionally expand text by column paths",
+            )
+            .category(Category::Strings)
+    }
+
+    fn usage(&self) -> &str {
+        "expands the braces in strings into lists of strings, like a shell's brace expansion"
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A brace holds either alternatives separated by commas, {a,b,c}, or a range of numbers or
+letters, {1..10} or {a..e}, with an optional step as in {0..100..10}. Braces can be nested.
+Numbers with leading zeros keep the width of the widest end, so {01..10} gives 01 to 10.
+Braces that are neither are kept as they are, and a backslash keeps the next character
+from being special."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        operate(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Expand alternatives and a range",
+                example: "'file{1..2}.{txt,md}' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("file1.txt"),
+                        Value::test_string("file1.md"),
+                        Value::test_string("file2.txt"),
+                        Value::test_string("file2.md"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Expand a zero-padded range with a step",
+                example: "'img{00..20..10}.png' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("img00.png"),
+                        Value::test_string("img10.png"),
+                        Value::test_string("img20.png"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Expand nested braces",
+                example: "'src/{lib,bin/{a,b}}.rs' | str expand",
+                result: Some(Value::List {
+                    vals: vec![
+                        Value::test_string("src/lib.rs"),
+                        Value::test_string("src/bin/a.rs"),
+                        Value::test_string("src/bin/b.rs"),
+                    ],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+}
+
+fn operate(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let column_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+    input.map(
+        move |v| {
+            if column_paths.is_empty() {
+                action(&v, head)
+            } else {
+                let mut ret = v;
+                for path in &column_paths {
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head));
+                    if let Err(error) = r {
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
+                    }
+                }
+                ret
+            }
+        },
+        engine_state.ctrlc.clone(),
+    )
+}
+
+fn action(input: &Value, head: Span) -> Value {
+    match input {
+        Value::String { val, span } => match expand(val) {
+            Ok(expanded) => Value::List {
+                vals: expanded
+                    .iter()
+                    .map(|expanded| Value::String {
+                        val: unescape(expanded),
+                        span: head,
+                    })
+                    .collect(),
+                span: head,
+            },
+            Err(message) => Value::Error {
+                error: Box::new(ShellError::UnsupportedInput(message, *span)),
+            },
+        },
+
+        other => Value::Error {
+            error: Box::new(ShellError::UnsupportedInput(
+                format!(
+                    "Input's type is {}. This command only works with strings.",
+                    other.get_type()
+                ),
+                Span::unknown(),
+            )),
+        },
+    }
+}
+
+// Expands the first brace that can be expanded, then the alternatives and the text after it.
+// Backslashes are left in place so that the escaped characters stay literal in the recursion.
+fn expand(text: &str) -> Result<Vec<String>, String> {
+    let mut search_from = 0;
+
+    while let Some((open, close, commas)) = find_brace(text, search_from) {
+        let alternatives = if commas.is_empty() {
+            match expand_range(&text[open + 1..close]) {
+                Some(range) => range?,
+                None => {
+                    search_from = open + 1;
+                    continue;
+                }
+            }
+        } else {
+            let mut bounds = vec![open];
+            bounds.extend(&commas);
+            bounds.push(close);
+
+            let mut alternatives = vec![];
+            for pair in bounds.windows(2) {
+                alternatives.extend(expand(&text[pair[0] + 1..pair[1]])?);
+                if alternatives.len() > MAX_EXPANSION {
+                    return Err(too_large());
+                }
+            }
+            alternatives
+        };
+
+        let prefix = &text[..open];
+        let suffixes = expand(&text[close + 1..])?;
+
+        if alternatives.len().saturating_mul(suffixes.len()) > MAX_EXPANSION {
+            return Err(too_large());
+        }
+
+        return Ok(alternatives
+            .iter()
+            .flat_map(|alternative| {
+                suffixes
+                    .iter()
+                    .map(move |suffix| format!("{}{}{}", prefix, alternative, suffix))
+            })
+            .collect());
+    }
+
+    Ok(vec![text.to_string()])
+}
+
+fn too_large() -> String {
+    format!("the braces expand to more than {} strings", MAX_EXPANSION)
+}
+
+// Finds the next unescaped `{` from `start` with its matching `}` and the positions of the
+// commas directly inside it
+fn find_brace(text: &str, start: usize) -> Option<(usize, usize, Vec<usize>)> {
+    let bytes = text.as_bytes();
+    let mut idx = start;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 1,
+            b'{' => {
+                if let Some((close, commas)) = find_close(bytes, idx) {
+                    return Some((idx, close, commas));
+                }
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+
+    None
+}
+
+fn find_close(bytes: &[u8], open: usize) -> Option<(usize, Vec<usize>)> {
+    let mut depth = 0;
+    let mut commas = vec![];
+    let mut idx = open + 1;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 1,
+            b'{' => depth += 1,
+            b'}' if depth == 0 => return Some((idx, commas)),
+            b'}' => depth -= 1,
+            b',' if depth == 0 => commas.push(idx),
+            _ => {}
+        }
+        idx += 1;
+    }
+
+    None
+}
+
+// The values of a `start..end` or `start..end..step` range of numbers or single letters, or
+// None if the body isn't a range
+fn expand_range(body: &str) -> Option<Result<Vec<String>, String>> {
+    let parts: Vec<&str> = body.split("..").collect();
+    let (start, end, step) = match parts.as_slice() {
+        [start, end] => (*start, *end, 1),
+        [start, end, step] => (
+            *start,
+            *end,
+            step.parse::<i64>().ok()?.unsigned_abs().max(1),
+        ),
+        _ => return None,
+    };
+
+    if let (Ok(from), Ok(to)) = (start.parse::<i64>(), end.parse::<i64>()) {
+        let width = if has_leading_zero(start) || has_leading_zero(end) {
+            start.len().max(end.len())
+        } else {
+            0
+        };
+
+        return Some(range_steps(from, to, step).map(|steps| {
+            steps
+                .map(|n| {
+                    if n < 0 {
+                        format!(
+                            "-{:0width$}",
+                            n.unsigned_abs(),
+                            width = width.saturating_sub(1)
+                        )
+                    } else {
+                        format!("{:0width$}", n, width = width)
+                    }
+                })
+                .collect()
+        }));
+    }
+
+    let mut start_chars = start.chars();
+    let mut end_chars = end.chars();
+    match (
+        start_chars.next(),
+        start_chars.next(),
+        end_chars.next(),
+        end_chars.next(),
+    ) {
+        (Some(from), None, Some(to), None)
+            if from.is_ascii_alphabetic() && to.is_ascii_alphabetic() =>
+        {
+            Some(
+                range_steps(from as i64, to as i64, step)
+                    .map(|steps| steps.map(|c| ((c as u8) as char).to_string()).collect()),
+            )
+        }
+        _ => None,
+    }
+}
+
+fn range_steps(from: i64, to: i64, step: u64) -> Result<impl Iterator<Item = i64>, String> {
+    let count = from.abs_diff(to) / step + 1;
+    if count > MAX_EXPANSION as u64 {
+        return Err(too_large());
+    }
+
+    // Every value lies between `from` and `to`, but the offset may not fit in an i64 on its own
+    let step = if from <= to {
+        step as i128
+    } else {
+        -(step as i128)
+    };
+    Ok((0..count).map(move |idx| (from as i128 + idx as i128 * step) as i64))
+}
+
+fn has_leading_zero(number: &str) -> bool {
+    let digits = number.trim_start_matches('-');
+    digits.len() > 1 && digits.starts_with('0')
+}
+
+// Drops the backslashes that kept characters from being special
+fn unescape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => output.extend(chars.next()),
+            c => output.push(c),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expanded(text: &str) -> Vec<String> {
+        expand(text).unwrap().iter().map(|s| unescape(s)).collect()
+    }
+
+    #[test]
+    fn refuses_huge_expansions() {
+        assert!(expand("{1..9223372036854775807}").is_err());
+        assert!(expand("{-9223372036854775808..9223372036854775807}").is_err());
+        assert!(expand("{1..1000}{1..1000}{1..1000}").is_err());
+    }
+
+    #[test]
+    fn expands_ranges_at_the_ends_of_i64() {
+        assert_eq!(
+            expanded("{-9223372036854775808..-9223372036854775807}"),
+            vec!["-9223372036854775808", "-9223372036854775807"]
+        );
+        assert_eq!(
+            expanded("{1..9223372036854775807..9223372036854775807}"),
+            vec!["1"]
+        );
+        assert_eq!(expanded("{1..2..-9223372036854775808}"), vec!["1"]);
+    }
+
+    #[test]
+    fn expands_ranges() {
+        assert_eq!(expanded("{3..1}"), vec!["3", "2", "1"]);
+        assert_eq!(expanded("{-1..1}"), vec!["-1", "0", "1"]);
+        assert_eq!(expanded("{08..10}"), vec!["08", "09", "10"]);
+        assert_eq!(expanded("{a..e..2}"), vec!["a", "c", "e"]);
+    }
+
+    #[test]
+    fn keeps_braces_that_do_not_expand() {
+        assert_eq!(expanded("{abc}"), vec!["{abc}"]);
+        assert_eq!(expanded("{x}{1,2}"), vec!["{x}1", "{x}2"]);
+        assert_eq!(expanded("a\\{1,2}"), vec!["a{1,2}"]);
+        assert_eq!(expanded("{a,b"), vec!["{a,b"]);
+        assert_eq!(expanded("{,x}y"), vec!["y", "xy"]);
+    }
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SubCommand {})
+    }
+}
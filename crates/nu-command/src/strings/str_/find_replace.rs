@@ -10,11 +10,28 @@ use std::sync::Arc;
 
 struct Arguments {
     all: bool,
-    find: String,
+    find: Regex,
     replace: String,
     column_paths: Vec<CellPath>,
 }
 
+fn signature(name: &str) -> Signature {
+    Signature::build(name)
+        .required("find", SyntaxShape::String, "the regex pattern to find")
+        .required(
+            "replace",
+            SyntaxShape::String,
+            "the replacement, where $1 or ${name} insert capture groups",
+        )
+        .rest(
+            "rest",
+            SyntaxShape::CellPath,
+            "optionally find and replace text by column paths",
+        )
+        .switch("all", "replace all occurrences of find string", Some('a'))
+        .category(Category::Strings)
+}
+
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -24,16 +41,7 @@ impl Command for SubCommand {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("str find-replace")
-            .required("find", SyntaxShape::String, "the pattern to find")
-            .required("replace", SyntaxShape::String, "the replacement pattern")
-            .rest(
-                "rest",
-                SyntaxShape::CellPath,
-                "optionally find and replace text by column paths",
-            )
-            .switch("all", "replace all occurrences of find string", Some('a'))
-            .category(Category::Strings)
+        signature(self.name()).deprecated("use str replace instead")
     }
 
     fn usage(&self) -> &str {
@@ -98,6 +106,61 @@ impl Command for SubCommand {
     }
 }
 
+#[derive(Clone)]
+pub struct Replace;
+
+impl Command for Replace {
+    fn name(&self) -> &str {
+        "str replace"
+    }
+
+    fn signature(&self) -> Signature {
+        signature(self.name())
+    }
+
+    fn usage(&self) -> &str {
+        "finds and replaces text"
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        operate(engine_state, stack, call, input)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Swap two words with capture groups",
+                example: "'hello world' | str replace '(\\w+) (\\w+)' '$2 $1'",
+                result: Some(Value::String {
+                    val: "world hello".to_string(),
+                    span: Span::unknown(),
+                }),
+            },
+            Example {
+                description: "Replace all the digits in a column",
+                example: "[[id]; [a1b2]] | str replace -a '\\d' '#' id",
+                result: Some(Value::List {
+                    vals: vec![Value::Record {
+                        cols: vec!["id".to_string()],
+                        vals: vec![Value::String {
+                            val: "a#b#".to_string(),
+                            span: Span::unknown(),
+                        }],
+                        span: Span::unknown(),
+                    }],
+                    span: Span::unknown(),
+                }),
+            },
+        ]
+    }
+}
+
 fn operate(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -107,9 +170,13 @@ fn operate(
     let find: Spanned<String> = call.req(engine_state, stack, 0)?;
     let replace: Spanned<String> = call.req(engine_state, stack, 1)?;
 
+    let regex = Regex::new(&find.item).map_err(|err| {
+        ShellError::UnsupportedInput(format!("invalid regex: {}", err), find.span)
+    })?;
+
     let options = Arc::new(Arguments {
         all: call.has_flag("all"),
-        find: find.item,
+        find: regex,
         replace: replace.item,
         column_paths: call.rest(engine_state, stack, 2)?,
     });
@@ -121,13 +188,11 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let opt = options.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, &opt, head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, &options, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -137,8 +202,6 @@ fn operate(
     )
 }
 
-struct FindReplace<'a>(&'a str, &'a str);
-
 fn action(
     input: &Value,
     Arguments {
@@ -148,37 +211,26 @@ fn action(
 ) -> Value {
     match input {
         Value::String { val, .. } => {
-            let FindReplace(find, replacement) = FindReplace(find, replace);
-            let regex = Regex::new(find);
-
-            match regex {
-                Ok(re) => {
-                    if *all {
-                        Value::String {
-                            val: re.replace_all(val, replacement).to_string(),
-                            span: head,
-                        }
-                    } else {
-                        Value::String {
-                            val: re.replace(val, replacement).to_string(),
-                            span: head,
-                        }
-                    }
+            if *all {
+                Value::String {
+                    val: find.replace_all(val, replace.as_str()).to_string(),
+                    span: head,
                 }
-                Err(_) => Value::String {
-                    val: val.to_string(),
+            } else {
+                Value::String {
+                    val: find.replace(val, replace.as_str()).to_string(),
                     span: head,
-                },
+                }
             }
         }
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
@@ -186,7 +238,7 @@ fn action(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use super::{action, Arguments, SubCommand};
+    use super::{action, Arguments, Replace, SubCommand};
 
     #[test]
     fn test_examples() {
@@ -195,6 +247,13 @@ mod tests {
         test_examples(SubCommand {})
     }
 
+    #[test]
+    fn test_replace_examples() {
+        use crate::test_examples;
+
+        test_examples(Replace {})
+    }
+
     #[test]
     fn can_have_capture_groups() {
         let word = Value::String {
@@ -203,7 +262,7 @@ mod tests {
         };
 
         let options = Arguments {
-            find: String::from("Cargo.(.+)"),
+            find: Regex::new("Cargo.(.+)").expect("valid regex"),
             replace: String::from("Carga.$1"),
             column_paths: vec![],
             all: false,
@@ -119,13 +119,11 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let opt = options.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, &opt, head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, &options, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -159,7 +157,7 @@ fn action(
         Value::String { val: s, .. } => {
             let (start_index, end_index) = match r {
                 Ok(r) => (r.0 as usize, r.1 as usize),
-                Err(e) => return Value::Error { error: e },
+                Err(e) => return Value::Error { error: Box::new(e) },
             };
 
             if *end {
@@ -187,13 +185,13 @@ fn action(
             }
         }
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
@@ -4,6 +4,7 @@ mod collect;
 mod contains;
 mod downcase;
 mod ends_with;
+mod expand;
 mod find_replace;
 mod index_of;
 mod length;
@@ -21,6 +22,8 @@ pub use collect::*;
 pub use contains::SubCommand as StrContains;
 pub use downcase::SubCommand as StrDowncase;
 pub use ends_with::SubCommand as StrEndswith;
+pub use expand::SubCommand as StrExpand;
+pub use find_replace::Replace as StrReplace;
 pub use find_replace::SubCommand as StrFindReplace;
 pub use index_of::SubCommand as StrIndexOf;
 pub use length::SubCommand as StrLength;
@@ -96,13 +96,11 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let opt = options.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, &opt, head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, &options, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -122,13 +120,13 @@ fn action(input: &Value, Arguments { pattern, .. }: &Arguments, head: Span) -> V
             }
         }
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 Span::unknown(),
-            ),
+            )),
         },
     }
 }
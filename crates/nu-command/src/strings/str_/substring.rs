@@ -1,19 +1,29 @@
 use nu_engine::CallExt;
 use nu_protocol::ast::Call;
-use nu_protocol::ast::CellPath;
+use nu_protocol::ast::{CellPath, RangeInclusion};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{Example, PipelineData, ShellError, Signature, Span, SyntaxShape, Value};
 use std::cmp::Ordering;
 use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 
 #[derive(Clone)]
 pub struct SubCommand;
 
 struct Arguments {
     range: Value,
+    indexing: Indexing,
     column_paths: Vec<CellPath>,
 }
 
+/// What the indexes of the substring count
+#[derive(Clone, Copy)]
+enum Indexing {
+    Chars,
+    GraphemeClusters,
+    Utf8Bytes,
+}
+
 #[derive(Clone)]
 struct Substring(isize, isize);
 
@@ -35,7 +45,17 @@ impl Command for SubCommand {
             .required(
                 "range",
                 SyntaxShape::Any,
-                "the indexes to substring [start end]",
+                "the indexes to substring, as a range or [start end]",
+            )
+            .switch(
+                "grapheme-clusters",
+                "count the indexes in grapheme clusters, like a user sees the characters",
+                Some('g'),
+            )
+            .switch(
+                "utf-8-bytes",
+                "count the indexes in the bytes of the UTF-8 encoded text",
+                Some('b'),
             )
             .rest(
                 "rest",
@@ -85,6 +105,22 @@ impl Command for SubCommand {
                 example: " 'good nushell' | str substring ',7'",
                 result: Some(Value::test_string("good nu")),
             },
+            Example {
+                description: "Get a substring using a range, which includes its end",
+                example: " 'good nushell' | str substring 5..11",
+                result: Some(Value::test_string("nushell")),
+            },
+            Example {
+                description:
+                    "Count the indexes in grapheme clusters, so an emoji with a skin tone stays whole",
+                example: " '👍🏽 nu' | str substring --grapheme-clusters 0..0",
+                result: Some(Value::test_string("👍🏽")),
+            },
+            Example {
+                description: "Count the indexes in UTF-8 bytes",
+                example: " 'nüshell' | str substring --utf-8-bytes 0..2",
+                result: Some(Value::test_string("nü")),
+            },
         ]
     }
 }
@@ -95,8 +131,24 @@ fn operate(
     call: &Call,
     input: PipelineData,
 ) -> Result<PipelineData, ShellError> {
+    let indexing = match (
+        call.has_flag("grapheme-clusters"),
+        call.has_flag("utf-8-bytes"),
+    ) {
+        (true, true) => {
+            return Err(ShellError::IncompatibleParametersSingle(
+                "only one of --grapheme-clusters and --utf-8-bytes can be used".into(),
+                call.head,
+            ))
+        }
+        (true, false) => Indexing::GraphemeClusters,
+        (false, true) => Indexing::Utf8Bytes,
+        (false, false) => Indexing::Chars,
+    };
+
     let options = Arc::new(Arguments {
         range: call.req(engine_state, stack, 0)?,
+        indexing,
         column_paths: call.rest(engine_state, stack, 1)?,
     });
 
@@ -106,17 +158,17 @@ fn operate(
     input.map(
         move |v| {
             if options.column_paths.is_empty() {
-                action(&v, &indexes, head)
+                action(&v, &indexes, options.indexing, head)
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let indexes = indexes.clone();
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| action(old, &indexes, head)),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| {
+                        action(old, &indexes, options.indexing, head)
+                    });
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -126,10 +178,14 @@ fn operate(
     )
 }
 
-fn action(input: &Value, options: &Substring, head: Span) -> Value {
+fn action(input: &Value, options: &Substring, indexing: Indexing, head: Span) -> Value {
     match input {
         Value::String { val: s, .. } => {
-            let len: isize = s.len() as isize;
+            let len = match indexing {
+                Indexing::Chars => s.chars().count(),
+                Indexing::GraphemeClusters => s.graphemes(true).count(),
+                Indexing::Utf8Bytes => s.len(),
+            } as isize;
 
             let start: isize = if options.0 < 0 {
                 options.0 + len
@@ -149,21 +205,20 @@ fn action(input: &Value, options: &Substring, head: Span) -> Value {
                         span: head,
                     },
                     Ordering::Greater => Value::Error {
-                        error: ShellError::UnsupportedInput(
+                        error: Box::new(ShellError::UnsupportedInput(
                             "End must be greater than or equal to Start".to_string(),
                             head,
-                        ),
+                        )),
                     },
                     Ordering::Less => Value::String {
                         val: {
-                            if end == isize::max_value() {
-                                s.chars().skip(start as usize).collect::<String>()
+                            let count = if end == isize::max_value() {
+                                usize::MAX
                             } else {
-                                s.chars()
-                                    .skip(start as usize)
-                                    .take((end - start) as usize)
-                                    .collect::<String>()
-                            }
+                                (end - start) as usize
+                            };
+
+                            substring(s, start as usize, count, indexing)
                         },
                         span: head,
                     },
@@ -176,18 +231,48 @@ fn action(input: &Value, options: &Substring, head: Span) -> Value {
             }
         }
         other => Value::Error {
-            error: ShellError::UnsupportedInput(
+            error: Box::new(ShellError::UnsupportedInput(
                 format!(
                     "Input's type is {}. This command only works with strings.",
                     other.get_type()
                 ),
                 head,
-            ),
+            )),
         },
     }
 }
 
+fn substring(s: &str, start: usize, count: usize, indexing: Indexing) -> String {
+    match indexing {
+        Indexing::Chars => s.chars().skip(start).take(count).collect(),
+        Indexing::GraphemeClusters => s.graphemes(true).skip(start).take(count).collect(),
+        // A cut through the middle of a character leaves a replacement character in its place
+        Indexing::Utf8Bytes => {
+            let bytes: Vec<u8> = s.bytes().skip(start).take(count).collect();
+            String::from_utf8_lossy(&bytes).to_string()
+        }
+    }
+}
+
 fn process_arguments(options: &Arguments, head: Span) -> Result<(isize, isize), ShellError> {
+    if let Value::Range { val: range, .. } = &options.range {
+        let start = match &range.from {
+            Value::Int { val, .. } => *val as isize,
+            _ => return Err(range_of_ints_expected(head)),
+        };
+
+        // Unlike the [start end] form, a range includes its end unless it's written as `start..<end`
+        let end = match (&range.to, &range.inclusion) {
+            (Value::Nothing { .. }, _)
+            | (Value::Int { val: -1, .. }, RangeInclusion::Inclusive) => isize::max_value(),
+            (Value::Int { val, .. }, RangeInclusion::Inclusive) => *val as isize + 1,
+            (Value::Int { val, .. }, RangeInclusion::RightExclusive) => *val as isize,
+            _ => return Err(range_of_ints_expected(head)),
+        };
+
+        return Ok((start, end));
+    }
+
     let search = match &options.range {
         Value::List { vals, .. } => {
             if vals.len() > 2 {
@@ -273,9 +358,16 @@ fn process_arguments(options: &Arguments, head: Span) -> Result<(isize, isize),
     Ok((start, end))
 }
 
+fn range_of_ints_expected(head: Span) -> ShellError {
+    ShellError::UnsupportedInput(
+        "could not perform substring. Expecting a range of ints".to_string(),
+        head,
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{action, Span, SubCommand, Substring, Value};
+    use super::{action, Indexing, Span, SubCommand, Substring, Value};
 
     #[test]
     fn test_examples() {
@@ -336,7 +428,12 @@ mod tests {
 
         for expectation in &cases {
             let expected = expectation.expected;
-            let actual = action(&word, &expectation.options(), Span::unknown());
+            let actual = action(
+                &word,
+                &expectation.options(),
+                Indexing::Chars,
+                Span::unknown(),
+            );
 
             assert_eq!(
                 actual,
@@ -347,4 +444,36 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn substrings_multibyte() {
+        let word = Value::String {
+            // The é is an e followed by a combining accent
+            val: "añdre\u{301}s".to_string(),
+            span: Span::unknown(),
+        };
+
+        let cases = vec![
+            (Indexing::Chars, (1, -1), "ñdre\u{301}"),
+            (Indexing::Chars, (-3, isize::max_value()), "e\u{301}s"),
+            (
+                Indexing::GraphemeClusters,
+                (3, isize::max_value()),
+                "re\u{301}s",
+            ),
+            (Indexing::GraphemeClusters, (-2, -1), "e\u{301}"),
+            (Indexing::Utf8Bytes, (0, 3), "añ"),
+        ];
+
+        for (indexing, options, expected) in cases {
+            let actual = action(
+                &word,
+                &Substring(options.0, options.1),
+                indexing,
+                Span::unknown(),
+            );
+
+            assert_eq!(actual, Value::test_string(expected));
+        }
+    }
 }
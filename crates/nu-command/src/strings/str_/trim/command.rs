@@ -161,21 +161,20 @@ where
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
-                    let r = ret.update_cell_path(
-                        &path.members,
-                        Box::new(move |old| {
-                            action(
-                                old,
-                                head,
-                                to_trim,
-                                &closure_flags,
-                                &trim_operation,
-                                ActionMode::Local,
-                            )
-                        }),
-                    );
+                    let r = ret.update_cell_path(&path.members, &|old| {
+                        action(
+                            old,
+                            head,
+                            to_trim,
+                            &closure_flags,
+                            &trim_operation,
+                            ActionMode::Local,
+                        )
+                    });
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -237,7 +236,7 @@ where
             ActionMode::Local => {
                 let got = format!("Input must be a string. Found {}", other.get_type());
                 Value::Error {
-                    error: ShellError::UnsupportedInput(got, head),
+                    error: Box::new(ShellError::UnsupportedInput(got, head)),
                 }
             }
         },
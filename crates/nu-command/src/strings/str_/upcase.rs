@@ -58,10 +58,11 @@ fn operate(
             } else {
                 let mut ret = v;
                 for path in &column_paths {
-                    let r =
-                        ret.update_cell_path(&path.members, Box::new(move |old| action(old, head)));
+                    let r = ret.update_cell_path(&path.members, &|old| action(old, head));
                     if let Err(error) = r {
-                        return Value::Error { error };
+                        return Value::Error {
+                            error: Box::new(error),
+                        };
                     }
                 }
                 ret
@@ -80,7 +81,7 @@ fn action(input: &Value, head: Span) -> Value {
         other => {
             let got = format!("Expected string but got {}", other.get_type());
             Value::Error {
-                error: ShellError::UnsupportedInput(got, head),
+                error: Box::new(ShellError::UnsupportedInput(got, head)),
             }
         }
     }
@@ -0,0 +1,140 @@
+use super::is_admin::is_admin;
+use crossterm::tty::IsTty;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+};
+use terminal_size::{terminal_size, Height, Width};
+
+#[derive(Clone)]
+pub struct SysCapabilities;
+
+impl Command for SysCapabilities {
+    fn name(&self) -> &str {
+        "sys capabilities"
+    }
+
+    fn usage(&self) -> &str {
+        "View what the terminal and environment nushell runs in support."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys capabilities").category(Category::System)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(capabilities(stack, call.head).into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the capabilities of the current terminal",
+                example: "sys capabilities",
+                result: None,
+            },
+            Example {
+                description: "Only use nerd font icons when the terminal can show them",
+                example: "if (sys capabilities).unicode { ls --icons } else { ls }",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn capabilities(stack: &Stack, span: Span) -> Value {
+    let env = |name: &str| {
+        stack
+            .get_env_var(name)
+            .and_then(|value| value.as_string().ok())
+            .filter(|value| !value.is_empty())
+    };
+
+    let stdout_tty = std::io::stdout().is_tty();
+    let (columns, rows) = match terminal_size() {
+        Some((Width(w), Height(h))) => (
+            Value::Int {
+                val: w as i64,
+                span,
+            },
+            Value::Int {
+                val: h as i64,
+                span,
+            },
+        ),
+        None => (Value::Nothing { span }, Value::Nothing { span }),
+    };
+
+    let term = env("TERM").unwrap_or_default();
+    let colors = if env("NO_COLOR").is_some() || term == "dumb" || !stdout_tty {
+        "none"
+    } else if matches!(
+        env("COLORTERM").as_deref(),
+        Some("truecolor") | Some("24bit")
+    ) {
+        "truecolor"
+    } else if term.contains("256color") {
+        "256"
+    } else {
+        "16"
+    };
+
+    // The first of the locale variables that is set decides the character encoding
+    let unicode = if cfg!(windows) {
+        true
+    } else {
+        ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .find_map(|name| env(name))
+            .map(|locale| {
+                let locale = locale.to_lowercase();
+                locale.contains("utf-8") || locale.contains("utf8")
+            })
+            .unwrap_or(false)
+    };
+
+    let bool_value = |val| Value::Bool { val, span };
+
+    Value::Record {
+        cols: vec![
+            "admin".into(),
+            "stdin_tty".into(),
+            "stdout_tty".into(),
+            "stderr_tty".into(),
+            "colors".into(),
+            "unicode".into(),
+            "columns".into(),
+            "rows".into(),
+        ],
+        vals: vec![
+            bool_value(is_admin()),
+            bool_value(std::io::stdin().is_tty()),
+            bool_value(stdout_tty),
+            bool_value(std::io::stderr().is_tty()),
+            Value::string(colors, span),
+            bool_value(unicode),
+            columns,
+            rows,
+        ],
+        span,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SysCapabilities {})
+    }
+}
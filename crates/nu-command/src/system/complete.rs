@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, ExternalCapture, Stack};
+use nu_protocol::{
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape,
+    Value,
+};
+
+#[derive(Clone)]
+pub struct Complete;
+
+impl Command for Complete {
+    fn name(&self) -> &str {
+        "complete"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block and return its stdout, stderr and exit code as a record."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The externals in the block have their stderr collected instead of printed, and the
+exit code is the one of the external that finished last. It is empty when the block didn't
+run any external, or when the external couldn't be started, in which case the reason is in
+stderr."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("complete")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to run",
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let block: Value = call.req(engine_state, stack, 0)?;
+        let block_id = block.as_block()?;
+        let block = engine_state.get_block(block_id);
+
+        let config = stack.get_config().unwrap_or_default();
+        let capture = Arc::new(Mutex::new(ExternalCapture::default()));
+
+        let mut stack = stack.collect_captures(&block.captures);
+        stack.external_capture = Some(capture.clone());
+
+        let (stdout, spawn_error) = match eval_block(engine_state, &mut stack, block, input) {
+            // Reading all of the output waits for the externals to finish
+            Ok(output) => (collect_bytes(output, &config), None),
+            Err(ShellError::ExternalCommand(message, _)) => (vec![], Some(message)),
+            Err(error) => return Err(error),
+        };
+
+        let span = call.head;
+        let capture = capture
+            .lock()
+            .map_err(|_| ShellError::NushellFailed("external capture is poisoned".into()))?;
+
+        let mut stderr = String::from_utf8_lossy(&capture.stderr).to_string();
+        if let Some(message) = spawn_error {
+            stderr.push_str(&message);
+        }
+
+        let stdout = match String::from_utf8(stdout) {
+            Ok(val) => Value::String { val, span },
+            Err(err) => Value::Binary {
+                val: err.into_bytes(),
+                span,
+            },
+        };
+
+        let exit_code = match capture.exit_code {
+            Some(val) => Value::Int { val, span },
+            None => Value::nothing(span),
+        };
+
+        Ok(Value::Record {
+            cols: vec!["stdout".into(), "stderr".into(), "exit_code".into()],
+            vals: vec![stdout, Value::string(stderr, span), exit_code],
+            span,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Run an external and get its exit code",
+                example: "complete { ^git status } | get exit_code",
+                result: None,
+            },
+            Example {
+                description: "Show what an external printed to stderr when it failed",
+                example:
+                    "let res = complete { ^cargo build }; if $res.exit_code != 0 { $res.stderr }",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn collect_bytes(output: PipelineData, config: &Config) -> Vec<u8> {
+    let mut bytes = vec![];
+
+    for value in output {
+        match value {
+            Value::String { val, .. } => bytes.extend(val.into_bytes()),
+            Value::Binary { val, .. } => bytes.extend(val),
+            Value::Nothing { .. } => {}
+            other => bytes.extend(other.into_string(", ", config).into_bytes()),
+        }
+    }
+
+    bytes
+}
@@ -0,0 +1,113 @@
+use nu_engine::CallExt;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+};
+
+#[derive(Clone)]
+pub struct Exec;
+
+impl Command for Exec {
+    fn name(&self) -> &str {
+        "exec"
+    }
+
+    fn usage(&self) -> &str {
+        "Execute a command, replacing the current process."
+    }
+
+    fn extra_usage(&self) -> &str {
+        "This command is only available on Unix. The shell does not get back control once the command is started, which is how a login shell hands over to another program."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("exec")
+            .required("command", SyntaxShape::String, "the command to execute")
+            .rest(
+                "rest",
+                SyntaxShape::String,
+                "any additional arguments for the command",
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        exec(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Execute external 'ps aux' tool",
+                example: "exec ps aux",
+                result: None,
+            },
+            Example {
+                description: "Execute 'nautilus'",
+                example: "exec nautilus",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(unix)]
+fn exec(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    use std::os::unix::process::CommandExt;
+
+    use nu_engine::env_to_strings;
+    use nu_protocol::ast::Expr;
+
+    use super::run_external::expand_argument;
+
+    let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+    let args: Vec<Spanned<String>> = call.rest(engine_state, stack, 1)?;
+
+    let config = stack.get_config().unwrap_or_default();
+    let env_vars = env_to_strings(engine_state, stack, &config)?;
+
+    let program = nu_path::expand_path(&name.item);
+    let mut process = std::process::Command::new(program);
+
+    // The first positional is the name of the command
+    for (arg, expr) in args.into_iter().zip(call.positional.iter().skip(1)) {
+        // The quotes are already gone from the value, so look at how the argument was written.
+        // Variables and subexpressions aren't strings there, so what they give isn't expanded.
+        let contents = engine_state.get_span_contents(&arg.span);
+        let quoted = contents.starts_with(b"\"") || contents.starts_with(b"'");
+        let bare = !quoted && matches!(expr.expr, Expr::String(_));
+
+        process.args(expand_argument(&arg.item, bare));
+    }
+
+    process.current_dir(std::env::current_dir()?);
+    process.envs(&env_vars);
+
+    // exec only returns if the process could not be replaced
+    let err = process.exec();
+
+    Err(ShellError::ExternalCommand(err.to_string(), name.span))
+}
+
+#[cfg(not(unix))]
+fn exec(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::UnsupportedInput(
+        "exec is only supported on Unix".into(),
+        call.head,
+    ))
+}
@@ -0,0 +1,108 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct IsAdmin;
+
+impl Command for IsAdmin {
+    fn name(&self) -> &str {
+        "is-admin"
+    }
+
+    fn usage(&self) -> &str {
+        "Check if nushell is running with administrator or root privileges."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("is-admin").category(Category::System)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::Bool {
+            val: is_admin(),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show a different prompt symbol when running as root",
+            example: "if (is-admin) { '# ' } else { '> ' }",
+            result: None,
+        }]
+    }
+}
+
+/// Whether the current process has root privileges (or runs elevated on Windows)
+#[cfg(unix)]
+pub(crate) fn is_admin() -> bool {
+    // Safety: geteuid has no preconditions and can't fail
+    unsafe { libc::geteuid() == 0 }
+}
+
+/// Whether the current process has root privileges (or runs elevated on Windows)
+#[cfg(windows)]
+pub(crate) fn is_admin() -> bool {
+    use std::mem;
+    use winapi::{
+        ctypes::c_void,
+        shared::minwindef::DWORD,
+        um::{
+            handleapi::CloseHandle,
+            processthreadsapi::{GetCurrentProcess, OpenProcessToken},
+            securitybaseapi::GetTokenInformation,
+            winnt::{TokenElevation, HANDLE, TOKEN_ELEVATION, TOKEN_QUERY},
+        },
+    };
+
+    let mut handle: HANDLE = std::ptr::null_mut();
+    let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+    let size = mem::size_of::<TOKEN_ELEVATION>() as DWORD;
+    let mut returned_size = size;
+
+    // Safety: the token handle is only used while it's open, and the buffer passed to
+    // GetTokenInformation is a TOKEN_ELEVATION of the size we report
+    unsafe {
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut handle) == 0 {
+            return false;
+        }
+
+        let success = GetTokenInformation(
+            handle,
+            TokenElevation,
+            &mut elevation as *mut TOKEN_ELEVATION as *mut c_void,
+            size,
+            &mut returned_size,
+        );
+        CloseHandle(handle);
+
+        success != 0 && elevation.TokenIsElevated != 0
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(crate) fn is_admin() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IsAdmin {})
+    }
+}
@@ -0,0 +1,44 @@
+use nu_engine::get_full_help;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct Job;
+
+impl Command for Job {
+    fn name(&self) -> &str {
+        "job"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job").category(Category::System)
+    }
+
+    fn usage(&self) -> &str {
+        "Run blocks in the background."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"'job spawn' starts a block on its own thread and returns the id of the job. Its
+output is kept until 'job wait' collects it, and 'job list' shows the jobs that
+haven't been waited for yet. Like with 'timeout', changes a job makes to the
+environment are not kept."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::String {
+            val: get_full_help(&Job.signature(), &Job.examples(), engine_state),
+            span: call.head,
+        }
+        .into_pipeline_data())
+    }
+}
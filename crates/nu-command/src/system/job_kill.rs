@@ -0,0 +1,60 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, Spanned, SyntaxShape};
+
+#[derive(Clone)]
+pub struct JobKill;
+
+impl Command for JobKill {
+    fn name(&self) -> &str {
+        "job kill"
+    }
+
+    fn usage(&self) -> &str {
+        "Stop a background job."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The job is interrupted the same way ctrl-c interrupts the shell, so it stops at the
+next point where it checks for ctrl-c. Its output is dropped either way."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job kill")
+            .required("id", SyntaxShape::Int, "the id of the job")
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let id: Spanned<i64> = call.req(engine_state, stack, 0)?;
+
+        if id.item < 0 || !engine_state.jobs.kill(id.item as usize) {
+            return Err(job_not_found(id));
+        }
+
+        Ok(PipelineData::new(call.head))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Stop the job with id 1",
+            example: "job kill 1",
+            result: None,
+        }]
+    }
+}
+
+pub(super) fn job_not_found(id: Spanned<i64>) -> ShellError {
+    ShellError::SpannedLabeledError(
+        "Job not found".into(),
+        format!("there is no job with id {}", id.item),
+        id.span,
+    )
+}
@@ -0,0 +1,69 @@
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, JobState, Stack};
+use nu_protocol::{
+    Category, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature, Value,
+};
+
+#[derive(Clone)]
+pub struct JobList;
+
+impl Command for JobList {
+    fn name(&self) -> &str {
+        "job list"
+    }
+
+    fn usage(&self) -> &str {
+        "List the background jobs that haven't been waited for."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job list").category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        Ok(engine_state
+            .jobs
+            .list()
+            .into_iter()
+            .map(move |job| Value::Record {
+                cols: vec!["id".into(), "status".into(), "command".into()],
+                vals: vec![
+                    Value::Int {
+                        val: job.id as i64,
+                        span,
+                    },
+                    Value::string(
+                        match job.state {
+                            JobState::Running => "running",
+                            JobState::Finished(Value::Error { .. }) => "failed",
+                            JobState::Finished(_) => "finished",
+                            JobState::Killed => "killed",
+                        },
+                        span,
+                    ),
+                    Value::String {
+                        val: job.description,
+                        span,
+                    },
+                ],
+                span,
+            })
+            .into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Show the jobs that are still running",
+            example: "job list | where status == running",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,92 @@
+use nu_engine::{eval_block, CallExt};
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Value,
+};
+
+#[derive(Clone)]
+pub struct JobSpawn;
+
+impl Command for JobSpawn {
+    fn name(&self) -> &str {
+        "job spawn"
+    }
+
+    fn usage(&self) -> &str {
+        "Run a block in the background, returning the id of the job."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job spawn")
+            .required(
+                "block",
+                SyntaxShape::Block(Some(vec![])),
+                "the block to run",
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let block: Value = call.req(engine_state, stack, 0)?;
+        let block_id = block.as_block()?;
+
+        let head = call.head;
+        let block_span = call.positional[0].span;
+        let description = String::from_utf8_lossy(engine_state.get_span_contents(&block_span))
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .trim()
+            .to_string();
+
+        let jobs = engine_state.jobs.clone();
+        let mut engine_state = engine_state.clone();
+        let block = engine_state.get_block(block_id).clone();
+        let mut stack = stack.collect_captures(&block.captures);
+        let input = input.into_value(head);
+
+        let id = jobs.spawn(description, move |interrupt| {
+            // The job is stopped through its own flag rather than the one of the shell
+            engine_state.ctrlc = Some(interrupt);
+
+            match eval_block(
+                &engine_state,
+                &mut stack,
+                &block,
+                input.into_pipeline_data(),
+            ) {
+                Ok(output) => output.into_value(head),
+                Err(error) => Value::Error {
+                    error: Box::new(error),
+                },
+            }
+        });
+
+        Ok(Value::Int {
+            val: id as i64,
+            span: head,
+        }
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Download a file in the background",
+                example: "job spawn { fetch https://example.com/big.iso | save big.iso }",
+                result: None,
+            },
+            Example {
+                description: "Start a calculation and collect its result later",
+                example: "let id = (job spawn { 1 + 2 }); job wait $id",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,64 @@
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, JobState, Stack};
+use nu_protocol::{
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Spanned, SyntaxShape,
+    Value,
+};
+
+use super::job_kill::job_not_found;
+
+#[derive(Clone)]
+pub struct JobWait;
+
+impl Command for JobWait {
+    fn name(&self) -> &str {
+        "job wait"
+    }
+
+    fn usage(&self) -> &str {
+        "Wait for a background job to finish and return its output."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The job is removed from 'job list' once it has been waited for. A job that failed
+returns its error, and a job that was killed returns nothing."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("job wait")
+            .required("id", SyntaxShape::Int, "the id of the job")
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let id: Spanned<i64> = call.req(engine_state, stack, 0)?;
+
+        let state = if id.item < 0 {
+            None
+        } else {
+            engine_state.jobs.wait(id.item as usize)
+        };
+
+        match state {
+            Some(JobState::Finished(Value::Error { error })) => Err(*error),
+            Some(JobState::Finished(value)) => Ok(value.into_pipeline_data()),
+            Some(JobState::Running) | Some(JobState::Killed) => Ok(PipelineData::new(call.head)),
+            None => Err(job_not_found(id)),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Wait for the job with id 1 and return its output",
+            example: "job wait 1",
+            result: None,
+        }]
+    }
+}
@@ -1,9 +1,41 @@
 mod benchmark;
+mod capabilities;
+mod complete;
+mod exec;
+mod is_admin;
+mod job;
+mod job_kill;
+mod job_list;
+mod job_spawn;
+mod job_wait;
 mod ps;
 mod run_external;
 mod sys;
+mod sys_disks;
+mod sys_host;
+mod sys_net;
+mod sys_temp;
+mod sys_users;
+mod ulimit;
+mod which_;
 
 pub use benchmark::Benchmark;
+pub use capabilities::SysCapabilities;
+pub use complete::Complete;
+pub use exec::Exec;
+pub use is_admin::IsAdmin;
+pub use job::Job;
+pub use job_kill::JobKill;
+pub use job_list::JobList;
+pub use job_spawn::JobSpawn;
+pub use job_wait::JobWait;
 pub use ps::Ps;
 pub use run_external::{External, ExternalCommand};
 pub use sys::Sys;
+pub use sys_disks::SysDisks;
+pub use sys_host::SysHost;
+pub use sys_net::SysNet;
+pub use sys_temp::SysTemp;
+pub use sys_users::SysUsers;
+pub use ulimit::Ulimit;
+pub use which_::Which;
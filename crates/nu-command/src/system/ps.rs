@@ -40,11 +40,23 @@ impl Command for Ps {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "List the system processes",
-            example: "ps",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "List the system processes",
+                example: "ps",
+                result: None,
+            },
+            Example {
+                description: "List the top 5 processes by memory use",
+                example: "ps | sort-by mem | reverse | first 5",
+                result: None,
+            },
+            Example {
+                description: "Find the processes of an application along with their commands",
+                example: "ps --long | where name =~ nu | select pid command",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -1,22 +1,29 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::env;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
-use std::process::{Command as CommandSys, Stdio};
+use std::process::{Command as CommandSys, ExitStatus, Stdio};
 use std::sync::atomic::Ordering;
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc, Mutex};
 
 use nu_engine::env_to_strings;
-use nu_protocol::engine::{EngineState, Stack};
-use nu_protocol::{ast::Call, engine::Command, ShellError, Signature, SyntaxShape, Value};
+use nu_protocol::engine::{EngineState, ExternalCapture, Stack};
+use nu_protocol::{
+    ast::{Call, Expr},
+    engine::Command,
+    ShellError, Signature, SyntaxShape, Value,
+};
 use nu_protocol::{Category, Config, IntoInterruptiblePipelineData, PipelineData, Span, Spanned};
 
+use glob::MatchOptions;
 use itertools::Itertools;
 
 use nu_engine::CallExt;
 use regex::Regex;
 
+use crate::change_directory;
+
 const OUTPUT_BUFFER_SIZE: usize = 8192;
 
 #[derive(Clone)]
@@ -51,7 +58,8 @@ impl Command for External {
     ) -> Result<PipelineData, ShellError> {
         let mut name: Spanned<String> = call.req(engine_state, stack, 0)?;
         let args: Vec<String> = call.rest(engine_state, stack, 1)?;
-        let last_expression = call.has_flag("last_expression");
+        // Under `complete` the output is collected, even at the end of the pipeline
+        let last_expression = call.has_flag("last_expression") && stack.external_capture.is_none();
 
         // Translate environment variables from Values to Strings
         let config = stack.get_config().unwrap_or_default();
@@ -63,25 +71,16 @@ impl Command for External {
         name.item = path.to_string_lossy().to_string();
 
         let path = Path::new(&name.item);
-        if (orig.starts_with('.')
+        let explicit_path = orig.starts_with('.')
             || orig.starts_with('~')
             || orig.starts_with('/')
-            || orig.starts_with('\\'))
-            && path.is_dir()
-            && args.is_empty()
-        {
+            || orig.starts_with('\\');
+        // A bare directory name, like 'src/', only changes into it if the config asks for that
+        let bare_dir = config.auto_cd && orig.ends_with(std::path::is_separator);
+
+        if (explicit_path || bare_dir) && path.is_dir() && args.is_empty() {
             // We have an auto-cd
-            let _ = std::env::set_current_dir(&path);
-
-            //FIXME: this only changes the current scope, but instead this environment variable
-            //should probably be a block that loads the information from the state in the overlay
-            stack.add_env_var(
-                "PWD".into(),
-                Value::String {
-                    val: name.item.clone(),
-                    span: Span::unknown(),
-                },
-            );
+            change_directory(stack, &orig, name.span, false)?;
             return Ok(PipelineData::new(call.head));
         }
 
@@ -91,6 +90,7 @@ impl Command for External {
             last_expression,
             env_vars: env_vars_str,
             call,
+            capture: stack.external_capture.clone(),
         };
         command.run_with_input(engine_state, input, config)
     }
@@ -102,6 +102,7 @@ pub struct ExternalCommand<'call> {
     pub last_expression: bool,
     pub env_vars: HashMap<String, String>,
     pub call: &'call Call,
+    pub capture: Option<Arc<Mutex<ExternalCapture>>>,
 }
 
 impl<'call> ExternalCommand<'call> {
@@ -135,6 +136,10 @@ impl<'call> ExternalCommand<'call> {
             process.stdin(Stdio::piped());
         }
 
+        if self.capture.is_some() {
+            process.stderr(Stdio::piped());
+        }
+
         match process.spawn() {
             Err(err) => Err(ShellError::ExternalCommand(
                 format!("{}", err),
@@ -173,9 +178,21 @@ impl<'call> ExternalCommand<'call> {
                 let last_expression = self.last_expression;
                 let span = self.name.span;
                 let output_ctrlc = ctrlc.clone();
+                let capture = self.capture.clone();
+                let last_exit_code = engine_state.last_exit_code.clone();
                 let (tx, rx) = mpsc::channel();
 
                 std::thread::spawn(move || {
+                    // Stderr is read on a thread of its own, so that the external doesn't block
+                    // on a full stderr pipe while stdout is being read
+                    let stderr_reader = child.stderr.take().map(|mut stderr| {
+                        std::thread::spawn(move || {
+                            let mut bytes = vec![];
+                            let _ = stderr.read_to_end(&mut bytes);
+                            bytes
+                        })
+                    });
+
                     // If this external is not the last expression, then its output is piped to a channel
                     // and we create a ValueStream that can be consumed
                     if !last_expression {
@@ -221,7 +238,26 @@ impl<'call> ExternalCommand<'call> {
 
                     match child.wait() {
                         Err(err) => Err(ShellError::ExternalCommand(format!("{}", err), span)),
-                        Ok(_) => Ok(()),
+                        Ok(status) => {
+                            // This happens before the channel closes, so the capture and the exit
+                            // code are complete by the time the output has been read
+                            if let Ok(mut last_exit_code) = last_exit_code.lock() {
+                                *last_exit_code = Some(exit_code(status));
+                            }
+
+                            if let Some(capture) = capture {
+                                let stderr = stderr_reader
+                                    .and_then(|reader| reader.join().ok())
+                                    .unwrap_or_default();
+
+                                if let Ok(mut capture) = capture.lock() {
+                                    capture.stderr.extend(stderr);
+                                    capture.exit_code = status.code().map(i64::from);
+                                }
+                            }
+
+                            Ok(())
+                        }
                     }
                 });
                 // The ValueStream is consumed by the next expression in the pipeline
@@ -255,10 +291,7 @@ impl<'call> ExternalCommand<'call> {
     fn spawn_simple_command(&self) -> std::process::Command {
         let mut process = std::process::Command::new(&self.name.item);
 
-        for arg in &self.args {
-            let arg = trim_enclosing_quotes(arg);
-            let arg = nu_path::expand_path(arg).to_string_lossy().to_string();
-
+        for arg in self.expand_args() {
             let arg = arg.replace("\\", "\\\\");
 
             process.arg(&arg);
@@ -284,13 +317,89 @@ impl<'call> ExternalCommand<'call> {
 
     /// Spawn a sh command with `sh -c args...`
     fn spawn_sh_command(&self) -> std::process::Command {
-        let joined_and_escaped_arguments =
-            self.args.iter().map(|arg| shell_arg_escape(arg)).join(" ");
+        let joined_and_escaped_arguments = self
+            .expand_args()
+            .iter()
+            .map(|arg| shell_arg_escape(arg))
+            .join(" ");
         let cmd_with_args = vec![self.name.item.clone(), joined_and_escaped_arguments].join(" ");
         let mut process = std::process::Command::new("sh");
         process.arg("-c").arg(cmd_with_args);
         process
     }
+
+    /// The arguments as the external sees them, with the quotes removed and bare words expanded
+    fn expand_args(&self) -> Vec<String> {
+        // The first positional is the name of the external
+        self.args
+            .iter()
+            .zip(self.call.positional.iter().skip(1))
+            .flat_map(|(arg, expr)| {
+                // What a variable or subexpression gives isn't a word written by the user
+                let bare = !is_quoted(arg) && matches!(expr.expr, Expr::String(_));
+                expand_argument(&trim_enclosing_quotes(arg), bare)
+            })
+            .collect()
+    }
+}
+
+/// Expands the tilde and the glob pattern of a bare word into the matching paths. Other
+/// arguments, like quoted ones and the values of variables, are passed as they are, and so
+/// is a pattern that doesn't match anything, as most shells do. As in those, a wildcard
+/// doesn't match the dot at the start of a hidden file.
+pub(super) fn expand_argument(arg: &str, bare: bool) -> Vec<String> {
+    if !bare {
+        return vec![arg.to_string()];
+    }
+
+    let arg = nu_path::expand_path(arg).to_string_lossy().to_string();
+
+    if !arg.contains(|c| matches!(c, '*' | '?' | '[')) {
+        return vec![arg];
+    }
+
+    let options = MatchOptions {
+        require_literal_leading_dot: true,
+        ..MatchOptions::new()
+    };
+
+    let paths = match glob::glob_with(&arg, options) {
+        Ok(paths) => paths
+            .filter_map(|path| path.ok())
+            .map(|path| path.to_string_lossy().to_string())
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+
+    if paths.is_empty() {
+        vec![arg]
+    } else {
+        paths
+    }
+}
+
+pub(super) fn is_quoted(input: &str) -> bool {
+    let mut chars = input.chars();
+
+    matches!(
+        (chars.next(), chars.next_back()),
+        (Some('"'), Some('"')) | (Some('\''), Some('\''))
+    )
+}
+
+// The exit code of a finished command. Commands killed by a signal have none, so like other
+// shells they get 128 plus the number of the signal.
+fn exit_code(status: ExitStatus) -> i64 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if let Some(signal) = status.signal() {
+            return 128 + i64::from(signal);
+        }
+    }
+
+    status.code().map(i64::from).unwrap_or(1)
 }
 
 fn has_unsafe_shell_characters(arg: &str) -> bool {
@@ -311,12 +420,10 @@ fn shell_arg_escape(arg: &str) -> String {
 }
 
 fn trim_enclosing_quotes(input: &str) -> String {
-    let mut chars = input.chars();
-
-    match (chars.next(), chars.next_back()) {
-        (Some('"'), Some('"')) => chars.collect(),
-        (Some('\''), Some('\'')) => chars.collect(),
-        _ => input.to_string(),
+    if is_quoted(input) {
+        input[1..input.len() - 1].to_string()
+    } else {
+        input.to_string()
     }
 }
 
@@ -1,8 +1,15 @@
+use nu_engine::CallExt;
 use nu_protocol::{
     ast::Call,
     engine::{Command, EngineState, Stack},
-    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+    Category, Example, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, ShellError,
+    Signature, Span, Spanned, Value,
 };
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
 use sysinfo::{ComponentExt, DiskExt, NetworkExt, ProcessorExt, System, SystemExt, UserExt};
 
 #[derive(Clone)]
@@ -35,11 +42,18 @@ impl Command for Sys {
     }
 
     fn examples(&self) -> Vec<Example> {
-        vec![Example {
-            description: "Show info about the system",
-            example: "sys",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Show info about the system",
+                example: "sys",
+                result: None,
+            },
+            Example {
+                description: "Show the memory that is available for new applications",
+                example: "(sys).mem.available",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -50,7 +64,7 @@ fn run_sys(call: &Call) -> Result<PipelineData, ShellError> {
     let mut headers = vec![];
     let mut values = vec![];
 
-    if let Some(value) = host(&mut sys, span) {
+    if let Some(value) = host(&mut sys, span, true, false) {
         headers.push("host".into());
         values.push(value);
     }
@@ -83,6 +97,98 @@ fn run_sys(call: &Call) -> Result<PipelineData, ShellError> {
     .into_pipeline_data())
 }
 
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+// Reads the --watch flag of the `sys` subcommands, returning None when it wasn't given
+pub(super) fn watch_interval(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Option<Duration>, ShellError> {
+    let interval: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "watch")?;
+
+    match interval {
+        Some(Spanned { item, span }) if item <= 0 => Err(ShellError::UnsupportedInput(
+            "the interval must be positive".into(),
+            span,
+        )),
+        Some(Spanned { item, .. }) => Ok(Some(Duration::from_nanos(item as u64))),
+        None => Ok(None),
+    }
+}
+
+pub(super) fn watch<F>(
+    interval: Duration,
+    first_sample: Instant,
+    engine_state: &EngineState,
+    sample: F,
+) -> PipelineData
+where
+    F: FnMut() -> Vec<Value> + Send + 'static,
+{
+    let ctrlc = engine_state.ctrlc.clone();
+
+    Watch {
+        interval,
+        next_sample: first_sample,
+        ctrlc: ctrlc.clone(),
+        rows: VecDeque::new(),
+        sample,
+    }
+    .into_pipeline_data(ctrlc)
+}
+
+// Streams the rows of a new sample every interval, until ctrl-c is pressed
+struct Watch<F> {
+    interval: Duration,
+    next_sample: Instant,
+    ctrlc: Option<Arc<AtomicBool>>,
+    rows: VecDeque<Value>,
+    sample: F,
+}
+
+impl<F> Watch<F> {
+    // Sleep until the next sample is due, returning false if ctrl-c was pressed in the meantime
+    fn wait(&self) -> bool {
+        loop {
+            if let Some(ctrlc) = &self.ctrlc {
+                if ctrlc.load(Ordering::SeqCst) {
+                    return false;
+                }
+            }
+
+            let now = Instant::now();
+            if now >= self.next_sample {
+                return true;
+            }
+
+            thread::sleep(CTRL_C_CHECK_INTERVAL.min(self.next_sample - now));
+        }
+    }
+}
+
+impl<F> Iterator for Watch<F>
+where
+    F: FnMut() -> Vec<Value> + Send + 'static,
+{
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = self.rows.pop_front() {
+                return Some(row);
+            }
+
+            if !self.wait() {
+                return None;
+            }
+
+            self.next_sample += self.interval;
+            self.rows.extend((self.sample)());
+        }
+    }
+}
+
 pub fn trim_cstyle_null(s: String) -> String {
     s.trim_matches(char::from(0)).to_string()
 }
@@ -135,12 +241,72 @@ pub fn disks(sys: &mut System, span: Span) -> Option<Value> {
     }
 }
 
+/// The disks in the shape of `df`: how much of each is used, and where it's mounted
+pub fn disk_usage(sys: &mut System, span: Span) -> Value {
+    sys.refresh_disks_list();
+    sys.refresh_disks();
+
+    let vals = sys
+        .disks()
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space().min(total);
+            let used = total - available;
+            let percent = if total == 0 {
+                0.0
+            } else {
+                (used as f64 / total as f64 * 1000.0).round() / 10.0
+            };
+
+            Value::Record {
+                cols: vec![
+                    "filesystem".into(),
+                    "total".into(),
+                    "used".into(),
+                    "available".into(),
+                    "percent".into(),
+                    "mount".into(),
+                ],
+                vals: vec![
+                    Value::string(
+                        trim_cstyle_null(disk.name().to_string_lossy().to_string()),
+                        span,
+                    ),
+                    Value::Filesize {
+                        val: total as i64,
+                        span,
+                    },
+                    Value::Filesize {
+                        val: used as i64,
+                        span,
+                    },
+                    Value::Filesize {
+                        val: available as i64,
+                        span,
+                    },
+                    Value::Float { val: percent, span },
+                    Value::string(disk.mount_point().to_string_lossy(), span),
+                ],
+                span,
+            }
+        })
+        .collect();
+
+    Value::List { vals, span }
+}
+
 pub fn net(sys: &mut System, span: Span) -> Option<Value> {
     sys.refresh_networks();
     sys.refresh_networks_list();
 
+    // The interfaces come in no particular order, so they're sorted by name to keep the rows in
+    // the same place between runs
+    let mut interfaces = sys.networks().into_iter().collect::<Vec<_>>();
+    interfaces.sort_by(|(a, _), (b, _)| a.cmp(b));
+
     let mut output = vec![];
-    for (iface, data) in sys.networks() {
+    for (iface, data) in interfaces {
         let mut cols = vec![];
         let mut vals = vec![];
 
@@ -197,6 +363,12 @@ pub fn cpu(sys: &mut System, span: Span) -> Option<Value> {
             span,
         });
 
+        cols.push("usage".into());
+        vals.push(Value::Float {
+            val: cpu.cpu_usage() as f64,
+            span,
+        });
+
         output.push(Value::Record { cols, vals, span });
     }
     if !output.is_empty() {
@@ -214,6 +386,8 @@ pub fn mem(sys: &mut System, span: Span) -> Option<Value> {
 
     let total_mem = sys.total_memory();
     let free_mem = sys.free_memory();
+    let used_mem = sys.used_memory();
+    let avail_mem = sys.available_memory();
     let total_swap = sys.total_swap();
     let free_swap = sys.free_swap();
 
@@ -229,6 +403,18 @@ pub fn mem(sys: &mut System, span: Span) -> Option<Value> {
         span,
     });
 
+    cols.push("used".into());
+    vals.push(Value::Filesize {
+        val: used_mem as i64 * 1000,
+        span,
+    });
+
+    cols.push("available".into());
+    vals.push(Value::Filesize {
+        val: avail_mem as i64 * 1000,
+        span,
+    });
+
     cols.push("swap total".into());
     vals.push(Value::Filesize {
         val: total_swap as i64 * 1000,
@@ -244,9 +430,8 @@ pub fn mem(sys: &mut System, span: Span) -> Option<Value> {
     Some(Value::Record { cols, vals, span })
 }
 
-pub fn host(sys: &mut System, span: Span) -> Option<Value> {
-    sys.refresh_users_list();
-
+// The fqdn is only looked up when asked for, as it can wait on a DNS server
+pub fn host(sys: &mut System, span: Span, with_users: bool, with_fqdn: bool) -> Option<Value> {
     let mut cols = vec![];
     let mut vals = vec![];
 
@@ -272,19 +457,231 @@ pub fn host(sys: &mut System, span: Span) -> Option<Value> {
         });
     }
     if let Some(hostname) = sys.host_name() {
+        let hostname = trim_cstyle_null(hostname);
+
+        if with_fqdn {
+            cols.push("fqdn".into());
+            vals.push(Value::String {
+                val: fqdn(&hostname).unwrap_or_else(|| hostname.clone()),
+                span,
+            });
+        }
+
         cols.push("hostname".into());
         vals.push(Value::String {
-            val: trim_cstyle_null(hostname),
+            val: hostname,
             span,
         });
     }
+    cols.push("arch".into());
+    vals.push(Value::String {
+        val: machine().unwrap_or_else(|| std::env::consts::ARCH.to_string()),
+        span,
+    });
+
+    let optional_string = |name: Option<&str>| match name {
+        Some(name) => Value::string(name, span),
+        None => Value::Nothing { span },
+    };
+    cols.push("container".into());
+    vals.push(optional_string(container()));
+    cols.push("virtualization".into());
+    vals.push(optional_string(virtualization()));
+
     cols.push("uptime".into());
     vals.push(Value::Duration {
         val: 1000000000 * sys.uptime() as i64,
         span,
     });
 
-    let mut users = vec![];
+    if with_users {
+        if let Some(users) = users(sys, span) {
+            cols.push("sessions".into());
+            vals.push(users);
+        }
+    }
+
+    Some(Value::Record { cols, vals, span })
+}
+
+// The hardware name, as `uname -m` gives it, which can differ from the architecture nu was
+// built for
+#[cfg(unix)]
+fn machine() -> Option<String> {
+    // Safety: uname only fills in the struct it is given, and the name is read out of it before
+    // it goes away
+    unsafe {
+        let mut name: libc::utsname = std::mem::zeroed();
+        if libc::uname(&mut name) != 0 {
+            return None;
+        }
+
+        Some(
+            std::ffi::CStr::from_ptr(name.machine.as_ptr())
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+#[cfg(not(unix))]
+fn machine() -> Option<String> {
+    None
+}
+
+// The fully qualified name of the host, looked up the same way `hostname -f` does
+#[cfg(unix)]
+fn fqdn(hostname: &str) -> Option<String> {
+    if hostname.contains('.') {
+        return Some(hostname.to_string());
+    }
+
+    let node = std::ffi::CString::new(hostname).ok()?;
+
+    // Safety: getaddrinfo gets a valid C string and zeroed hints, and the list it returns is read
+    // before it's freed
+    unsafe {
+        let mut hints: libc::addrinfo = std::mem::zeroed();
+        hints.ai_flags = libc::AI_CANONNAME;
+        hints.ai_family = libc::AF_UNSPEC;
+
+        let mut found = std::ptr::null_mut();
+        if libc::getaddrinfo(node.as_ptr(), std::ptr::null(), &hints, &mut found) != 0 {
+            return None;
+        }
+
+        let canonical = (*found).ai_canonname;
+        let name = if canonical.is_null() {
+            None
+        } else {
+            Some(
+                std::ffi::CStr::from_ptr(canonical)
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        };
+        libc::freeaddrinfo(found);
+
+        name
+    }
+}
+
+#[cfg(not(unix))]
+fn fqdn(_hostname: &str) -> Option<String> {
+    None
+}
+
+// The kind of container nu runs in, going by the files container runtimes leave behind
+#[cfg(target_os = "linux")]
+fn container() -> Option<&'static str> {
+    use std::path::Path;
+
+    if Path::new("/.dockerenv").exists() {
+        return Some("docker");
+    }
+    if Path::new("/run/.containerenv").exists() {
+        return Some("podman");
+    }
+    if std::env::var_os("KUBERNETES_SERVICE_HOST").is_some() {
+        return Some("kubernetes");
+    }
+
+    let environ = std::fs::read("/proc/1/environ").unwrap_or_default();
+    if let Some(runtime) = environ
+        .split(|b| *b == 0)
+        .find_map(|var| var.strip_prefix(b"container="))
+    {
+        return match runtime {
+            b"lxc" => Some("lxc"),
+            b"podman" => Some("podman"),
+            b"docker" => Some("docker"),
+            b"systemd-nspawn" => Some("systemd-nspawn"),
+            _ => Some("other"),
+        };
+    }
+
+    let cgroup = std::fs::read_to_string("/proc/1/cgroup").unwrap_or_default();
+    if cgroup.contains("/docker") {
+        Some("docker")
+    } else if cgroup.contains("/kubepods") {
+        Some("kubernetes")
+    } else if cgroup.contains("/lxc") {
+        Some("lxc")
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn container() -> Option<&'static str> {
+    None
+}
+
+// The hypervisor nu runs under, or wsl or wsl2 for the Windows Subsystem for Linux
+#[cfg(target_os = "linux")]
+fn virtualization() -> Option<&'static str> {
+    let release = std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .unwrap_or_default()
+        .to_lowercase();
+    if release.contains("wsl2") {
+        return Some("wsl2");
+    }
+    if release.contains("microsoft") {
+        return Some("wsl");
+    }
+
+    let read_dmi = |name: &str| {
+        std::fs::read_to_string(format!("/sys/class/dmi/id/{}", name))
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase()
+    };
+    let product = read_dmi("product_name");
+
+    // Microsoft also makes the Surface, so only its virtual machines are Hyper-V
+    if product == "virtual machine" && read_dmi("sys_vendor") == "microsoft corporation" {
+        return Some("hyper-v");
+    }
+
+    let vendor = format!("{} {}", read_dmi("sys_vendor"), product);
+
+    let known = [
+        ("kvm", "kvm"),
+        ("qemu", "qemu"),
+        ("vmware", "vmware"),
+        ("virtualbox", "virtualbox"),
+        ("xen", "xen"),
+        ("amazon ec2", "amazon"),
+        ("google compute engine", "google"),
+        ("parallels", "parallels"),
+    ];
+    if let Some((_, name)) = known.iter().find(|(text, _)| vendor.contains(text)) {
+        return Some(*name);
+    }
+
+    // A hypervisor that isn't known by name still sets the hypervisor flag of the cpu
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+    if cpuinfo
+        .lines()
+        .any(|line| line.starts_with("flags") && line.split_whitespace().any(|f| f == "hypervisor"))
+    {
+        Some("other")
+    } else {
+        None
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn virtualization() -> Option<&'static str> {
+    None
+}
+
+pub fn users(sys: &mut System, span: Span) -> Option<Value> {
+    sys.refresh_users_list();
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    let processes = process_counts(sys);
+
+    let mut output = vec![];
     for user in sys.users() {
         let mut cols = vec![];
         let mut vals = vec![];
@@ -295,6 +692,15 @@ pub fn host(sys: &mut System, span: Span) -> Option<Value> {
             span,
         });
 
+        #[cfg(unix)]
+        {
+            cols.push("uid".into());
+            vals.push(Value::Int {
+                val: *user.uid() as i64,
+                span,
+            });
+        }
+
         let mut groups = vec![];
         for group in user.groups() {
             groups.push(Value::String {
@@ -306,14 +712,36 @@ pub fn host(sys: &mut System, span: Span) -> Option<Value> {
         cols.push("groups".into());
         vals.push(Value::List { vals: groups, span });
 
-        users.push(Value::Record { cols, vals, span });
+        #[cfg(any(target_os = "linux", target_os = "macos"))]
+        {
+            cols.push("processes".into());
+            vals.push(Value::Int {
+                val: processes.get(&*user.uid()).copied().unwrap_or(0),
+                span,
+            });
+        }
+
+        output.push(Value::Record { cols, vals, span });
     }
-    if !users.is_empty() {
-        cols.push("sessions".into());
-        vals.push(Value::List { vals: users, span });
+    if !output.is_empty() {
+        Some(Value::List { vals: output, span })
+    } else {
+        None
     }
+}
 
-    Some(Value::Record { cols, vals, span })
+// The number of running processes of each user id. Only Linux and macOS processes say who they
+// belong to.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn process_counts(sys: &mut System) -> std::collections::HashMap<u32, i64> {
+    sys.refresh_processes();
+
+    let mut counts = std::collections::HashMap::new();
+    for process in sys.processes().values() {
+        *counts.entry(process.uid).or_insert(0) += 1;
+    }
+
+    counts
 }
 
 pub fn temp(sys: &mut System, span: Span) -> Option<Value> {
@@ -0,0 +1,81 @@
+use super::sys::{disk_usage, disks};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+use sysinfo::{System, SystemExt};
+
+#[derive(Clone)]
+pub struct SysDisks;
+
+impl Command for SysDisks {
+    fn name(&self) -> &str {
+        "sys disks"
+    }
+
+    fn usage(&self) -> &str {
+        "View information about the disks of the system."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"With --usage, the disks are listed the way df lists them: the filesystem, its total,
+used and available space, the percent of it that is used, and where it's mounted."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys disks")
+            .switch(
+                "usage",
+                "list how much of each disk is used, like df",
+                Some('u'),
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut sys = System::new();
+
+        let disks = if call.has_flag("usage") {
+            disk_usage(&mut sys, span)
+        } else {
+            disks(&mut sys, span).unwrap_or(Value::List { vals: vec![], span })
+        };
+
+        Ok(disks.into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the disks of the system",
+                example: "sys disks",
+                result: None,
+            },
+            Example {
+                description: "Find the disks that are more than 90 percent full",
+                example: "sys disks --usage | where percent > 90",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SysDisks {})
+    }
+}
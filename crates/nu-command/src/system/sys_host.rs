@@ -0,0 +1,99 @@
+use super::sys::host;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+use sysinfo::{System, SystemExt};
+
+#[derive(Clone)]
+pub struct SysHost;
+
+impl Command for SysHost {
+    fn name(&self) -> &str {
+        "sys host"
+    }
+
+    fn usage(&self) -> &str {
+        "View information about the host: its name, operating system, architecture and uptime."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The container column names the container nu runs in, like docker, podman, lxc or
+kubernetes, and the virtualization column the virtual machine, like kvm, vmware, hyper-v,
+or wsl and wsl2 for the Windows Subsystem for Linux. They're empty when nu runs on bare
+metal, and only detected on Linux."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys host")
+            .switch(
+                "users",
+                "add the users of the system, as 'sys users' lists them",
+                Some('u'),
+            )
+            .switch(
+                "fqdn",
+                "add the fully qualified name of the host, which can need a DNS lookup",
+                Some('f'),
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut sys = System::new();
+
+        Ok(host(
+            &mut sys,
+            span,
+            call.has_flag("users"),
+            call.has_flag("fqdn"),
+        )
+        .unwrap_or(Value::Nothing { span })
+        .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the name and operating system of the host",
+                example: "sys host",
+                result: None,
+            },
+            Example {
+                description: "Show the container the shell runs in, if any",
+                example: "(sys host).container",
+                result: None,
+            },
+            Example {
+                description: "Show the fully qualified name of the host",
+                example: "sys host --fqdn | get fqdn",
+                result: None,
+            },
+            Example {
+                description: "Show the host together with its users",
+                example: "sys host --users | get sessions",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SysHost {})
+    }
+}
@@ -0,0 +1,166 @@
+use super::sys::{net, trim_cstyle_null, watch, watch_interval};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
+    Value,
+};
+use std::collections::BTreeMap;
+use std::time::Instant;
+use sysinfo::{NetworkExt, System, SystemExt};
+
+#[derive(Clone)]
+pub struct SysNet;
+
+impl Command for SysNet {
+    fn name(&self) -> &str {
+        "sys net"
+    }
+
+    fn usage(&self) -> &str {
+        "View the traffic of the network interfaces."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"With --watch, a row is streamed for each interface every interval until ctrl-c is
+pressed. These rows also have the rate at which data was sent and received during the
+interval, in bytes per second."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys net")
+            .named(
+                "watch",
+                SyntaxShape::Duration,
+                "sample the interfaces again every interval",
+                Some('w'),
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut sys = System::new();
+
+        let interval = match watch_interval(engine_state, stack, call)? {
+            Some(interval) => interval,
+            None => {
+                return Ok(net(&mut sys, span)
+                    .unwrap_or(Value::List { vals: vec![], span })
+                    .into_pipeline_data())
+            }
+        };
+
+        sys.refresh_networks_list();
+        let mut previous = Instant::now();
+        let mut totals = interface_totals(&sys);
+
+        Ok(watch(
+            interval,
+            previous + interval,
+            engine_state,
+            move || {
+                sys.refresh_networks();
+
+                let now = Instant::now();
+                let elapsed = (now - previous).as_secs_f64();
+                previous = now;
+
+                let current = interface_totals(&sys);
+                let rows = current
+                    .iter()
+                    .map(|(name, (sent, recv))| {
+                        let (last_sent, last_recv) =
+                            totals.get(name).copied().unwrap_or((*sent, *recv));
+
+                        Value::Record {
+                            cols: vec![
+                                "name".into(),
+                                "sent".into(),
+                                "recv".into(),
+                                "sent rate".into(),
+                                "recv rate".into(),
+                            ],
+                            vals: vec![
+                                Value::String {
+                                    val: trim_cstyle_null(name.clone()),
+                                    span,
+                                },
+                                Value::Filesize {
+                                    val: *sent as i64,
+                                    span,
+                                },
+                                Value::Filesize {
+                                    val: *recv as i64,
+                                    span,
+                                },
+                                rate(*sent, last_sent, elapsed, span),
+                                rate(*recv, last_recv, elapsed, span),
+                            ],
+                            span,
+                        }
+                    })
+                    .collect();
+
+                totals = current;
+                rows
+            },
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the traffic of each network interface",
+                example: "sys net",
+                result: None,
+            },
+            Example {
+                description: "Show how fast data is received on each interface, every second",
+                example: "sys net --watch 1sec | select name 'recv rate'",
+                result: None,
+            },
+        ]
+    }
+}
+
+// The bytes sent and received by each interface, sorted by name like the rows of sys net
+fn interface_totals(sys: &System) -> BTreeMap<String, (u64, u64)> {
+    sys.networks()
+        .into_iter()
+        .map(|(name, data)| {
+            (
+                name.clone(),
+                (data.total_transmitted(), data.total_received()),
+            )
+        })
+        .collect()
+}
+
+fn rate(current: u64, previous: u64, elapsed: f64, span: Span) -> Value {
+    let bytes = current.saturating_sub(previous) as f64;
+    let val = if elapsed > 0.0 { bytes / elapsed } else { 0.0 };
+
+    Value::Filesize {
+        val: val as i64,
+        span,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SysNet {})
+    }
+}
@@ -0,0 +1,94 @@
+use super::sys::{temp, watch, watch_interval};
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, SyntaxShape, Value,
+};
+use std::time::Instant;
+use sysinfo::{System, SystemExt};
+
+#[derive(Clone)]
+pub struct SysTemp;
+
+impl Command for SysTemp {
+    fn name(&self) -> &str {
+        "sys temp"
+    }
+
+    fn usage(&self) -> &str {
+        "View the temperatures of the system's components."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"With --watch, a row is streamed for each component every interval until ctrl-c is
+pressed."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys temp")
+            .named(
+                "watch",
+                SyntaxShape::Duration,
+                "read the temperatures again every interval",
+                Some('w'),
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut sys = System::new();
+
+        let interval = match watch_interval(engine_state, stack, call)? {
+            Some(interval) => interval,
+            None => {
+                return Ok(temp(&mut sys, span)
+                    .unwrap_or(Value::List { vals: vec![], span })
+                    .into_pipeline_data())
+            }
+        };
+
+        Ok(watch(
+            interval,
+            Instant::now(),
+            engine_state,
+            move || match temp(&mut sys, span) {
+                Some(Value::List { vals, .. }) => vals,
+                _ => vec![],
+            },
+        ))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the temperature of each component",
+                example: "sys temp",
+                result: None,
+            },
+            Example {
+                description: "Keep an eye on the temperatures, reading them every 5 seconds",
+                example: "sys temp --watch 5sec",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SysTemp {})
+    }
+}
@@ -0,0 +1,71 @@
+use super::sys::users;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, IntoPipelineData, PipelineData, ShellError, Signature, Value,
+};
+use sysinfo::{System, SystemExt};
+
+#[derive(Clone)]
+pub struct SysUsers;
+
+impl Command for SysUsers {
+    fn name(&self) -> &str {
+        "sys users"
+    }
+
+    fn usage(&self) -> &str {
+        "View the users of the system, with the number of processes each one is running."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"The process count is only there on Linux and macOS, the uid only on unix systems. A user
+with running processes is one that is logged in, or has something running for it."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys users").category(Category::System)
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let mut sys = System::new();
+
+        Ok(users(&mut sys, span)
+            .unwrap_or(Value::List { vals: vec![], span })
+            .into_pipeline_data())
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show the users of the system",
+                example: "sys users",
+                result: None,
+            },
+            Example {
+                description: "Show the users that are running something",
+                example: "sys users | where processes > 0 | get name",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SysUsers {})
+    }
+}
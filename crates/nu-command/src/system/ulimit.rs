@@ -0,0 +1,363 @@
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack},
+    Category, Example, PipelineData, ShellError, Signature, SyntaxShape,
+};
+
+#[derive(Clone)]
+pub struct Ulimit;
+
+impl Command for Ulimit {
+    fn name(&self) -> &str {
+        "ulimit"
+    }
+
+    fn usage(&self) -> &str {
+        "View or set the resource limits of the shell and the commands it runs."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"This command is only available on Unix. Without a limit, the soft and hard limits of each
+resource are listed. With one, it is set as both the soft and the hard limit, unless --soft or
+--hard picks one of them. Only root can raise a hard limit again once it has been lowered.
+
+Limits are given as a filesize for the resources measured in bytes, a duration for cpu, a
+number for the others, or 'unlimited'. Commands started afterwards inherit the new limits."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ulimit")
+            .optional(
+                "resource",
+                SyntaxShape::String,
+                "the resource to view or set the limit of",
+            )
+            .optional("limit", SyntaxShape::Any, "the new limit of the resource")
+            .switch("soft", "only set the soft limit", Some('S'))
+            .switch("hard", "only set the hard limit", Some('H'))
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        ulimit(engine_state, stack, call)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "List the limits of all resources",
+                example: "ulimit",
+                result: None,
+            },
+            Example {
+                description: "Allow commands started from here to open at most 256 files",
+                example: "ulimit nofile 256",
+                result: None,
+            },
+            Example {
+                description: "Stop commands that use more than a minute of cpu time",
+                example: "ulimit --soft cpu 1min",
+                result: None,
+            },
+            Example {
+                description: "Let core dumps be as large as they need to be",
+                example: "ulimit core unlimited",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(unix)]
+fn ulimit(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    use nu_engine::CallExt;
+    use nu_protocol::{IntoInterruptiblePipelineData, Spanned, Value};
+
+    let resource: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+    let limit: Option<Value> = call.opt(engine_state, stack, 1)?;
+    let span = call.head;
+
+    let resources = match &resource {
+        Some(resource) => vec![unix::find_resource(resource)?],
+        None => unix::RESOURCES.iter().collect(),
+    };
+
+    if let Some(limit) = limit {
+        let resource = resources[0];
+        let limit = unix::parse_limit(resource, &limit)?;
+
+        let (soft, hard) = match (call.has_flag("soft"), call.has_flag("hard")) {
+            (true, false) => (true, false),
+            (false, true) => (false, true),
+            _ => (true, true),
+        };
+
+        unix::set_limit(resource, limit, soft, hard, span)?;
+
+        return Ok(PipelineData::new(span));
+    }
+
+    let rows = resources
+        .into_iter()
+        .map(|resource| unix::limit_row(resource, span))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(rows.into_pipeline_data(engine_state.ctrlc.clone()))
+}
+
+#[cfg(not(unix))]
+fn ulimit(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::UnsupportedInput(
+        "ulimit is only supported on Unix".into(),
+        call.head,
+    ))
+}
+
+#[cfg(unix)]
+mod unix {
+    use nu_protocol::{ShellError, Span, Spanned, Value};
+
+    // glibc takes its own type for resources, where everything else takes an int
+    #[cfg(all(target_os = "linux", target_env = "gnu"))]
+    type ResourceId = libc::__rlimit_resource_t;
+    #[cfg(not(all(target_os = "linux", target_env = "gnu")))]
+    type ResourceId = libc::c_int;
+
+    #[derive(Clone, Copy)]
+    enum Unit {
+        Bytes,
+        Seconds,
+        Count,
+    }
+
+    pub(super) struct Resource {
+        name: &'static str,
+        id: ResourceId,
+        unit: Unit,
+        description: &'static str,
+    }
+
+    pub(super) const RESOURCES: &[Resource] = &[
+        Resource {
+            name: "as",
+            id: libc::RLIMIT_AS,
+            unit: Unit::Bytes,
+            description: "virtual memory",
+        },
+        Resource {
+            name: "core",
+            id: libc::RLIMIT_CORE,
+            unit: Unit::Bytes,
+            description: "core dump size",
+        },
+        Resource {
+            name: "cpu",
+            id: libc::RLIMIT_CPU,
+            unit: Unit::Seconds,
+            description: "cpu time",
+        },
+        Resource {
+            name: "data",
+            id: libc::RLIMIT_DATA,
+            unit: Unit::Bytes,
+            description: "data segment size",
+        },
+        Resource {
+            name: "fsize",
+            id: libc::RLIMIT_FSIZE,
+            unit: Unit::Bytes,
+            description: "size of files written",
+        },
+        Resource {
+            name: "memlock",
+            id: libc::RLIMIT_MEMLOCK,
+            unit: Unit::Bytes,
+            description: "locked memory",
+        },
+        Resource {
+            name: "nofile",
+            id: libc::RLIMIT_NOFILE,
+            unit: Unit::Count,
+            description: "open files",
+        },
+        Resource {
+            name: "nproc",
+            id: libc::RLIMIT_NPROC,
+            unit: Unit::Count,
+            description: "processes of the user",
+        },
+        Resource {
+            name: "stack",
+            id: libc::RLIMIT_STACK,
+            unit: Unit::Bytes,
+            description: "stack size",
+        },
+    ];
+
+    pub(super) fn find_resource(name: &Spanned<String>) -> Result<&'static Resource, ShellError> {
+        RESOURCES
+            .iter()
+            .find(|resource| resource.name == name.item)
+            .ok_or_else(|| {
+                let names = RESOURCES
+                    .iter()
+                    .map(|resource| resource.name)
+                    .collect::<Vec<_>>();
+
+                ShellError::SpannedLabeledError(
+                    "Unknown resource".into(),
+                    format!("expected one of: {}", names.join(", ")),
+                    name.span,
+                )
+            })
+    }
+
+    fn get_limit(resource: &Resource, span: Span) -> Result<libc::rlimit, ShellError> {
+        let mut limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+
+        // Safety: getrlimit only writes to the rlimit it is given
+        if unsafe { libc::getrlimit(resource.id, &mut limit) } != 0 {
+            return Err(os_error("Can't read the limit", span));
+        }
+
+        Ok(limit)
+    }
+
+    pub(super) fn set_limit(
+        resource: &Resource,
+        limit: libc::rlim_t,
+        soft: bool,
+        hard: bool,
+        span: Span,
+    ) -> Result<(), ShellError> {
+        let mut new_limit = get_limit(resource, span)?;
+        if soft {
+            new_limit.rlim_cur = limit;
+        }
+        if hard {
+            new_limit.rlim_max = limit;
+        }
+
+        // Safety: setrlimit only reads the rlimit it is given
+        if unsafe { libc::setrlimit(resource.id, &new_limit) } != 0 {
+            return Err(os_error("Can't set the limit", span));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn parse_limit(
+        resource: &Resource,
+        limit: &Value,
+    ) -> Result<libc::rlim_t, ShellError> {
+        let span = limit.span()?;
+
+        let value = match (limit, resource.unit) {
+            (Value::String { val, .. }, _) if val == "unlimited" => return Ok(libc::RLIM_INFINITY),
+            (Value::Filesize { val, .. }, Unit::Bytes) => *val,
+            (Value::Int { val, .. }, Unit::Bytes | Unit::Count) => *val,
+            (Value::Duration { val, .. }, Unit::Seconds) => *val / 1_000_000_000,
+            (Value::Int { val, .. }, Unit::Seconds) => *val,
+            (other, unit) => {
+                let expected = match unit {
+                    Unit::Bytes => "a filesize",
+                    Unit::Seconds => "a duration",
+                    Unit::Count => "a number",
+                };
+
+                return Err(ShellError::SpannedLabeledError(
+                    format!(
+                        "Can't use {} as the limit of {}",
+                        other.get_type(),
+                        resource.name
+                    ),
+                    format!("expected {} or 'unlimited'", expected),
+                    span,
+                ));
+            }
+        };
+
+        if value < 0 {
+            return Err(ShellError::SpannedLabeledError(
+                "Negative limit".into(),
+                "limits can't be below zero".into(),
+                span,
+            ));
+        }
+
+        Ok(value as libc::rlim_t)
+    }
+
+    pub(super) fn limit_row(resource: &Resource, span: Span) -> Result<Value, ShellError> {
+        let limit = get_limit(resource, span)?;
+
+        Ok(Value::Record {
+            cols: vec![
+                "resource".into(),
+                "soft".into(),
+                "hard".into(),
+                "description".into(),
+            ],
+            vals: vec![
+                Value::string(resource.name, span),
+                limit_value(limit.rlim_cur, resource.unit, span),
+                limit_value(limit.rlim_max, resource.unit, span),
+                Value::string(resource.description, span),
+            ],
+            span,
+        })
+    }
+
+    fn limit_value(limit: libc::rlim_t, unit: Unit, span: Span) -> Value {
+        if limit == libc::RLIM_INFINITY || limit > i64::MAX as libc::rlim_t {
+            return Value::string("unlimited", span);
+        }
+
+        let val = limit as i64;
+        match unit {
+            Unit::Bytes => Value::Filesize { val, span },
+            Unit::Seconds => Value::Duration {
+                val: val.saturating_mul(1_000_000_000),
+                span,
+            },
+            Unit::Count => Value::Int { val, span },
+        }
+    }
+
+    fn os_error(message: &str, span: Span) -> ShellError {
+        ShellError::SpannedLabeledError(
+            message.into(),
+            std::io::Error::last_os_error().to_string(),
+            span,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Ulimit {})
+    }
+}
@@ -0,0 +1,272 @@
+use std::path::{Path, PathBuf};
+
+use nu_engine::CallExt;
+use nu_protocol::ast::Call;
+use nu_protocol::engine::{Command, EngineState, Stack};
+use nu_protocol::{
+    Category, DeclId, Example, IntoInterruptiblePipelineData, PipelineData, ShellError, Signature,
+    Span, Spanned, SyntaxShape, Value,
+};
+
+use crate::path_entries;
+
+#[derive(Clone)]
+pub struct Which;
+
+impl Command for Which {
+    fn name(&self) -> &str {
+        "which"
+    }
+
+    fn usage(&self) -> &str {
+        "Show what runs under a name: an alias, a command, or an external program."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"A name is looked up the same way the shell runs it: aliases come first, then the
+commands in scope, then the programs in the directories of PATH. With --all, the
+matches that lose out are listed as well, including commands that are shadowed by a
+newer definition of the same name or hidden with 'hide', which helps to find out why
+a command from a module or overlay isn't the one that runs."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("which")
+            .required("name", SyntaxShape::String, "the name to look up")
+            .switch(
+                "all",
+                "list every match of the name, not only the one that runs",
+                Some('a'),
+            )
+            .category(Category::System)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let all = call.has_flag("all");
+        let span = call.head;
+
+        let mut rows = vec![];
+
+        // Whether an earlier match already takes the name
+        let mut resolved = false;
+
+        if let Some(expansion) = engine_state.find_alias(name.item.as_bytes()) {
+            let expansion = expansion
+                .iter()
+                .map(|span| String::from_utf8_lossy(engine_state.get_span_contents(span)))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            rows.push(alias_record(&name.item, &expansion, span));
+            resolved = true;
+        }
+
+        if all || !resolved {
+            let active = engine_state.find_decl(name.item.as_bytes());
+
+            let decls = if all {
+                engine_state.find_decls_all(name.item.as_bytes())
+            } else {
+                active.map(|decl_id| (decl_id, true)).into_iter().collect()
+            };
+
+            for (decl_id, visible) in decls {
+                let status = if !visible {
+                    "hidden"
+                } else if Some(decl_id) == active && !resolved {
+                    "active"
+                } else {
+                    "shadowed"
+                };
+
+                rows.push(decl_record(engine_state, &name.item, decl_id, status, span));
+            }
+
+            resolved = resolved || active.is_some();
+        }
+
+        if all || !resolved {
+            let dirs = path_entries(stack)?;
+
+            for path in find_externals(&name.item, &dirs, all) {
+                let status = if resolved { "shadowed" } else { "active" };
+                rows.push(external_record(&name.item, &path, status, span));
+                resolved = true;
+            }
+        }
+
+        if rows.is_empty() {
+            return Err(ShellError::SpannedLabeledError(
+                "Command not found".into(),
+                format!(
+                    "there is no alias, command or external program named '{}'",
+                    name.item
+                ),
+                name.span,
+            ));
+        }
+
+        Ok(rows.into_pipeline_data(engine_state.ctrlc.clone()))
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example {
+                description: "Show where the ls command comes from",
+                example: "which ls",
+                result: None,
+            },
+            Example {
+                description: "Show what an alias expands to",
+                example: "alias ll = ls -l; which ll | get expansion.0",
+                result: None,
+            },
+            Example {
+                description: "Find the module command that a custom one shadows",
+                example: "module spam { export def foo [] { 1 } }; use spam foo; def foo [] { 2 }; which foo --all",
+                result: None,
+            },
+            Example {
+                description: "List every git executable in PATH",
+                example: "which git --all | where origin == external | get defined_in",
+                result: None,
+            },
+        ]
+    }
+}
+
+// The columns of every row, so that matches of different kinds can be shown in the same table
+fn record(name: &str, origin: &str, status: &str, span: Span) -> (Vec<String>, Vec<Value>) {
+    (
+        vec![
+            "command".into(),
+            "origin".into(),
+            "status".into(),
+            "defined_in".into(),
+            "expansion".into(),
+            "module".into(),
+            "overlays".into(),
+        ],
+        vec![
+            Value::string(name, span),
+            Value::string(origin, span),
+            Value::string(status, span),
+            Value::nothing(span),
+            Value::nothing(span),
+            Value::nothing(span),
+            Value::List { vals: vec![], span },
+        ],
+    )
+}
+
+fn alias_record(name: &str, expansion: &str, span: Span) -> Value {
+    let (cols, mut vals) = record(name, "alias", "active", span);
+    vals[4] = Value::string(expansion, span);
+
+    Value::Record { cols, vals, span }
+}
+
+fn decl_record(
+    engine_state: &EngineState,
+    name: &str,
+    decl_id: DeclId,
+    status: &str,
+    span: Span,
+) -> Value {
+    let origin = engine_state.get_decl_origin(decl_id);
+    let (cols, mut vals) = record(name, origin.kind(), status, span);
+
+    if let Some(location) = origin.location() {
+        vals[3] = Value::string(location, span);
+    }
+    if let Some(module) = origin.module() {
+        vals[5] = Value::string(module, span);
+    }
+    vals[6] = Value::List {
+        vals: engine_state
+            .find_overlays_with_decl(decl_id)
+            .into_iter()
+            .map(|overlay| Value::string(overlay, span))
+            .collect(),
+        span,
+    };
+
+    Value::Record { cols, vals, span }
+}
+
+fn external_record(name: &str, path: &Path, status: &str, span: Span) -> Value {
+    let (cols, mut vals) = record(name, "external", status, span);
+    vals[3] = Value::string(path.to_string_lossy(), span);
+
+    Value::Record { cols, vals, span }
+}
+
+/// Looks for executables called `name` in the given directories, stopping at the first one
+/// unless `all` is set
+fn find_externals(name: &str, dirs: &[String], all: bool) -> Vec<PathBuf> {
+    let mut output = vec![];
+
+    // A name with a path separator is run as a path rather than looked up
+    if name.is_empty() || name.contains(std::path::is_separator) {
+        return output;
+    }
+
+    for dir in dirs {
+        for file_name in executable_names(name) {
+            let path = Path::new(dir).join(file_name);
+
+            if is_executable(&path) && !output.contains(&path) {
+                output.push(path);
+
+                if !all {
+                    return output;
+                }
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(windows)]
+fn executable_names(name: &str) -> Vec<String> {
+    let mut names = vec![name.to_string()];
+
+    // Windows finds programs by their name without the extension, in the order of PATHEXT
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into());
+    names.extend(
+        extensions
+            .split(';')
+            .filter(|extension| !extension.is_empty())
+            .map(|extension| format!("{}{}", name, extension.to_lowercase())),
+    );
+
+    names
+}
+
+#[cfg(not(windows))]
+fn executable_names(name: &str) -> Vec<String> {
+    vec![name.to_string()]
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    match std::fs::metadata(path) {
+        Ok(metadata) => metadata.is_file() && metadata.permissions().mode() & 0o111 != 0,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
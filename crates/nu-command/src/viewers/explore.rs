@@ -0,0 +1,635 @@
+use std::collections::HashSet;
+use std::io::{Stdout, Write};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, ClearType},
+    tty::IsTty,
+};
+use nu_engine::eval_block;
+use nu_parser::parse;
+use nu_protocol::{
+    ast::Call,
+    engine::{Command, EngineState, Stack, StateWorkingSet},
+    Category, Config, Example, IntoPipelineData, PipelineData, ShellError, Signature, Span, Value,
+};
+
+use super::table::value_to_table_string;
+
+#[derive(Clone)]
+pub struct Explore;
+
+impl Command for Explore {
+    fn name(&self) -> &str {
+        "explore"
+    }
+
+    fn usage(&self) -> &str {
+        "Browse the input interactively."
+    }
+
+    fn extra_usage(&self) -> &str {
+        r#"Move with the arrow keys or j/k, open the selected value with Enter or l, and go back
+with Backspace or h. Space expands a nested value in place, and collapses it again.
+PageUp/PageDown and g/G jump through long lists, and q or Esc quits.
+
+t switches between this tree and the value drawn the way the table command draws it.
+
+Typing ':' opens a command bar: the pipeline entered there runs with the selected value
+as its input, and its result is opened as if it was part of the data."#
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("explore").category(Category::Viewers)
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example {
+            description: "Browse the contents of a file",
+            example: "open Cargo.toml | explore",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        let head = call.head;
+
+        if !std::io::stdout().is_tty() {
+            return Err(ShellError::UnsupportedInput(
+                "explore needs to run in a terminal".into(),
+                head,
+            ));
+        }
+
+        let config = stack.get_config().unwrap_or_default();
+        let mut explorer = Explorer {
+            levels: vec![Level::new("$".into(), input.into_value(head))],
+            prompt: Prompt::None,
+            config,
+        };
+
+        let mut stdout = std::io::stdout();
+        let _screen = Screen::enter(&mut stdout)?;
+
+        loop {
+            explorer.draw(&mut stdout)?;
+
+            if let Event::Key(key) = event::read()? {
+                if !explorer.handle_key(key, engine_state, stack, head) {
+                    break;
+                }
+            }
+        }
+
+        Ok(PipelineData::new(head))
+    }
+}
+
+// Puts the terminal in raw mode on the alternate screen for as long as it is alive
+struct Screen;
+
+impl Screen {
+    fn enter(stdout: &mut Stdout) -> Result<Self, ShellError> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(
+            std::io::stdout(),
+            cursor::Show,
+            terminal::LeaveAlternateScreen
+        );
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// One value being browsed, along with where its parent was opened
+struct Level {
+    label: String,
+    value: Value,
+    // The rows that are expanded in place, by their path of indices from the top
+    expanded: HashSet<Vec<usize>>,
+    selected: usize,
+    offset: usize,
+    // Set while the value is shown as a table, with the width it was rendered for and its lines
+    table: Option<(usize, Vec<String>)>,
+}
+
+// A line of the tree view: an entry of the value, or of a row expanded above it
+struct Row<'a> {
+    path: Vec<usize>,
+    key: String,
+    value: &'a Value,
+}
+
+impl Level {
+    fn new(label: String, value: Value) -> Self {
+        Level {
+            label,
+            value,
+            expanded: HashSet::new(),
+            selected: 0,
+            offset: 0,
+            table: None,
+        }
+    }
+
+    fn rows(&self) -> Vec<Row> {
+        let mut rows = vec![];
+        if is_nested(&self.value) {
+            self.push_rows(&self.value, &mut vec![], &mut rows);
+        } else {
+            rows.push(Row {
+                path: vec![0],
+                key: String::new(),
+                value: &self.value,
+            });
+        }
+
+        rows
+    }
+
+    fn push_rows<'a>(&self, value: &'a Value, path: &mut Vec<usize>, rows: &mut Vec<Row<'a>>) {
+        for (idx, (key, value)) in entries(value).into_iter().enumerate() {
+            path.push(idx);
+            rows.push(Row {
+                path: path.clone(),
+                key,
+                value,
+            });
+            if self.expanded.contains(&*path) {
+                self.push_rows(value, path, rows);
+            }
+            path.pop();
+        }
+    }
+
+    fn selected_row(&self) -> Option<(String, Value)> {
+        let rows = self.rows();
+        let row = rows.get(self.selected)?;
+
+        // The label goes through every expanded row down to the selected one
+        let mut keys = vec![];
+        let mut value = &self.value;
+        for idx in &row.path {
+            let (key, next) = entries(value).into_iter().nth(*idx)?;
+            keys.push(key);
+            value = next;
+        }
+
+        Some((keys.join("."), row.value.clone()))
+    }
+
+    // Expands the selected row in place, or collapses it if it already is
+    fn toggle_selected(&mut self) {
+        let path = match self.rows().get(self.selected) {
+            Some(row) if is_nested(row.value) => row.path.clone(),
+            _ => return,
+        };
+
+        if !self.expanded.remove(&path) {
+            self.expanded.insert(path);
+        }
+    }
+
+    fn table_lines(&mut self, width: usize, config: &Config) -> &[String] {
+        let stale = !matches!(&self.table, Some((rendered_width, _)) if *rendered_width == width);
+        if stale {
+            let lines = match value_to_table_string(self.value.clone(), width, config) {
+                Ok(Some(table)) => table.lines().map(|line| line.to_string()).collect(),
+                Ok(None) => vec![self.value.clone().into_abbreviated_string(config)],
+                Err(err) => vec![err.to_string()],
+            };
+            self.table = Some((width, lines));
+        }
+
+        match &self.table {
+            Some((_, lines)) => lines,
+            None => &[],
+        }
+    }
+}
+
+// The keys and values one level down, which is only the value itself if it isn't nested
+fn entries(value: &Value) -> Vec<(String, &Value)> {
+    match value {
+        Value::Record { cols, vals, .. } => cols.iter().cloned().zip(vals.iter()).collect(),
+        Value::List { vals, .. } => vals
+            .iter()
+            .enumerate()
+            .map(|(idx, val)| (idx.to_string(), val))
+            .collect(),
+        value => vec![(String::new(), value)],
+    }
+}
+
+fn is_nested(value: &Value) -> bool {
+    matches!(value, Value::Record { .. } | Value::List { .. })
+}
+
+enum Prompt {
+    None,
+    Command(String),
+    Message(String),
+}
+
+struct Explorer {
+    levels: Vec<Level>,
+    prompt: Prompt,
+    config: Config,
+}
+
+impl Explorer {
+    fn current(&mut self) -> &mut Level {
+        self.levels
+            .last_mut()
+            .expect("internal error: explore has no value to show")
+    }
+
+    // Returns false once the user asks to quit
+    fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        head: Span,
+    ) -> bool {
+        if let Prompt::Command(command) = &mut self.prompt {
+            match key.code {
+                KeyCode::Esc => self.prompt = Prompt::None,
+                KeyCode::Backspace => {
+                    command.pop();
+                }
+                KeyCode::Char(c) => command.push(c),
+                KeyCode::Enter => {
+                    let command = std::mem::take(command);
+                    self.run_command(&command, engine_state, stack, head);
+                }
+                _ => {}
+            }
+
+            return true;
+        }
+
+        self.prompt = Prompt::None;
+        let (width, height) = terminal::size()
+            .map(|(width, height)| (width as usize, height as usize))
+            .unwrap_or((80, 24));
+        let page = height.saturating_sub(3).max(1);
+
+        // In the table view, the keys that move the selection scroll the lines instead
+        let config = self.config.clone();
+        let level = self.current();
+        let (len, position) = if level.table.is_some() {
+            (level.table_lines(width, &config).len(), &mut level.offset)
+        } else {
+            (level.rows().len(), &mut level.selected)
+        };
+
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') => {
+                *position = (*position + 1).min(len.saturating_sub(1))
+            }
+            KeyCode::Up | KeyCode::Char('k') => *position = position.saturating_sub(1),
+            KeyCode::PageDown => *position = (*position + page).min(len.saturating_sub(1)),
+            KeyCode::PageUp => *position = position.saturating_sub(page),
+            KeyCode::Home | KeyCode::Char('g') => *position = 0,
+            KeyCode::End | KeyCode::Char('G') => *position = len.saturating_sub(1),
+            KeyCode::Char('t') => {
+                if level.table.take().is_none() {
+                    level.table_lines(width, &config);
+                }
+                level.offset = 0;
+            }
+            _ if level.table.is_some() => {}
+            KeyCode::Char(' ') | KeyCode::Tab => level.toggle_selected(),
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => self.open_selected(),
+            KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') if self.levels.len() > 1 => {
+                self.levels.pop();
+            }
+            KeyCode::Char(':') => self.prompt = Prompt::Command(String::new()),
+            _ => {}
+        }
+
+        true
+    }
+
+    fn open_selected(&mut self) {
+        let level = self.current();
+        if let Some((key, value)) = level.selected_row() {
+            if is_nested(&value) {
+                let label = format!("{}.{}", level.label, key);
+                self.levels.push(Level::new(label, value));
+            }
+        }
+    }
+
+    fn run_command(
+        &mut self,
+        command: &str,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        head: Span,
+    ) {
+        let level = self.current();
+        let (label, input) = match level.selected_row() {
+            Some((key, value)) if is_nested(&level.value) => {
+                (format!("{}.{}", level.label, key), value)
+            }
+            _ => (level.label.clone(), level.value.clone()),
+        };
+
+        match eval_pipeline(command, input, engine_state, stack, head) {
+            Ok(value) => {
+                self.prompt = Prompt::None;
+                self.levels
+                    .push(Level::new(format!("{} | {}", label, command), value));
+            }
+            Err(message) => self.prompt = Prompt::Message(message),
+        }
+    }
+
+    fn draw(&mut self, stdout: &mut Stdout) -> Result<(), ShellError> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        let rows = height.saturating_sub(2).max(1);
+
+        let config = self.config.clone();
+        let level = self.current();
+
+        queue!(
+            stdout,
+            terminal::Clear(ClearType::All),
+            cursor::MoveTo(0, 0),
+            SetAttribute(Attribute::Bold),
+            Print(fit(&level.label, width)),
+            SetAttribute(Attribute::Reset)
+        )?;
+
+        let status = if level.table.is_some() {
+            let offset = level.offset;
+            let lines = level.table_lines(width, &config);
+
+            // The table renderer already fits the lines to the width, colors included
+            for (row, line) in lines.iter().skip(offset).take(rows).enumerate() {
+                queue!(stdout, cursor::MoveTo(0, (row + 1) as u16), Print(line))?;
+            }
+
+            format!(
+                "{}/{}  t: tree  ':': run a pipeline  q: quit",
+                (offset + 1).min(lines.len()),
+                lines.len()
+            )
+        } else {
+            if level.selected < level.offset {
+                level.offset = level.selected;
+            } else if level.selected >= level.offset + rows {
+                level.offset = level.selected + 1 - rows;
+            }
+
+            let entries = level.rows();
+            let key_width = entries
+                .iter()
+                .map(|row| 2 * row.path.len() + row.key.chars().count())
+                .max()
+                .unwrap_or(0);
+
+            for (row, entry) in entries.iter().enumerate().skip(level.offset).take(rows) {
+                let marker = match (is_nested(entry.value), level.expanded.contains(&entry.path)) {
+                    (true, true) => "▾ ",
+                    (true, false) => "▸ ",
+                    (false, _) => "  ",
+                };
+                let key = format!("{}{}", "  ".repeat(entry.path.len() - 1), entry.key);
+                let line = format!(
+                    "{}{:key_width$}  {}",
+                    marker,
+                    key,
+                    entry.value.clone().into_abbreviated_string(&config),
+                    key_width = key_width
+                );
+
+                queue!(stdout, cursor::MoveTo(0, (row - level.offset + 1) as u16))?;
+                if row == level.selected {
+                    queue!(
+                        stdout,
+                        SetAttribute(Attribute::Reverse),
+                        Print(fit(&line, width)),
+                        SetAttribute(Attribute::Reset)
+                    )?;
+                } else {
+                    queue!(stdout, Print(fit(&line, width)))?;
+                }
+            }
+
+            format!(
+                "{}/{}  enter: open  space: expand  t: table  backspace: back  ':': run a pipeline  q: quit",
+                (level.selected + 1).min(entries.len()),
+                entries.len()
+            )
+        };
+
+        let status = match &self.prompt {
+            Prompt::Command(command) => format!(":{}", command),
+            Prompt::Message(message) => message.clone(),
+            Prompt::None => status,
+        };
+        queue!(
+            stdout,
+            cursor::MoveTo(0, height.saturating_sub(1) as u16),
+            Print(fit(&status, width))
+        )?;
+
+        stdout.flush()?;
+
+        Ok(())
+    }
+}
+
+// Keep a line on a single row of the terminal
+fn fit(line: &str, width: usize) -> String {
+    line.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .take(width)
+        .collect()
+}
+
+fn eval_pipeline(
+    source: &str,
+    input: Value,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    head: Span,
+) -> Result<Value, String> {
+    let (block, delta) = {
+        let mut working_set = StateWorkingSet::new(engine_state);
+        let (block, err) = parse(&mut working_set, Some("explore"), source.as_bytes(), false);
+        if let Some(err) = err {
+            return Err(err.to_string());
+        }
+
+        (block, working_set.render())
+    };
+
+    let mut engine_state = engine_state.clone();
+    engine_state
+        .merge_delta(delta)
+        .map_err(|err| err.to_string())?;
+
+    let mut stack = stack.collect_captures(&block.captures);
+    eval_block(
+        &engine_state,
+        &mut stack,
+        &block,
+        input.into_pipeline_data(),
+    )
+    .map(|output| output.into_value(head))
+    .map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Explore {})
+    }
+
+    fn record(cols: &[&str], vals: Vec<Value>) -> Value {
+        Value::Record {
+            cols: cols.iter().map(|col| col.to_string()).collect(),
+            vals,
+            span: Span::unknown(),
+        }
+    }
+
+    fn explorer(value: Value) -> Explorer {
+        Explorer {
+            levels: vec![Level::new("$".into(), value)],
+            prompt: Prompt::None,
+            config: Config::default(),
+        }
+    }
+
+    fn press(explorer: &mut Explorer, code: KeyCode) -> bool {
+        let engine_state = EngineState::new();
+        let mut stack = Stack::new();
+
+        explorer.handle_key(
+            KeyEvent::new(code, KeyModifiers::NONE),
+            &engine_state,
+            &mut stack,
+            Span::unknown(),
+        )
+    }
+
+    fn keys(explorer: &mut Explorer) -> Vec<String> {
+        explorer
+            .current()
+            .rows()
+            .into_iter()
+            .map(|row| format!("{}{}", "  ".repeat(row.path.len() - 1), row.key))
+            .collect()
+    }
+
+    #[test]
+    fn space_expands_and_collapses_in_place() {
+        let mut explorer = explorer(record(
+            &["a", "d"],
+            vec![
+                record(&["b", "c"], vec![Value::test_int(1), Value::test_int(2)]),
+                Value::test_int(3),
+            ],
+        ));
+        assert_eq!(keys(&mut explorer), vec!["a", "d"]);
+
+        press(&mut explorer, KeyCode::Char(' '));
+        assert_eq!(keys(&mut explorer), vec!["a", "  b", "  c", "d"]);
+
+        // Values that aren't nested have nothing to expand
+        press(&mut explorer, KeyCode::Char('j'));
+        press(&mut explorer, KeyCode::Char(' '));
+        assert_eq!(keys(&mut explorer), vec!["a", "  b", "  c", "d"]);
+
+        press(&mut explorer, KeyCode::Char('k'));
+        press(&mut explorer, KeyCode::Char(' '));
+        assert_eq!(keys(&mut explorer), vec!["a", "d"]);
+    }
+
+    #[test]
+    fn opens_rows_of_expanded_values() {
+        let inner = Value::List {
+            vals: vec![Value::test_int(1), Value::test_int(2)],
+            span: Span::unknown(),
+        };
+        let mut explorer = explorer(record(&["a"], vec![record(&["b"], vec![inner.clone()])]));
+
+        press(&mut explorer, KeyCode::Char(' '));
+        press(&mut explorer, KeyCode::Char('j'));
+        press(&mut explorer, KeyCode::Enter);
+
+        assert_eq!(explorer.levels.len(), 2);
+        assert_eq!(explorer.current().label, "$.a.b");
+        assert_eq!(explorer.current().value, inner);
+
+        press(&mut explorer, KeyCode::Backspace);
+        assert_eq!(explorer.levels.len(), 1);
+    }
+
+    #[test]
+    fn t_draws_the_value_with_the_table_renderer() {
+        let mut explorer = explorer(Value::List {
+            vals: vec![
+                record(&["name"], vec![Value::test_string("nu")]),
+                record(&["name"], vec![Value::test_string("shell")]),
+            ],
+            span: Span::unknown(),
+        });
+
+        press(&mut explorer, KeyCode::Char('t'));
+        let config = explorer.config.clone();
+        let lines = explorer.current().table_lines(80, &config).to_vec();
+        let expected = value_to_table_string(explorer.current().value.clone(), 80, &config)
+            .expect("the table to render")
+            .expect("a list to be drawn as a table");
+
+        assert_eq!(lines.join("\n"), expected.trim_end_matches('\n'));
+        assert!(lines.iter().any(|line| line.contains("shell")));
+
+        // The keys that move the selection scroll the table instead
+        press(&mut explorer, KeyCode::Char('j'));
+        assert_eq!(explorer.current().offset, 1);
+        assert_eq!(explorer.current().selected, 0);
+
+        press(&mut explorer, KeyCode::Char('t'));
+        assert!(explorer.current().table.is_none());
+        assert_eq!(keys(&mut explorer), vec!["0", "1"]);
+    }
+
+    #[test]
+    fn q_quits() {
+        let mut explorer = explorer(Value::test_int(1));
+
+        assert!(press(&mut explorer, KeyCode::Char('j')));
+        assert!(!press(&mut explorer, KeyCode::Char('q')));
+    }
+}
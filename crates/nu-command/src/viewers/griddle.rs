@@ -1,13 +1,11 @@
-// use super::icons::{icon_for_file, iconify_style_ansi_to_nu};
-use super::icons::icon_for_file;
-use lscolors::{LsColors, Style};
-use nu_engine::env_to_string;
+use crate::{get_ls_colors, style_file_name};
+use lscolors::LsColors;
 use nu_engine::CallExt;
 use nu_protocol::{
     ast::{Call, PathMember},
     engine::{Command, EngineState, Stack},
-    Category, Config, IntoPipelineData, PipelineData, ShellError, Signature, Span, SyntaxShape,
-    Value,
+    AmbiguousWidth, Category, Config, DataSource, IntoPipelineData, PipelineData, PipelineMetadata,
+    ShellError, Signature, Span, SyntaxShape, Value,
 };
 use nu_term_grid::grid::{Alignment, Cell, Direction, Filling, Grid, GridOptions};
 use terminal_size::{Height, Width};
@@ -33,6 +31,11 @@ impl Command for Griddle {
                 Some('w'),
             )
             .switch("color", "draw output with color", Some('c'))
+            .switch(
+                "icons",
+                "draw nerd font icons next to file names",
+                Some('i'),
+            )
             .named(
                 "separator",
                 SyntaxShape::String,
@@ -62,11 +65,20 @@ prints out the list properly."#
         let color_param: bool = call.has_flag("color");
         let separator_param: Option<String> = call.get_flag(engine_state, stack, "separator")?;
         let config = stack.get_config().unwrap_or_default();
-        let env_str = match stack.get_env_var("LS_COLORS") {
-            Some(v) => Some(env_to_string("LS_COLORS", v, engine_state, stack, &config)?),
-            None => None,
-        };
-        let use_grid_icons = config.use_grid_icons;
+        let ls_colors = get_ls_colors(engine_state, stack, &config)?;
+        // the icons from the config only apply to colored grids
+        let use_grid_icons = (color_param && config.use_grid_icons)
+            || call.has_flag("icons")
+            || matches!(
+                input,
+                PipelineData::Stream(
+                    _,
+                    Some(PipelineMetadata {
+                        data_source: DataSource::Ls { icons: true, .. },
+                        ..
+                    }),
+                )
+            );
 
         match input {
             PipelineData::Value(Value::List { vals, .. }, ..) => {
@@ -77,10 +89,10 @@ prints out the list properly."#
                         items,
                         call,
                         width_param,
-                        color_param,
                         separator_param,
-                        env_str,
+                        &ls_colors,
                         use_grid_icons,
+                        config.ambiguous_width,
                     )?)
                 } else {
                     Ok(PipelineData::new(call.head))
@@ -94,10 +106,10 @@ prints out the list properly."#
                         items,
                         call,
                         width_param,
-                        color_param,
                         separator_param,
-                        env_str,
+                        &ls_colors,
                         use_grid_icons,
+                        config.ambiguous_width,
                     )?)
                 } else {
                     // dbg!(data);
@@ -116,10 +128,10 @@ prints out the list properly."#
                     items,
                     call,
                     width_param,
-                    color_param,
                     separator_param,
-                    env_str,
+                    &ls_colors,
                     use_grid_icons,
+                    config.ambiguous_width,
                 )?)
             }
             x => {
@@ -143,16 +155,12 @@ fn create_grid_output(
     items: Vec<(usize, String, String)>,
     call: &Call,
     width_param: Option<String>,
-    color_param: bool,
     separator_param: Option<String>,
-    env_str: Option<String>,
+    ls_colors: &LsColors,
     use_grid_icons: bool,
+    ambiguous_width: AmbiguousWidth,
 ) -> Result<PipelineData, ShellError> {
-    let ls_colors = match env_str {
-        Some(s) => LsColors::from_string(&s),
-        None => LsColors::default(),
-    };
-
+    let color_param = call.has_flag("color");
     let cols = if let Some(col) = width_param {
         col.parse::<u16>().unwrap_or(80)
     } else if let Some((Width(w), Height(_h))) = terminal_size::terminal_size() {
@@ -174,46 +182,20 @@ fn create_grid_output(
     for (_row_index, header, value) in items {
         // only output value if the header name is 'name'
         if header == "name" {
-            if color_param {
-                if use_grid_icons {
-                    let no_ansi = strip_ansi(&value);
-                    let path = std::path::Path::new(&no_ansi);
-                    let icon = icon_for_file(path)?;
-                    let ls_colors_style = ls_colors.style_for_path(path);
-                    // eprintln!("ls_colors_style: {:?}", &ls_colors_style);
-
-                    let icon_style = match ls_colors_style {
-                        Some(c) => c.to_crossterm_style(),
-                        None => crossterm::style::ContentStyle::default(),
-                    };
-                    // eprintln!("icon_style: {:?}", &icon_style);
-
-                    let ansi_style = ls_colors_style
-                        .map(Style::to_crossterm_style)
-                        .unwrap_or_default();
-                    // eprintln!("ansi_style: {:?}", &ansi_style);
-
-                    let item = format!(
-                        "{} {}",
-                        icon_style.apply(icon).to_string(),
-                        ansi_style.apply(value).to_string()
-                    );
-
-                    let mut cell = Cell::from(item);
-                    cell.alignment = Alignment::Left;
-                    grid.add(cell);
-                } else {
-                    let style = ls_colors.style_for_path(value.clone());
-                    let ansi_style = style.map(Style::to_crossterm_style).unwrap_or_default();
-                    let mut cell = Cell::from(ansi_style.apply(value).to_string());
-                    cell.alignment = Alignment::Left;
-                    grid.add(cell);
-                }
+            let item = if color_param || use_grid_icons {
+                // the name may already be colored, so look up the file without the escapes
+                let no_ansi = strip_ansi(&value);
+                style_file_name(ls_colors, &no_ansi, color_param, use_grid_icons)?
             } else {
-                let mut cell = Cell::from(value);
-                cell.alignment = Alignment::Left;
-                grid.add(cell);
-            }
+                value
+            };
+
+            let mut cell = match ambiguous_width {
+                AmbiguousWidth::Narrow => Cell::from(item),
+                AmbiguousWidth::Wide => Cell::from_cjk(item),
+            };
+            cell.alignment = Alignment::Left;
+            grid.add(cell);
         }
     }
 
@@ -1,6 +1,7 @@
+mod explore;
 mod griddle;
-mod icons;
 mod table;
 
+pub use explore::Explore;
 pub use griddle::Griddle;
 pub use table::Table;
@@ -1,17 +1,21 @@
-use lscolors::{LsColors, Style};
+use crate::{get_ls_colors, hyperlink, style_file_name};
+use nu_ansi_term::Style;
 use nu_color_config::{get_color_config, style_primitive};
-use nu_engine::env_to_string;
+use nu_engine::CallExt;
 use nu_protocol::ast::{Call, PathMember};
 use nu_protocol::engine::{Command, EngineState, Stack};
 use nu_protocol::{
-    Category, Config, DataSource, IntoInterruptiblePipelineData, IntoPipelineData, PipelineData,
-    PipelineMetadata, ShellError, Signature, Span, Value, ValueStream,
+    get_config_locale, localize_number, number_locale, Category, Config, DataSource,
+    IntoInterruptiblePipelineData, IntoPipelineData, PipelineData, PipelineMetadata, ShellError,
+    Signature, Span, SyntaxShape, Value, ValueStream,
 };
 use nu_table::{StyledString, TextStyle, Theme};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use terminal_size::{Height, Width};
+use url::Url;
 
 const STREAM_PAGE_SIZE: usize = 1000;
 const STREAM_TIMEOUT_CHECK_INTERVAL: usize = 100;
@@ -29,8 +33,23 @@ impl Command for Table {
         "Render the table."
     }
 
+    fn extra_usage(&self) -> &str {
+        r#"The theme is taken from --theme, then from the NU_TABLE_THEME environment variable,
+and then from 'table_mode' in the config. The 'markdown' theme draws a Markdown table
+without colors, and with every cell in full whatever the width of the terminal, which keeps
+the output the same from one run to the next for tests and documentation."#
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
-        Signature::build("table").category(Category::Viewers)
+        Signature::build("table")
+            .switch("no-color", "Don't color file names using LS_COLORS", None)
+            .named(
+                "theme",
+                SyntaxShape::String,
+                "the theme to draw the table with, like rounded, light or markdown",
+                Some('t'),
+            )
+            .category(Category::Viewers)
     }
 
     fn run(
@@ -41,8 +60,21 @@ impl Command for Table {
         input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
         let ctrlc = engine_state.ctrlc.clone();
-        let config = stack.get_config().unwrap_or_default();
+        let mut config = stack.get_config().unwrap_or_default();
+        // The flag comes before the environment variable, which comes before the config
+        let theme: Option<String> = match call.get_flag(engine_state, stack, "theme")? {
+            Some(theme) => Some(theme),
+            None => stack
+                .get_env_var("NU_TABLE_THEME")
+                .map(|theme| theme.as_string())
+                .transpose()?,
+        };
+        if let Some(theme) = theme {
+            config.table_mode = theme;
+        }
+        let markdown = config.table_mode == "markdown";
         let color_hm = get_color_config(&config);
+        let no_color = call.has_flag("no-color") || markdown;
 
         let term_width = if let Some((Width(w), Height(_h))) = terminal_size::terminal_size() {
             (w - 1) as usize
@@ -55,7 +87,7 @@ impl Command for Table {
                 let table = convert_to_table(0, vals, ctrlc, &config)?;
 
                 if let Some(table) = table {
-                    let result = nu_table::draw_table(&table, term_width, &color_hm, &config);
+                    let result = render_table(&table, term_width, &color_hm, &config);
 
                     Ok(Value::String {
                         val: result,
@@ -69,82 +101,51 @@ impl Command for Table {
             PipelineData::Stream(stream, metadata) => {
                 let stream = match metadata {
                     Some(PipelineMetadata {
-                        data_source: DataSource::Ls,
-                    }) => {
-                        let config = config.clone();
-                        let ctrlc = ctrlc.clone();
-
-                        let ls_colors = match stack.get_env_var("LS_COLORS") {
-                            Some(v) => LsColors::from_string(&env_to_string(
-                                "LS_COLORS",
-                                v,
-                                engine_state,
-                                stack,
-                                &config,
-                            )?),
-                            None => LsColors::default(),
-                        };
+                        data_source: DataSource::Ls { colors, icons },
+                        ..
+                    }) if !markdown
+                        && ((colors && config.use_ls_colors && !no_color)
+                            || icons
+                            || config.use_ls_links) =>
+                    {
+                        let use_color = colors && config.use_ls_colors && !no_color;
+                        let use_links = config.use_ls_links;
+                        let ls_colors = get_ls_colors(engine_state, stack, &config)?;
+                        let cwd = std::env::current_dir()?;
 
                         ValueStream::from_stream(
                             stream.map(move |mut x| match &mut x {
                                 Value::Record { cols, vals, .. } => {
-                                    let mut idx = 0;
+                                    for (col, val) in cols.iter().zip(vals.iter_mut()) {
+                                        if col != "name" {
+                                            continue;
+                                        }
 
-                                    while idx < cols.len() {
-                                        if cols[idx] == "name" {
-                                            if let Some(Value::String { val: path, span }) =
-                                                vals.get(idx)
+                                        if let Value::String { val: path, .. } = val {
+                                            // The link is made before styling, which changes the name
+                                            let url = if use_links {
+                                                Url::from_file_path(cwd.join(path.as_str())).ok()
+                                            } else {
+                                                None
+                                            };
+
+                                            if let Ok(styled) =
+                                                style_file_name(&ls_colors, path, use_color, icons)
                                             {
-                                                match std::fs::symlink_metadata(&path) {
-                                                    Ok(metadata) => {
-                                                        let style = ls_colors
-                                                            .style_for_path_with_metadata(
-                                                                path.clone(),
-                                                                Some(&metadata),
-                                                            );
-                                                        let ansi_style = style
-                                                            .map(Style::to_crossterm_style)
-                                                            .unwrap_or_default();
-                                                        let use_ls_colors = config.use_ls_colors;
-
-                                                        if use_ls_colors {
-                                                            vals[idx] = Value::String {
-                                                                val: ansi_style
-                                                                    .apply(path)
-                                                                    .to_string(),
-                                                                span: *span,
-                                                            };
-                                                        }
-                                                    }
-                                                    Err(_) => {
-                                                        let style =
-                                                            ls_colors.style_for_path(path.clone());
-                                                        let ansi_style = style
-                                                            .map(Style::to_crossterm_style)
-                                                            .unwrap_or_default();
-                                                        let use_ls_colors = config.use_ls_colors;
-
-                                                        if use_ls_colors {
-                                                            vals[idx] = Value::String {
-                                                                val: ansi_style
-                                                                    .apply(path)
-                                                                    .to_string(),
-                                                                span: *span,
-                                                            };
-                                                        }
-                                                    }
-                                                }
+                                                *path = styled;
                                             }
-                                        }
 
-                                        idx += 1;
+                                            if let Some(url) = url {
+                                                *path = hyperlink(url.as_str(), path);
+                                            }
+                                        }
                                     }
 
                                     x
                                 }
                                 _ => x,
                             }),
-                            ctrlc,
+                            ctrlc.clone(),
                         )
                     }
                     _ => stream,
@@ -176,28 +177,8 @@ impl Command for Table {
                 // }
             }
             PipelineData::Value(Value::Record { cols, vals, .. }, ..) => {
-                let mut output = vec![];
-
-                for (c, v) in cols.into_iter().zip(vals.into_iter()) {
-                    output.push(vec![
-                        StyledString {
-                            contents: c,
-                            style: TextStyle::default_field(),
-                        },
-                        StyledString {
-                            contents: v.into_abbreviated_string(&config),
-                            style: TextStyle::default(),
-                        },
-                    ])
-                }
-
-                let table = nu_table::Table {
-                    headers: vec![],
-                    data: output,
-                    theme: load_theme_from_config(&config),
-                };
-
-                let result = nu_table::draw_table(&table, term_width, &color_hm, &config);
+                let table = record_to_table(cols, vals, &config);
+                let result = render_table(&table, term_width, &color_hm, &config);
 
                 Ok(Value::String {
                     val: result,
@@ -205,7 +186,7 @@ impl Command for Table {
                 }
                 .into_pipeline_data())
             }
-            PipelineData::Value(Value::Error { error }, ..) => Err(error),
+            PipelineData::Value(Value::Error { error }, ..) => Err(*error),
             PipelineData::Value(Value::CustomValue { val, span }, ..) => {
                 let base_pipeline = val.to_base_value(span)?.into_pipeline_data();
                 self.run(engine_state, stack, call, base_pipeline)
@@ -215,6 +196,46 @@ impl Command for Table {
     }
 }
 
+// Renders a list or a record the same way `table` does, for viewers that draw it themselves.
+// None if the value is neither.
+pub(super) fn value_to_table_string(
+    value: Value,
+    term_width: usize,
+    config: &Config,
+) -> Result<Option<String>, ShellError> {
+    let table = match value {
+        Value::List { vals, .. } => convert_to_table(0, vals, None, config)?,
+        Value::Record { cols, vals, .. } => Some(record_to_table(cols, vals, config)),
+        _ => None,
+    };
+    let color_hm = get_color_config(config);
+
+    Ok(table.map(|table| render_table(&table, term_width, &color_hm, config)))
+}
+
+fn record_to_table(cols: Vec<String>, vals: Vec<Value>, config: &Config) -> nu_table::Table {
+    let mut output = vec![];
+
+    for (c, v) in cols.into_iter().zip(vals.into_iter()) {
+        output.push(vec![
+            StyledString {
+                contents: c,
+                style: TextStyle::default_field(),
+            },
+            StyledString {
+                contents: v.into_abbreviated_string(config),
+                style: TextStyle::default(),
+            },
+        ])
+    }
+
+    nu_table::Table {
+        headers: vec![],
+        data: output,
+        theme: load_theme_from_config(config),
+    }
+}
+
 fn convert_to_table(
     row_offset: usize,
     iter: impl IntoIterator<Item = Value>,
@@ -224,6 +245,11 @@ fn convert_to_table(
     let mut iter = iter.into_iter().peekable();
     let color_hm = get_color_config(config);
     let float_precision = config.float_precision as usize;
+    let locale = get_config_locale(config).map(|name| number_locale(&name));
+    let localize = |number: String| match &locale {
+        Some(locale) => localize_number(&number, locale),
+        None => number,
+    };
 
     if let Some(first) = iter.peek() {
         let mut headers = first.columns();
@@ -242,7 +268,7 @@ fn convert_to_table(
                 }
             }
             if let Value::Error { error } = item {
-                return Err(error);
+                return Err(*error);
             }
             // String1 = datatype, String2 = value as string
             let mut row: Vec<(String, String)> =
@@ -309,7 +335,12 @@ fn convert_to_table(
                                         Err(e) => e.to_string(),
                                     };
                                 StyledString {
-                                    contents: precise_number,
+                                    contents: localize(precise_number),
+                                    style: style_primitive(&y.0, &color_hm),
+                                }
+                            } else if &y.0 == "int" {
+                                StyledString {
+                                    contents: localize(y.1),
                                     style: style_primitive(&y.0, &color_hm),
                                 }
                             } else {
@@ -404,19 +435,34 @@ impl Iterator for PagingTableCreator {
 
         match table {
             Ok(Some(table)) => {
-                let result = nu_table::draw_table(&table, term_width, &color_hm, &self.config);
+                let result = render_table(&table, term_width, &color_hm, &self.config);
 
                 Some(Value::String {
                     val: result,
                     span: self.head,
                 })
             }
-            Err(err) => Some(Value::Error { error: err }),
+            Err(err) => Some(Value::Error {
+                error: Box::new(err),
+            }),
             _ => None,
         }
     }
 }
 
+fn render_table(
+    table: &nu_table::Table,
+    term_width: usize,
+    color_hm: &HashMap<String, Style>,
+    config: &Config,
+) -> String {
+    if config.table_mode == "markdown" {
+        nu_table::draw_markdown_table(table, config)
+    } else {
+        nu_table::draw_table(table, term_width, color_hm, config)
+    }
+}
+
 fn load_theme_from_config(config: &Config) -> Theme {
     match config.table_mode.as_str() {
         "basic" => nu_table::Theme::basic(),
@@ -158,6 +158,10 @@ pub fn get_documentation(
         long_desc.push_str("\n\n");
     }
 
+    if let Some(replacement) = &sig.deprecated {
+        long_desc.push_str(&format!("Deprecated: {}\n\n", replacement));
+    }
+
     let mut subcommands = vec![];
     if !config.no_subcommands {
         let signatures = engine_state.get_signatures(true);
@@ -333,6 +337,10 @@ fn get_flags_section(signature: &Signature) -> String {
         } else {
             format!("  --{} {}\n", flag.long, flag.desc)
         };
+        let msg = match &flag.deprecated {
+            Some(replacement) => format!("{} (deprecated: {})\n", msg.trim_end(), replacement),
+            None => msg,
+        };
         long_desc.push_str(&msg);
     }
     long_desc
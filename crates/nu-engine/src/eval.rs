@@ -3,7 +3,8 @@ use std::cmp::Ordering;
 use nu_protocol::ast::{Block, Call, Expr, Expression, Operator, Statement};
 use nu_protocol::engine::{EngineState, Stack};
 use nu_protocol::{
-    IntoPipelineData, PipelineData, Range, ShellError, Span, Spanned, Type, Unit, Value, VarId,
+    IntOverflow, IntoPipelineData, PipelineData, Range, ShellError, Span, Spanned, Type, Unit,
+    Value, VarId,
 };
 
 use crate::get_full_help;
@@ -259,9 +260,18 @@ pub fn eval_expression(
             let rhs = eval_expression(engine_state, stack, rhs)?;
 
             match op {
-                Operator::Plus => lhs.add(op_span, &rhs),
-                Operator::Minus => lhs.sub(op_span, &rhs),
-                Operator::Multiply => lhs.mul(op_span, &rhs),
+                Operator::Plus => {
+                    let result = lhs.add(op_span, &rhs);
+                    on_int_overflow(stack, result, &lhs, &rhs, IntOverflow::add)
+                }
+                Operator::Minus => {
+                    let result = lhs.sub(op_span, &rhs);
+                    on_int_overflow(stack, result, &lhs, &rhs, IntOverflow::sub)
+                }
+                Operator::Multiply => {
+                    let result = lhs.mul(op_span, &rhs);
+                    on_int_overflow(stack, result, &lhs, &rhs, IntOverflow::mul)
+                }
                 Operator::Divide => lhs.div(op_span, &rhs),
                 Operator::LessThan => lhs.lt(op_span, &rhs),
                 Operator::LessThanOrEqual => lhs.lte(op_span, &rhs),
@@ -277,8 +287,20 @@ pub fn eval_expression(
                 Operator::And => lhs.and(op_span, &rhs),
                 Operator::Or => lhs.or(op_span, &rhs),
                 Operator::Pow => lhs.pow(op_span, &rhs),
+                Operator::Append => lhs.append(op_span, &rhs),
             }
         }
+        Expr::UnaryNot(inner) => match eval_expression(engine_state, stack, inner)? {
+            Value::Bool { val, .. } => Ok(Value::Bool {
+                val: !val,
+                span: expr.span,
+            }),
+            x => Err(ShellError::CantConvert(
+                "boolean".into(),
+                x.get_type().to_string(),
+                inner.span,
+            )),
+        },
         Expr::RowCondition(block_id) | Expr::Subexpression(block_id) => {
             let block = engine_state.get_block(*block_id);
 
@@ -357,6 +379,35 @@ pub fn eval_expression(
     }
 }
 
+// Integer overflow is an error unless the config asks for wrapping or saturating arithmetic.
+// The config is only looked up once an operation has overflowed, keeping the common case fast.
+fn on_int_overflow(
+    stack: &Stack,
+    result: Result<Value, ShellError>,
+    lhs: &Value,
+    rhs: &Value,
+    op: fn(IntOverflow, i64, i64) -> Option<i64>,
+) -> Result<Value, ShellError> {
+    match (&result, lhs, rhs) {
+        (
+            Err(ShellError::OperatorOverflow(_, span)),
+            Value::Int { val: lhs, .. },
+            Value::Int { val: rhs, .. },
+        ) => {
+            let overflow = stack
+                .get_config()
+                .map(|config| config.int_overflow)
+                .unwrap_or(IntOverflow::Checked);
+
+            match op(overflow, *lhs, *rhs) {
+                Some(val) => Ok(Value::Int { val, span: *span }),
+                None => result,
+            }
+        }
+        _ => result,
+    }
+}
+
 pub fn eval_block(
     engine_state: &EngineState,
     stack: &mut Stack,
@@ -772,6 +823,32 @@ pub fn eval_variable(
                     span,
                 });
 
+                let origin = engine_state.get_decl_origin(*command.1);
+                cols.push("origin".to_string());
+                vals.push(Value::string(origin.kind(), span));
+
+                cols.push("defined_in".to_string());
+                vals.push(match origin.location() {
+                    Some(location) => Value::string(location, span),
+                    None => Value::nothing(span),
+                });
+
+                cols.push("module".to_string());
+                vals.push(match origin.module() {
+                    Some(module) => Value::string(module, span),
+                    None => Value::nothing(span),
+                });
+
+                cols.push("overlays".to_string());
+                vals.push(Value::List {
+                    vals: engine_state
+                        .find_overlays_with_decl(*command.1)
+                        .into_iter()
+                        .map(|overlay| Value::string(overlay, span))
+                        .collect(),
+                    span,
+                });
+
                 commands.push(Value::Record { cols, vals, span })
             }
 
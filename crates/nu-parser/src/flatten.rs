@@ -133,6 +133,16 @@ pub fn flatten_expression(
         Expr::Float(_) => {
             vec![(expr.span, FlatShape::Float)]
         }
+        Expr::UnaryNot(inner) => {
+            let keyword = Span {
+                start: expr.span.start,
+                end: expr.span.start + b"not".len(),
+            };
+            let mut output = vec![(keyword, FlatShape::Operator)];
+            output.extend(flatten_expression(working_set, inner));
+
+            output
+        }
         Expr::ValueWithUnit(x, unit) => {
             let mut output = flatten_expression(working_set, x);
             output.push((unit.span, FlatShape::String));
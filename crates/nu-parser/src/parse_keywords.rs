@@ -4,10 +4,11 @@ use nu_protocol::{
         Block, Call, Expr, Expression, ImportPattern, ImportPatternHead, ImportPatternMember,
         Pipeline, Statement,
     },
-    engine::StateWorkingSet,
+    engine::{ParsedFile, StateWorkingSet},
     span, Exportable, Overlay, Span, SyntaxShape, Type, CONFIG_VARIABLE_ID,
 };
-use std::collections::{HashMap, HashSet};
+use std::collections::{hash_map::DefaultHasher, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 
 use crate::{
     lex, lite_parse,
@@ -48,7 +49,7 @@ pub fn parse_def_predecl(working_set: &mut StateWorkingSet, spans: &[Span]) -> O
             signature.name = name;
             let decl = signature.predeclare();
 
-            if working_set.add_predecl(decl).is_some() {
+            if working_set.add_predecl(decl, spans[1]).is_some() {
                 return Some(ParseError::DuplicateCommandDef(spans[1]));
             }
         }
@@ -478,11 +479,13 @@ pub fn parse_export(
 
 pub fn parse_module_block(
     working_set: &mut StateWorkingSet,
+    module_name: &str,
     span: Span,
 ) -> (Block, Overlay, Option<ParseError>) {
     let mut error = None;
 
     working_set.enter_scope();
+    let parent_module = working_set.current_module.replace(module_name.into());
 
     let source = working_set.get_span_contents(span);
 
@@ -565,6 +568,7 @@ pub fn parse_module_block(
         })
         .into();
 
+    working_set.current_module = parent_module;
     working_set.exit_scope();
 
     (block, overlay, error)
@@ -611,7 +615,7 @@ pub fn parse_module(
 
         let block_span = Span { start, end };
 
-        let (block, overlay, err) = parse_module_block(working_set, block_span);
+        let (block, overlay, err) = parse_module_block(working_set, &module_name, block_span);
         error = error.or(err);
 
         let block_id = working_set.add_block(block);
@@ -695,8 +699,11 @@ pub fn parse_use(
                             working_set.add_file(module_filename, &contents);
                             let span_end = working_set.next_span_start();
 
-                            let (block, overlay, err) =
-                                parse_module_block(working_set, Span::new(span_start, span_end));
+                            let (block, overlay, err) = parse_module_block(
+                                working_set,
+                                &module_name,
+                                Span::new(span_start, span_end),
+                            );
                             error = error.or(err);
 
                             let _ = working_set.add_block(block);
@@ -1053,52 +1060,88 @@ pub fn parse_source(
                 if let Ok(filename) = String::from_utf8(name_expr.to_vec()) {
                     if let Ok(path) = canonicalize(&filename) {
                         if let Ok(contents) = std::fs::read(&path) {
-                            // This will load the defs from the file into the
-                            // working set, if it was a successful parse.
-                            let (block, err) = parse(
-                                working_set,
-                                path.file_name().and_then(|x| x.to_str()),
-                                &contents,
-                                false,
-                            );
-
-                            if err.is_some() {
-                                // Unsuccessful parse of file
-                                return (
-                                    Statement::Pipeline(Pipeline::from_vec(vec![Expression {
-                                        expr: Expr::Call(call),
-                                        span: span(&spans[1..]),
-                                        ty: Type::Unknown,
-                                        custom_completion: None,
-                                    }])),
-                                    // Return the file parse error
-                                    err,
-                                );
+                            let hash = {
+                                let mut hasher = DefaultHasher::new();
+                                contents.hash(&mut hasher);
+                                hasher.finish()
+                            };
+
+                            let block_id = if let Some(parsed) =
+                                working_set.find_parsed_file(&path, hash)
+                            {
+                                // The file hasn't changed since it was last sourced, so only
+                                // its definitions need to be brought back into scope
+                                let ParsedFile {
+                                    block_id, frame, ..
+                                } = parsed.clone();
+                                working_set.merge_scope(frame);
+
+                                block_id
                             } else {
+                                // This will load the defs from the file into the
+                                // working set, if it was a successful parse.
+                                // The file gets its own scope frame so that its definitions
+                                // can be remembered along with the block.
+                                working_set.enter_scope();
+                                let (block, err) = parse(
+                                    working_set,
+                                    path.file_name().and_then(|x| x.to_str()),
+                                    &contents,
+                                    false,
+                                );
+                                let frame = working_set.take_scope();
+                                working_set.merge_scope(frame.clone());
+
+                                if err.is_some() {
+                                    // Unsuccessful parse of file
+                                    return (
+                                        Statement::Pipeline(Pipeline::from_vec(vec![Expression {
+                                            expr: Expr::Call(call),
+                                            span: span(&spans[1..]),
+                                            ty: Type::Unknown,
+                                            custom_completion: None,
+                                        }])),
+                                        // Return the file parse error
+                                        err,
+                                    );
+                                }
+
                                 // Save the block into the working set
                                 let block_id = working_set.add_block(block);
+                                let scope_generation = working_set.scope_generation();
+                                working_set.add_parsed_file(
+                                    path,
+                                    ParsedFile {
+                                        hash,
+                                        block_id,
+                                        frame,
+                                        scope_generation,
+                                    },
+                                );
+
+                                block_id
+                            };
+
+                            let mut call_with_block = call;
 
-                                let mut call_with_block = call;
+                            // Adding this expression to the positional creates a syntax highlighting error
+                            // after writing `source example.nu`
+                            call_with_block.positional.push(Expression {
+                                expr: Expr::Int(block_id as i64),
+                                span: spans[1],
+                                ty: Type::Unknown,
+                                custom_completion: None,
+                            });
 
-                                // Adding this expression to the positional creates a syntax highlighting error
-                                // after writing `source example.nu`
-                                call_with_block.positional.push(Expression {
-                                    expr: Expr::Int(block_id as i64),
-                                    span: spans[1],
+                            return (
+                                Statement::Pipeline(Pipeline::from_vec(vec![Expression {
+                                    expr: Expr::Call(call_with_block),
+                                    span: call_span,
                                     ty: Type::Unknown,
                                     custom_completion: None,
-                                });
-
-                                return (
-                                    Statement::Pipeline(Pipeline::from_vec(vec![Expression {
-                                        expr: Expr::Call(call_with_block),
-                                        span: call_span,
-                                        ty: Type::Unknown,
-                                        custom_completion: None,
-                                    }])),
-                                    None,
-                                );
-                            }
+                                }])),
+                                None,
+                            );
                         }
                     } else {
                         error = error.or(Some(ParseError::FileNotFound(filename, spans[1])));
@@ -12,8 +12,8 @@ use nu_protocol::{
         Statement,
     },
     engine::StateWorkingSet,
-    span, Flag, PositionalArg, Signature, Span, Spanned, SyntaxShape, Type, Unit, VarId,
-    CONFIG_VARIABLE_ID,
+    span, Flag, ParseWarning, PositionalArg, Signature, Span, Spanned, SyntaxShape, Type, Unit,
+    VarId, CONFIG_VARIABLE_ID,
 };
 
 use crate::parse_keywords::{
@@ -613,6 +613,8 @@ pub fn parse_internal_call(
     let err = check_call(command_span, &signature, &call);
     error = error.or(err);
 
+    warn_deprecated(working_set, &signature, &call);
+
     if signature.creates_scope {
         working_set.exit_scope();
     }
@@ -621,6 +623,33 @@ pub fn parse_internal_call(
     (Box::new(call), span(spans), error)
 }
 
+// Deprecated commands and flags still work, but each use of them gets a warning
+fn warn_deprecated(working_set: &mut StateWorkingSet, signature: &Signature, call: &Call) {
+    if let Some(replacement) = &signature.deprecated {
+        working_set
+            .parse_warnings
+            .push(ParseWarning::DeprecatedCommand(
+                signature.name.clone(),
+                replacement.clone(),
+                call.head,
+            ));
+    }
+
+    for (name, _) in &call.named {
+        if let Some(flag) = signature.get_long_flag(&name.item) {
+            if let Some(replacement) = flag.deprecated {
+                working_set
+                    .parse_warnings
+                    .push(ParseWarning::DeprecatedFlag(
+                        name.item.clone(),
+                        replacement,
+                        name.span,
+                    ));
+            }
+        }
+    }
+}
+
 pub fn parse_call(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -1748,10 +1777,31 @@ pub fn parse_string_strict(
 
 //TODO: Handle error case for unknown shapes
 pub fn parse_shape_name(
-    _working_set: &StateWorkingSet,
+    working_set: &StateWorkingSet,
     bytes: &[u8],
     span: Span,
 ) -> (SyntaxShape, Option<ParseError>) {
+    // A shape can name where its completions come from, eg `string@"animals"`
+    if let Some(at) = bytes.iter().position(|b| *b == b'@') {
+        let (shape, err) = parse_shape_name(working_set, &bytes[..at], span);
+        let completion = trim_quotes(&bytes[(at + 1)..]);
+
+        if completion.is_empty() {
+            return (
+                shape,
+                Some(ParseError::Expected("completion command".into(), span)),
+            );
+        }
+
+        return (
+            SyntaxShape::Custom(
+                Box::new(shape),
+                String::from_utf8_lossy(completion).to_string(),
+            ),
+            err,
+        );
+    }
+
     let result = match bytes {
         b"any" => SyntaxShape::Any,
         b"string" => SyntaxShape::String,
@@ -2144,6 +2194,7 @@ pub fn parse_signature_helper(
                                         short: None,
                                         required: false,
                                         var_id: Some(var_id),
+                                        deprecated: None,
                                     }));
                                 } else {
                                     let short_flag = &flags[1];
@@ -2174,6 +2225,7 @@ pub fn parse_signature_helper(
                                             short: Some(chars[0]),
                                             required: false,
                                             var_id: Some(var_id),
+                                            deprecated: None,
                                         }));
                                     } else {
                                         error = error.or_else(|| {
@@ -2200,6 +2252,7 @@ pub fn parse_signature_helper(
                                         short: None,
                                         required: false,
                                         var_id: None,
+                                        deprecated: None,
                                     }));
                                 } else {
                                     let mut encoded_var_name = vec![0u8; 4];
@@ -2215,6 +2268,7 @@ pub fn parse_signature_helper(
                                         short: Some(chars[0]),
                                         required: false,
                                         var_id: Some(var_id),
+                                        deprecated: None,
                                     }));
                                 }
                             } else if contents.starts_with(b"(-") {
@@ -2920,9 +2974,10 @@ pub fn parse_operator(
         b"in" => Operator::In,
         b"not-in" => Operator::NotIn,
         b"mod" => Operator::Modulo,
-        b"&&" => Operator::And,
-        b"||" => Operator::Or,
+        b"&&" | b"and" => Operator::And,
+        b"||" | b"or" => Operator::Or,
         b"**" => Operator::Pow,
+        b"++" => Operator::Append,
         _ => {
             return (
                 garbage(span),
@@ -2942,6 +2997,75 @@ pub fn parse_operator(
     )
 }
 
+// Parses the value at `spans[*idx]`, or a `not` and the comparisons it negates. `not` binds
+// looser than comparisons and tighter than `and`/`or`, so `not a == b or c` is `(not a == b) or c`.
+// Leaves `idx` at the last span of the operand.
+fn parse_math_operand(
+    working_set: &mut StateWorkingSet,
+    spans: &[Span],
+    idx: &mut usize,
+    lhs_row_var_id: Option<VarId>,
+) -> (Expression, Option<ParseError>) {
+    if working_set.get_span_contents(spans[*idx]) != b"not" {
+        return parse_value(working_set, spans[*idx], &SyntaxShape::Any);
+    }
+
+    let start = *idx + 1;
+    if start == spans.len() {
+        return (
+            garbage(spans[*idx]),
+            Some(ParseError::IncompleteMathExpression(spans[*idx])),
+        );
+    }
+
+    // Find the end of the negated operand: the next `and`/`or`, skipping nested `not`s
+    let mut end = start;
+    loop {
+        while end < spans.len() && working_set.get_span_contents(spans[end]) == b"not" {
+            end += 1;
+        }
+        end += 1;
+        if end >= spans.len()
+            || matches!(
+                working_set.get_span_contents(spans[end]),
+                b"and" | b"or" | b"&&" | b"||"
+            )
+        {
+            break;
+        }
+        end += 1;
+    }
+    let end = end.min(spans.len());
+
+    let (mut inner, mut error) =
+        parse_math_expression(working_set, &spans[start..end], lhs_row_var_id);
+    if let Some(row_var_id) = lhs_row_var_id {
+        expand_to_cell_path(working_set, &mut inner, row_var_id);
+    }
+    if !matches!(inner.ty, Type::Bool | Type::Unknown) {
+        error = error.or_else(|| {
+            Some(ParseError::TypeMismatch(
+                Type::Bool,
+                inner.ty.clone(),
+                inner.span,
+            ))
+        });
+    }
+
+    let not_span = span(&[spans[*idx], inner.span]);
+    *idx = end - 1;
+
+    (
+        Expression {
+            expr: Expr::UnaryNot(Box::new(inner)),
+            span: not_span,
+            ty: Type::Bool,
+            custom_completion: None,
+        },
+        error,
+    )
+}
+
 pub fn parse_math_expression(
     working_set: &mut StateWorkingSet,
     spans: &[Span],
@@ -2960,10 +3084,9 @@ pub fn parse_math_expression(
     let mut expr_stack: Vec<Expression> = vec![];
 
     let mut idx = 0;
-    let mut last_prec = 1000000;
 
     let mut error = None;
-    let (lhs, err) = parse_value(working_set, spans[0], &SyntaxShape::Any);
+    let (lhs, err) = parse_math_operand(working_set, spans, &mut idx, lhs_row_var_id);
     error = error.or(err);
     idx += 1;
 
@@ -2987,10 +3110,10 @@ pub fn parse_math_expression(
             break;
         }
 
-        let (rhs, err) = parse_value(working_set, spans[idx], &SyntaxShape::Any);
+        let (rhs, err) = parse_math_operand(working_set, spans, &mut idx, lhs_row_var_id);
         error = error.or(err);
 
-        if op_prec <= last_prec && expr_stack.len() > 1 {
+        while expr_stack.len() > 1 && op_prec <= expr_stack[expr_stack.len() - 2].precedence() {
             // Collapse the right associated operations first
             // so that we can get back to a stack with a lower precedence
             let mut rhs = expr_stack
@@ -3022,8 +3145,6 @@ pub fn parse_math_expression(
         expr_stack.push(op);
         expr_stack.push(rhs);
 
-        last_prec = op_prec;
-
         idx += 1;
     }
 
@@ -3124,7 +3245,7 @@ pub fn parse_expression(
 
     let bytes = working_set.get_span_contents(spans[pos]);
 
-    let (output, err) = if is_math_expression_byte(bytes[0]) {
+    let (output, err) = if is_math_expression_byte(bytes[0]) || bytes == b"not" {
         parse_math_expression(working_set, &spans[pos..], None)
     } else {
         parse_call(working_set, &spans[pos..], expand_aliases)
@@ -3534,7 +3655,7 @@ pub fn find_captures_in_expr(
                 }
             }
         }
-        Expr::ValueWithUnit(expr, _) => {
+        Expr::UnaryNot(expr) | Expr::ValueWithUnit(expr, _) => {
             let result = find_captures_in_expr(working_set, expr, seen);
             output.extend(&result);
         }
@@ -62,6 +62,28 @@ pub fn math_result_type(
                     )
                 }
             },
+            Operator::Append => match (&lhs.ty, &rhs.ty) {
+                (Type::String, Type::String) => (Type::String, None),
+                (Type::List(a), Type::List(b)) if a == b => (Type::List(a.clone()), None),
+                (Type::List(_), Type::List(_)) => (Type::List(Box::new(Type::Unknown)), None),
+                (Type::List(_), _) => (Type::List(Box::new(Type::Unknown)), None),
+
+                (Type::Unknown, _) => (Type::Unknown, None),
+                (_, Type::Unknown) => (Type::Unknown, None),
+                _ => {
+                    *op = Expression::garbage(op.span);
+                    (
+                        Type::Unknown,
+                        Some(ParseError::UnsupportedOperation(
+                            op.span,
+                            lhs.span,
+                            lhs.ty.clone(),
+                            rhs.span,
+                            rhs.ty.clone(),
+                        )),
+                    )
+                }
+            },
             Operator::Minus => match (&lhs.ty, &rhs.ty) {
                 (Type::Int, Type::Int) => (Type::Int, None),
                 (Type::Float, Type::Int) => (Type::Float, None),
@@ -91,6 +113,24 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Filesize, Type::Int)
+                | (Type::Filesize, Type::Float)
+                | (Type::Int, Type::Filesize)
+                | (Type::Float, Type::Filesize)
+                    if matches!(operator, Operator::Multiply) =>
+                {
+                    (Type::Filesize, None)
+                }
+                (Type::String, Type::Int) | (Type::Int, Type::String)
+                    if matches!(operator, Operator::Multiply) =>
+                {
+                    (Type::String, None)
+                }
+                (Type::List(ty), Type::Int) | (Type::Int, Type::List(ty))
+                    if matches!(operator, Operator::Multiply) =>
+                {
+                    (Type::List(ty.clone()), None)
+                }
 
                 (Type::Unknown, _) => (Type::Unknown, None),
                 (_, Type::Unknown) => (Type::Unknown, None),
@@ -113,6 +153,14 @@ pub fn math_result_type(
                 (Type::Float, Type::Int) => (Type::Float, None),
                 (Type::Int, Type::Float) => (Type::Float, None),
                 (Type::Float, Type::Float) => (Type::Float, None),
+                (Type::Filesize, Type::Int) | (Type::Filesize, Type::Float)
+                    if matches!(operator, Operator::Divide) =>
+                {
+                    (Type::Filesize, None)
+                }
+                (Type::Filesize, Type::Filesize) if matches!(operator, Operator::Divide) => {
+                    (Type::Float, None)
+                }
 
                 (Type::Unknown, _) => (Type::Unknown, None),
                 (_, Type::Unknown) => (Type::Unknown, None),
@@ -156,6 +204,8 @@ pub fn math_result_type(
                 (Type::Float, Type::Float) => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
+                (Type::Filesize, Type::Int) => (Type::Bool, None),
+                (Type::Int, Type::Filesize) => (Type::Bool, None),
 
                 (Type::Unknown, _) => (Type::Bool, None),
                 (_, Type::Unknown) => (Type::Bool, None),
@@ -180,6 +230,8 @@ pub fn math_result_type(
                 (Type::Float, Type::Float) => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
+                (Type::Filesize, Type::Int) => (Type::Bool, None),
+                (Type::Int, Type::Filesize) => (Type::Bool, None),
 
                 (Type::Unknown, _) => (Type::Bool, None),
                 (_, Type::Unknown) => (Type::Bool, None),
@@ -204,6 +256,8 @@ pub fn math_result_type(
                 (Type::Float, Type::Float) => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
+                (Type::Filesize, Type::Int) => (Type::Bool, None),
+                (Type::Int, Type::Filesize) => (Type::Bool, None),
 
                 (Type::Unknown, _) => (Type::Bool, None),
                 (_, Type::Unknown) => (Type::Bool, None),
@@ -228,6 +282,8 @@ pub fn math_result_type(
                 (Type::Float, Type::Float) => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
+                (Type::Filesize, Type::Int) => (Type::Bool, None),
+                (Type::Int, Type::Filesize) => (Type::Bool, None),
 
                 (Type::Unknown, _) => (Type::Bool, None),
                 (_, Type::Unknown) => (Type::Bool, None),
@@ -250,6 +306,8 @@ pub fn math_result_type(
                 (Type::Int, Type::Float) => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
+                (Type::Filesize, Type::Int) => (Type::Bool, None),
+                (Type::Int, Type::Filesize) => (Type::Bool, None),
 
                 (x, y) if x == y => (Type::Bool, None),
                 (Type::Unknown, _) => (Type::Bool, None),
@@ -275,6 +333,8 @@ pub fn math_result_type(
                 (Type::Float, Type::Float) => (Type::Bool, None),
                 (Type::Duration, Type::Duration) => (Type::Bool, None),
                 (Type::Filesize, Type::Filesize) => (Type::Bool, None),
+                (Type::Filesize, Type::Int) => (Type::Bool, None),
+                (Type::Int, Type::Filesize) => (Type::Bool, None),
 
                 (Type::Unknown, _) => (Type::Bool, None),
                 (_, Type::Unknown) => (Type::Bool, None),
@@ -1,9 +1,9 @@
 use nu_parser::ParseError;
 use nu_parser::*;
 use nu_protocol::{
-    ast::{Expr, Expression, Pipeline, Statement},
+    ast::{Expr, Expression, Operator, Pipeline, Statement},
     engine::{Command, EngineState, Stack, StateWorkingSet},
-    Signature, SyntaxShape,
+    ParseWarning, Signature, Span, SyntaxShape,
 };
 
 #[cfg(test)]
@@ -94,6 +94,112 @@ pub fn parse_call() {
     }
 }
 
+#[test]
+pub fn parse_custom_completion() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let (shape, err) = parse_shape_name(&working_set, br#"string@"animals""#, Span::unknown());
+    assert!(err.is_none());
+    assert_eq!(
+        shape,
+        SyntaxShape::Custom(Box::new(SyntaxShape::String), "animals".into())
+    );
+
+    let sig = Signature::build("foo").required("animal", shape, "animal");
+    working_set.add_decl(sig.predeclare());
+
+    let (block, err) = parse(&mut working_set, None, b"foo cat", true);
+
+    assert!(err.is_none());
+    match &block[0] {
+        Statement::Pipeline(Pipeline { expressions }) => match &expressions[0].expr {
+            Expr::Call(call) => {
+                assert_eq!(call.positional[0].custom_completion, Some("animals".into()));
+            }
+            _ => panic!("not a call"),
+        },
+        _ => panic!("not a pipeline"),
+    }
+}
+
+// Writes a file that defines greet to a temporary folder, and sources it in an engine state
+// that knows source and def. The folder is returned so that it lives as long as the test.
+fn source_greet_file() -> (tempfile::TempDir, std::path::PathBuf, String, EngineState) {
+    let dir = tempfile::tempdir().expect("could not create test folder");
+    let path = dir.path().join("greet.nu");
+    std::fs::write(&path, "def greet [] { 3 }").expect("could not write test file");
+    let source = format!("source {}", path.to_string_lossy());
+
+    let mut engine_state = EngineState::new();
+    let delta = {
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        working_set.add_decl(
+            Signature::build("source")
+                .required("filename", SyntaxShape::Filepath, "file to source")
+                .predeclare(),
+        );
+        working_set.add_decl(
+            Signature::build("def")
+                .required("def_name", SyntaxShape::String, "definition name")
+                .required("params", SyntaxShape::Signature, "parameters")
+                .required("block", SyntaxShape::Block(Some(vec![])), "body")
+                .predeclare(),
+        );
+
+        let (_, err) = parse(&mut working_set, None, source.as_bytes(), false);
+        assert!(err.is_none());
+        working_set.render()
+    };
+    assert!(engine_state.merge_delta(delta).is_ok());
+
+    (dir, path, source, engine_state)
+}
+
+#[test]
+pub fn parse_source_reuses_unchanged_file() {
+    let (_dir, path, source, engine_state) = source_greet_file();
+
+    // Sourcing the file again doesn't parse it or create a new block
+    let num_blocks = engine_state.num_blocks();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+    let (_, err) = parse(&mut working_set, None, source.as_bytes(), true);
+
+    assert!(err.is_none());
+    assert_eq!(working_set.num_files(), engine_state.num_files() + 1);
+    assert_eq!(working_set.num_blocks(), num_blocks);
+    assert!(working_set.find_decl(b"greet").is_some());
+
+    // Changing the file parses it again
+    std::fs::write(&path, "def greet [] { 4 }").expect("could not write test file");
+    let mut working_set = StateWorkingSet::new(&engine_state);
+    let (_, err) = parse(&mut working_set, None, source.as_bytes(), true);
+
+    assert!(err.is_none());
+    assert_eq!(working_set.num_files(), engine_state.num_files() + 2);
+    assert!(working_set.num_blocks() > num_blocks);
+}
+
+#[test]
+pub fn parse_source_reparses_after_scope_change() {
+    let (_dir, _path, source, mut engine_state) = source_greet_file();
+
+    // A command defined since then could change what the file calls
+    let delta = {
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        working_set.add_decl(Signature::build("other").predeclare());
+        working_set.render()
+    };
+    assert!(engine_state.merge_delta(delta).is_ok());
+
+    let num_blocks = engine_state.num_blocks();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+    let (_, err) = parse(&mut working_set, None, source.as_bytes(), true);
+
+    assert!(err.is_none());
+    assert!(working_set.num_blocks() > num_blocks);
+}
+
 #[test]
 pub fn parse_call_missing_flag_arg() {
     let engine_state = EngineState::new();
@@ -156,6 +262,45 @@ pub fn parse_call_extra_positional() {
     assert!(matches!(err, Some(ParseError::ExtraPositional(..))));
 }
 
+#[test]
+pub fn parse_call_deprecated_command_warns() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let sig = Signature::build("foo").deprecated("use bar instead");
+    working_set.add_decl(sig.predeclare());
+    let (_, err) = parse(&mut working_set, None, b"foo", true);
+
+    assert!(err.is_none());
+    assert!(matches!(
+        working_set.parse_warnings.as_slice(),
+        [ParseWarning::DeprecatedCommand(..)]
+    ));
+}
+
+#[test]
+pub fn parse_call_deprecated_flag_warns() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let sig = Signature::build("foo")
+        .switch("jazz", "jazz!!", Some('j'))
+        .switch("blues", "blues!!", Some('b'))
+        .deprecated_flag("jazz", "use --blues instead");
+    working_set.add_decl(sig.predeclare());
+
+    let (_, err) = parse(&mut working_set, None, b"foo --blues", true);
+    assert!(err.is_none());
+    assert!(working_set.parse_warnings.is_empty());
+
+    let (_, err) = parse(&mut working_set, None, b"foo -j", true);
+    assert!(err.is_none());
+    assert!(matches!(
+        working_set.parse_warnings.as_slice(),
+        [ParseWarning::DeprecatedFlag(..)]
+    ));
+}
+
 #[test]
 pub fn parse_call_missing_req_positional() {
     let engine_state = EngineState::new();
@@ -178,6 +323,58 @@ pub fn parse_call_missing_req_flag() {
     assert!(matches!(err, Some(ParseError::MissingRequiredFlag(..))));
 }
 
+#[test]
+pub fn parse_and_binds_tighter_than_or() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let (block, err) = parse(&mut working_set, None, b"1 == 2 and 3 == 4 or 5 == 5", true);
+
+    assert!(err.is_none());
+    match &block[0] {
+        Statement::Pipeline(Pipeline { expressions }) => match &expressions[0].expr {
+            Expr::BinaryOp(lhs, op, _) => {
+                assert!(matches!(op.expr, Expr::Operator(Operator::Or)));
+                assert!(matches!(
+                    &lhs.expr,
+                    Expr::BinaryOp(_, op, _) if matches!(op.expr, Expr::Operator(Operator::And))
+                ));
+            }
+            _ => panic!("not a binary operation"),
+        },
+        _ => panic!("not a pipeline"),
+    }
+}
+
+#[test]
+pub fn parse_not_binds_tighter_than_and() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let (block, err) = parse(&mut working_set, None, b"not 1 == 2 and 3 == 4", true);
+
+    assert!(err.is_none());
+    match &block[0] {
+        Statement::Pipeline(Pipeline { expressions }) => match &expressions[0].expr {
+            Expr::BinaryOp(lhs, op, _) => {
+                assert!(matches!(op.expr, Expr::Operator(Operator::And)));
+                assert!(matches!(lhs.expr, Expr::UnaryNot(_)));
+            }
+            _ => panic!("not a binary operation"),
+        },
+        _ => panic!("not a pipeline"),
+    }
+}
+
+#[test]
+pub fn parse_not_needs_a_bool() {
+    let engine_state = EngineState::new();
+    let mut working_set = StateWorkingSet::new(&engine_state);
+
+    let (_, err) = parse(&mut working_set, None, b"not 5", true);
+    assert!(matches!(err, Some(ParseError::TypeMismatch(..))));
+}
+
 mod range {
     use super::*;
     use nu_protocol::ast::{RangeInclusion, RangeOperator};
@@ -176,6 +176,7 @@ pub(crate) fn deserialize_signature(reader: signature::Reader) -> Result<Signatu
         is_filter,
         creates_scope: false,
         category,
+        deprecated: None,
     })
 }
 
@@ -250,6 +251,7 @@ fn deserialize_flag(reader: flag::Reader) -> Result<Flag, ShellError> {
         required,
         desc: desc.to_string(),
         var_id: None,
+        deprecated: None,
     })
 }
 
@@ -19,6 +19,7 @@ pub enum Expr {
     Operator(Operator),
     RowCondition(BlockId),
     BinaryOp(Box<Expression>, Box<Expression>, Box<Expression>), //lhs, op, rhs
+    UnaryNot(Box<Expression>),
     Subexpression(BlockId),
     Block(BlockId),
     List(Vec<Expression>),
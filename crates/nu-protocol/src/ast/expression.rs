@@ -27,7 +27,7 @@ impl Expression {
                 match operator {
                     Operator::Pow => 100,
                     Operator::Multiply | Operator::Divide | Operator::Modulo => 95,
-                    Operator::Plus | Operator::Minus => 90,
+                    Operator::Plus | Operator::Minus | Operator::Append => 90,
                     Operator::NotContains
                     | Operator::Contains
                     | Operator::LessThan
@@ -222,6 +222,7 @@ impl Expression {
                 false
             }
 
+            Expr::UnaryNot(expr) => expr.has_in_variable(working_set),
             Expr::ValueWithUnit(expr, _) => expr.has_in_variable(working_set),
             Expr::Var(var_id) => *var_id == IN_VARIABLE_ID,
             Expr::VarDecl(_) => false,
@@ -362,6 +363,7 @@ impl Expression {
                 }
             }
 
+            Expr::UnaryNot(expr) => expr.replace_in_variable(working_set, new_var_id),
             Expr::ValueWithUnit(expr, _) => expr.replace_in_variable(working_set, new_var_id),
             Expr::Var(x) => {
                 if *x == IN_VARIABLE_ID {
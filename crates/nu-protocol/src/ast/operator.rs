@@ -23,6 +23,7 @@ pub enum Operator {
     And,
     Or,
     Pow,
+    Append,
 }
 
 impl Display for Operator {
@@ -44,6 +45,7 @@ impl Display for Operator {
             Operator::And => write!(f, "&&"),
             Operator::Or => write!(f, "||"),
             Operator::Pow => write!(f, "**"),
+            Operator::Append => write!(f, "++"),
             Operator::LessThanOrEqual => write!(f, "<="),
             Operator::GreaterThanOrEqual => write!(f, ">="),
         }
@@ -45,11 +45,106 @@ impl EnvConversion {
     }
 }
 
+/// Layout and colors of a menu shown by the line editor
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MenuConfig {
+    /// Number of rows shown at once
+    pub page_size: usize,
+    /// Maximum number of columns, fewer are used if the terminal is too narrow
+    pub columns: usize,
+    /// Spaces between columns
+    pub col_padding: usize,
+    pub text_style: String,
+    pub selected_text_style: String,
+}
+
+impl MenuConfig {
+    pub fn completion_menu() -> Self {
+        MenuConfig {
+            page_size: 10,
+            columns: 4,
+            col_padding: 2,
+            text_style: "green".into(),
+            selected_text_style: "green_reverse".into(),
+        }
+    }
+
+    pub fn history_menu() -> Self {
+        MenuConfig {
+            page_size: 10,
+            columns: 1,
+            col_padding: 2,
+            text_style: "white".into(),
+            selected_text_style: "blue_reverse".into(),
+        }
+    }
+
+    fn update_from_record(&mut self, value: &Value) -> Result<(), ShellError> {
+        let (cols, vals) = value.as_record()?;
+
+        for (key, value) in cols.iter().zip(vals) {
+            match key.as_str() {
+                "page_size" => self.page_size = value.as_integer()?.max(1) as usize,
+                "columns" => self.columns = value.as_integer()?.max(1) as usize,
+                "col_padding" => self.col_padding = value.as_integer()?.max(0) as usize,
+                "text_style" => self.text_style = value.as_string()?,
+                "selected_text_style" => self.selected_text_style = value.as_string()?,
+                _ => return Err(ShellError::UnsupportedConfigValue(
+                    "'page_size', 'columns', 'col_padding', 'text_style' or 'selected_text_style'"
+                        .into(),
+                    key.into(),
+                    value.span()?,
+                )),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A keybinding from the config, which the line editor turns into one of its own
+///
+/// The parts are kept as values so that their spans can be pointed at when they don't name a key,
+/// modifier or event the line editor knows.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ParsedKeybinding {
+    /// The edit mode the binding is for: emacs, vi_insert or vi_normal, or a list of them
+    pub mode: Value,
+    /// none, control, shift, alt, or several joined with `_`, like control_alt
+    pub modifier: Value,
+    /// The key, like char_a, enter or f1
+    pub keycode: Value,
+    /// A record like `{send: clearscreen}` or `{edit: insertchar, value: x}`, or a list of them
+    pub event: Value,
+}
+
+impl ParsedKeybinding {
+    fn from_record(value: &Value) -> Result<Self, ShellError> {
+        let (cols, vals) = value.as_record()?;
+        let span = value.span()?;
+
+        let get = |name: &str| {
+            cols.iter()
+                .position(|col| col == name)
+                .map(|idx| vals[idx].clone())
+                .ok_or_else(|| ShellError::MissingConfigValue(format!("'{}' field", name), span))
+        };
+
+        Ok(ParsedKeybinding {
+            mode: get("mode")?,
+            modifier: get("modifier")?,
+            keycode: get("keycode")?,
+            event: get("event")?,
+        })
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Config {
     pub filesize_metric: bool,
     pub table_mode: String,
     pub use_ls_colors: bool,
+    pub use_ls_links: bool,
     pub color_config: HashMap<String, String>,
     pub use_grid_icons: bool,
     pub footer_mode: FooterMode,
@@ -58,6 +153,17 @@ pub struct Config {
     pub filesize_format: String,
     pub use_ansi_coloring: bool,
     pub env_conversions: HashMap<String, EnvConversion>,
+    pub shell_integration: bool,
+    pub history_ignore_space: bool,
+    pub max_history_size: i64,
+    pub completion_menu: MenuConfig,
+    pub history_menu: MenuConfig,
+    pub int_overflow: IntOverflow,
+    pub ambiguous_width: AmbiguousWidth,
+    pub auto_cd: bool,
+    pub locale: String,
+    pub edit_mode: String,
+    pub keybindings: Vec<ParsedKeybinding>,
 }
 
 impl Default for Config {
@@ -66,6 +172,7 @@ impl Default for Config {
             filesize_metric: false,
             table_mode: "rounded".into(),
             use_ls_colors: true,
+            use_ls_links: false,
             color_config: HashMap::new(),
             use_grid_icons: false,
             footer_mode: FooterMode::Never,
@@ -74,10 +181,69 @@ impl Default for Config {
             filesize_format: "auto".into(),
             use_ansi_coloring: true,
             env_conversions: HashMap::new(), // TODO: Add default conversoins
+            shell_integration: false,
+            history_ignore_space: false,
+            max_history_size: 1000,
+            completion_menu: MenuConfig::completion_menu(),
+            history_menu: MenuConfig::history_menu(),
+            int_overflow: IntOverflow::Checked,
+            ambiguous_width: AmbiguousWidth::Narrow,
+            auto_cd: false,
+            locale: String::new(),
+            edit_mode: "emacs".into(),
+            keybindings: vec![],
         }
     }
 }
 
+/// What integer arithmetic does when a result doesn't fit in 64 bits
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum IntOverflow {
+    /// Fail with an overflow error
+    Checked,
+    /// Wrap around at the boundary of the type
+    Wrapping,
+    /// Stay at the largest or smallest value
+    Saturating,
+}
+
+impl IntOverflow {
+    /// Returns None when the operation overflows in checked mode
+    pub fn add(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            IntOverflow::Checked => lhs.checked_add(rhs),
+            IntOverflow::Wrapping => Some(lhs.wrapping_add(rhs)),
+            IntOverflow::Saturating => Some(lhs.saturating_add(rhs)),
+        }
+    }
+
+    /// Returns None when the operation overflows in checked mode
+    pub fn sub(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            IntOverflow::Checked => lhs.checked_sub(rhs),
+            IntOverflow::Wrapping => Some(lhs.wrapping_sub(rhs)),
+            IntOverflow::Saturating => Some(lhs.saturating_sub(rhs)),
+        }
+    }
+
+    /// Returns None when the operation overflows in checked mode
+    pub fn mul(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            IntOverflow::Checked => lhs.checked_mul(rhs),
+            IntOverflow::Wrapping => Some(lhs.wrapping_mul(rhs)),
+            IntOverflow::Saturating => Some(lhs.saturating_mul(rhs)),
+        }
+    }
+}
+
+/// How many columns tables and grids count for the characters of East Asian ambiguous width, like
+/// `±` or `Ω`, which CJK terminals usually draw twice as wide as others do
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum AmbiguousWidth {
+    Narrow,
+    Wide,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum FooterMode {
     /// Never show the footer
@@ -107,6 +273,9 @@ impl Value {
                 "use_ls_colors" => {
                     config.use_ls_colors = value.as_bool()?;
                 }
+                "use_ls_links" => {
+                    config.use_ls_links = value.as_bool()?;
+                }
                 "color_config" => {
                     let (cols, inner_vals) = value.as_record()?;
                     let mut hm = HashMap::new();
@@ -182,6 +351,97 @@ impl Value {
 
                     config.env_conversions = env_conversions;
                 }
+                "shell_integration" => {
+                    config.shell_integration = value.as_bool()?;
+                }
+                "history_ignore_space" => {
+                    config.history_ignore_space = value.as_bool()?;
+                }
+                "max_history_size" => {
+                    config.max_history_size = value.as_integer()?;
+                }
+                "auto_cd" => {
+                    config.auto_cd = value.as_bool()?;
+                }
+                "locale" => {
+                    config.locale = value.as_string()?;
+                }
+                "edit_mode" => {
+                    config.edit_mode = match value.as_string()?.to_lowercase().as_str() {
+                        "emacs" => "emacs".into(),
+                        "vi" => "vi".into(),
+                        other => {
+                            return Err(ShellError::UnsupportedConfigValue(
+                                "'emacs' or 'vi'".into(),
+                                other.into(),
+                                value.span()?,
+                            ))
+                        }
+                    };
+                }
+                "keybindings" => {
+                    config.keybindings = match value {
+                        Value::List { vals, .. } => vals
+                            .iter()
+                            .map(ParsedKeybinding::from_record)
+                            .collect::<Result<_, _>>()?,
+                        other => {
+                            return Err(ShellError::UnsupportedConfigValue(
+                                "a list of keybindings".into(),
+                                other.get_type().to_string(),
+                                other.span()?,
+                            ))
+                        }
+                    };
+                }
+                "int_overflow" => {
+                    config.int_overflow = match value.as_string()?.to_lowercase().as_str() {
+                        "checked" => IntOverflow::Checked,
+                        "wrapping" => IntOverflow::Wrapping,
+                        "saturating" => IntOverflow::Saturating,
+                        other => {
+                            return Err(ShellError::UnsupportedConfigValue(
+                                "'checked', 'wrapping' or 'saturating'".into(),
+                                other.into(),
+                                value.span()?,
+                            ))
+                        }
+                    };
+                }
+                "ambiguous_width" => {
+                    config.ambiguous_width = match value.as_string()?.to_lowercase().as_str() {
+                        "narrow" => AmbiguousWidth::Narrow,
+                        "wide" => AmbiguousWidth::Wide,
+                        other => {
+                            return Err(ShellError::UnsupportedConfigValue(
+                                "'narrow' or 'wide'".into(),
+                                other.into(),
+                                value.span()?,
+                            ))
+                        }
+                    };
+                }
+                "menus" => {
+                    let (menus, menu_configs) = value.as_record()?;
+
+                    for (menu, menu_config) in menus.iter().zip(menu_configs) {
+                        match menu.as_str() {
+                            "completion_menu" => {
+                                config.completion_menu.update_from_record(menu_config)?
+                            }
+                            "history_menu" => {
+                                config.history_menu.update_from_record(menu_config)?
+                            }
+                            _ => {
+                                return Err(ShellError::UnsupportedConfigValue(
+                                    "'completion_menu' or 'history_menu'".into(),
+                                    menu.into(),
+                                    menu_config.span()?,
+                                ))
+                            }
+                        }
+                    }
+                }
                 _ => {}
             }
         }
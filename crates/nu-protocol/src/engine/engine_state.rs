@@ -1,17 +1,15 @@
-use super::Command;
+use super::{Command, Jobs};
 use crate::{
-    ast::Block, BlockId, DeclId, Example, Overlay, OverlayId, ShellError, Signature, Span, Type,
-    VarId,
+    ast::Block, BlockId, DeclId, Example, Overlay, OverlayId, ParseWarning, ShellError, Signature,
+    Span, Type, VarId, Warnings,
 };
 use core::panic;
 use std::{
     collections::HashMap,
-    sync::{atomic::AtomicBool, Arc},
+    path::{Path, PathBuf},
+    sync::{atomic::AtomicBool, Arc, Mutex},
 };
 
-#[cfg(feature = "plugin")]
-use std::path::PathBuf;
-
 // Tells whether a decl etc. is visible or not
 #[derive(Debug, Clone)]
 struct Visibility {
@@ -64,6 +62,71 @@ pub struct ScopeFrame {
     visibility: Visibility,
 }
 
+/// A file that has already been parsed, kept so that it doesn't need to be parsed again as long as its
+/// contents stay the same
+#[derive(Debug, Clone)]
+pub struct ParsedFile {
+    /// Hash of the file contents the block was parsed from
+    pub hash: u64,
+    pub block_id: BlockId,
+    /// Everything the file added to the scope it was parsed in
+    pub frame: ScopeFrame,
+    /// The scope generation after the file was parsed, as the block is only valid as long as the
+    /// names it calls resolve the same way
+    pub scope_generation: u64,
+}
+
+/// The working directories of the shells opened with `enter`. While there is only the shell nushell
+/// started with, `dirs` is empty and PWD is its directory.
+#[derive(Debug, Clone, Default)]
+pub struct ShellRing {
+    pub dirs: Vec<String>,
+    /// Index into `dirs` of the active shell, whose directory is kept in PWD while it is active
+    pub current: usize,
+}
+
+/// Where a declaration came from, recorded when it's added to the working set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeclOrigin {
+    Builtin,
+    /// Registered from the plugin at the given path
+    Plugin(PathBuf),
+    /// Defined with `def` in the given file, inside the given module if there was one
+    Custom {
+        file: String,
+        module: Option<String>,
+    },
+}
+
+impl DeclOrigin {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DeclOrigin::Builtin => "builtin",
+            DeclOrigin::Plugin(_) => "plugin",
+            DeclOrigin::Custom { .. } => "custom",
+        }
+    }
+
+    /// The file the declaration was defined in, or the plugin it was registered from
+    pub fn location(&self) -> Option<String> {
+        match self {
+            DeclOrigin::Builtin => None,
+            DeclOrigin::Plugin(path) => Some(path.to_string_lossy().to_string()),
+            DeclOrigin::Custom { file, .. } => Some(file.clone()),
+        }
+    }
+
+    pub fn module(&self) -> Option<&str> {
+        match self {
+            DeclOrigin::Custom {
+                module: Some(module),
+                ..
+            } => Some(module),
+            _ => None,
+        }
+    }
+}
+
 impl ScopeFrame {
     pub fn new() -> Self {
         Self {
@@ -80,6 +143,26 @@ impl ScopeFrame {
     pub fn get_var(&self, var_name: &[u8]) -> Option<&VarId> {
         self.vars.get(var_name)
     }
+
+    // Adds the names of the commands and aliases starting with `prefix` that can be called
+    fn push_commands_by_prefix(
+        &self,
+        visibility: &Visibility,
+        prefix: &[u8],
+        output: &mut Vec<Vec<u8>>,
+    ) {
+        for (name, decl_id) in &self.decls {
+            if name.starts_with(prefix) && visibility.is_decl_id_visible(decl_id) {
+                output.push(name.clone());
+            }
+        }
+
+        for name in self.aliases.keys() {
+            if name.starts_with(prefix) {
+                output.push(name.clone());
+            }
+        }
+    }
 }
 
 impl Default for ScopeFrame {
@@ -136,10 +219,23 @@ pub struct EngineState {
     file_contents: im::Vector<(Vec<u8>, usize, usize)>,
     vars: im::Vector<Type>,
     decls: im::Vector<Box<dyn Command + 'static>>,
+    decl_origins: im::Vector<DeclOrigin>,
     blocks: im::Vector<Block>,
     overlays: im::Vector<Overlay>,
     pub scope: im::Vector<ScopeFrame>,
+    parsed_files: im::HashMap<PathBuf, ParsedFile>,
+    scope_generation: u64,
     pub ctrlc: Option<Arc<AtomicBool>>,
+    pub history_session_id: i64,
+    pub repl_buffer_state: Arc<Mutex<String>>,
+    pub repl_cursor_pos: Arc<Mutex<usize>>,
+    pub warnings: Warnings,
+    pub jobs: Jobs,
+    /// The directories saved by `pushd`, the most recent first
+    pub dir_stack: Arc<Mutex<Vec<String>>>,
+    pub shells: Arc<Mutex<ShellRing>>,
+    /// The exit code of the last external command to finish, None if none has run yet
+    pub last_exit_code: Arc<Mutex<Option<i64>>>,
     #[cfg(feature = "plugin")]
     pub plugin_signatures: Option<PathBuf>,
 }
@@ -156,10 +252,21 @@ impl EngineState {
             file_contents: im::vector![],
             vars: im::vector![Type::Unknown, Type::Unknown, Type::Unknown, Type::Unknown],
             decls: im::vector![],
+            decl_origins: im::vector![],
             blocks: im::vector![],
             overlays: im::vector![],
             scope: im::vector![ScopeFrame::new()],
+            parsed_files: im::HashMap::new(),
+            scope_generation: 0,
             ctrlc: None,
+            history_session_id: 0,
+            repl_buffer_state: Arc::new(Mutex::new(String::new())),
+            repl_cursor_pos: Arc::new(Mutex::new(0)),
+            warnings: Warnings::default(),
+            jobs: Jobs::default(),
+            dir_stack: Arc::new(Mutex::new(vec![])),
+            shells: Arc::new(Mutex::new(ShellRing::default())),
+            last_exit_code: Arc::new(Mutex::new(None)),
             #[cfg(feature = "plugin")]
             plugin_signatures: None,
         }
@@ -177,9 +284,12 @@ impl EngineState {
         self.files.extend(delta.files);
         self.file_contents.extend(delta.file_contents);
         self.decls.extend(delta.decls);
+        self.decl_origins.extend(delta.decl_origins);
         self.vars.extend(delta.vars);
         self.blocks.extend(delta.blocks);
         self.overlays.extend(delta.overlays);
+        self.parsed_files.extend(delta.parsed_files);
+        self.scope_generation += delta.scope_changes;
 
         if let Some(last) = self.scope.back_mut() {
             let first = delta.scope.remove(0);
@@ -308,6 +418,62 @@ impl EngineState {
         None
     }
 
+    /// Get every declaration that goes by `name`, newest first, together with whether it's visible
+    ///
+    /// Unlike `find_decl`, this includes the declarations that are shadowed by a newer one of the same
+    /// name, as well as the ones defined with that name but only reachable under another one, like the
+    /// commands of a module.
+    pub fn find_decls_all(&self, name: &[u8]) -> Vec<(DeclId, bool)> {
+        let mut visibility: Visibility = Visibility::new();
+        let mut output: Vec<(DeclId, bool)> = vec![];
+
+        for scope in self.scope.iter().rev() {
+            visibility.append(&scope.visibility);
+
+            if let Some(decl_id) = scope.decls.get(name) {
+                if !output.iter().any(|(id, _)| id == decl_id) {
+                    output.push((*decl_id, visibility.is_decl_id_visible(decl_id)));
+                }
+            }
+        }
+
+        for (decl_id, decl) in self.decls.iter().enumerate().rev() {
+            if decl.name().as_bytes() == name && !output.iter().any(|(id, _)| *id == decl_id) {
+                output.push((decl_id, visibility.is_decl_id_visible(&decl_id)));
+            }
+        }
+
+        output
+    }
+
+    pub fn get_decl_origin(&self, decl_id: DeclId) -> &DeclOrigin {
+        self.decl_origins
+            .get(decl_id)
+            .expect("internal error: missing declaration origin")
+    }
+
+    /// Get the names of the overlays in scope that export the declaration, sorted by name
+    pub fn find_overlays_with_decl(&self, decl_id: DeclId) -> Vec<String> {
+        let mut output = vec![];
+
+        for scope in &self.scope {
+            for (name, overlay_id) in &scope.overlays {
+                if self
+                    .get_overlay(*overlay_id)
+                    .decls
+                    .values()
+                    .any(|id| *id == decl_id)
+                {
+                    output.push(String::from_utf8_lossy(name).to_string());
+                }
+            }
+        }
+
+        output.sort();
+        output.dedup();
+        output
+    }
+
     #[cfg(feature = "plugin")]
     pub fn plugin_decls(&self) -> impl Iterator<Item = &Box<dyn Command + 'static>> {
         let mut unique_plugin_decls = HashMap::new();
@@ -335,17 +501,32 @@ impl EngineState {
         None
     }
 
+    pub fn find_alias(&self, name: &[u8]) -> Option<&[Span]> {
+        for scope in self.scope.iter().rev() {
+            if let Some(spans) = scope.aliases.get(name) {
+                return Some(spans);
+            }
+        }
+
+        None
+    }
+
+    /// Get the names of the commands and aliases in scope that start with `name`, sorted
+    ///
+    /// Hidden commands are left out, and so are the ones only reachable under another name, like
+    /// the commands of a module that hasn't been imported.
     pub fn find_commands_by_prefix(&self, name: &[u8]) -> Vec<Vec<u8>> {
+        let mut visibility: Visibility = Visibility::new();
         let mut output = vec![];
 
         for scope in self.scope.iter().rev() {
-            for decl in &scope.decls {
-                if decl.0.starts_with(name) {
-                    output.push(decl.0.clone());
-                }
-            }
+            visibility.append(&scope.visibility);
+            scope.push_commands_by_prefix(&visibility, name, &mut output);
         }
 
+        output.sort();
+        output.dedup();
+
         output
     }
 
@@ -517,6 +698,10 @@ impl Default for EngineState {
 pub struct StateWorkingSet<'a> {
     pub permanent_state: &'a EngineState,
     pub delta: StateDelta,
+    /// Warnings found while parsing, which unlike parse errors don't stop the code from running
+    pub parse_warnings: Vec<ParseWarning>,
+    /// The module whose block is being parsed, recorded as the origin of the commands defined in it
+    pub current_module: Option<String>,
 }
 
 /// A delta (or change set) between the current global state and a possible future global state. Deltas
@@ -525,11 +710,14 @@ pub struct StateWorkingSet<'a> {
 pub struct StateDelta {
     files: Vec<(String, usize, usize)>,
     pub(crate) file_contents: Vec<(Vec<u8>, usize, usize)>,
-    vars: Vec<Type>,              // indexed by VarId
-    decls: Vec<Box<dyn Command>>, // indexed by DeclId
-    blocks: Vec<Block>,           // indexed by BlockId
-    overlays: Vec<Overlay>,       // indexed by OverlayId
+    vars: Vec<Type>,               // indexed by VarId
+    decls: Vec<Box<dyn Command>>,  // indexed by DeclId
+    decl_origins: Vec<DeclOrigin>, // indexed by DeclId
+    blocks: Vec<Block>,            // indexed by BlockId
+    overlays: Vec<Overlay>,        // indexed by OverlayId
     pub scope: Vec<ScopeFrame>,
+    parsed_files: HashMap<PathBuf, ParsedFile>,
+    scope_changes: u64, // counts the changes to what command and alias names resolve to
     #[cfg(feature = "plugin")]
     plugins_changed: bool, // marks whether plugin file should be updated
 }
@@ -568,13 +756,18 @@ impl<'a> StateWorkingSet<'a> {
                 file_contents: vec![],
                 vars: vec![],
                 decls: vec![],
+                decl_origins: vec![],
                 blocks: vec![],
                 overlays: vec![],
                 scope: vec![ScopeFrame::new()],
+                parsed_files: HashMap::new(),
+                scope_changes: 0,
                 #[cfg(feature = "plugin")]
                 plugins_changed: false,
             },
             permanent_state,
+            parse_warnings: vec![],
+            current_module: None,
         }
     }
 
@@ -596,8 +789,14 @@ impl<'a> StateWorkingSet<'a> {
 
     pub fn add_decl(&mut self, decl: Box<dyn Command>) -> DeclId {
         let name = decl.name().as_bytes().to_vec();
+        let origin = match decl.is_plugin() {
+            Some((path, _)) => DeclOrigin::Plugin(path.clone()),
+            None => DeclOrigin::Builtin,
+        };
 
+        self.delta.scope_changes += 1;
         self.delta.decls.push(decl);
+        self.delta.decl_origins.push(origin);
         let decl_id = self.num_decls() - 1;
 
         let scope_frame = self
@@ -613,6 +812,7 @@ impl<'a> StateWorkingSet<'a> {
     }
 
     pub fn use_decls(&mut self, decls: Vec<(Vec<u8>, DeclId)>) {
+        self.delta.scope_changes += 1;
         let scope_frame = self
             .delta
             .scope
@@ -625,10 +825,22 @@ impl<'a> StateWorkingSet<'a> {
         }
     }
 
-    pub fn add_predecl(&mut self, decl: Box<dyn Command>) -> Option<DeclId> {
+    /// Add the declaration of a custom command, whose name is at `name_span`, without bringing it into
+    /// scope yet
+    pub fn add_predecl(&mut self, decl: Box<dyn Command>, name_span: Span) -> Option<DeclId> {
         let name = decl.name().as_bytes().to_vec();
+        let file = self
+            .files()
+            .find(|(_, start, end)| name_span.start >= *start && name_span.end <= *end)
+            .map(|(filename, ..)| filename.clone())
+            .unwrap_or_else(|| "<unknown>".into());
+        let origin = DeclOrigin::Custom {
+            file,
+            module: self.current_module.clone(),
+        };
 
         self.delta.decls.push(decl);
+        self.delta.decl_origins.push(origin);
         let decl_id = self.num_decls() - 1;
 
         let scope_frame = self
@@ -646,6 +858,7 @@ impl<'a> StateWorkingSet<'a> {
     }
 
     pub fn merge_predecl(&mut self, name: &[u8]) -> Option<DeclId> {
+        self.delta.scope_changes += 1;
         let scope_frame = self
             .delta
             .scope
@@ -663,6 +876,7 @@ impl<'a> StateWorkingSet<'a> {
     }
 
     pub fn hide_decl(&mut self, name: &[u8]) -> Option<DeclId> {
+        self.delta.scope_changes += 1;
         let mut visibility: Visibility = Visibility::new();
 
         // Since we can mutate scope frames in delta, remove the id directly
@@ -823,6 +1037,49 @@ impl<'a> StateWorkingSet<'a> {
         self.delta.exit_scope();
     }
 
+    /// Leave the current scope, handing back everything that was defined in it
+    pub fn take_scope(&mut self) -> ScopeFrame {
+        self.delta.scope.pop().unwrap_or_default()
+    }
+
+    /// Add everything defined in `frame` to the current scope
+    pub fn merge_scope(&mut self, frame: ScopeFrame) {
+        let last = self
+            .delta
+            .scope
+            .last_mut()
+            .expect("internal error: missing required scope frame");
+
+        last.vars.extend(frame.vars);
+        last.predecls.extend(frame.predecls);
+        last.decls.extend(frame.decls);
+        last.aliases.extend(frame.aliases);
+        last.env_vars.extend(frame.env_vars);
+        last.overlays.extend(frame.overlays);
+        last.visibility.merge_with(frame.visibility);
+    }
+
+    /// Goes up whenever a command or alias is defined, brought into scope or hidden
+    pub fn scope_generation(&self) -> u64 {
+        self.permanent_state.scope_generation + self.delta.scope_changes
+    }
+
+    /// Look up an earlier parse of the file at `path`, if it was parsed from contents with the same hash
+    /// and no command or alias has changed since
+    pub fn find_parsed_file(&self, path: &Path, hash: u64) -> Option<&ParsedFile> {
+        let scope_generation = self.scope_generation();
+
+        self.delta
+            .parsed_files
+            .get(path)
+            .or_else(|| self.permanent_state.parsed_files.get(path))
+            .filter(|parsed| parsed.hash == hash && parsed.scope_generation == scope_generation)
+    }
+
+    pub fn add_parsed_file(&mut self, path: PathBuf, parsed: ParsedFile) {
+        self.delta.parsed_files.insert(path, parsed);
+    }
+
     pub fn find_decl(&self, name: &[u8]) -> Option<DeclId> {
         let mut visibility: Visibility = Visibility::new();
 
@@ -951,6 +1208,7 @@ impl<'a> StateWorkingSet<'a> {
     }
 
     pub fn add_alias(&mut self, name: Vec<u8>, replacement: Vec<Span>) {
+        self.delta.scope_changes += 1;
         let last = self
             .delta
             .scope
@@ -1007,19 +1265,22 @@ impl<'a> StateWorkingSet<'a> {
     }
 
     pub fn find_commands_by_prefix(&self, name: &[u8]) -> Vec<Vec<u8>> {
+        let mut visibility: Visibility = Visibility::new();
         let mut output = vec![];
 
-        for scope in self.delta.scope.iter().rev() {
-            for decl in &scope.decls {
-                if decl.0.starts_with(name) {
-                    output.push(decl.0.clone());
-                }
-            }
+        for scope in self
+            .delta
+            .scope
+            .iter()
+            .rev()
+            .chain(self.permanent_state.scope.iter().rev())
+        {
+            visibility.append(&scope.visibility);
+            scope.push_commands_by_prefix(&visibility, name, &mut output);
         }
 
-        let mut permanent = self.permanent_state.find_commands_by_prefix(name);
-
-        output.append(&mut permanent);
+        output.sort();
+        output.dedup();
 
         output
     }
@@ -1189,4 +1450,35 @@ mod engine_state_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn find_commands_by_prefix_leaves_out_hidden() -> Result<(), ShellError> {
+        let mut engine_state = EngineState::new();
+
+        let delta = {
+            let mut working_set = StateWorkingSet::new(&engine_state);
+            working_set.add_decl(Signature::build("spam eggs").predeclare());
+            working_set.add_decl(Signature::build("spam").predeclare());
+            working_set.add_decl(Signature::build("spoon").predeclare());
+            working_set.add_decl(Signature::build("bacon").predeclare());
+            working_set.render()
+        };
+
+        engine_state.merge_delta(delta)?;
+
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        working_set.add_decl(Signature::build("spam").predeclare());
+        working_set.hide_decl(b"spoon");
+
+        assert_eq!(
+            working_set.find_commands_by_prefix(b"sp"),
+            vec![b"spam".to_vec(), b"spam eggs".to_vec()]
+        );
+        assert_eq!(
+            engine_state.find_commands_by_prefix(b"sp"),
+            vec![b"spam".to_vec(), b"spam eggs".to_vec(), b"spoon".to_vec()]
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+use crate::Value;
+
+/// The blocks running in the background, started with `job spawn`
+///
+/// Each job runs on its own thread with its own interrupt flag, which takes the place of
+/// ctrl-c for the job, so killing one job doesn't stop the others. Clones share the same table.
+#[derive(Debug, Clone, Default)]
+pub struct Jobs {
+    inner: Arc<Mutex<JobTable>>,
+}
+
+#[derive(Debug, Default)]
+struct JobTable {
+    last_id: usize,
+    jobs: BTreeMap<usize, Job>,
+}
+
+#[derive(Debug)]
+struct Job {
+    description: String,
+    state: JobState,
+    interrupt: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[derive(Debug, Clone)]
+pub enum JobState {
+    Running,
+    /// The output of the job, which is an error value if it failed
+    Finished(Value),
+    Killed,
+}
+
+/// A job as shown by `job list`
+#[derive(Debug, Clone)]
+pub struct JobInfo {
+    pub id: usize,
+    pub description: String,
+    pub state: JobState,
+}
+
+impl Jobs {
+    /// Runs `job` on a new thread, handing it the interrupt flag of the job, and returns the
+    /// id of the job
+    pub fn spawn<F>(&self, description: String, job: F) -> usize
+    where
+        F: FnOnce(Arc<AtomicBool>) -> Value + Send + 'static,
+    {
+        let mut table = self.lock();
+        table.last_id += 1;
+        let id = table.last_id;
+
+        let interrupt = Arc::new(AtomicBool::new(false));
+        let jobs = self.clone();
+        let job_interrupt = interrupt.clone();
+
+        // The table stays locked until the job is in it, so a job that finishes right away
+        // still finds itself there
+        let handle = thread::spawn(move || {
+            let output = job(job_interrupt);
+
+            if let Some(job) = jobs.lock().jobs.get_mut(&id) {
+                if let JobState::Running = job.state {
+                    job.state = JobState::Finished(output);
+                }
+            }
+        });
+
+        table.jobs.insert(
+            id,
+            Job {
+                description,
+                state: JobState::Running,
+                interrupt,
+                handle: Some(handle),
+            },
+        );
+
+        id
+    }
+
+    pub fn list(&self) -> Vec<JobInfo> {
+        self.lock()
+            .jobs
+            .iter()
+            .map(|(id, job)| JobInfo {
+                id: *id,
+                description: job.description.clone(),
+                state: job.state.clone(),
+            })
+            .collect()
+    }
+
+    /// Interrupts a job, returning false if there is no job with that id
+    ///
+    /// A job only stops at the points where it checks for ctrl-c, but its output is dropped
+    /// either way.
+    pub fn kill(&self, id: usize) -> bool {
+        match self.lock().jobs.get_mut(&id) {
+            Some(job) => {
+                job.interrupt.store(true, Ordering::SeqCst);
+                if let JobState::Running = job.state {
+                    job.state = JobState::Killed;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Blocks until a job has finished, then removes it from the table and returns how it
+    /// ended, or None if there is no job with that id
+    pub fn wait(&self, id: usize) -> Option<JobState> {
+        let handle = self.lock().jobs.get_mut(&id)?.handle.take();
+
+        // A killed job may never finish, so it isn't waited for
+        if let Some(handle) = handle {
+            if !matches!(self.lock().jobs.get(&id)?.state, JobState::Killed) {
+                let _ = handle.join();
+            }
+        }
+
+        self.lock().jobs.remove(&id).map(|job| job.state)
+    }
+
+    fn lock(&self) -> MutexGuard<JobTable> {
+        // A job that panicked while holding the lock leaves the table itself intact
+        match self.inner.lock() {
+            Ok(table) => table,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
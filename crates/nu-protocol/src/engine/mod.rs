@@ -1,9 +1,11 @@
 mod call_info;
 mod command;
 mod engine_state;
+mod jobs;
 mod stack;
 
 pub use call_info::*;
 pub use command::*;
 pub use engine_state::*;
+pub use jobs::*;
 pub use stack::*;
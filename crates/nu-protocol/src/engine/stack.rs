@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use crate::{Config, ShellError, Value, VarId, CONFIG_VARIABLE_ID};
 
@@ -25,6 +26,16 @@ pub struct Stack {
     pub vars: HashMap<VarId, Value>,
     /// Environment variables arranged as a stack to be able to recover values from parent scopes
     pub env_vars: Vec<HashMap<String, Value>>,
+    /// Where externals leave their stderr and exit code when they run under `complete`
+    pub external_capture: Option<Arc<Mutex<ExternalCapture>>>,
+}
+
+/// The parts of running an external that don't go down the pipeline
+#[derive(Debug, Default)]
+pub struct ExternalCapture {
+    pub stderr: Vec<u8>,
+    /// The exit code of the external that finished last, if it exited rather than being killed
+    pub exit_code: Option<i64>,
 }
 
 impl Default for Stack {
@@ -38,6 +49,7 @@ impl Stack {
         Stack {
             vars: HashMap::new(),
             env_vars: vec![],
+            external_capture: None,
         }
     }
 
@@ -75,6 +87,7 @@ impl Stack {
         // FIXME: this is probably slow
         output.env_vars = self.env_vars.clone();
         output.env_vars.push(HashMap::new());
+        output.external_capture = self.external_capture.clone();
 
         let config = self
             .get_var(CONFIG_VARIABLE_ID)
@@ -4,7 +4,9 @@ pub mod engine;
 mod example;
 mod exportable;
 mod id;
+mod locale;
 mod overlay;
+mod parse_warning;
 mod pipeline_data;
 mod shell_error;
 mod signature;
@@ -19,7 +21,9 @@ pub use engine::{CONFIG_VARIABLE_ID, IN_VARIABLE_ID, NU_VARIABLE_ID, SCOPE_VARIA
 pub use example::*;
 pub use exportable::*;
 pub use id::*;
+pub use locale::*;
 pub use overlay::*;
+pub use parse_warning::*;
 pub use pipeline_data::*;
 pub use shell_error::*;
 pub use signature::*;
@@ -0,0 +1,101 @@
+use num_format::{Grouping, Locale};
+
+use crate::Config;
+
+/// The name of the locale to format numbers and dates for, from `$config.locale`, or else the
+/// LC_ALL environment variable. None when neither is set, or the locale is C or POSIX, which keep
+/// the plain formatting.
+pub fn get_config_locale(config: &Config) -> Option<String> {
+    let name = if config.locale.is_empty() {
+        std::env::var("LC_ALL").ok()?
+    } else {
+        config.locale.clone()
+    };
+
+    // Names can carry an encoding and a modifier, like de_DE.UTF-8@euro
+    let name = name.split(|c| c == '.' || c == '@').next().unwrap_or("");
+
+    match name {
+        "" | "C" | "POSIX" => None,
+        name => Some(name.to_string()),
+    }
+}
+
+/// Looks up the number locale for a name like `de_DE` or `de-DE`, trying the language alone when
+/// there is no exact match, and falling back to English
+pub fn number_locale(name: &str) -> Locale {
+    // Locale::from_name() wants de-DE or only de, where the system gives de_DE
+    let name = name.replace("_", "-");
+
+    match Locale::from_name(&name) {
+        Ok(locale) => locale,
+        Err(_) => {
+            let language = name.split('-').next().unwrap_or_default();
+            Locale::from_name(language).unwrap_or(Locale::en)
+        }
+    }
+}
+
+/// Groups the digits of a number written the way nushell writes them, like `-1234567.25`, and
+/// uses the decimal point of the locale. Anything else, like `inf`, is returned unchanged.
+pub fn localize_number(number: &str, locale: &Locale) -> String {
+    let (sign, digits) = match number.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", number),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits, None),
+    };
+
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(integer) || !fraction.map_or(true, is_digits) {
+        return number.to_string();
+    }
+
+    let mut output = sign.to_string();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && starts_group(integer.len() - i, locale.grouping()) {
+            output.push_str(locale.separator());
+        }
+        output.push(digit);
+    }
+
+    if let Some(fraction) = fraction {
+        output.push_str(locale.decimal());
+        output.push_str(fraction);
+    }
+
+    output
+}
+
+// Whether a separator goes in front of the digit that has `remaining` digits from it to the end
+fn starts_group(remaining: usize, grouping: Grouping) -> bool {
+    match grouping {
+        Grouping::Standard => remaining % 3 == 0,
+        // The last three digits, then groups of two: 12,34,567
+        Grouping::Indian => remaining == 3 || (remaining > 3 && (remaining - 3) % 2 == 0),
+        Grouping::Posix => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn groups_digits_for_locale() {
+        assert_eq!(localize_number("1234567", &Locale::en), "1,234,567");
+        assert_eq!(localize_number("-1234.5", &Locale::de), "-1.234,5");
+        assert_eq!(localize_number("123", &Locale::de), "123");
+        assert_eq!(localize_number("1234567", &Locale::en_IN), "12,34,567");
+        assert_eq!(localize_number("inf", &Locale::en), "inf");
+    }
+
+    #[test]
+    fn finds_locale_by_language() {
+        assert_eq!(number_locale("de_DE"), Locale::de);
+        assert_eq!(number_locale("fr-FR"), Locale::fr);
+        assert_eq!(number_locale("xx_XX"), Locale::en);
+    }
+}
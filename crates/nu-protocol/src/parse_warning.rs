@@ -0,0 +1,28 @@
+use miette::Diagnostic;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Span;
+
+/// Problems the parser found that don't stop the code from running, like uses of deprecated
+/// commands. They are collected in the working set instead of failing the parse.
+#[derive(Debug, Clone, Error, Diagnostic, Serialize, Deserialize)]
+pub enum ParseWarning {
+    #[error("Deprecated command {0}")]
+    #[diagnostic(
+        code(nu::parser::deprecated_command),
+        url(docsrs),
+        severity(Warning),
+        help("{1}")
+    )]
+    DeprecatedCommand(String, String, #[label("deprecated")] Span),
+
+    #[error("Deprecated flag --{0}")]
+    #[diagnostic(
+        code(nu::parser::deprecated_flag),
+        url(docsrs),
+        severity(Warning),
+        help("{1}")
+    )]
+    DeprecatedFlag(String, String, #[label("deprecated")] Span),
+}
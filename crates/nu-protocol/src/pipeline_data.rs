@@ -1,4 +1,4 @@
-use std::sync::{atomic::AtomicBool, Arc};
+use std::sync::{atomic::AtomicBool, Arc, Mutex};
 
 use crate::{ast::PathMember, Config, ShellError, Span, Value, ValueStream};
 
@@ -37,14 +37,47 @@ pub enum PipelineData {
     Stream(ValueStream, Option<PipelineMetadata>),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PipelineMetadata {
     pub data_source: DataSource,
+    /// The media type of the data, like "text/csv", when it's text or binary in a known format
+    pub content_type: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DataSource {
-    Ls,
+    /// Data that doesn't come from a source the viewers treat specially
+    None,
+    /// Output of `ls`, telling the viewers how to render the file names
+    Ls { colors: bool, icons: bool },
+    /// Contents of a file read by `open`, with the format it was read as
+    Open { format: String },
+}
+
+/// Problems that a command chose to skip over instead of failing the pipeline
+///
+/// Commands like `ls --ignore-errors` push to this while their output streams, and the
+/// warnings are reported once the pipeline has finished. The shell gives every evaluation a
+/// new list, so they are reported with the output they came from. Clones share the same list.
+#[derive(Debug, Clone, Default)]
+pub struct Warnings {
+    inner: Arc<Mutex<Vec<ShellError>>>,
+}
+
+impl Warnings {
+    pub fn push(&self, warning: ShellError) {
+        if let Ok(mut warnings) = self.inner.lock() {
+            warnings.push(warning);
+        }
+    }
+
+    /// Remove and return the warnings collected so far
+    pub fn take(&self) -> Vec<ShellError> {
+        match self.inner.lock() {
+            Ok(mut warnings) => std::mem::take(&mut *warnings),
+            Err(_) => vec![],
+        }
+    }
 }
 
 impl PipelineData {
@@ -52,6 +85,22 @@ impl PipelineData {
         PipelineData::Value(Value::Nothing { span }, None)
     }
 
+    pub fn metadata(&self) -> Option<&PipelineMetadata> {
+        match self {
+            PipelineData::Value(_, metadata) | PipelineData::Stream(_, metadata) => {
+                metadata.as_ref()
+            }
+        }
+    }
+
+    /// Replace the metadata that travels with the data
+    pub fn set_metadata(self, metadata: Option<PipelineMetadata>) -> PipelineData {
+        match self {
+            PipelineData::Value(v, ..) => PipelineData::Value(v, metadata),
+            PipelineData::Stream(s, ..) => PipelineData::Stream(s, metadata),
+        }
+    }
+
     pub fn into_value(self, span: Span) -> Value {
         match self {
             PipelineData::Value(v, ..) => v,
@@ -94,7 +143,7 @@ impl PipelineData {
     pub fn update_cell_path(
         &mut self,
         cell_path: &[PathMember],
-        callback: Box<dyn FnOnce(&Value) -> Value>,
+        callback: &dyn Fn(&Value) -> Value,
     ) -> Result<(), ShellError> {
         match self {
             // FIXME: there are probably better ways of doing this
@@ -126,7 +175,7 @@ impl PipelineData {
                 Ok(val.into_range_iter()?.map(f).into_pipeline_data(ctrlc))
             }
             PipelineData::Value(v, ..) => match f(v) {
-                Value::Error { error } => Err(error),
+                Value::Error { error } => Err(*error),
                 v => Ok(v.into_pipeline_data()),
             },
         }
@@ -222,7 +271,9 @@ impl IntoIterator for PipelineData {
                     )),
                     Err(error) => PipelineIterator(PipelineData::Stream(
                         ValueStream {
-                            stream: Box::new(std::iter::once(Value::Error { error })),
+                            stream: Box::new(std::iter::once(Value::Error {
+                                error: Box::new(error),
+                            })),
                             ctrlc: None,
                         },
                         metadata,
@@ -152,6 +152,20 @@ pub enum ShellError {
     #[diagnostic(code(nu::shell::unsupported_input), url(docsrs))]
     UnsupportedInput(String, #[label("{0}")] Span),
 
+    #[error("Network failure")]
+    #[diagnostic(code(nu::shell::network_failure), url(docsrs))]
+    NetworkFailure(String, #[label("{0}")] Span),
+
+    #[error("Interrupted")]
+    #[diagnostic(code(nu::shell::interrupted), url(docsrs))]
+    Interrupted(#[label("interrupted by ctrl-c")] Span),
+
+    /// A deprecated form of a command that can only be told apart while it runs, reported as a
+    /// warning rather than returned
+    #[error("Deprecated use of {0}")]
+    #[diagnostic(code(nu::shell::deprecated), url(docsrs), help("{1}"))]
+    Deprecated(String, String, #[label("deprecated")] Span),
+
     #[error("Command not found")]
     #[diagnostic(code(nu::shell::command_not_found), url(docsrs))]
     CommandNotFound(#[label("command not found")] Span),
@@ -19,6 +19,8 @@ pub struct Flag {
     pub desc: String,
     // For custom commands
     pub var_id: Option<VarId>,
+    /// What to use instead, when the flag is deprecated
+    pub deprecated: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -35,6 +37,7 @@ pub enum Category {
     Default,
     Conversions,
     Core,
+    Database,
     Date,
     Env,
     Experimental,
@@ -60,6 +63,7 @@ impl std::fmt::Display for Category {
             Category::Default => "default",
             Category::Conversions => "conversions",
             Category::Core => "core",
+            Category::Database => "database",
             Category::Date => "date",
             Category::Env => "env",
             Category::Experimental => "experimental",
@@ -96,6 +100,8 @@ pub struct Signature {
     pub creates_scope: bool,
     // Signature category used to classify commands stored in the list of declarations
     pub category: Category,
+    /// What to use instead, when the command is deprecated
+    pub deprecated: Option<String>,
 }
 
 impl PartialEq for Signature {
@@ -121,6 +127,7 @@ impl Signature {
             desc: "Display this help message".into(),
             required: false,
             var_id: None,
+            deprecated: None,
         };
 
         Signature {
@@ -134,6 +141,7 @@ impl Signature {
             is_filter: false,
             creates_scope: false,
             category: Category::Default,
+            deprecated: None,
         }
     }
     pub fn build(name: impl Into<String>) -> Signature {
@@ -213,6 +221,7 @@ impl Signature {
             required: false,
             desc: desc.into(),
             var_id: None,
+            deprecated: None,
         });
 
         self
@@ -235,6 +244,7 @@ impl Signature {
             required: true,
             desc: desc.into(),
             var_id: None,
+            deprecated: None,
         });
 
         self
@@ -256,6 +266,7 @@ impl Signature {
             required: false,
             desc: desc.into(),
             var_id: None,
+            deprecated: None,
         });
 
         self
@@ -268,6 +279,25 @@ impl Signature {
         self
     }
 
+    /// Marks the command as deprecated, with a hint about what to use instead. The parser warns
+    /// about each use of it.
+    pub fn deprecated(mut self, replacement: impl Into<String>) -> Signature {
+        self.deprecated = Some(replacement.into());
+        self
+    }
+
+    /// Marks an already added flag as deprecated, with a hint about what to use instead
+    pub fn deprecated_flag(mut self, name: &str, replacement: impl Into<String>) -> Signature {
+        let flag = self.named.iter_mut().find(|flag| flag.long == name);
+        debug_assert!(flag.is_some(), "Deprecating a flag that doesn't exist");
+
+        if let Some(flag) = flag {
+            flag.deprecated = Some(replacement.into());
+        }
+
+        self
+    }
+
     /// Sets that signature will create a scope as it parses
     pub fn creates_scope(mut self) -> Signature {
         self.creates_scope = true;
@@ -10,7 +10,7 @@ use chrono::{DateTime, FixedOffset};
 use chrono_humanize::HumanTime;
 pub use from_value::FromValue;
 use indexmap::map::IndexMap;
-use num_format::{Locale, ToFormattedString};
+use num_format::ToFormattedString;
 pub use range::*;
 use serde::{Deserialize, Serialize};
 pub use stream::*;
@@ -18,10 +18,12 @@ use sys_locale::get_locale;
 pub use unit::*;
 
 use std::collections::HashMap;
-use std::{cmp::Ordering, fmt::Debug};
+use std::{cmp::Ordering, convert::TryFrom, fmt::Debug};
 
 use crate::ast::{CellPath, PathMember};
-use crate::{did_you_mean, span, BlockId, Config, Span, Spanned, Type};
+use crate::{
+    did_you_mean, get_config_locale, number_locale, span, BlockId, Config, Span, Spanned, Type,
+};
 
 use crate::ast::Operator;
 pub use custom_value::CustomValue;
@@ -80,7 +82,8 @@ pub enum Value {
         span: Span,
     },
     Error {
-        error: ShellError,
+        // Boxed because errors are much larger than any other value
+        error: Box<ShellError>,
     },
     Binary {
         val: Vec<u8>,
@@ -231,7 +234,7 @@ impl Value {
     /// Get the span for the current value
     pub fn span(&self) -> Result<Span, ShellError> {
         match self {
-            Value::Error { error } => Err(error.clone()),
+            Value::Error { error } => Err(*error.clone()),
             Value::Bool { span, .. } => Ok(*span),
             Value::Int { span, .. } => Ok(*span),
             Value::Float { span, .. } => Ok(*span),
@@ -571,19 +574,65 @@ impl Value {
         Ok(current)
     }
 
-    /// Follow a given column path into the value: for example accessing nth elements in a stream or list
+    /// Replace the data at the cell path with the result of the callback, walking the value in
+    /// place. When the path crosses a list, the rest of the path is updated in every row.
     pub fn update_cell_path(
         &mut self,
         cell_path: &[PathMember],
-        callback: Box<dyn FnOnce(&Value) -> Value>,
+        callback: &dyn Fn(&Value) -> Value,
     ) -> Result<(), ShellError> {
-        let orig = self.clone();
+        let (member, rest) = match cell_path.split_first() {
+            Some(split) => split,
+            None => {
+                return match callback(self) {
+                    Value::Error { error } => Err(*error),
+                    new_val => {
+                        *self = new_val;
+                        Ok(())
+                    }
+                }
+            }
+        };
 
-        let new_val = callback(&orig.follow_cell_path(cell_path)?);
+        match member {
+            PathMember::String {
+                val: col_name,
+                span: origin_span,
+            } => match self {
+                Value::Record { cols, vals, span } => {
+                    match cols.iter().position(|col| col == col_name) {
+                        Some(index) => vals[index].update_cell_path(rest, callback),
+                        None => match did_you_mean(cols, col_name) {
+                            Some(suggestion) => {
+                                Err(ShellError::DidYouMean(suggestion, *origin_span))
+                            }
+                            None => Err(ShellError::CantFindColumn(*origin_span, *span)),
+                        },
+                    }
+                }
+                Value::List { vals, .. } => {
+                    for val in vals.iter_mut() {
+                        val.update_cell_path(cell_path, callback)?;
+                    }
 
-        match new_val {
-            Value::Error { error } => Err(error),
-            new_val => self.replace_data_at_cell_path(cell_path, new_val),
+                    Ok(())
+                }
+                v => Err(ShellError::CantFindColumn(*origin_span, v.span()?)),
+            },
+            PathMember::Int {
+                val: row_num,
+                span: origin_span,
+            } => match self {
+                Value::List { vals, .. } => {
+                    let len = vals.len();
+
+                    match vals.get_mut(*row_num) {
+                        Some(val) => val.update_cell_path(rest, callback),
+                        None => Err(ShellError::AccessBeyondEnd(len, *origin_span)),
+                    }
+                }
+                v => Err(ShellError::NotAList(*origin_span, v.span()?)),
+            },
         }
     }
 
@@ -776,6 +825,44 @@ impl PartialEq for Value {
     }
 }
 
+// How many times `'ab' * 3` or `[1 2] * 3` repeats a string or list of the given length
+fn repeat_count(ty: &str, len: usize, count: i64, span: Span) -> Result<usize, ShellError> {
+    let count = usize::try_from(count).map_err(|_| {
+        ShellError::UnsupportedInput(
+            format!("can't repeat a {} a negative number of times", ty),
+            span,
+        )
+    })?;
+
+    match len.checked_mul(count) {
+        Some(_) => Ok(count),
+        None => Err(ShellError::OperatorOverflow(
+            "multiply operation overflowed".into(),
+            span,
+        )),
+    }
+}
+
+// The repeated string or list is allocated up front, so a count that would need more memory
+// than there is fails here instead of aborting the shell
+fn repeat_too_large(ty: &str, span: Span) -> ShellError {
+    ShellError::OperatorOverflow(format!("the repeated {} doesn't fit in memory", ty), span)
+}
+
+// Filesizes are whole bytes, so scaling one by a float rounds to the nearest byte
+fn filesize_from_f64(val: f64, span: Span) -> Option<Value> {
+    let val = val.round();
+
+    if val.is_finite() && val >= i64::MIN as f64 && val < i64::MAX as f64 {
+        Some(Value::Filesize {
+            val: val as i64,
+            span,
+        })
+    } else {
+        None
+    }
+}
+
 impl Value {
     pub fn add(&self, op: Span, rhs: &Value) -> Result<Value, ShellError> {
         let span = span(&[self.span()?, rhs.span()?]);
@@ -945,6 +1032,49 @@ impl Value {
                 val: lhs * rhs,
                 span,
             }),
+            (Value::Filesize { val: lhs, .. }, Value::Int { val: rhs, .. })
+            | (Value::Int { val: rhs, .. }, Value::Filesize { val: lhs, .. }) => {
+                if let Some(val) = lhs.checked_mul(*rhs) {
+                    Ok(Value::Filesize { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow(
+                        "multiply operation overflowed".into(),
+                        span,
+                    ))
+                }
+            }
+            (Value::Filesize { val: lhs, .. }, Value::Float { val: rhs, .. })
+            | (Value::Float { val: rhs, .. }, Value::Filesize { val: lhs, .. }) => {
+                filesize_from_f64(*lhs as f64 * *rhs, span).ok_or_else(|| {
+                    ShellError::OperatorOverflow("multiply operation overflowed".into(), span)
+                })
+            }
+            (Value::String { val: lhs, .. }, Value::Int { val: rhs, .. })
+            | (Value::Int { val: rhs, .. }, Value::String { val: lhs, .. }) => {
+                let count = repeat_count("string", lhs.len(), *rhs, span)?;
+
+                let mut val = String::new();
+                if !lhs.is_empty() {
+                    val.try_reserve_exact(lhs.len() * count)
+                        .map_err(|_| repeat_too_large("string", span))?;
+                    for _ in 0..count {
+                        val.push_str(lhs);
+                    }
+                }
+
+                Ok(Value::String { val, span })
+            }
+            (Value::List { vals: lhs, .. }, Value::Int { val: rhs, .. })
+            | (Value::Int { val: rhs, .. }, Value::List { vals: lhs, .. }) => {
+                let count = repeat_count("list", lhs.len(), *rhs, span)?;
+
+                let mut vals = Vec::new();
+                vals.try_reserve_exact(lhs.len() * count)
+                    .map_err(|_| repeat_too_large("list", span))?;
+                vals.extend(lhs.iter().cycle().take(lhs.len() * count).cloned());
+
+                Ok(Value::List { vals, span })
+            }
             (Value::CustomValue { val: lhs, span }, rhs) => {
                 lhs.operation(*span, Operator::Multiply, op, rhs)
             }
@@ -1009,6 +1139,37 @@ impl Value {
                     Err(ShellError::DivisionByZero(op))
                 }
             }
+            (Value::Filesize { val: lhs, .. }, Value::Int { val: rhs, .. }) => {
+                if *rhs == 0 {
+                    Err(ShellError::DivisionByZero(op))
+                } else if let Some(val) = lhs.checked_div(*rhs) {
+                    Ok(Value::Filesize { val, span })
+                } else {
+                    Err(ShellError::OperatorOverflow(
+                        "division operation overflowed".into(),
+                        span,
+                    ))
+                }
+            }
+            (Value::Filesize { val: lhs, .. }, Value::Float { val: rhs, .. }) => {
+                if *rhs != 0.0 {
+                    filesize_from_f64(*lhs as f64 / *rhs, span).ok_or_else(|| {
+                        ShellError::OperatorOverflow("division operation overflowed".into(), span)
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero(op))
+                }
+            }
+            (Value::Filesize { val: lhs, .. }, Value::Filesize { val: rhs, .. }) => {
+                if *rhs != 0 {
+                    Ok(Value::Float {
+                        val: *lhs as f64 / *rhs as f64,
+                        span,
+                    })
+                } else {
+                    Err(ShellError::DivisionByZero(op))
+                }
+            }
             (Value::CustomValue { val: lhs, span }, rhs) => {
                 lhs.operation(*span, Operator::Divide, op, rhs)
             }
@@ -1022,6 +1183,19 @@ impl Value {
             }),
         }
     }
+
+    // How the comparison operators order two values. Unlike `partial_cmp`, a plain number
+    // compared with a filesize counts bytes, without making the two equal as values.
+    fn compare(&self, rhs: &Value) -> Option<Ordering> {
+        match (self, rhs) {
+            (Value::Filesize { val: lhs, .. }, Value::Int { val: rhs, .. })
+            | (Value::Int { val: lhs, .. }, Value::Filesize { val: rhs, .. }) => {
+                lhs.partial_cmp(rhs)
+            }
+            _ => self.partial_cmp(rhs),
+        }
+    }
+
     pub fn lt(&self, op: Span, rhs: &Value) -> Result<Value, ShellError> {
         let span = span(&[self.span()?, rhs.span()?]);
 
@@ -1029,7 +1203,7 @@ impl Value {
             return lhs.operation(*span, Operator::LessThan, op, rhs);
         }
 
-        match self.partial_cmp(rhs) {
+        match self.compare(rhs) {
             Some(ordering) => Ok(Value::Bool {
                 val: matches!(ordering, Ordering::Less),
                 span,
@@ -1050,7 +1224,7 @@ impl Value {
             return lhs.operation(*span, Operator::LessThanOrEqual, op, rhs);
         }
 
-        match self.partial_cmp(rhs) {
+        match self.compare(rhs) {
             Some(ordering) => Ok(Value::Bool {
                 val: matches!(ordering, Ordering::Less | Ordering::Equal),
                 span,
@@ -1071,7 +1245,7 @@ impl Value {
             return lhs.operation(*span, Operator::GreaterThan, op, rhs);
         }
 
-        match self.partial_cmp(rhs) {
+        match self.compare(rhs) {
             Some(ordering) => Ok(Value::Bool {
                 val: matches!(ordering, Ordering::Greater),
                 span,
@@ -1092,7 +1266,7 @@ impl Value {
             return lhs.operation(*span, Operator::GreaterThanOrEqual, op, rhs);
         }
 
-        match self.partial_cmp(rhs) {
+        match self.compare(rhs) {
             Some(ordering) => Ok(Value::Bool {
                 val: matches!(ordering, Ordering::Greater | Ordering::Equal),
                 span,
@@ -1113,7 +1287,7 @@ impl Value {
             return lhs.operation(*span, Operator::Equal, op, rhs);
         }
 
-        match self.partial_cmp(rhs) {
+        match self.compare(rhs) {
             Some(ordering) => Ok(Value::Bool {
                 val: matches!(ordering, Ordering::Equal),
                 span,
@@ -1134,7 +1308,7 @@ impl Value {
             return lhs.operation(*span, Operator::NotEqual, op, rhs);
         }
 
-        match self.partial_cmp(rhs) {
+        match self.compare(rhs) {
             Some(ordering) => Ok(Value::Bool {
                 val: !matches!(ordering, Ordering::Equal),
                 span,
@@ -1396,6 +1570,40 @@ impl Value {
             }),
         }
     }
+
+    pub fn append(&self, op: Span, rhs: &Value) -> Result<Value, ShellError> {
+        let span = span(&[self.span()?, rhs.span()?]);
+
+        match (self, rhs) {
+            (Value::List { vals: lhs, .. }, Value::List { vals: rhs, .. }) => Ok(Value::List {
+                vals: lhs.iter().chain(rhs.iter()).cloned().collect(),
+                span,
+            }),
+            (Value::List { vals: lhs, .. }, rhs) => Ok(Value::List {
+                vals: lhs.iter().chain(std::iter::once(rhs)).cloned().collect(),
+                span,
+            }),
+            (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => Ok(Value::String {
+                val: lhs.to_string() + rhs,
+                span,
+            }),
+            (Value::Binary { val: lhs, .. }, Value::Binary { val: rhs, .. }) => Ok(Value::Binary {
+                val: lhs.iter().chain(rhs.iter()).copied().collect(),
+                span,
+            }),
+            (Value::CustomValue { val: lhs, span }, rhs) => {
+                lhs.operation(*span, Operator::Append, op, rhs)
+            }
+
+            _ => Err(ShellError::OperatorMismatch {
+                op_span: op,
+                lhs_ty: self.get_type(),
+                lhs_span: self.span()?,
+                rhs_ty: rhs.get_type(),
+                rhs_span: rhs.span()?,
+            }),
+        }
+    }
 }
 
 /// Create a Value::Record from a spanned hashmap
@@ -1497,25 +1705,10 @@ fn format_filesize(num_bytes: i64, config: &Config) -> String {
 
     match adj_byte.get_unit() {
         byte_unit::ByteUnit::B => {
-            let locale_string = get_locale().unwrap_or_else(|| String::from("en-US"));
-            // Since get_locale() and Locale::from_name() don't always return the same items
-            // we need to try and parse it to match. For instance, a valid locale is de_DE
-            // however Locale::from_name() wants only de so we split and parse it out.
-            let locale_string = locale_string.replace("_", "-"); // en_AU -> en-AU
-            let locale = match Locale::from_name(&locale_string) {
-                Ok(loc) => loc,
-                _ => {
-                    let all = num_format::Locale::available_names();
-                    let locale_prefix = &locale_string.split('-').collect::<Vec<&str>>();
-                    if all.contains(&locale_prefix[0]) {
-                        // eprintln!("Found alternate: {}", &locale_prefix[0]);
-                        Locale::from_name(locale_prefix[0]).unwrap_or(Locale::en)
-                    } else {
-                        // eprintln!("Unable to find matching locale. Defaulting to en-US");
-                        Locale::en
-                    }
-                }
-            };
+            let locale_string = get_config_locale(config)
+                .or_else(get_locale)
+                .unwrap_or_else(|| String::from("en-US"));
+            let locale = number_locale(&locale_string);
             let locale_byte = adj_byte.get_value() as u64;
             let locale_byte_string = locale_byte.to_formatted_string(&locale);
 
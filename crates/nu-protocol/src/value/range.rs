@@ -34,24 +34,20 @@ impl Range {
             from
         };
 
-        let to = if let Value::Nothing { .. } = to {
-            if let Ok(Value::Bool { val: true, .. }) = next.lt(expr_span, &from) {
-                Value::Int {
-                    val: -100i64,
-                    span: Span::unknown(),
-                }
-            } else {
-                Value::Int {
-                    val: 100i64,
-                    span: Span::unknown(),
-                }
-            }
-        } else {
-            to
+        // A range without an upper bound, like `1..`, is left open and streams forever
+        let unbounded = matches!(to, Value::Nothing { .. });
+
+        let zero = Value::Int {
+            val: 0i64,
+            span: Span::unknown(),
         };
 
         // Check if the range counts up or down
-        let moves_up = matches!(from.lte(expr_span, &to), Ok(Value::Bool { val: true, .. }));
+        let moves_up = if unbounded {
+            !matches!(next.lt(expr_span, &from), Ok(Value::Bool { val: true, .. }))
+        } else {
+            matches!(from.lte(expr_span, &to), Ok(Value::Bool { val: true, .. }))
+        };
 
         // Convert the next value into the inctement
         let incr = if let Value::Nothing { .. } = next {
@@ -70,30 +66,27 @@ impl Range {
             next.sub(operator.next_op_span, &from)?
         };
 
-        let zero = Value::Int {
-            val: 0i64,
-            span: Span::unknown(),
-        };
-
         // Increment must be non-zero, otherwise we iterate forever
         if matches!(incr.eq(expr_span, &zero), Ok(Value::Bool { val: true, .. })) {
             return Err(ShellError::CannotCreateRange(expr_span));
         }
 
-        // If to > from, then incr > 0, otherwise we iterate forever
-        if let (Value::Bool { val: true, .. }, Value::Bool { val: false, .. }) = (
-            to.gt(operator.span, &from)?,
-            incr.gt(operator.next_op_span, &zero)?,
-        ) {
-            return Err(ShellError::CannotCreateRange(expr_span));
-        }
+        if !unbounded {
+            // If to > from, then incr > 0, otherwise we iterate forever
+            if let (Value::Bool { val: true, .. }, Value::Bool { val: false, .. }) = (
+                to.gt(operator.span, &from)?,
+                incr.gt(operator.next_op_span, &zero)?,
+            ) {
+                return Err(ShellError::CannotCreateRange(expr_span));
+            }
 
-        // If to < from, then incr < 0, otherwise we iterate forever
-        if let (Value::Bool { val: true, .. }, Value::Bool { val: false, .. }) = (
-            to.lt(operator.span, &from)?,
-            incr.lt(operator.next_op_span, &zero)?,
-        ) {
-            return Err(ShellError::CannotCreateRange(expr_span));
+            // If to < from, then incr < 0, otherwise we iterate forever
+            if let (Value::Bool { val: true, .. }, Value::Bool { val: false, .. }) = (
+                to.lt(operator.span, &from)?,
+                incr.lt(operator.next_op_span, &zero)?,
+            ) {
+                return Err(ShellError::CannotCreateRange(expr_span));
+            }
         }
 
         Ok(Range {
@@ -106,7 +99,20 @@ impl Range {
 
     #[inline]
     fn moves_up(&self) -> bool {
-        self.from <= self.to
+        let zero = Value::Int {
+            val: 0,
+            span: Span::unknown(),
+        };
+
+        match self.to {
+            Value::Nothing { .. } => self.incr > zero,
+            _ => self.from <= self.to,
+        }
+    }
+
+    /// Whether the range has no upper bound, like `1..`
+    pub fn is_unbounded(&self) -> bool {
+        matches!(self.to, Value::Nothing { .. })
     }
 
     #[inline]
@@ -115,6 +121,15 @@ impl Range {
     }
 
     pub fn contains(&self, item: &Value) -> bool {
+        if self.is_unbounded() {
+            return match item.partial_cmp(&self.from) {
+                Some(Ordering::Greater) => self.moves_up(),
+                Some(Ordering::Less) => !self.moves_up(),
+                Some(Ordering::Equal) => true,
+                None => false,
+            };
+        }
+
         match (item.partial_cmp(&self.from), item.partial_cmp(&self.to)) {
             (Some(Ordering::Greater | Ordering::Equal), Some(Ordering::Less)) => self.moves_up(),
             (Some(Ordering::Less | Ordering::Equal), Some(Ordering::Greater)) => !self.moves_up(),
@@ -150,18 +165,10 @@ impl RangeIterator {
             x => x,
         };
 
-        let end = match range.to {
-            Value::Nothing { .. } => Value::Int {
-                val: i64::MAX,
-                span,
-            },
-            x => x,
-        };
-
         RangeIterator {
             moves_up,
             curr: start,
-            end,
+            end: range.to,
             span,
             is_end_inclusive,
             done: false,
@@ -177,8 +184,15 @@ impl Iterator for RangeIterator {
             return None;
         }
 
+        let desired_ordering = if self.moves_up {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+
+        // An unbounded range never reaches its end
         let ordering = if matches!(self.end, Value::Nothing { .. }) {
-            Some(Ordering::Less)
+            Some(desired_ordering)
         } else {
             self.curr.partial_cmp(&self.end)
         };
@@ -188,16 +202,10 @@ impl Iterator for RangeIterator {
         } else {
             self.done = true;
             return Some(Value::Error {
-                error: ShellError::CannotCreateRange(self.span),
+                error: Box::new(ShellError::CannotCreateRange(self.span)),
             });
         };
 
-        let desired_ordering = if self.moves_up {
-            Ordering::Less
-        } else {
-            Ordering::Greater
-        };
-
         if (ordering == desired_ordering) || (self.is_end_inclusive && ordering == Ordering::Equal)
         {
             let next_value = self.curr.add(self.span, &self.incr);
@@ -207,7 +215,9 @@ impl Iterator for RangeIterator {
 
                 Err(error) => {
                     self.done = true;
-                    return Some(Value::Error { error });
+                    return Some(Value::Error {
+                        error: Box::new(error),
+                    });
                 }
             };
             std::mem::swap(&mut self.curr, &mut next);
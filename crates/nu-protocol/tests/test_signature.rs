@@ -76,7 +76,8 @@ fn test_signature_chained() {
             arg: Some(SyntaxShape::String),
             required: true,
             desc: "required named description".to_string(),
-            var_id: None
+            var_id: None,
+            deprecated: None
         })
     );
 
@@ -88,7 +89,8 @@ fn test_signature_chained() {
             arg: Some(SyntaxShape::String),
             required: true,
             desc: "required named description".to_string(),
-            var_id: None
+            var_id: None,
+            deprecated: None
         })
     );
 }
@@ -0,0 +1,20 @@
+use nu_protocol::{ShellError, Span, Value};
+
+#[test]
+fn test_value_size() {
+    // Values are created for every cell of every table, so errors are boxed
+    // to keep them from setting the size of all the other variants
+    assert!(std::mem::size_of::<Value>() <= 72);
+}
+
+#[test]
+fn test_error_value_round_trip() {
+    let value = Value::Error {
+        error: Box::new(ShellError::CantFindColumn(Span::unknown(), Span::unknown())),
+    };
+
+    assert!(matches!(
+        value.span(),
+        Err(ShellError::CantFindColumn(_, _))
+    ));
+}
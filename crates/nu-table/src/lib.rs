@@ -1,5 +1,5 @@
 mod table;
 mod wrap;
 
-pub use table::{draw_table, StyledString, Table, TextStyle, Theme};
+pub use table::{draw_markdown_table, draw_table, StyledString, Table, TextStyle, Theme};
 pub use wrap::Alignment;
@@ -1,6 +1,9 @@
-use crate::wrap::{column_width, split_sublines, wrap, Alignment, Subline, WrappedCell};
+use crate::wrap::{
+    column_width, split_sublines, strip_ansi, unicode_width_strip_ansi, wrap, Alignment, Subline,
+    WrappedCell,
+};
 use nu_ansi_term::{Color, Style};
-use nu_protocol::{Config, FooterMode};
+use nu_protocol::{AmbiguousWidth, Config, FooterMode};
 use std::collections::HashMap;
 use std::fmt::Write;
 
@@ -968,13 +971,13 @@ impl WrappedTable {
     }
 }
 
-fn process_table(table: &Table) -> ProcessedTable {
+fn process_table(table: &Table, ambiguous_width: AmbiguousWidth) -> ProcessedTable {
     let mut processed_data = vec![];
     for row in &table.data {
         let mut out_row = vec![];
         for column in row {
             out_row.push(ProcessedCell {
-                contents: split_sublines(&column.contents),
+                contents: split_sublines(&column.contents, ambiguous_width),
                 style: column.style,
             });
         }
@@ -984,7 +987,7 @@ fn process_table(table: &Table) -> ProcessedTable {
     let mut processed_headers = vec![];
     for header in &table.headers {
         processed_headers.push(ProcessedCell {
-            contents: split_sublines(&header.contents),
+            contents: split_sublines(&header.contents, ambiguous_width),
             style: header.style,
         });
     }
@@ -1054,6 +1057,76 @@ pub fn maybe_truncate_columns(termwidth: usize, processed_table: &mut ProcessedT
     }
 }
 
+/// Draws the table as Markdown, with every cell in full and without colors, so that the output
+/// is the same whatever the width of the terminal
+pub fn draw_markdown_table(table: &Table, config: &Config) -> String {
+    let headers: Vec<String> = table
+        .headers
+        .iter()
+        .map(|header| markdown_cell(&header.contents))
+        .collect();
+    let rows: Vec<Vec<String>> = table
+        .data
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| markdown_cell(&cell.contents))
+                .collect()
+        })
+        .collect();
+
+    let num_columns = rows.iter().map(Vec::len).chain([headers.len()]).max();
+    let num_columns = match num_columns {
+        Some(num_columns) if num_columns > 0 => num_columns,
+        _ => return String::new(),
+    };
+
+    // The separator under the headers needs at least three dashes
+    let mut widths = vec![3; num_columns];
+    for row in std::iter::once(&headers).chain(rows.iter()) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(unicode_width_strip_ansi(cell, config.ambiguous_width));
+        }
+    }
+
+    let mut output = String::new();
+
+    if !headers.is_empty() {
+        write_markdown_row(&mut output, &headers, &widths, config.ambiguous_width);
+
+        let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        write_markdown_row(&mut output, &separator, &widths, config.ambiguous_width);
+    }
+
+    for row in &rows {
+        write_markdown_row(&mut output, row, &widths, config.ambiguous_width);
+    }
+
+    output
+}
+
+fn markdown_cell(contents: &str) -> String {
+    strip_ansi(contents).replace('|', "\\|").replace('\n', " ")
+}
+
+fn write_markdown_row(
+    output: &mut String,
+    row: &[String],
+    widths: &[usize],
+    ambiguous_width: AmbiguousWidth,
+) {
+    output.push('|');
+
+    for (i, width) in widths.iter().enumerate() {
+        let cell = row.get(i).map(String::as_str).unwrap_or("");
+        let padding = width - unicode_width_strip_ansi(cell, ambiguous_width);
+
+        let _ = write!(output, " {}{} |", cell, " ".repeat(padding));
+    }
+
+    output.push('\n');
+}
+
 pub fn draw_table(
     table: &Table,
     termwidth: usize,
@@ -1069,7 +1142,7 @@ pub fn draw_table(
         termwidth
     };
 
-    let mut processed_table = process_table(table);
+    let mut processed_table = process_table(table, config.ambiguous_width);
 
     let max_per_column = get_max_column_widths(&processed_table);
 
@@ -1117,6 +1190,7 @@ pub fn draw_table(
         color_hm,
         &re_leading,
         &re_trailing,
+        config.ambiguous_width,
     );
 
     wrapped_table.print_table(color_hm, config)
@@ -1128,6 +1202,7 @@ fn wrap_cells(
     color_hm: &HashMap<String, Style>,
     re_leading: &regex::Regex,
     re_trailing: &regex::Regex,
+    ambiguous_width: AmbiguousWidth,
 ) -> WrappedTable {
     let mut column_widths = vec![
         0;
@@ -1155,6 +1230,7 @@ fn wrap_cells(
                 color_hm,
                 re_leading,
                 re_trailing,
+                ambiguous_width,
             );
             wrapped.lines.append(&mut lines);
             if inner_max_width > wrapped.max_width {
@@ -1183,6 +1259,7 @@ fn wrap_cells(
                     color_hm,
                     re_leading,
                     re_trailing,
+                    ambiguous_width,
                 );
                 wrapped.lines.append(&mut lines);
                 if inner_max_width > wrapped.max_width {
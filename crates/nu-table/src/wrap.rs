@@ -1,6 +1,7 @@
 use crate::table::TextStyle;
 use ansi_cut::AnsiCut;
 use nu_ansi_term::Style;
+use nu_protocol::AmbiguousWidth;
 use std::collections::HashMap;
 use std::{fmt::Display, iter::Iterator};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
@@ -53,7 +54,7 @@ impl Display for Line {
     }
 }
 
-fn strip_ansi(astring: &str) -> String {
+pub(crate) fn strip_ansi(astring: &str) -> String {
     if let Ok(bytes) = strip_ansi_escapes::strip(astring) {
         String::from_utf8_lossy(&bytes).to_string()
     } else {
@@ -61,19 +62,23 @@ fn strip_ansi(astring: &str) -> String {
     }
 }
 
-fn unicode_width_strip_ansi(astring: &str) -> usize {
-    let stripped_string: String = {
-        if let Ok(bytes) = strip_ansi_escapes::strip(astring) {
-            String::from_utf8_lossy(&bytes).to_string()
-        } else {
-            astring.to_string()
-        }
-    };
+pub(crate) fn unicode_width_strip_ansi(astring: &str, ambiguous_width: AmbiguousWidth) -> usize {
+    let stripped_string = strip_ansi(astring);
+
+    match ambiguous_width {
+        AmbiguousWidth::Narrow => UnicodeWidthStr::width(&stripped_string[..]),
+        AmbiguousWidth::Wide => UnicodeWidthStr::width_cjk(&stripped_string[..]),
+    }
+}
 
-    UnicodeWidthStr::width(&stripped_string[..])
+fn unicode_width_char(c: char, ambiguous_width: AmbiguousWidth) -> Option<usize> {
+    match ambiguous_width {
+        AmbiguousWidth::Narrow => UnicodeWidthChar::width(c),
+        AmbiguousWidth::Wide => UnicodeWidthChar::width_cjk(c),
+    }
 }
 
-pub fn split_sublines(input: &str) -> Vec<Vec<Subline>> {
+pub fn split_sublines(input: &str, ambiguous_width: AmbiguousWidth) -> Vec<Vec<Subline>> {
     input
         .split_terminator('\n')
         .map(|line| {
@@ -88,7 +93,7 @@ pub fn split_sublines(input: &str) -> Vec<Vec<Subline>> {
                         // let c = x.chars().count();
                         // let u = UnicodeWidthStr::width(x);
                         // std::cmp::min(c, u)
-                        unicode_width_strip_ansi(x)
+                        unicode_width_strip_ansi(x, ambiguous_width)
                     },
                 })
                 .collect::<Vec<_>>()
@@ -122,23 +127,27 @@ pub fn column_width(input: &[Vec<Subline>]) -> usize {
     max
 }
 
-fn split_word(cell_width: usize, word: &str) -> Vec<Subline> {
+fn split_word(cell_width: usize, word: &str, ambiguous_width: AmbiguousWidth) -> Vec<Subline> {
     let mut output = vec![];
     let mut current_width = 0;
     let mut start_index = 0;
-    let mut end_index;
+    let mut num_chars = 0;
 
+    // `cut` counts chars rather than bytes, skipping the ansi escapes, so the word is split at the
+    // char positions of its text without escapes
     let word_no_ansi = strip_ansi(word);
-    for c in word_no_ansi.char_indices() {
-        if let Some(width) = c.1.width() {
-            end_index = c.0;
-            if current_width + width > cell_width {
+    for (index, c) in word_no_ansi.chars().enumerate() {
+        num_chars = index + 1;
+
+        if let Some(width) = unicode_width_char(c, ambiguous_width) {
+            // A char wider than the cell goes on a line of its own rather than after an empty one
+            if current_width + width > cell_width && current_width > 0 {
                 output.push(Subline {
-                    subline: word.cut(start_index..end_index),
+                    subline: word.cut(start_index..index),
                     width: current_width,
                 });
 
-                start_index = c.0;
+                start_index = index;
                 current_width = width;
             } else {
                 current_width += width;
@@ -146,7 +155,7 @@ fn split_word(cell_width: usize, word: &str) -> Vec<Subline> {
         }
     }
 
-    if start_index != word.len() {
+    if start_index != num_chars {
         output.push(Subline {
             subline: word.cut(start_index..),
             width: current_width,
@@ -162,6 +171,7 @@ pub fn wrap(
     color_hm: &HashMap<String, Style>,
     re_leading: &regex::Regex,
     re_trailing: &regex::Regex,
+    ambiguous_width: AmbiguousWidth,
 ) -> (Vec<WrappedLine>, usize) {
     let mut lines = vec![];
     let mut current_line: Vec<Subline> = vec![];
@@ -186,7 +196,8 @@ pub fn wrap(
                     // If this is a really long single word, we need to split the word
                     if current_line.len() == 1 && current_width > cell_width {
                         max_width = cell_width;
-                        let sublines = split_word(cell_width, &current_line[0].subline);
+                        let sublines =
+                            split_word(cell_width, &current_line[0].subline, ambiguous_width);
                         for subline in sublines {
                             let width = subline.width;
                             lines.push(Line {
@@ -221,7 +232,8 @@ pub fn wrap(
             None => {
                 if current_width > cell_width {
                     // We need to break up the last word
-                    let sublines = split_word(cell_width, &current_line[0].subline);
+                    let sublines =
+                        split_word(cell_width, &current_line[0].subline, ambiguous_width);
                     for subline in sublines {
                         let width = subline.width;
                         lines.push(Line {
@@ -293,3 +305,77 @@ pub fn wrap(
 
     (output, current_max)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // The text and width of each line the word is split into, without the ansi escapes
+    fn split(
+        cell_width: usize,
+        word: &str,
+        ambiguous_width: AmbiguousWidth,
+    ) -> Vec<(String, usize)> {
+        split_word(cell_width, word, ambiguous_width)
+            .into_iter()
+            .map(|subline| (strip_ansi(&subline.subline), subline.width))
+            .collect()
+    }
+
+    fn lines(sublines: &[(&str, usize)]) -> Vec<(String, usize)> {
+        sublines
+            .iter()
+            .map(|(text, width)| (text.to_string(), *width))
+            .collect()
+    }
+
+    #[test]
+    fn splits_multi_byte_chars_at_char_boundaries() {
+        assert_eq!(
+            split(4, "héllowörld", AmbiguousWidth::Narrow),
+            lines(&[("héll", 4), ("owör", 4), ("ld", 2)])
+        );
+    }
+
+    #[test]
+    fn splits_wide_chars_by_their_width() {
+        assert_eq!(
+            split(3, "日本語", AmbiguousWidth::Narrow),
+            lines(&[("日", 2), ("本", 2), ("語", 2)])
+        );
+        assert_eq!(
+            split(4, "日本語", AmbiguousWidth::Narrow),
+            lines(&[("日本", 4), ("語", 2)])
+        );
+    }
+
+    #[test]
+    fn puts_chars_wider_than_the_cell_on_lines_of_their_own() {
+        assert_eq!(
+            split(1, "日本", AmbiguousWidth::Narrow),
+            lines(&[("日", 2), ("本", 2)])
+        );
+    }
+
+    #[test]
+    fn counts_ambiguous_chars_as_configured() {
+        assert_eq!(
+            split(2, "±±±", AmbiguousWidth::Narrow),
+            lines(&[("±±", 2), ("±", 1)])
+        );
+        assert_eq!(
+            split(2, "±±±", AmbiguousWidth::Wide),
+            lines(&[("±", 2), ("±", 2), ("±", 2)])
+        );
+    }
+
+    #[test]
+    fn keeps_ansi_escapes_out_of_the_width() {
+        let word = "\x1b[31mabcdef\x1b[0m";
+
+        assert_eq!(
+            split(3, word, AmbiguousWidth::Narrow),
+            lines(&[("abc", 3), ("def", 3)])
+        );
+    }
+}
@@ -97,16 +97,16 @@ use std::iter::repeat;
 use strip_ansi_escapes::strip;
 use unicode_width::UnicodeWidthStr;
 
-fn unicode_width_strip_ansi(astring: &str) -> usize {
-    let stripped_string: String = {
-        if let Ok(bytes) = strip(astring) {
-            String::from_utf8_lossy(&bytes).to_string()
-        } else {
-            astring.to_string()
-        }
-    };
+fn strip_ansi(astring: &str) -> String {
+    if let Ok(bytes) = strip(astring) {
+        String::from_utf8_lossy(&bytes).to_string()
+    } else {
+        astring.to_string()
+    }
+}
 
-    UnicodeWidthStr::width(&stripped_string[..])
+fn unicode_width_strip_ansi(astring: &str) -> usize {
+    UnicodeWidthStr::width(&strip_ansi(astring)[..])
 }
 
 /// Alignment indicate on which side the content should stick if some filling
@@ -137,6 +137,18 @@ pub struct Cell {
     pub alignment: Alignment,
 }
 
+impl Cell {
+    /// Like `Cell::from`, but counts the characters of East Asian ambiguous width as two columns
+    /// wide, the way CJK terminals display them.
+    pub fn from_cjk(string: String) -> Self {
+        Self {
+            width: UnicodeWidthStr::width_cjk(&strip_ansi(&string)[..]),
+            contents: string,
+            alignment: Alignment::Left,
+        }
+    }
+}
+
 impl From<String> for Cell {
     fn from(string: String) -> Self {
         Self {
@@ -574,6 +586,12 @@ mod test {
         assert_eq!(display.width(), 1);
     }
 
+    #[test]
+    fn cjk_ambiguous_width() {
+        assert_eq!(Cell::from("日本 ±").width, 6);
+        assert_eq!(Cell::from_cjk("日本 ±".into()).width, 7);
+    }
+
     #[test]
     fn one_item_exact_width() {
         let mut grid = Grid::new(GridOptions {
@@ -1,41 +1,142 @@
 #[cfg(windows)]
 use crossterm_winapi::{ConsoleMode, Handle};
-use dialoguer::{
-    console::{Style, Term},
-    theme::ColorfulTheme,
-    Select,
+use miette::{IntoDiagnostic, Result, Severity};
+use nu_cli::{
+    create_keybindings, CliError, GridMenu, KeybindingsMode, NuCompleter, NuHighlighter,
+    NuValidator, NushellPrompt, HISTORY_MENU_MARKER,
+};
+use nu_command::{
+    create_default_context, history_entries, history_path, history_session_path, HistoryFile,
 };
-use miette::{IntoDiagnostic, Result};
-use nu_cli::{CliError, NuCompleter, NuHighlighter, NuValidator, NushellPrompt};
-use nu_command::create_default_context;
 use nu_engine::{env_to_values, eval_block};
 use nu_parser::{lex, parse, Token, TokenContents};
 use nu_protocol::{
-    ast::Call,
+    ast::{Block, Call},
     engine::{EngineState, Stack, StateWorkingSet},
-    Config, PipelineData, ShellError, Span, Value, CONFIG_VARIABLE_ID,
+    Config, IntoPipelineData, ParsedKeybinding, PipelineData, ShellError, Span, Value, Warnings,
+    CONFIG_VARIABLE_ID,
 };
 use reedline::{Completer, CompletionActionHandler, DefaultPrompt, LineBuffer, Prompt};
 use std::{
+    collections::HashSet,
     io::Write,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
+use sysinfo::{System, SystemExt};
 
 #[cfg(test)]
 mod tests;
 
 // Name of environment variable where the prompt could be stored
 const PROMPT_COMMAND: &str = "PROMPT_COMMAND";
+// Name of environment variable where the right prompt could be stored
+const PROMPT_COMMAND_RIGHT: &str = "PROMPT_COMMAND_RIGHT";
+// Name of environment variable where the terminal title could be stored
+const PROMPT_TITLE_COMMAND: &str = "PROMPT_TITLE_COMMAND";
+
+// OSC 133 markers used by terminals with shell integration
+const PROMPT_MARKER: &str = "\x1b]133;A\x1b\\";
+const PRE_EXECUTION_MARKER: &str = "\x1b]133;C\x1b\\";
+
+// Starting the shell with this flag prints how long each step of the startup took
+const TIME_STARTUP_FLAG: &str = "--time-startup";
+
+// Starting the shell with this flag and a file parses the file without running it, and prints
+// the problems found as JSON for editors and CI
+const IDE_CHECK_FLAG: &str = "--ide-check";
+
+// How long each step of the startup took, in the order they ran
+#[derive(Default)]
+struct StartupTimes {
+    steps: Vec<(&'static str, Duration)>,
+}
+
+impl StartupTimes {
+    fn time<T>(&mut self, step: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let output = f();
+        self.steps.push((step, start.elapsed()));
 
-struct FuzzyCompletion {
+        output
+    }
+
+    fn into_value(self) -> Value {
+        let span = Span::unknown();
+        let total: Duration = self.steps.iter().map(|(_, time)| *time).sum();
+
+        let vals = self
+            .steps
+            .into_iter()
+            .chain(std::iter::once(("total", total)))
+            .map(|(step, time)| Value::Record {
+                cols: vec!["step".into(), "time".into()],
+                vals: vec![
+                    Value::string(step, span),
+                    Value::Duration {
+                        val: time.as_nanos() as i64,
+                        span,
+                    },
+                ],
+                span,
+            })
+            .collect();
+
+        Value::List { vals, span }
+    }
+}
+
+struct MenuCompletion {
     completer: Box<dyn Completer>,
+    config: Config,
+}
+
+impl MenuCompletion {
+    // Replaces the whole line with an entry picked from the history, most recent first
+    fn handle_history(&self, present_buffer: &mut LineBuffer) {
+        let filter = &present_buffer.get_buffer()[HISTORY_MENU_MARKER.len_utf8()..];
+
+        let mut entries: Vec<String> = history_path()
+            .and_then(|path| history_entries(&path).ok())
+            .unwrap_or_default();
+        entries.reverse();
+
+        let mut seen = HashSet::new();
+        entries.retain(|entry| entry.contains(filter) && seen.insert(entry.clone()));
+
+        // Multi-line entries are shown on a single line
+        let selections: Vec<String> = entries
+            .iter()
+            .map(|entry| entry.replace('\n', " ↵ "))
+            .collect();
+
+        let len = present_buffer.get_buffer().len();
+
+        match GridMenu::new(&selections, &self.config.history_menu).select() {
+            Ok(Some(result)) => {
+                present_buffer.replace(0..len, &entries[result]);
+                present_buffer.set_insertion_point(entries[result].len());
+            }
+            // Closing the menu leaves the line as it was before it opened
+            _ => {
+                let filter = filter.to_string();
+                present_buffer.replace(0..len, &filter);
+                present_buffer.set_insertion_point(filter.len());
+            }
+        }
+    }
 }
 
-impl CompletionActionHandler for FuzzyCompletion {
+impl CompletionActionHandler for MenuCompletion {
     fn handle(&mut self, present_buffer: &mut LineBuffer) {
+        if present_buffer.get_buffer().starts_with(HISTORY_MENU_MARKER) {
+            self.handle_history(present_buffer);
+            return;
+        }
+
         let completions = self
             .completer
             .complete(present_buffer.get_buffer(), present_buffer.offset());
@@ -52,20 +153,14 @@ impl CompletionActionHandler for FuzzyCompletion {
             present_buffer.replace(span.start..span.end, &completions[0].1);
             present_buffer.set_insertion_point(offset);
         } else {
-            let selections: Vec<_> = completions.iter().map(|(_, string)| string).collect();
+            let selections: Vec<String> = completions
+                .iter()
+                .map(|(_, string)| string.clone())
+                .collect();
 
-            let _ = crossterm::terminal::disable_raw_mode();
-            println!();
-            let theme = ColorfulTheme {
-                active_item_style: Style::new().for_stderr().on_green().black(),
-                ..Default::default()
-            };
-            let result = Select::with_theme(&theme)
-                .default(0)
-                .items(&selections[..])
-                .interact_on_opt(&Term::stdout())
+            let result = GridMenu::new(&selections, &self.config.completion_menu)
+                .select()
                 .unwrap_or(None);
-            let _ = crossterm::terminal::enable_raw_mode();
 
             if let Some(result) = result {
                 let span = completions[result].0;
@@ -89,7 +184,19 @@ fn main() -> Result<()> {
         miette_hook(x);
     }));
 
-    let mut engine_state = create_default_context();
+    let time_startup = std::env::args().nth(1).as_deref() == Some(TIME_STARTUP_FLAG);
+    let mut startup_times = StartupTimes::default();
+
+    let mut engine_state = startup_times.time("register commands", create_default_context);
+
+    if std::env::args().nth(1).as_deref() == Some(IDE_CHECK_FLAG) {
+        let path = std::env::args().nth(2).ok_or_else(|| {
+            miette::miette!("{} needs the path of the file to check", IDE_CHECK_FLAG)
+        })?;
+        let has_errors = ide_check(&engine_state, &path)?;
+
+        std::process::exit(if has_errors { 1 } else { 0 });
+    }
 
     // TODO: make this conditional in the future
     // Ctrl-c protection section
@@ -105,7 +212,7 @@ fn main() -> Result<()> {
     engine_state.ctrlc = Some(engine_state_ctrlc);
     // End ctrl-c protection section
 
-    if let Some(path) = std::env::args().nth(1) {
+    if let Some(path) = std::env::args().nth(1).filter(|_| !time_startup) {
         let file = std::fs::read(&path).into_diagnostic()?;
 
         let (block, delta) = {
@@ -116,6 +223,7 @@ fn main() -> Result<()> {
 
                 std::process::exit(1);
             }
+            report_parse_warnings(&working_set);
             (output, working_set.render())
         };
 
@@ -156,6 +264,8 @@ fn main() -> Result<()> {
             std::process::exit(1);
         }
 
+        let warnings = fresh_warnings(&mut engine_state);
+
         match eval_block(
             &engine_state,
             &mut stack,
@@ -167,12 +277,13 @@ fn main() -> Result<()> {
                     if let Value::Error { error } = item {
                         let working_set = StateWorkingSet::new(&engine_state);
 
-                        report_error(&working_set, &error);
+                        report_error(&working_set, &*error);
 
                         std::process::exit(1);
                     }
                     println!("{}", item.into_string("\n", &config));
                 }
+                report_warnings(&engine_state, &warnings);
 
                 // Next, let's check if there are any flags we want to pass to the main function
                 let args: Vec<String> = std::env::args().skip(2).collect();
@@ -210,7 +321,7 @@ fn main() -> Result<()> {
                             if let Value::Error { error } = item {
                                 let working_set = StateWorkingSet::new(&engine_state);
 
-                                report_error(&working_set, &error);
+                                report_error(&working_set, &*error);
 
                                 std::process::exit(1);
                             }
@@ -237,16 +348,21 @@ fn main() -> Result<()> {
 
         Ok(())
     } else {
-        use reedline::{FileBackedHistory, Reedline, Signal};
+        use reedline::{EditCommand, Emacs, FileBackedHistory, History, Reedline, Signal, Vi};
 
         let mut entry_num = 0;
+        let mut last_success = None;
+        // The line buffer and cursor position `commandline` left for the next line
+        let mut next_line: Option<(String, usize)> = None;
 
         let default_prompt = DefaultPrompt::new(1);
         let mut nu_prompt = NushellPrompt::new();
         let mut stack = nu_protocol::engine::Stack::new();
 
         // First, set up env vars as strings only
-        gather_parent_env_vars(&mut engine_state, &mut stack);
+        startup_times.time("gather environment", || {
+            gather_parent_env_vars(&mut engine_state, &mut stack)
+        });
 
         // Set up our initial config to start from
         stack.vars.insert(
@@ -269,7 +385,14 @@ fn main() -> Result<()> {
                 let config_filename = config_path.to_string_lossy().to_owned();
 
                 if let Ok(contents) = std::fs::read_to_string(&config_path) {
-                    eval_source(&mut engine_state, &mut stack, &contents, &config_filename);
+                    let block = startup_times.time("parse config", || {
+                        parse_source(&mut engine_state, &contents, &config_filename)
+                    });
+                    if let Some(block) = block {
+                        startup_times.time("evaluate config", || {
+                            eval_parsed_block(&mut engine_state, &mut stack, &block)
+                        });
+                    }
                 }
             }
         }
@@ -286,19 +409,24 @@ fn main() -> Result<()> {
         };
 
         // Translate environment variables from Strings to Values
-        if let Some(e) = env_to_values(&engine_state, &mut stack, &config) {
+        let env_error = startup_times.time("convert environment", || {
+            env_to_values(&engine_state, &mut stack, &config)
+        });
+        if let Some(e) = env_error {
             let working_set = StateWorkingSet::new(&engine_state);
             report_error(&working_set, &e);
         }
 
-        let history_path = if let Some(mut history_path) = nu_path::config_dir() {
-            history_path.push("nushell");
-            history_path.push("history.txt");
+        // The history files are read once, and only appended to after that
+        let mut history_file = history_path().map(HistoryFile::open);
+        let mut history_session_file = history_session_path().map(HistoryFile::open);
 
-            Some(history_path)
-        } else {
-            None
-        };
+        // Each interactive shell gets its own id so that `history --session` can tell its
+        // entries apart from those of other shells sharing the history file
+        engine_state.history_session_id = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|time| time.as_nanos() as i64)
+            .unwrap_or_default();
 
         #[cfg(feature = "plugin")]
         {
@@ -313,11 +441,26 @@ fn main() -> Result<()> {
                 let plugin_filename = plugin_path.to_string_lossy().to_owned();
 
                 if let Ok(contents) = std::fs::read_to_string(&plugin_path) {
-                    eval_source(&mut engine_state, &mut stack, &contents, &plugin_filename);
+                    startup_times.time("load plugins", || {
+                        eval_source(&mut engine_state, &mut stack, &contents, &plugin_filename)
+                    });
                 }
             }
         }
 
+        if time_startup {
+            let times = startup_times.into_value().into_pipeline_data();
+            if let Err(err) = print_pipeline_data(times, &engine_state, &mut stack) {
+                let working_set = StateWorkingSet::new(&engine_state);
+                report_error(&working_set, &err);
+            }
+        }
+
+        // The keybindings are built again only when the config changes them, so that a bad one
+        // is reported once instead of before every prompt
+        let mut keybindings: Option<(String, Vec<ParsedKeybinding>, Option<KeybindingsMode>)> =
+            None;
+
         loop {
             let config = match stack.get_config() {
                 Ok(config) => config,
@@ -334,8 +477,9 @@ fn main() -> Result<()> {
 
             let line_editor = Reedline::create()
                 .into_diagnostic()?
-                .with_completion_action_handler(Box::new(FuzzyCompletion {
-                    completer: Box::new(NuCompleter::new(engine_state.clone())),
+                .with_completion_action_handler(Box::new(MenuCompletion {
+                    completer: Box::new(NuCompleter::new(engine_state.clone(), get_cwd(&stack))),
+                    config: config.clone(),
                 }))
                 .with_highlighter(Box::new(NuHighlighter {
                     engine_state: engine_state.clone(),
@@ -352,36 +496,135 @@ fn main() -> Result<()> {
             //FIXME: if config.use_ansi_coloring is false then we should
             // turn off the hinter but I don't see any way to do that yet.
 
-            let mut line_editor = if let Some(history_path) = history_path.clone() {
+            let unchanged = matches!(
+                &keybindings,
+                Some((edit_mode, parsed, _))
+                    if *edit_mode == config.edit_mode && *parsed == config.keybindings
+            );
+            if !unchanged {
+                let mode = match create_keybindings(&config) {
+                    Ok(mode) => Some(mode),
+                    Err(e) => {
+                        let working_set = StateWorkingSet::new(&engine_state);
+                        report_error(&working_set, &e);
+
+                        None
+                    }
+                };
+
+                keybindings = Some((config.edit_mode.clone(), config.keybindings.clone(), mode));
+            }
+
+            let line_editor = match keybindings.as_ref().and_then(|(_, _, mode)| mode.clone()) {
+                Some(KeybindingsMode::Emacs(keybindings)) => {
+                    line_editor.with_edit_mode(Box::new(Emacs::new(keybindings)))
+                }
+                Some(KeybindingsMode::Vi {
+                    insert_keybindings,
+                    normal_keybindings,
+                }) => line_editor
+                    .with_edit_mode(Box::new(Vi::new(insert_keybindings, normal_keybindings))),
+                None => line_editor,
+            };
+
+            let max_history_size = config.max_history_size.max(0) as usize;
+
+            // The line editor only keeps the history in memory. The entries are written to the
+            // file below, after deciding which ones to keep.
+            let mut line_editor = if let Some(history_file) = &mut history_file {
+                let mut history = FileBackedHistory::new(max_history_size);
+                for entry in history_file.entries() {
+                    history.append(entry);
+                }
+
                 line_editor
-                    .with_history(Box::new(
-                        FileBackedHistory::with_file(1000, history_path.clone())
-                            .into_diagnostic()?,
-                    ))
+                    .with_history(Box::new(history))
                     .into_diagnostic()?
             } else {
                 line_editor
             };
 
-            let prompt = update_prompt(
-                PROMPT_COMMAND,
-                &engine_state,
-                &stack,
-                &mut nu_prompt,
-                &default_prompt,
-            );
+            if let Some((buffer, cursor_pos)) = next_line.take() {
+                let mut commands = vec![
+                    EditCommand::Clear,
+                    EditCommand::InsertString(buffer.clone()),
+                    EditCommand::MoveToStart,
+                ];
+                commands.extend(buffer[..cursor_pos].chars().map(|_| EditCommand::MoveRight));
+
+                line_editor.run_edit_commands(&commands);
+            }
+
+            update_title(PROMPT_TITLE_COMMAND, &engine_state, &stack);
+
+            if config.shell_integration {
+                if let Some(success) = last_success.take() {
+                    run_ansi_sequence(&get_command_finished_marker(success));
+                }
+                if let Some(cwd) = get_cwd_report(&stack) {
+                    run_ansi_sequence(&cwd);
+                }
+                run_ansi_sequence(PROMPT_MARKER);
+            }
+
+            let prompt = update_prompt(&engine_state, &stack, &mut nu_prompt, &default_prompt);
 
             entry_num += 1;
 
             let input = line_editor.read_line(prompt);
             match input {
                 Ok(Signal::Success(s)) => {
-                    eval_source(
+                    // Let `commandline` see the line that is being run
+                    if let Ok(mut buffer) = engine_state.repl_buffer_state.lock() {
+                        *buffer = s.clone();
+                    }
+                    if let Ok(mut cursor_pos) = engine_state.repl_cursor_pos.lock() {
+                        *cursor_pos = s.len();
+                    }
+
+                    let ignored =
+                        s.trim().is_empty() || (config.history_ignore_space && s.starts_with(' '));
+
+                    if !ignored {
+                        if let Some(history_file) = &mut history_file {
+                            let _ = history_file.append_entry(&s, max_history_size);
+                        }
+                        if let Some(history_session_file) = &mut history_session_file {
+                            let _ = history_session_file.record_session_entry(
+                                engine_state.history_session_id,
+                                &s,
+                                max_history_size,
+                            );
+                        }
+                    }
+
+                    if config.shell_integration {
+                        run_ansi_sequence(PRE_EXECUTION_MARKER);
+                    }
+
+                    if let Ok(mut last_exit_code) = engine_state.last_exit_code.lock() {
+                        *last_exit_code = None;
+                    }
+                    let start_time = Instant::now();
+
+                    let success = eval_source(
                         &mut engine_state,
                         &mut stack,
                         &s,
                         &format!("entry #{}", entry_num),
                     );
+                    last_success = Some(success);
+
+                    set_last_command_env_vars(&engine_state, &mut stack, success, start_time);
+
+                    if let (Ok(buffer), Ok(cursor_pos)) = (
+                        engine_state.repl_buffer_state.lock(),
+                        engine_state.repl_cursor_pos.lock(),
+                    ) {
+                        if *buffer != s || *cursor_pos != s.len() {
+                            next_line = Some((buffer.clone(), *cursor_pos));
+                        }
+                    }
                 }
                 Ok(Signal::CtrlC) => {
                     // `Reedline` clears the line content. New prompt is shown
@@ -495,7 +738,7 @@ fn print_pipeline_data(
                 let stdout = std::io::stdout();
 
                 if let Value::Error { error } = item {
-                    return Err(error);
+                    return Err(*error);
                 }
 
                 let mut out = item.into_string("\n", &config);
@@ -512,7 +755,7 @@ fn print_pipeline_data(
                 let stdout = std::io::stdout();
 
                 if let Value::Error { error } = item {
-                    return Err(error);
+                    return Err(*error);
                 }
 
                 let mut out = item.into_string("\n", &config);
@@ -530,43 +773,160 @@ fn print_pipeline_data(
 }
 
 fn update_prompt<'prompt>(
-    env_variable: &str,
     engine_state: &EngineState,
     stack: &Stack,
     nu_prompt: &'prompt mut NushellPrompt,
     default_prompt: &'prompt DefaultPrompt,
 ) -> &'prompt dyn Prompt {
-    let block_id = match stack.get_env_var(env_variable) {
-        Some(v) => match v.as_block() {
-            Ok(b) => b,
-            Err(_) => return default_prompt as &dyn Prompt,
-        },
-        None => return default_prompt as &dyn Prompt,
-    };
+    let left_prompt = eval_prompt_block(PROMPT_COMMAND, engine_state, stack);
+    let right_prompt = eval_prompt_block(PROMPT_COMMAND_RIGHT, engine_state, stack);
 
+    // If there is no custom prompt that runs, give them the default
+    if left_prompt.is_none() && right_prompt.is_none() {
+        return default_prompt as &dyn Prompt;
+    }
+
+    nu_prompt.update_prompt(left_prompt.unwrap_or_default());
+    nu_prompt.update_prompt_right(right_prompt.unwrap_or_default());
+
+    nu_prompt as &dyn Prompt
+}
+
+// Runs the block stored in the environment variable and sets the terminal title to its output
+fn update_title(env_variable: &str, engine_state: &EngineState, stack: &Stack) {
+    if let Some(title) = eval_prompt_block(env_variable, engine_state, stack) {
+        // Control characters would end the escape sequence early
+        let title: String = title.chars().filter(|c| !c.is_control()).collect();
+
+        run_ansi_sequence(&format!("\x1b]2;{}\x1b\\", title));
+    }
+}
+
+// Runs the block stored in the environment variable, returning its output as a string. None if
+// the variable isn't a block, or the block fails.
+fn eval_prompt_block(
+    env_variable: &str,
+    engine_state: &EngineState,
+    stack: &Stack,
+) -> Option<String> {
+    let block_id = stack.get_env_var(env_variable)?.as_block().ok()?;
     let block = engine_state.get_block(block_id);
 
     let mut stack = stack.clone();
 
-    let evaluated_prompt = match eval_block(
+    let pipeline_data = eval_block(
         engine_state,
         &mut stack,
         block,
         PipelineData::new(Span::unknown()),
-    ) {
-        Ok(pipeline_data) => {
-            let config = stack.get_config().unwrap_or_default();
-            pipeline_data.collect_string("", &config)
-        }
-        Err(..) => {
-            // If we can't run the custom prompt, give them the default
-            return default_prompt as &dyn Prompt;
-        }
+    )
+    .ok()?;
+
+    let config = stack.get_config().unwrap_or_default();
+    Some(pipeline_data.collect_string("", &config))
+}
+
+// Sets LAST_EXIT_CODE and CMD_DURATION_MS for the prompt blocks, and anything run after. The exit
+// code is the one of the last external command, or else 0 on success and 1 on an error. Both are
+// ints, so prompts can compare them without converting.
+fn set_last_command_env_vars(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    success: bool,
+    start_time: Instant,
+) {
+    let external_exit_code = engine_state
+        .last_exit_code
+        .lock()
+        .ok()
+        .and_then(|last_exit_code| *last_exit_code);
+
+    let exit_code = match external_exit_code {
+        Some(exit_code) if success => exit_code,
+        _ if success => 0,
+        _ => 1,
+    };
+
+    stack.add_env_var(
+        "LAST_EXIT_CODE".into(),
+        Value::Int {
+            val: exit_code,
+            span: Span::unknown(),
+        },
+    );
+    stack.add_env_var(
+        "CMD_DURATION_MS".into(),
+        Value::Int {
+            val: i64::try_from(start_time.elapsed().as_millis()).unwrap_or(i64::MAX),
+            span: Span::unknown(),
+        },
+    );
+}
+
+fn get_command_finished_marker(success: bool) -> String {
+    format!("\x1b]133;D;{}\x1b\\", if success { 0 } else { 1 })
+}
+
+// The directory that relative paths are taken from, which cd keeps in PWD
+fn get_cwd(stack: &Stack) -> String {
+    match stack
+        .get_env_var("PWD")
+        .and_then(|pwd| pwd.as_string().ok())
+    {
+        Some(cwd) => cwd,
+        None => std::env::current_dir()
+            .map(|cwd| cwd.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+// Builds the OSC 7 sequence that tells the terminal the current directory, so that new tabs
+// and splits can open in the same place
+fn get_cwd_report(stack: &Stack) -> Option<String> {
+    let cwd = stack.get_env_var("PWD")?.as_string().ok()?;
+
+    let hostname = System::new().host_name().unwrap_or_default();
+
+    // Windows paths don't start with a separator, but file URLs need one
+    let cwd = cwd.replace('\\', "/");
+    let cwd = if cwd.starts_with('/') {
+        cwd
+    } else {
+        format!("/{}", cwd)
     };
 
-    nu_prompt.update_prompt(evaluated_prompt);
+    Some(format!(
+        "\x1b]7;file://{}{}\x1b\\",
+        percent_encode(&hostname),
+        percent_encode(&cwd)
+    ))
+}
 
-    nu_prompt as &dyn Prompt
+fn percent_encode(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+
+    for byte in input.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                output.push(byte as char)
+            }
+            _ => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+
+    output
+}
+
+fn run_ansi_sequence(seq: &str) {
+    let mut stdout = std::io::stdout();
+
+    match stdout
+        .write_all(seq.as_bytes())
+        .and_then(|_| stdout.flush())
+    {
+        Ok(_) => (),
+        Err(err) => eprintln!("{}", err),
+    };
 }
 
 fn eval_source(
@@ -575,6 +935,14 @@ fn eval_source(
     source: &str,
     fname: &str,
 ) -> bool {
+    match parse_source(engine_state, source, fname) {
+        Some(block) => eval_parsed_block(engine_state, stack, &block),
+        None => false,
+    }
+}
+
+// Parses the source into the engine state, reporting any error
+fn parse_source(engine_state: &mut EngineState, source: &str, fname: &str) -> Option<Block> {
     let (block, delta) = {
         let mut working_set = StateWorkingSet::new(engine_state);
         let (output, err) = parse(
@@ -585,8 +953,9 @@ fn eval_source(
         );
         if let Some(err) = err {
             report_error(&working_set, &err);
-            return false;
+            return None;
         }
+        report_parse_warnings(&working_set);
 
         (output, working_set.render())
     };
@@ -596,14 +965,25 @@ fn eval_source(
         report_error(&working_set, &err);
     }
 
+    Some(block)
+}
+
+fn eval_parsed_block(engine_state: &mut EngineState, stack: &mut Stack, block: &Block) -> bool {
+    let warnings = fresh_warnings(engine_state);
+
     match eval_block(
         engine_state,
         stack,
-        &block,
+        block,
         PipelineData::new(Span::unknown()),
     ) {
         Ok(pipeline_data) => {
-            if let Err(err) = print_pipeline_data(pipeline_data, engine_state, stack) {
+            let result = print_pipeline_data(pipeline_data, engine_state, stack);
+
+            // Streams only produce their warnings while being printed
+            report_warnings(engine_state, &warnings);
+
+            if let Err(err) = result {
                 let working_set = StateWorkingSet::new(engine_state);
 
                 report_error(&working_set, &err);
@@ -650,6 +1030,117 @@ pub fn enable_vt_processing() -> Result<(), ShellError> {
     Ok(())
 }
 
+// Gives an evaluation a list of warnings of its own, which is reported with its output. Clones
+// of the engine state that outlive an evaluation, like the ones in background jobs, still push
+// to the old list, so their warnings don't show up with the output of later evaluations.
+fn fresh_warnings(engine_state: &mut EngineState) -> Warnings {
+    engine_state.warnings = Warnings::default();
+
+    engine_state.warnings.clone()
+}
+
+fn report_warnings(engine_state: &EngineState, warnings: &Warnings) {
+    let warnings = warnings.take();
+
+    if !warnings.is_empty() {
+        let working_set = StateWorkingSet::new(engine_state);
+
+        for warning in warnings {
+            eprintln!("Warning: {:?}", CliError(&warning, &working_set));
+        }
+    }
+}
+
+// Parses a file and prints its diagnostics as a JSON list, returning whether any was an error
+fn ide_check(engine_state: &EngineState, path: &str) -> Result<bool> {
+    let file = std::fs::read(path).into_diagnostic()?;
+
+    let mut working_set = StateWorkingSet::new(engine_state);
+    let file_start = working_set.next_span_start();
+    let (_, err) = parse(&mut working_set, Some(path), &file, false);
+
+    let mut diagnostics: Vec<&(dyn miette::Diagnostic + Send + Sync)> = match &err {
+        Some(err) => vec![err],
+        None => vec![],
+    };
+    for warning in &working_set.parse_warnings {
+        diagnostics.push(warning);
+    }
+
+    let has_errors = diagnostics
+        .iter()
+        .any(|diagnostic| matches!(diagnostic.severity(), None | Some(Severity::Error)));
+
+    let json: Vec<_> = diagnostics
+        .into_iter()
+        .map(|diagnostic| diagnostic_to_json(diagnostic, path, &file, file_start))
+        .collect();
+    println!("{}", serde_json::to_string(&json).into_diagnostic()?);
+
+    Ok(has_errors)
+}
+
+fn diagnostic_to_json(
+    diagnostic: &(dyn miette::Diagnostic + Send + Sync),
+    path: &str,
+    file: &[u8],
+    file_start: usize,
+) -> serde_json::Value {
+    let severity = match diagnostic.severity() {
+        None | Some(Severity::Error) => "error",
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Advice) => "advice",
+    };
+
+    let labels: Vec<_> = diagnostic
+        .labels()
+        .into_iter()
+        .flatten()
+        .map(|label| {
+            // Spans count from the start of everything the engine has parsed, not of this file
+            let start = label.offset().saturating_sub(file_start).min(file.len());
+            let end = (start + label.len()).min(file.len());
+            let (line, column) = line_and_column(file, start);
+
+            serde_json::json!({
+                "message": label.label(),
+                "start": start,
+                "end": end,
+                "line": line,
+                "column": column,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "file": path,
+        "severity": severity,
+        "code": diagnostic.code().map(|code| code.to_string()),
+        "message": diagnostic.to_string(),
+        "help": diagnostic.help().map(|help| help.to_string()),
+        "labels": labels,
+    })
+}
+
+// One-based line and column of a byte offset, with the column counted in characters
+fn line_and_column(file: &[u8], offset: usize) -> (usize, usize) {
+    let before = String::from_utf8_lossy(&file[..offset]);
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map_or(0, |line| line.chars().count())
+        + 1;
+
+    (line, column)
+}
+
+fn report_parse_warnings(working_set: &StateWorkingSet) {
+    for warning in &working_set.parse_warnings {
+        eprintln!("Warning: {:?}", CliError(warning, working_set));
+    }
+}
+
 pub fn report_error(
     working_set: &StateWorkingSet,
     error: &(dyn miette::Diagnostic + Send + Sync + 'static),
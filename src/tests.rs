@@ -1,7 +1,11 @@
 use assert_cmd::prelude::*;
+use nu_protocol::Value;
 use pretty_assertions::assert_eq;
+#[cfg(feature = "network")]
+use std::io::Read;
 use std::io::Write;
 use std::process::Command;
+use std::time::Instant;
 use tempfile::NamedTempFile;
 
 type TestResult = Result<(), Box<dyn std::error::Error>>;
@@ -103,1187 +107,2968 @@ fn pow() -> TestResult {
 }
 
 #[test]
-fn contains() -> TestResult {
-    run_test("'testme' =~ 'test'", "true")
+fn filesize_comparison() -> TestResult {
+    run_test("2kb > 1kb", "true")
 }
 
 #[test]
-fn not_contains() -> TestResult {
-    run_test("'testme' !~ 'test'", "false")
+fn filesize_int_comparison() -> TestResult {
+    run_test("1kb == 1000", "true")
 }
 
 #[test]
-fn if_test1() -> TestResult {
-    run_test("if $true { 10 } else { 20 } ", "10")
+fn filesize_and_int_stay_different_values() -> TestResult {
+    run_test("[1kb 1000] | uniq | length", "2")
 }
 
 #[test]
-fn if_test2() -> TestResult {
-    run_test("if $false { 10 } else { 20 } ", "20")
+fn filesize_multiplication() -> TestResult {
+    run_test("(2kb * 3) == 6kb", "true")
 }
 
 #[test]
-fn simple_if() -> TestResult {
-    run_test("if $true { 10 } ", "10")
+fn filesize_division() -> TestResult {
+    run_test("(3kb / 2) == 1500b", "true")
 }
 
 #[test]
-fn simple_if2() -> TestResult {
-    run_test("if $false { 10 } ", "")
+fn filesize_ratio() -> TestResult {
+    run_test("3kb / 2kb", "1.5")
 }
 
 #[test]
-fn if_cond() -> TestResult {
-    run_test("if 2 < 3 { 3 } ", "3")
+fn filesize_multiplication_overflow() -> TestResult {
+    fail_test("1pb * 100000", "overflow")
 }
 
 #[test]
-fn if_cond2() -> TestResult {
-    run_test("if 2 > 3 { 3 } ", "")
+fn string_repetition() -> TestResult {
+    run_test("'ab' * 3", "ababab")
 }
 
 #[test]
-fn if_cond3() -> TestResult {
-    run_test("if 2 < 3 { 5 } else { 4 } ", "5")
+fn string_negative_repetition() -> TestResult {
+    fail_test("'ab' * -1", "negative")
 }
 
 #[test]
-fn if_cond4() -> TestResult {
-    run_test("if 2 > 3 { 5 } else { 4 } ", "4")
+fn list_repetition() -> TestResult {
+    run_test("[1 2] * 2 | str collect", "1212")
 }
 
 #[test]
-fn if_elseif1() -> TestResult {
-    run_test("if 2 > 3 { 5 } else if 6 < 7 { 4 } ", "4")
+fn repetition_overflow() -> TestResult {
+    fail_test("'ab' * 9223372036854775807", "overflowed")
 }
 
 #[test]
-fn if_elseif2() -> TestResult {
-    run_test("if 2 < 3 { 5 } else if 6 < 7 { 4 } else { 8 } ", "5")
+fn list_repetition_too_large() -> TestResult {
+    fail_test("[1] * 9223372036854775807", "doesn't fit in memory")
 }
 
 #[test]
-fn if_elseif3() -> TestResult {
-    run_test("if 2 > 3 { 5 } else if 6 > 7 { 4 } else { 8 } ", "8")
+fn list_append() -> TestResult {
+    run_test("[1 2] ++ [3] | length", "3")
 }
 
 #[test]
-fn if_elseif4() -> TestResult {
-    run_test("if 2 > 3 { 5 } else if 6 < 7 { 4 } else { 8 } ", "4")
+fn string_append() -> TestResult {
+    run_test("'nu' ++ 'shell'", "nushell")
 }
 
 #[test]
-fn no_scope_leak1() -> TestResult {
-    fail_test(
-        "if $false { let $x = 10 } else { let $x = 20 }; $x",
-        "Variable not found",
-    )
+fn unbounded_range_streams() -> TestResult {
+    run_test("1.. | first 3 | math sum", "6")
 }
 
 #[test]
-fn no_scope_leak2() -> TestResult {
-    fail_test(
-        "def foo [] { $x }; def bar [] { let $x = 10; foo }; bar",
-        "Variable not found",
-    )
+fn stepped_range_filter() -> TestResult {
+    run_test("[0 1 2 3 4 5] | range 0..2..5 | math sum", "6")
 }
 
 #[test]
-fn no_scope_leak3() -> TestResult {
+fn generate_until_nothing() -> TestResult {
     run_test(
-        "def foo [$x] { $x }; def bar [] { let $x = 10; foo 20}; bar",
-        "20",
+        "generate 0 {|i| if $i <= 10 { {out: $i, next: ($i + 2)} } } | math sum",
+        "30",
     )
 }
 
 #[test]
-fn no_scope_leak4() -> TestResult {
+fn generate_infinite_stream() -> TestResult {
     run_test(
-        "def foo [$x] { $x }; def bar [] { let $x = 10; (foo 20) + $x}; bar",
-        "30",
+        "generate [0 1] {|fib| {out: $fib.0, next: [$fib.1, ($fib.0 + $fib.1)]} } | first 5 | str collect ','",
+        "0,1,1,2,3",
     )
 }
 
 #[test]
-fn simple_var_closing() -> TestResult {
-    run_test("let $x = 10; def foo [] { $x }; foo", "10")
+fn retry_succeeds() -> TestResult {
+    run_test("retry --times 2 { 1 + 2 }", "3")
 }
 
 #[test]
-fn predecl_check() -> TestResult {
-    run_test("def bob [] { sam }; def sam [] { 3 }; bob", "3")
+fn retry_gives_up() -> TestResult {
+    fail_test("retry --times 2 { 1 / 0 }", "Failed after 2 attempts")?;
+    fail_test(
+        "retry --times 2 { 1 / 0 }",
+        "the last attempt failed with: Division by zero",
+    )
 }
 
 #[test]
-fn def_with_no_dollar() -> TestResult {
-    run_test("def bob [x] { $x + 3 }; bob 4", "7")
+fn timeout_finishes() -> TestResult {
+    run_test("timeout 1sec { 'done' }", "done")
 }
 
 #[test]
-fn env_shorthand() -> TestResult {
-    run_test("FOO=BAR if $false { 3 } else { 4 }", "4")
+fn timeout_expires() -> TestResult {
+    fail_test("timeout 10ms { sleep 1sec }", "timed out")
 }
 
 #[test]
-fn floating_add() -> TestResult {
-    run_test("10.1 + 0.8", "10.9")
+fn sys_mem_available() -> TestResult {
+    run_test("(sys).mem.available | describe", "filesize")
 }
 
 #[test]
-fn subcommand() -> TestResult {
-    run_test("def foo [] {}; def \"foo bar\" [] {3}; foo bar", "3")
+fn sys_host_uptime() -> TestResult {
+    run_test("sys host | get uptime | describe", "duration")
 }
 
 #[test]
-fn alias_1() -> TestResult {
-    run_test("def foo [$x] { $x + 10 }; alias f = foo; f 100", "110")
+fn sys_host_arch() -> TestResult {
+    run_test("sys host | get arch | describe", "string")
 }
 
 #[test]
-fn alias_2() -> TestResult {
+fn sys_host_fqdn_only_when_asked() -> TestResult {
+    run_test("sys host | columns | any? $it == fqdn", "false")?;
+    run_test("sys host --fqdn | get fqdn | describe", "string")
+}
+
+#[test]
+fn sys_net_rows_are_sorted_by_name() -> TestResult {
     run_test(
-        "def foo [$x $y] { $x + $y + 10 }; alias f = foo 33; f 100",
-        "143",
+        "let names = (sys net | get name | str collect ','); $names == (sys net | get name | sort | str collect ',')",
+        "true",
     )
 }
 
 #[test]
-fn alias_2_multi_word() -> TestResult {
+fn sys_net_table_shape() -> TestResult {
+    run_test("sys net | columns | str collect ','", "name,sent,recv")?;
     run_test(
-        r#"def "foo bar" [$x $y] { $x + $y + 10 }; alias f = foo bar 33; f 100"#,
-        "143",
+        "sys net --watch 10ms | first | columns | str collect ','",
+        "name,sent,recv,sent rate,recv rate",
     )
 }
 
 #[test]
-fn block_param1() -> TestResult {
-    run_test("[3] | each { $it + 10 } | get 0", "13")
+fn sys_users_is_table() -> TestResult {
+    run_test("sys users | length | describe", "int")
 }
 
 #[test]
-fn block_param2() -> TestResult {
-    run_test("[3] | each { |y| $y + 10 } | get 0", "13")
+fn sys_disks_usage_columns() -> TestResult {
+    run_test(
+        "sys disks --usage | each { |d| $d.used + $d.available == $d.total } | all? $it",
+        "true",
+    )
 }
 
+#[cfg(unix)]
 #[test]
-fn block_param3_list_iteration() -> TestResult {
-    run_test("[1,2,3] | each { $it + 10 } | get 1", "12")
+fn ulimit_lists_resource() -> TestResult {
+    run_test("ulimit nofile | get resource.0", "nofile")
 }
 
+#[cfg(unix)]
 #[test]
-fn block_param4_list_iteration() -> TestResult {
-    run_test("[1,2,3] | each { |y| $y + 10 } | get 2", "13")
+fn ulimit_sets_soft_limit() -> TestResult {
+    run_test("ulimit --soft core 0; ulimit core | get soft.0", "0 B")
 }
 
+#[cfg(unix)]
 #[test]
-fn range_iteration1() -> TestResult {
-    run_test("1..4 | each { |y| $y + 10 } | get 0", "11")
+fn ulimit_unknown_resource() -> TestResult {
+    fail_test("ulimit nothing_like_it", "Unknown resource")
 }
 
 #[test]
-fn range_iteration2() -> TestResult {
-    run_test("4..1 | each { |y| $y + 100 } | get 3", "101")
+fn ps_lists_processes_as_table() -> TestResult {
+    run_test("ps --long | first | get command | describe", "string")
 }
 
 #[test]
-fn simple_value_iteration() -> TestResult {
-    run_test("4 | each { $it + 10 }", "14")
+fn job_spawn_and_wait() -> TestResult {
+    run_test("let id = (job spawn { 1 + 2 }); job wait $id", "3")
 }
 
 #[test]
-fn concrete_variable_assignment() -> TestResult {
+fn job_wait_returns_error() -> TestResult {
+    fail_test("job wait (job spawn { 1 / 0 })", "division by zero")
+}
+
+#[test]
+fn job_list_forgets_waited_jobs() -> TestResult {
     run_test(
-        "let x = (1..100 | each { |y| $y + 100 }); $x | length; $x | length",
-        "100",
+        "let id = (job spawn { 'done' }); job wait $id; job list | length",
+        "0",
     )
 }
 
 #[test]
-fn build_string1() -> TestResult {
-    run_test("build-string 'nu' 'shell'", "nushell")
+fn job_kill_missing_job() -> TestResult {
+    fail_test("job kill 42", "no job with id 42")
 }
 
 #[test]
-fn build_string2() -> TestResult {
-    run_test("'nu' | each {build-string $it 'shell'}", "nushell")
+fn every_times() -> TestResult {
+    run_test("every 1ms --times 3 { 2 } | math sum", "6")
 }
 
 #[test]
-fn build_string3() -> TestResult {
+fn every_unbounded_stream() -> TestResult {
+    run_test("every 1ms { 'tick' } | first 2 | length", "2")
+}
+
+#[test]
+fn commandline_replace_and_insert() -> TestResult {
     run_test(
-        "build-string 'nu' 'shell' | each {build-string $it ' rocks'}",
-        "nushell rocks",
+        "commandline 'ls'; commandline --cursor 0; commandline --insert 'x'; commandline",
+        "xls",
     )
 }
 
 #[test]
-fn build_string4() -> TestResult {
+fn commandline_set_buffer_and_cursor() -> TestResult {
     run_test(
-        "['sam','rick','pete'] | each { build-string $it ' is studying'} | get 2",
-        "pete is studying",
+        "commandline 'hello' --cursor 2; commandline --insert '-'; commandline",
+        "he-llo",
     )
 }
 
 #[test]
-fn build_string5() -> TestResult {
+fn commandline_negative_cursor() -> TestResult {
+    fail_test("commandline --cursor -1", "non-negative")
+}
+
+#[test]
+fn commandline_append_cursor() -> TestResult {
     run_test(
-        "['sam','rick','pete'] | each { |x| build-string $x ' is studying'} | get 1",
-        "rick is studying",
+        "commandline 'ls'; commandline --append ' -a'; commandline --get-cursor",
+        "2",
     )
 }
 
+#[cfg(feature = "sqlite")]
 #[test]
-fn cell_path_subexpr1() -> TestResult {
-    run_test("([[lang, gems]; [nu, 100]]).lang | get 0", "nu")
+fn stor_insert_and_query() -> TestResult {
+    run_test(
+        "stor create -t nums -c {n: int}; [[n]; [1] [2] [3]] | stor insert -t nums; stor query 'select sum(n) as total from nums' | get total.0",
+        "6",
+    )
 }
 
+#[cfg(feature = "sqlite")]
 #[test]
-fn cell_path_subexpr2() -> TestResult {
-    run_test("([[lang, gems]; [nu, 100]]).lang.0", "nu")
+fn stor_bad_column_type() -> TestResult {
+    fail_test("stor create -t nums -c {n: foo}", "unsupported column type")
 }
 
 #[test]
-fn cell_path_var1() -> TestResult {
-    run_test("let x = [[lang, gems]; [nu, 100]]; $x.lang | get 0", "nu")
+fn random_seed_is_reproducible() -> TestResult {
+    run_test(
+        "(random float 0..100 --seed 7) == (random float 0..100 --seed 7)",
+        "true",
+    )
 }
 
 #[test]
-fn cell_path_var2() -> TestResult {
-    run_test("let x = [[lang, gems]; [nu, 100]]; $x.lang.0", "nu")
+fn random_float_rejects_too_wide_ranges() -> TestResult {
+    fail_test(r#"random decimal -1.7e308..1.7e308"#, "too wide")
 }
 
 #[test]
-fn custom_rest_var() -> TestResult {
-    run_test("def foo [...x] { $x.0 + $x.1 }; foo 10 80", "90")
+fn random_binary_type() -> TestResult {
+    run_test("random binary 12 | describe", "binary")
 }
 
 #[test]
-fn row_iteration() -> TestResult {
-    run_test(
-        "[[name, size]; [tj, 100], [rl, 200]] | each { $it.size * 8 } | get 1",
-        "1600",
+fn random_binary_too_large() -> TestResult {
+    fail_test(
+        "random binary 9223372036854775807",
+        "the length doesn't fit in memory",
     )
 }
 
 #[test]
-fn record_iteration() -> TestResult {
-    run_test("([[name, level]; [aa, 100], [bb, 200]] | each { $it | each { |x| if $x.column == \"level\" { $x.value + 100 } else { $x.value } } }).level | get 1", "300")
+fn json_binary_round_trip() -> TestResult {
+    run_test(
+        "'hello' | into binary | to json -t | from json -t | to json -t | from json -t | describe",
+        "binary",
+    )
 }
 
 #[test]
-fn row_condition1() -> TestResult {
+fn json_special_floats_round_trip() -> TestResult {
     run_test(
-        "([[name, size]; [a, 1], [b, 2], [c, 3]] | where size < 3).name | get 1",
-        "b",
+        "['inf' '-inf'] | into decimal | to json -t | from json -t | each { $it > 0 } | to json",
+        "[\n  true,\n  false\n]",
     )
 }
 
 #[test]
-fn row_condition2() -> TestResult {
-    run_test(
-        "[[name, size]; [a, 1], [b, 2], [c, 3]] | where $it.size > 2 | length",
-        "1",
-    )
+fn json_rejects_special_floats() -> TestResult {
+    fail_test("'NaN' | into decimal | to json", "--tagged")
 }
 
 #[test]
-fn better_block_types() -> TestResult {
+fn json_tags_are_data_unless_asked() -> TestResult {
     run_test(
-        r#"([1, 2, 3] | each -n { $"($it.index) is ($it.item)" }).1"#,
-        "1 is 2",
+        r#"'{"$binary": "AQL/"}' | from json | get "$binary""#,
+        "AQL/",
     )
 }
 
 #[test]
-fn module_def_imports_1() -> TestResult {
+fn version_lists_features() -> TestResult {
+    run_test("(version).features | any? $it == default", "true")
+}
+
+#[test]
+fn to_xml_round_trip() -> TestResult {
     run_test(
-        r#"module foo { export def a [] { 1 }; def b [] { 2 } }; use foo; foo a"#,
-        "1",
+        r#"'<a x="1"><b>t</b></a>' | from xml | update a.attributes.x 2 | to xml"#,
+        r#"<a x="2"><b>t</b></a>"#,
     )
 }
 
 #[test]
-fn module_def_imports_2() -> TestResult {
+fn to_xml_rejects_negative_indent() -> TestResult {
+    fail_test(r#"'<a></a>' | from xml | to xml --pretty -1"#, "negative")
+}
+
+#[cfg(unix)]
+#[test]
+fn ls_ignore_errors_warns_once() -> TestResult {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir()?;
+    let locked = dir.path().join("locked");
+    std::fs::create_dir(&locked)?;
+    std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000))?;
+
+    // Permissions don't stop root, so there would be nothing to warn about
+    let output = if std::fs::read_dir(&locked).is_err() {
+        let mut file = NamedTempFile::new()?;
+        writeln!(
+            file,
+            r#"ls --ignore-errors "{}/**/*" | length"#,
+            dir.path().display()
+        )?;
+
+        Some(Command::cargo_bin("engine-q")?.arg(file.path()).output()?)
+    } else {
+        None
+    };
+
+    std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o755))?;
+
+    if let Some(output) = output {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        println!("stderr: {}", stderr);
+
+        assert!(output.status.success());
+        assert_eq!(stderr.matches("Could not read").count(), 1);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn time_startup_reports_each_phase() -> TestResult {
+    use std::io::{BufRead, BufReader};
+    use std::process::Stdio;
+
+    // Keeps the config of the user from being loaded
+    let config_dir = tempfile::tempdir()?;
+
+    let mut child = Command::cargo_bin("engine-q")?
+        .arg("--time-startup")
+        .env("XDG_CONFIG_HOME", config_dir.path())
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // The prompt comes after the report, so the shell is stopped once the total is read
+    let stdout = child.stdout.take().ok_or("no stdout")?;
+    let mut report = String::new();
+    for line in BufReader::new(stdout).lines().take(100) {
+        let line = line?;
+        report.push_str(&line);
+        report.push('\n');
+
+        if line.contains("total") {
+            break;
+        }
+    }
+
+    child.kill()?;
+    child.wait()?;
+    println!("report: {}", report);
+
+    for phase in [
+        "register commands",
+        "gather environment",
+        "convert environment",
+        "total",
+    ] {
+        assert!(
+            report.contains(phase),
+            "{} is missing from the report",
+            phase
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn save_uses_extension_format() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("data.json");
+
     run_test(
-        r#"module foo { export def a [] { 1 }; def b [] { 2 } }; use foo a; a"#,
-        "1",
+        &format!(
+            r#"{{a: 1, b: [x y]}} | save "{0}"; open "{0}" | get b.1"#,
+            path.display()
+        ),
+        "y",
     )
 }
 
 #[test]
-fn module_def_imports_3() -> TestResult {
+fn save_append() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("log.txt");
+
     run_test(
-        r#"module foo { export def a [] { 1 }; export def b [] { 2 } }; use foo *; b"#,
-        "2",
+        &format!(
+            r#""a" | save "{0}"; "b" | save --append "{0}"; open "{0}""#,
+            path.display()
+        ),
+        "ab",
     )
 }
 
 #[test]
-fn module_def_imports_4() -> TestResult {
+fn save_raw_rejects_table() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("data.json");
+
     fail_test(
-        r#"module foo { export def a [] { 1 }; export def b [] { 2 } }; use foo c"#,
-        "not find import",
+        &format!(r#"[[a]; [1]] | save --raw "{}""#, path.display()),
+        "convert it to text first",
     )
 }
 
 #[test]
-fn module_def_imports_5() -> TestResult {
+fn save_backup() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("notes.txt");
+
     run_test(
-        r#"module foo { export def a [] { 1 }; def b [] { '2' }; export def c [] { '3' } }; use foo [a, c]; c"#,
-        "3",
+        &format!(
+            r#""a" | save "{0}"; "b" | save --backup .bak "{0}"; open "{0}.bak""#,
+            path.display()
+        ),
+        "a",
     )
 }
 
 #[test]
-fn module_env_imports_1() -> TestResult {
+fn save_read_only_needs_force() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("locked.txt");
+
+    std::fs::write(&path, "a")?;
+    let mut permissions = std::fs::metadata(&path)?.permissions();
+    permissions.set_readonly(true);
+    std::fs::set_permissions(&path, permissions)?;
+
+    fail_test(&format!(r#""b" | save "{}""#, path.display()), "read-only")?;
     run_test(
-        r#"module foo { export env a { '1' } }; use foo; $nu.env.'foo a'"#,
-        "1",
+        &format!(r#""b" | save --force "{0}"; open "{0}""#, path.display()),
+        "b",
     )
 }
 
+#[cfg(unix)]
 #[test]
-fn module_env_imports_2() -> TestResult {
+fn save_force_keeps_the_file_read_only() -> TestResult {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("locked.txt");
+
+    std::fs::write(&path, "a")?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o444))?;
+
     run_test(
-        r#"module foo { export env a { '1' } }; use foo a; $nu.env.a"#,
-        "1",
-    )
+        &format!(
+            r#""b" | save --force "{0}"; "c" | save --force --append "{0}"; open "{0}""#,
+            path.display()
+        ),
+        "bc",
+    )?;
+
+    let mode = std::fs::metadata(&path)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o444);
+
+    Ok(())
 }
 
 #[test]
-fn module_env_imports_3() -> TestResult {
+fn int_overflow_wrapping_config() -> TestResult {
     run_test(
-        r#"module foo { export env a { '1' }; export env b { '2' } }; use foo *; $nu.env.b"#,
-        "2",
+        r#"do { let config = ($config | merge {int_overflow: wrapping}); 9223372036854775807 + 1 }"#,
+        "-9223372036854775808",
     )
 }
 
 #[test]
-fn module_env_imports_4() -> TestResult {
-    fail_test(
-        r#"module foo { export env a { '1' }; export env b { '2' } }; use foo c"#,
-        "not find import",
-    )
+fn int_overflow_checked_by_default() -> TestResult {
+    fail_test(r#"9223372036854775807 + 1"#, "overflow")
 }
 
 #[test]
-fn module_env_imports_5() -> TestResult {
+fn math_saturating_mul() -> TestResult {
     run_test(
-        r#"module foo { export env a { '1' }; export env b { '2' }; export env c { '3' } }; use foo [a, c]; $nu.env.c"#,
-        "3",
+        r#"4611686018427387904 | math saturating-mul 4"#,
+        "9223372036854775807",
     )
 }
 
 #[test]
-fn module_def_and_env_imports_1() -> TestResult {
+fn from_csv_custom_quote() -> TestResult {
     run_test(
-        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; $nu.env.foo"#,
-        "foo",
+        r#"["a,b" "'1,2',3"] | str collect (char nl) | from csv --quote "'" | get a.0"#,
+        "1,2",
     )
 }
 
 #[test]
-fn module_def_and_env_imports_2() -> TestResult {
+fn from_csv_streamed_lines() -> TestResult {
     run_test(
-        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; foo"#,
-        "bar",
+        r#"["a,b" "1,2"] | each {|l| $l + (char nl) } | from csv | get b.0"#,
+        "2",
     )
 }
 
 #[test]
-fn module_def_import_uses_internal_command() -> TestResult {
+fn yaml_roundtrip_keeps_column_order() -> TestResult {
     run_test(
-        r#"module foo { def b [] { 2 }; export def a [] { b }  }; use foo; foo a"#,
-        "2",
+        r#"let x = {z: 1, a: [1 2], m: {y: true, b: 'c'}}; ($x | to yaml | from yaml | to yaml) == ($x | to yaml)"#,
+        "true",
     )
 }
 
 #[test]
-fn module_env_import_uses_internal_command() -> TestResult {
+fn yaml_multi_documents() -> TestResult {
     run_test(
-        r#"module foo { def b [] { "2" }; export env a { b }  }; use foo; $nu.env.'foo a'"#,
+        r#"[{a: 1} {a: 2}] | to yaml --multi | from yaml --multi | get a.1"#,
         "2",
     )
 }
 
-// TODO: Test the use/hide tests also as separate lines in REPL (i.e., with  merging the delta in between)
-#[test]
-fn hides_def() -> TestResult {
-    fail_test(r#"def foo [] { "foo" }; hide foo; foo"#, not_found_msg())
-}
-
 #[test]
-fn hides_env() -> TestResult {
+fn yaml_int_too_large() -> TestResult {
     fail_test(
-        r#"let-env foo = "foo"; hide foo; $nu.env.foo"#,
-        "did you mean",
+        "'a: 18446744073709551615' | from yaml",
+        "too large for an int",
     )
 }
 
 #[test]
-fn hides_def_then_redefines() -> TestResult {
-    // this one should fail because of predecl -- cannot have more defs with the same name in a
-    // block
-    fail_test(
-        r#"def foo [] { "foo" }; hide foo; def foo [] { "bar" }; foo"#,
-        "defined more than once",
+fn partition_matched_and_rest() -> TestResult {
+    run_test(
+        r#"let parts = ([1 2 3 4 5] | partition $it > 3); ($parts.matched | math sum) - ($parts.rest | math sum)"#,
+        "3",
+    )
+}
+
+#[test]
+fn group_streamed_input() -> TestResult {
+    run_test(r#"1..7 | each { $it } | group 3 | get 2.0"#, "7")
+}
+
+#[test]
+fn ide_check_reports_parse_errors_as_json() -> TestResult {
+    let mut file = NamedTempFile::new()?;
+    writeln!(file, "let x = 1")?;
+    writeln!(file, "ls | where size >")?;
+
+    let output = Command::cargo_bin("engine-q")?
+        .arg("--ide-check")
+        .arg(file.path())
+        .output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    println!("stdout: {}", stdout);
+
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_str(&stdout)?;
+    assert_eq!(json[0]["code"], "nu::parser::incomplete_math_expression");
+    assert_eq!(json[0]["labels"][0]["line"], 2);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn ide_check_escapes_quotes_and_backslashes() -> TestResult {
+    let mut file = tempfile::Builder::new()
+        .prefix(r#"quote"and\backslash"#)
+        .tempfile()?;
+    writeln!(file, "ls | where size >")?;
+
+    let output = Command::cargo_bin("engine-q")?
+        .arg("--ide-check")
+        .arg(file.path())
+        .output()?;
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json[0]["file"], file.path().to_string_lossy().as_ref());
+
+    Ok(())
+}
+
+#[test]
+fn ide_check_does_not_run_the_file() -> TestResult {
+    let mut file = NamedTempFile::new()?;
+    writeln!(file, "echo ran")?;
+
+    let output = Command::cargo_bin("engine-q")?
+        .arg("--ide-check")
+        .arg(file.path())
+        .output()?;
+
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "[]");
+
+    Ok(())
+}
+
+#[test]
+fn contains() -> TestResult {
+    run_test("'testme' =~ 'test'", "true")
+}
+
+#[test]
+fn not_contains() -> TestResult {
+    run_test("'testme' !~ 'test'", "false")
+}
+
+#[test]
+fn if_test1() -> TestResult {
+    run_test("if $true { 10 } else { 20 } ", "10")
+}
+
+#[test]
+fn if_test2() -> TestResult {
+    run_test("if $false { 10 } else { 20 } ", "20")
+}
+
+#[test]
+fn simple_if() -> TestResult {
+    run_test("if $true { 10 } ", "10")
+}
+
+#[test]
+fn simple_if2() -> TestResult {
+    run_test("if $false { 10 } ", "")
+}
+
+#[test]
+fn if_cond() -> TestResult {
+    run_test("if 2 < 3 { 3 } ", "3")
+}
+
+#[test]
+fn if_cond2() -> TestResult {
+    run_test("if 2 > 3 { 3 } ", "")
+}
+
+#[test]
+fn if_cond3() -> TestResult {
+    run_test("if 2 < 3 { 5 } else { 4 } ", "5")
+}
+
+#[test]
+fn if_cond4() -> TestResult {
+    run_test("if 2 > 3 { 5 } else { 4 } ", "4")
+}
+
+#[test]
+fn if_elseif1() -> TestResult {
+    run_test("if 2 > 3 { 5 } else if 6 < 7 { 4 } ", "4")
+}
+
+#[test]
+fn if_elseif2() -> TestResult {
+    run_test("if 2 < 3 { 5 } else if 6 < 7 { 4 } else { 8 } ", "5")
+}
+
+#[test]
+fn if_elseif3() -> TestResult {
+    run_test("if 2 > 3 { 5 } else if 6 > 7 { 4 } else { 8 } ", "8")
+}
+
+#[test]
+fn if_elseif4() -> TestResult {
+    run_test("if 2 > 3 { 5 } else if 6 < 7 { 4 } else { 8 } ", "4")
+}
+
+#[test]
+fn no_scope_leak1() -> TestResult {
+    fail_test(
+        "if $false { let $x = 10 } else { let $x = 20 }; $x",
+        "Variable not found",
+    )
+}
+
+#[test]
+fn no_scope_leak2() -> TestResult {
+    fail_test(
+        "def foo [] { $x }; def bar [] { let $x = 10; foo }; bar",
+        "Variable not found",
+    )
+}
+
+#[test]
+fn no_scope_leak3() -> TestResult {
+    run_test(
+        "def foo [$x] { $x }; def bar [] { let $x = 10; foo 20}; bar",
+        "20",
+    )
+}
+
+#[test]
+fn no_scope_leak4() -> TestResult {
+    run_test(
+        "def foo [$x] { $x }; def bar [] { let $x = 10; (foo 20) + $x}; bar",
+        "30",
+    )
+}
+
+#[test]
+fn simple_var_closing() -> TestResult {
+    run_test("let $x = 10; def foo [] { $x }; foo", "10")
+}
+
+#[test]
+fn predecl_check() -> TestResult {
+    run_test("def bob [] { sam }; def sam [] { 3 }; bob", "3")
+}
+
+#[test]
+fn def_with_no_dollar() -> TestResult {
+    run_test("def bob [x] { $x + 3 }; bob 4", "7")
+}
+
+#[test]
+fn env_shorthand() -> TestResult {
+    run_test("FOO=BAR if $false { 3 } else { 4 }", "4")
+}
+
+#[test]
+fn floating_add() -> TestResult {
+    run_test("10.1 + 0.8", "10.9")
+}
+
+#[test]
+fn subcommand() -> TestResult {
+    run_test("def foo [] {}; def \"foo bar\" [] {3}; foo bar", "3")
+}
+
+#[test]
+fn alias_1() -> TestResult {
+    run_test("def foo [$x] { $x + 10 }; alias f = foo; f 100", "110")
+}
+
+#[test]
+fn alias_2() -> TestResult {
+    run_test(
+        "def foo [$x $y] { $x + $y + 10 }; alias f = foo 33; f 100",
+        "143",
+    )
+}
+
+#[test]
+fn alias_2_multi_word() -> TestResult {
+    run_test(
+        r#"def "foo bar" [$x $y] { $x + $y + 10 }; alias f = foo bar 33; f 100"#,
+        "143",
+    )
+}
+
+#[test]
+fn block_param1() -> TestResult {
+    run_test("[3] | each { $it + 10 } | get 0", "13")
+}
+
+#[test]
+fn block_param2() -> TestResult {
+    run_test("[3] | each { |y| $y + 10 } | get 0", "13")
+}
+
+#[test]
+fn block_param3_list_iteration() -> TestResult {
+    run_test("[1,2,3] | each { $it + 10 } | get 1", "12")
+}
+
+#[test]
+fn block_param4_list_iteration() -> TestResult {
+    run_test("[1,2,3] | each { |y| $y + 10 } | get 2", "13")
+}
+
+#[test]
+fn range_iteration1() -> TestResult {
+    run_test("1..4 | each { |y| $y + 10 } | get 0", "11")
+}
+
+#[test]
+fn range_iteration2() -> TestResult {
+    run_test("4..1 | each { |y| $y + 100 } | get 3", "101")
+}
+
+#[test]
+fn simple_value_iteration() -> TestResult {
+    run_test("4 | each { $it + 10 }", "14")
+}
+
+#[test]
+fn concrete_variable_assignment() -> TestResult {
+    run_test(
+        "let x = (1..100 | each { |y| $y + 100 }); $x | length; $x | length",
+        "100",
+    )
+}
+
+#[test]
+fn build_string1() -> TestResult {
+    run_test("build-string 'nu' 'shell'", "nushell")
+}
+
+#[test]
+fn build_string2() -> TestResult {
+    run_test("'nu' | each {build-string $it 'shell'}", "nushell")
+}
+
+#[test]
+fn build_string3() -> TestResult {
+    run_test(
+        "build-string 'nu' 'shell' | each {build-string $it ' rocks'}",
+        "nushell rocks",
+    )
+}
+
+#[test]
+fn build_string4() -> TestResult {
+    run_test(
+        "['sam','rick','pete'] | each { build-string $it ' is studying'} | get 2",
+        "pete is studying",
+    )
+}
+
+#[test]
+fn build_string5() -> TestResult {
+    run_test(
+        "['sam','rick','pete'] | each { |x| build-string $x ' is studying'} | get 1",
+        "rick is studying",
+    )
+}
+
+#[test]
+fn cell_path_subexpr1() -> TestResult {
+    run_test("([[lang, gems]; [nu, 100]]).lang | get 0", "nu")
+}
+
+#[test]
+fn cell_path_subexpr2() -> TestResult {
+    run_test("([[lang, gems]; [nu, 100]]).lang.0", "nu")
+}
+
+#[test]
+fn cell_path_var1() -> TestResult {
+    run_test("let x = [[lang, gems]; [nu, 100]]; $x.lang | get 0", "nu")
+}
+
+#[test]
+fn cell_path_var2() -> TestResult {
+    run_test("let x = [[lang, gems]; [nu, 100]]; $x.lang.0", "nu")
+}
+
+#[test]
+fn custom_rest_var() -> TestResult {
+    run_test("def foo [...x] { $x.0 + $x.1 }; foo 10 80", "90")
+}
+
+#[test]
+fn row_iteration() -> TestResult {
+    run_test(
+        "[[name, size]; [tj, 100], [rl, 200]] | each { $it.size * 8 } | get 1",
+        "1600",
+    )
+}
+
+#[test]
+fn record_iteration() -> TestResult {
+    run_test("([[name, level]; [aa, 100], [bb, 200]] | each { $it | each { |x| if $x.column == \"level\" { $x.value + 100 } else { $x.value } } }).level | get 1", "300")
+}
+
+#[test]
+fn row_condition1() -> TestResult {
+    run_test(
+        "([[name, size]; [a, 1], [b, 2], [c, 3]] | where size < 3).name | get 1",
+        "b",
+    )
+}
+
+#[test]
+fn row_condition2() -> TestResult {
+    run_test(
+        "[[name, size]; [a, 1], [b, 2], [c, 3]] | where $it.size > 2 | length",
+        "1",
+    )
+}
+
+#[test]
+fn better_block_types() -> TestResult {
+    run_test(
+        r#"([1, 2, 3] | each -n { $"($it.index) is ($it.item)" }).1"#,
+        "1 is 2",
+    )
+}
+
+#[test]
+fn module_def_imports_1() -> TestResult {
+    run_test(
+        r#"module foo { export def a [] { 1 }; def b [] { 2 } }; use foo; foo a"#,
+        "1",
+    )
+}
+
+#[test]
+fn module_def_imports_2() -> TestResult {
+    run_test(
+        r#"module foo { export def a [] { 1 }; def b [] { 2 } }; use foo a; a"#,
+        "1",
+    )
+}
+
+#[test]
+fn module_def_imports_3() -> TestResult {
+    run_test(
+        r#"module foo { export def a [] { 1 }; export def b [] { 2 } }; use foo *; b"#,
+        "2",
+    )
+}
+
+#[test]
+fn module_def_imports_4() -> TestResult {
+    fail_test(
+        r#"module foo { export def a [] { 1 }; export def b [] { 2 } }; use foo c"#,
+        "not find import",
+    )
+}
+
+#[test]
+fn module_def_imports_5() -> TestResult {
+    run_test(
+        r#"module foo { export def a [] { 1 }; def b [] { '2' }; export def c [] { '3' } }; use foo [a, c]; c"#,
+        "3",
+    )
+}
+
+#[test]
+fn module_env_imports_1() -> TestResult {
+    run_test(
+        r#"module foo { export env a { '1' } }; use foo; $nu.env.'foo a'"#,
+        "1",
+    )
+}
+
+#[test]
+fn module_env_imports_2() -> TestResult {
+    run_test(
+        r#"module foo { export env a { '1' } }; use foo a; $nu.env.a"#,
+        "1",
+    )
+}
+
+#[test]
+fn module_env_imports_3() -> TestResult {
+    run_test(
+        r#"module foo { export env a { '1' }; export env b { '2' } }; use foo *; $nu.env.b"#,
+        "2",
+    )
+}
+
+#[test]
+fn module_env_imports_4() -> TestResult {
+    fail_test(
+        r#"module foo { export env a { '1' }; export env b { '2' } }; use foo c"#,
+        "not find import",
+    )
+}
+
+#[test]
+fn module_env_imports_5() -> TestResult {
+    run_test(
+        r#"module foo { export env a { '1' }; export env b { '2' }; export env c { '3' } }; use foo [a, c]; $nu.env.c"#,
+        "3",
+    )
+}
+
+#[test]
+fn module_def_and_env_imports_1() -> TestResult {
+    run_test(
+        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; $nu.env.foo"#,
+        "foo",
+    )
+}
+
+#[test]
+fn module_def_and_env_imports_2() -> TestResult {
+    run_test(
+        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; foo"#,
+        "bar",
+    )
+}
+
+#[test]
+fn module_def_import_uses_internal_command() -> TestResult {
+    run_test(
+        r#"module foo { def b [] { 2 }; export def a [] { b }  }; use foo; foo a"#,
+        "2",
+    )
+}
+
+#[test]
+fn module_env_import_uses_internal_command() -> TestResult {
+    run_test(
+        r#"module foo { def b [] { "2" }; export env a { b }  }; use foo; $nu.env.'foo a'"#,
+        "2",
+    )
+}
+
+// TODO: Test the use/hide tests also as separate lines in REPL (i.e., with  merging the delta in between)
+#[test]
+fn hides_def() -> TestResult {
+    fail_test(r#"def foo [] { "foo" }; hide foo; foo"#, not_found_msg())
+}
+
+#[test]
+fn hides_env() -> TestResult {
+    fail_test(
+        r#"let-env foo = "foo"; hide foo; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_def_then_redefines() -> TestResult {
+    // this one should fail because of predecl -- cannot have more defs with the same name in a
+    // block
+    fail_test(
+        r#"def foo [] { "foo" }; hide foo; def foo [] { "bar" }; foo"#,
+        "defined more than once",
     )
 }
 
 #[test]
 fn hides_env_then_redefines() -> TestResult {
     run_test(
-        r#"let-env foo = "foo"; hide foo; let-env foo = "bar"; $nu.env.foo"#,
+        r#"let-env foo = "foo"; hide foo; let-env foo = "bar"; $nu.env.foo"#,
+        "bar",
+    )
+}
+
+#[test]
+fn hides_def_in_scope_1() -> TestResult {
+    fail_test(
+        r#"def foo [] { "foo" }; do { hide foo; foo }"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_in_scope_2() -> TestResult {
+    run_test(
+        r#"def foo [] { "foo" }; do { def foo [] { "bar" }; hide foo; foo }"#,
+        "foo",
+    )
+}
+
+#[test]
+fn hides_def_in_scope_3() -> TestResult {
+    fail_test(
+        r#"def foo [] { "foo" }; do { hide foo; def foo [] { "bar" }; hide foo; foo }"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_in_scope_4() -> TestResult {
+    fail_test(
+        r#"def foo [] { "foo" }; do { def foo [] { "bar" }; hide foo; hide foo; foo }"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_env_in_scope_1() -> TestResult {
+    fail_test(
+        r#"let-env foo = "foo"; do { hide foo; $nu.env.foo }"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_in_scope_2() -> TestResult {
+    run_test(
+        r#"let-env foo = "foo"; do { let-env foo = "bar"; hide foo; $nu.env.foo }"#,
+        "foo",
+    )
+}
+
+#[test]
+fn hides_env_in_scope_3() -> TestResult {
+    fail_test(
+        r#"let-env foo = "foo"; do { hide foo; let-env foo = "bar"; hide foo; $nu.env.foo }"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_in_scope_4() -> TestResult {
+    fail_test(
+        r#"let-env foo = "foo"; do { let-env foo = "bar"; hide foo; hide foo; $nu.env.foo }"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hide_def_twice_not_allowed() -> TestResult {
+    fail_test(
+        r#"def foo [] { "foo" }; hide foo; hide foo"#,
+        "did not find",
+    )
+}
+
+#[test]
+fn hide_env_twice_not_allowed() -> TestResult {
+    fail_test(r#"let-env foo = "foo"; hide foo; hide foo"#, "did not find")
+}
+
+#[test]
+fn hides_def_runs_env_1() -> TestResult {
+    run_test(
+        r#"let-env foo = "bar"; def foo [] { "foo" }; hide foo; $nu.env.foo"#,
+        "bar",
+    )
+}
+
+#[test]
+fn hides_def_runs_env_2() -> TestResult {
+    run_test(
+        r#"def foo [] { "foo" }; let-env foo = "bar"; hide foo; $nu.env.foo"#,
+        "bar",
+    )
+}
+
+#[test]
+fn hides_def_and_env() -> TestResult {
+    fail_test(
+        r#"let-env foo = "bar"; def foo [] { "foo" }; hide foo; hide foo; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_def_import_1() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam; hide spam foo; spam foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_import_2() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam; hide spam; spam foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_import_3() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam; hide spam [foo]; spam foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_import_4() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam foo; hide foo; foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_import_5() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam *; hide foo; foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_def_import_6() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam *; hide spam *; foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_env_import_1() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" } }; use spam; hide spam foo; $nu.env.'spam foo'"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_import_2() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" } }; use spam; hide spam; $nu.env.'spam foo'"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_import_3() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" } }; use spam; hide spam [foo]; $nu.env.'spam foo'"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_import_4() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" } }; use spam foo; hide foo; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_import_5() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" } }; use spam *; hide foo; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_env_import_6() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" } }; use spam *; hide spam *; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_def_runs_env_import() -> TestResult {
+    run_test(
+        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; hide foo; $nu.env.foo"#,
+        "foo",
+    )
+}
+
+#[test]
+fn hides_def_and_env_import_1() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; hide foo; hide foo; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn hides_def_and_env_import_2() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; hide foo; hide foo; foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn def_twice_should_fail() -> TestResult {
+    fail_test(
+        r#"def foo [] { "foo" }; def foo [] { "bar" }"#,
+        "defined more than once",
+    )
+}
+
+#[test]
+fn use_def_import_after_hide() -> TestResult {
+    run_test(
+        r#"module spam { export def foo [] { "foo" } }; use spam foo; hide foo; use spam foo; foo"#,
+        "foo",
+    )
+}
+
+#[test]
+fn use_env_import_after_hide() -> TestResult {
+    run_test(
+        r#"module spam { export env foo { "foo" } }; use spam foo; hide foo; use spam foo; $nu.env.foo"#,
+        "foo",
+    )
+}
+
+#[test]
+fn hide_shadowed_decl() -> TestResult {
+    run_test(
+        r#"module spam { export def foo [] { "bar" } }; def foo [] { "foo" }; do { use spam foo; hide foo; foo }"#,
+        "foo",
+    )
+}
+
+#[test]
+fn hide_shadowed_env() -> TestResult {
+    run_test(
+        r#"module spam { export env foo { "bar" } }; let-env foo = "foo"; do { use spam foo; hide foo; $nu.env.foo }"#,
+        "foo",
+    )
+}
+
+#[test]
+fn hides_all_decls_within_scope() -> TestResult {
+    fail_test(
+        r#"module spam { export def foo [] { "bar" } }; def foo [] { "foo" }; use spam foo; hide foo; foo"#,
+        not_found_msg(),
+    )
+}
+
+#[test]
+fn hides_all_envs_within_scope() -> TestResult {
+    fail_test(
+        r#"module spam { export env foo { "bar" } }; let-env foo = "foo"; use spam foo; hide foo; $nu.env.foo"#,
+        "did you mean",
+    )
+}
+
+#[test]
+fn from_json_1() -> TestResult {
+    run_test(r#"('{"name": "Fred"}' | from json).name"#, "Fred")
+}
+
+#[test]
+fn from_json_2() -> TestResult {
+    run_test(
+        r#"('{"name": "Fred"}
+                   {"name": "Sally"}' | from json -o).name.1"#,
+        "Sally",
+    )
+}
+
+#[test]
+fn open_parses_by_extension() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let tsv = dir.path().join("data.tsv");
+    std::fs::write(&tsv, "name\tsize\nnu\t3\n")?;
+    let yaml = dir.path().join("data.yml");
+    std::fs::write(&yaml, "name: nu\n")?;
+
+    run_test(&format!(r#"open "{}" | get name.0"#, tsv.display()), "nu")?;
+    run_test(&format!(r#"open "{}" | get name"#, yaml.display()), "nu")?;
+    run_test(
+        &format!(r#"open --raw "{}" | describe"#, tsv.display()),
+        "binary",
+    )
+}
+
+#[test]
+fn wrap() -> TestResult {
+    run_test(r#"([1, 2, 3] | wrap foo).foo.1"#, "2")
+}
+
+#[test]
+fn get() -> TestResult {
+    run_test(
+        r#"[[name, grade]; [Alice, A], [Betty, B]] | get grade.1"#,
+        "B",
+    )
+}
+
+#[test]
+fn select() -> TestResult {
+    run_test(
+        r#"([[name, age]; [a, 1], [b, 2]]) | select name | get 1 | get name"#,
+        "b",
+    )
+}
+
+#[test]
+fn string_cell_path() -> TestResult {
+    run_test(
+        r#"let x = "name"; [["name", "score"]; [a, b], [c, d]] | get $x | get 1"#,
+        "c",
+    )
+}
+
+#[test]
+fn split_row() -> TestResult {
+    run_test(r#""hello world" | split row " " | get 1"#, "world")
+}
+
+#[test]
+fn split_column() -> TestResult {
+    run_test(
+        r#""hello world" | split column " " | get "Column1".0"#,
+        "hello",
+    )
+}
+
+#[test]
+fn for_loops() -> TestResult {
+    run_test(r#"(for x in [1, 2, 3] { $x + 10 }).1"#, "12")
+}
+
+#[test]
+fn par_each() -> TestResult {
+    run_test(
+        r#"1..10 | par-each --numbered { ([[index, item]; [$it.index, ($it.item > 5)]]).0 } | where index == 4 | get item.0"#,
+        "false",
+    )
+}
+
+#[test]
+fn type_in_list_of_this_type() -> TestResult {
+    run_test(r#"42 in [41 42 43]"#, "true")
+}
+
+#[test]
+fn type_in_list_of_non_this_type() -> TestResult {
+    fail_test(r#"'hello' in [41 42 43]"#, "mismatched for operation")
+}
+
+#[test]
+fn string_in_string() -> TestResult {
+    run_test(r#"'z' in 'abc'"#, "false")
+}
+
+#[test]
+fn non_string_in_string() -> TestResult {
+    fail_test(r#"42 in 'abc'"#, "mismatched for operation")
+}
+
+#[test]
+fn int_in_inc_range() -> TestResult {
+    run_test(r#"1 in -4..9.42"#, "true")
+}
+
+#[test]
+fn int_in_dec_range() -> TestResult {
+    run_test(r#"1 in 9.42..-4"#, "true")
+}
+
+#[test]
+fn int_in_exclusive_range() -> TestResult {
+    run_test(r#"3 in 0..<3"#, "false")
+}
+
+#[test]
+fn non_number_in_range() -> TestResult {
+    fail_test(r#"'a' in 1..3"#, "mismatched for operation")
+}
+
+#[test]
+fn string_in_record() -> TestResult {
+    run_test(r#""a" in ('{ "a": 13, "b": 14 }' | from json)"#, "true")
+}
+
+#[test]
+fn non_string_in_record() -> TestResult {
+    fail_test(
+        r#"4 in ('{ "a": 13, "b": 14 }' | from json)"#,
+        "mismatch during operation",
+    )
+}
+
+#[test]
+fn string_in_valuestream() -> TestResult {
+    run_test(
+        r#"
+    'Hello' in ("Hello
+    World" | lines)"#,
+        "true",
+    )
+}
+
+#[test]
+fn string_not_in_string() -> TestResult {
+    run_test(r#"'d' not-in 'abc'"#, "true")
+}
+
+#[test]
+fn float_not_in_inc_range() -> TestResult {
+    run_test(r#"1.4 not-in 2..9.42"#, "true")
+}
+
+#[test]
+fn earlier_errors() -> TestResult {
+    fail_test(
+        r#"[1, "bob"] | each { $it + 3 } | each { $it / $it } | table"#,
+        "int",
+    )
+}
+
+#[test]
+fn each_skip_errors() -> TestResult {
+    run_test(
+        r#"[1, "bob", 2] | each { $it + 3 } | each --skip-errors { $it / $it } | math sum"#,
+        "2",
+    )
+}
+
+#[test]
+fn each_keep_errors() -> TestResult {
+    run_test(
+        r#"[1, "bob", 2] | each { $it + 3 } | each --keep-errors { $it * 2 } | length"#,
+        "3",
+    )
+}
+
+#[test]
+fn each_errors_in_records() -> TestResult {
+    run_test(
+        r#"{a: 1, b: "x"} | each --skip-errors { $it.value + 1 } | columns | length"#,
+        "1",
+    )?;
+    run_test(
+        r#"{a: 1, b: "x"} | each --keep-errors { $it.value + 1 } | columns | length"#,
+        "2",
+    )
+}
+
+#[test]
+fn each_errors_in_single_values() -> TestResult {
+    run_test(
+        r#""x" | each --skip-errors { $it + 1 } | describe"#,
+        "nothing",
+    )?;
+    run_test(
+        r#""x" | each --keep-errors { $it + 1 } | describe"#,
+        "error",
+    )
+}
+
+#[test]
+fn par_each_skip_errors() -> TestResult {
+    run_test(
+        r#"[1, "bob", 2] | par-each --skip-errors { $it + 3 } | math sum"#,
+        "9",
+    )
+}
+
+#[test]
+fn each_keep_and_skip_errors_conflict() -> TestResult {
+    fail_test(
+        r#"[1 2] | each --keep-errors --skip-errors { $it }"#,
+        "can't be used together",
+    )
+}
+
+#[test]
+fn split_list_keeps_empty_sublists() -> TestResult {
+    run_test(
+        r#"[1 0 0 2] | split list 0 | each { $it | length } | str collect ' '"#,
+        "1 0 1",
+    )
+}
+
+#[test]
+fn split_list_reports_block_errors() -> TestResult {
+    fail_test(
+        r#"[1 2] | split list {|x| $x + "a" }"#,
+        "mismatch during operation",
+    )
+}
+
+#[test]
+fn missing_column_error() -> TestResult {
+    fail_test(
+        r#"([([[name, size]; [ABC, 10], [DEF, 20]]).1, ([[name]; [HIJ]]).0]).size | table"#,
+        "did you mean 'name'?",
+    )
+}
+
+#[test]
+fn missing_parameters() -> TestResult {
+    fail_test(r#"def foo {}"#, "expected [")
+}
+
+#[test]
+fn flag_param_value() -> TestResult {
+    run_test(
+        r#"def foo [--bob: int] { $bob + 100 }; foo --bob 55"#,
+        "155",
+    )
+}
+
+#[test]
+fn do_rest_args() -> TestResult {
+    run_test(r#"(do { |...rest| $rest } 1 2).1 + 10"#, "12")
+}
+
+#[test]
+fn custom_switch1() -> TestResult {
+    run_test(
+        r#"def florb [ --dry-run: bool ] { if ($dry-run) { "foo" } else { "bar" } }; florb --dry-run"#,
+        "foo",
+    )
+}
+
+#[test]
+fn custom_switch2() -> TestResult {
+    run_test(
+        r#"def florb [ --dry-run: bool ] { if ($dry-run) { "foo" } else { "bar" } }; florb"#,
         "bar",
     )
 }
 
 #[test]
-fn hides_def_in_scope_1() -> TestResult {
-    fail_test(
-        r#"def foo [] { "foo" }; do { hide foo; foo }"#,
-        not_found_msg(),
+fn custom_switch3() -> TestResult {
+    run_test(
+        r#"def florb [ --dry-run ] { if ($dry-run) { "foo" } else { "bar" } }; florb --dry-run"#,
+        "foo",
     )
 }
 
 #[test]
-fn hides_def_in_scope_2() -> TestResult {
+fn custom_switch4() -> TestResult {
     run_test(
-        r#"def foo [] { "foo" }; do { def foo [] { "bar" }; hide foo; foo }"#,
-        "foo",
+        r#"def florb [ --dry-run ] { if ($dry-run) { "foo" } else { "bar" } }; florb"#,
+        "bar",
     )
 }
 
 #[test]
-fn hides_def_in_scope_3() -> TestResult {
-    fail_test(
-        r#"def foo [] { "foo" }; do { hide foo; def foo [] { "bar" }; hide foo; foo }"#,
-        not_found_msg(),
+fn bad_var_name() -> TestResult {
+    fail_test(r#"let $"foo bar" = 4"#, "can't contain")
+}
+
+#[test]
+fn long_flag() -> TestResult {
+    run_test(
+        r#"([a, b, c] | each --numbered { if $it.index == 1 { 100 } else { 0 } }).1"#,
+        "100",
     )
 }
 
 #[test]
-fn hides_def_in_scope_4() -> TestResult {
-    fail_test(
-        r#"def foo [] { "foo" }; do { def foo [] { "bar" }; hide foo; hide foo; foo }"#,
-        not_found_msg(),
+fn help_works_with_missing_requirements() -> TestResult {
+    run_test(r#"each --help | lines --skip-empty | length"#, "14")
+}
+
+#[test]
+fn scope_variable() -> TestResult {
+    run_test(r#"let x = 3; $scope.vars.'$x'"#, "int")
+}
+
+#[test]
+fn scope_command_origin() -> TestResult {
+    run_test(
+        r#"module spam { export def foo [] { 1 } }; use spam foo; $scope.commands | where command == foo | get module.0"#,
+        "spam",
     )
 }
 
 #[test]
-fn hides_env_in_scope_1() -> TestResult {
-    fail_test(
-        r#"let-env foo = "foo"; do { hide foo; $nu.env.foo }"#,
-        "did you mean",
+fn which_builtin() -> TestResult {
+    run_test(r#"which ls | get origin.0"#, "builtin")
+}
+
+#[test]
+fn which_all_shows_shadowed() -> TestResult {
+    run_test(
+        r#"module spam { export def foo [] { 1 } }; use spam foo; def foo [] { 2 }; which foo --all | get status | str collect ','"#,
+        "active,shadowed",
     )
 }
 
 #[test]
-fn hides_env_in_scope_2() -> TestResult {
+fn which_alias_expansion() -> TestResult {
     run_test(
-        r#"let-env foo = "foo"; do { let-env foo = "bar"; hide foo; $nu.env.foo }"#,
-        "foo",
+        r#"def foo [$x] { $x + 10 }; alias f = foo 33; which f | get expansion.0"#,
+        "foo 33",
     )
 }
 
+#[cfg(unix)]
 #[test]
-fn hides_env_in_scope_3() -> TestResult {
+fn which_external() -> TestResult {
+    run_test(r#"which sh | get origin.0"#, "external")
+}
+
+#[test]
+fn which_missing_command() -> TestResult {
     fail_test(
-        r#"let-env foo = "foo"; do { hide foo; let-env foo = "bar"; hide foo; $nu.env.foo }"#,
-        "did you mean",
+        r#"which not-a-command"#,
+        "no alias, command or external program named",
     )
 }
 
 #[test]
-fn hides_env_in_scope_4() -> TestResult {
-    fail_test(
-        r#"let-env foo = "foo"; do { let-env foo = "bar"; hide foo; hide foo; $nu.env.foo }"#,
-        "did you mean",
+fn zip_ranges() -> TestResult {
+    run_test(r#"1..3 | zip 4..6 | get 2.1"#, "6")
+}
+
+#[test]
+fn shorthand_env_1() -> TestResult {
+    run_test(r#"FOO=BAZ $nu.env.FOO"#, "BAZ")
+}
+
+#[test]
+fn shorthand_env_2() -> TestResult {
+    run_test(r#"FOO=BAZ FOO=MOO $nu.env.FOO"#, "MOO")
+}
+
+#[test]
+fn shorthand_env_3() -> TestResult {
+    run_test(r#"FOO=BAZ BAR=MOO $nu.env.FOO"#, "BAZ")
+}
+
+#[test]
+fn update_cell_path_1() -> TestResult {
+    run_test(
+        r#"[[name, size]; [a, 1.1]] | into int size | get size.0"#,
+        "1",
     )
 }
 
 #[test]
-fn hide_def_twice_not_allowed() -> TestResult {
-    fail_test(
-        r#"def foo [] { "foo" }; hide foo; hide foo"#,
-        "did not find",
+fn update_cell_path_through_list() -> TestResult {
+    run_test(r#"{a: [[b]; [x] [y]]} | str upcase a.b | get a.b.1"#, "Y")
+}
+
+#[test]
+fn range_and_reduction() -> TestResult {
+    run_test(r#"1..6..36 | math sum"#, "148")
+}
+
+#[test]
+fn precedence_of_or_groups() -> TestResult {
+    run_test(r#"4 mod 3 == 0 || 5 mod 5 == 0"#, "true")
+}
+
+#[test]
+fn where_and_binds_tighter_than_or() -> TestResult {
+    run_test(
+        r#"[1 2 3] | where $it > 1 and $it < 3 or $it == 1 | math sum"#,
+        "3",
     )
 }
 
 #[test]
-fn hide_env_twice_not_allowed() -> TestResult {
-    fail_test(r#"let-env foo = "foo"; hide foo; hide foo"#, "did not find")
+fn where_not_keyword() -> TestResult {
+    run_test(
+        r#"[1 2 3 4] | where not $it == 2 and $it < 4 | math sum"#,
+        "4",
+    )
 }
 
 #[test]
-fn hides_def_runs_env_1() -> TestResult {
+fn where_not_flag() -> TestResult {
+    run_test(r#"[1 2 3 4] | where --not $it > 2 | math sum"#, "3")
+}
+
+#[test]
+fn not_keyword_in_expression() -> TestResult {
+    run_test(r#"not 1 == 2"#, "true")
+}
+
+#[test]
+fn sort_reversed_keeps_ties_in_order() -> TestResult {
+    run_test("[b A a] | sort -i --reverse | str collect ','", "b,A,a")
+}
+
+#[test]
+fn sort_by_several_columns_reversed() -> TestResult {
     run_test(
-        r#"let-env foo = "bar"; def foo [] { "foo" }; hide foo; $nu.env.foo"#,
-        "bar",
+        r#"[[kind size]; [b 1] [a 2] [a 10]] | sort-by kind size -r | get 1.size"#,
+        "10",
     )
 }
 
 #[test]
-fn hides_def_runs_env_2() -> TestResult {
+fn sort_by_reversed_keeps_ties_in_order() -> TestResult {
     run_test(
-        r#"def foo [] { "foo" }; let-env foo = "bar"; hide foo; $nu.env.foo"#,
-        "bar",
+        "[[kind n]; [a 1] [b 2] [a 3]] | sort-by kind --reverse | get n | str collect ','",
+        "2,1,3",
     )
 }
 
 #[test]
-fn hides_def_and_env() -> TestResult {
+fn sort_by_rejects_unknown_locales() -> TestResult {
     fail_test(
-        r#"let-env foo = "bar"; def foo [] { "foo" }; hide foo; hide foo; $nu.env.foo"#,
-        "did you mean",
+        r#"[b a] | sort-by --locale xx-YY"#,
+        "Unsupported config value",
     )
 }
 
 #[test]
-fn hides_def_import_1() -> TestResult {
+fn group_by_column() -> TestResult {
+    run_test(
+        r#"[[lang name]; [rs nu] [go hugo] [rs fd]] | group-by lang | get rs.name.1"#,
+        "fd",
+    )
+}
+
+#[test]
+fn group_by_block_to_table() -> TestResult {
+    run_test(
+        r#"[1 2 3 4 5] | group-by {|n| $n > 2 } -t | get 1.items | math sum"#,
+        "12",
+    )
+}
+
+#[test]
+fn str_expand_braces() -> TestResult {
+    run_test(
+        r#"'file{1..3}.{txt,md}' | str expand | str collect ' '"#,
+        "file1.txt file1.md file2.txt file2.md file3.txt file3.md",
+    )
+}
+
+#[test]
+fn format_filesize_in_column() -> TestResult {
+    run_test(
+        r#"[[name size]; [a 3mib]] | format filesize mib size | get size.0"#,
+        "3.0 MiB",
+    )
+}
+
+#[test]
+fn format_date() -> TestResult {
+    run_test(
+        r#"'2021-10-22 20:00:12 +01:00' | into datetime | format date '%d/%m/%Y'"#,
+        "22/10/2021",
+    )
+}
+
+#[test]
+fn uniq_streamed_ignore_case_keeps_first() -> TestResult {
+    run_test(
+        r#"[B a A b] | each { $it } | uniq -i | str collect ' '"#,
+        "B a",
+    )
+}
+
+#[test]
+fn to_csv_null_value() -> TestResult {
+    run_test(
+        r#"'[{"a": 1}, {"a": null}]' | from json | to csv --null-value NULL | lines | str collect '|'"#,
+        "a|1|NULL",
+    )
+}
+
+#[test]
+fn to_json_null_value() -> TestResult {
+    run_test(
+        r#"'{"a": null}' | from json | to json --null-value '' | from json | get a | str length"#,
+        "0",
+    )
+}
+
+#[test]
+fn to_csv_flatten_nested() -> TestResult {
+    run_test(
+        r#"[[name meta]; [a {size: 1, tags: [x y]}]] | to csv --flatten-nested | lines | first"#,
+        "name,meta.size,meta.tags.0,meta.tags.1",
+    )
+}
+
+#[test]
+fn to_json_error_on_nested() -> TestResult {
     fail_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam; hide spam foo; spam foo"#,
-        not_found_msg(),
+        r#"[[name meta]; [a {size: 1}]] | to json --error-on-nested"#,
+        "holds a nested value",
     )
 }
 
 #[test]
-fn hides_def_import_2() -> TestResult {
+fn str_replace_capture_groups() -> TestResult {
+    run_test(
+        r#"'hello world' | str replace '(\w+) (\w+)' '$2 $1'"#,
+        "world hello",
+    )
+}
+
+#[test]
+fn str_replace_invalid_regex() -> TestResult {
+    fail_test(r#"'abc' | str replace '(' 'x'"#, "invalid regex")
+}
+
+#[test]
+fn from_csv_flexible_pads_missing_fields() -> TestResult {
+    run_test(
+        r#"["a,b" "1" "2,3"] | str collect (char nl) | from csv --flexible | get b | length"#,
+        "2",
+    )
+}
+
+#[test]
+fn from_csv_flexible_collects_extra_fields() -> TestResult {
+    run_test(
+        r#"["a,b" "1,2,3,4"] | str collect (char nl) | from csv --flexible | get extra.0 | str collect '|'"#,
+        "3|4",
+    )
+}
+
+#[test]
+fn from_csv_flexible_extra_column_does_not_clobber_the_header() -> TestResult {
+    run_test(
+        r#"["a,extra" "1,2,3,4"] | str collect (char nl) | from csv --flexible | get 0 | columns | str collect ','"#,
+        "a,extra,extra_1",
+    )?;
+    run_test(
+        r#"["a,extra" "1,2,3,4"] | str collect (char nl) | from csv --flexible | get 0.extra"#,
+        "2",
+    )
+}
+
+#[test]
+fn from_csv_ragged_row_reports_row() -> TestResult {
     fail_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam; hide spam; spam foo"#,
-        not_found_msg(),
+        r#"["a,b" "1,2" "3"] | str collect (char nl) | from csv | to json"#,
+        "row 2",
+    )
+}
+
+#[test]
+fn ls_hides_dotfiles_without_all() -> TestResult {
+    run_test(
+        r#"[(ls | where name == ".gitignore" | length) (ls -a | where name == ".gitignore" | length)] | str collect ','"#,
+        "0,1",
+    )
+}
+
+#[test]
+fn ls_long_columns() -> TestResult {
+    run_test(r#"ls --long Cargo.toml | get readonly.0"#, "false")
+}
+
+#[test]
+fn ls_du_measures_directory_contents() -> TestResult {
+    run_test(
+        r#"(ls --du crates | where name == "crates/nu-json" | get size.0) > (ls crates | where name == "crates/nu-json" | get size.0)"#,
+        "true",
+    )
+}
+
+#[test]
+fn metadata_set_content_type() -> TestResult {
+    run_test(
+        r#"'a,b' | metadata set --content-type text/csv | metadata | get content_type"#,
+        "text/csv",
     )
 }
 
 #[test]
-fn hides_def_import_3() -> TestResult {
-    fail_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam; hide spam [foo]; spam foo"#,
-        not_found_msg(),
-    )
+fn metadata_set_keeps_source() -> TestResult {
+    run_test(
+        r#"ls | metadata set -c text/plain | metadata | get source"#,
+        "ls",
+    )
+}
+
+#[test]
+fn where_on_ranges() -> TestResult {
+    run_test(r#"1..10 | where $it > 8 | math sum"#, "19")
+}
+
+#[test]
+fn index_on_list() -> TestResult {
+    run_test(r#"[1, 2, 3].1"#, "2")
+}
+
+#[test]
+fn in_variable_1() -> TestResult {
+    run_test(r#"[3] | if $in.0 > 4 { "yay!" } else { "boo" }"#, "boo")
+}
+
+#[test]
+fn in_variable_2() -> TestResult {
+    run_test(r#"3 | if $in > 2 { "yay!" } else { "boo" }"#, "yay!")
+}
+
+#[test]
+fn in_variable_3() -> TestResult {
+    run_test(r#"3 | if $in > 4 { "yay!" } else { $in }"#, "3")
 }
 
 #[test]
-fn hides_def_import_4() -> TestResult {
-    fail_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam foo; hide foo; foo"#,
-        not_found_msg(),
-    )
+fn in_variable_4() -> TestResult {
+    run_test(r#"3 | do { $in }"#, "3")
 }
 
 #[test]
-fn hides_def_import_5() -> TestResult {
-    fail_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam *; hide foo; foo"#,
-        not_found_msg(),
-    )
+fn in_variable_5() -> TestResult {
+    run_test(r#"3 | if $in > 2 { $in - 10 } else { $in * 10 }"#, "-7")
 }
 
 #[test]
-fn hides_def_import_6() -> TestResult {
-    fail_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam *; hide spam *; foo"#,
-        not_found_msg(),
-    )
+fn in_variable_6() -> TestResult {
+    run_test(r#"3 | if $in > 6 { $in - 10 } else { $in * 10 }"#, "30")
 }
 
 #[test]
-fn hides_env_import_1() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" } }; use spam; hide spam foo; $nu.env.'spam foo'"#,
-        "did you mean",
-    )
+fn record_1() -> TestResult {
+    run_test(r#"{'a': 'b'} | get a"#, "b")
 }
 
 #[test]
-fn hides_env_import_2() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" } }; use spam; hide spam; $nu.env.'spam foo'"#,
-        "did you mean",
-    )
+fn record_2() -> TestResult {
+    run_test(r#"{'b': 'c'}.b"#, "c")
 }
 
 #[test]
-fn hides_env_import_3() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" } }; use spam; hide spam [foo]; $nu.env.'spam foo'"#,
-        "did you mean",
+fn multi_word_imports() -> TestResult {
+    run_test(
+        r#"module spam { export def "foo bar" [] { 10 } }; use spam "foo bar"; foo bar"#,
+        "10",
     )
 }
 
 #[test]
-fn hides_env_import_4() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" } }; use spam foo; hide foo; $nu.env.foo"#,
-        "did you mean",
+fn config_filesize_format_with_metric_true() -> TestResult {
+    // Note: this tests both the config variable and that it is properly captured into a block
+    run_test(
+        r#"let config = {"filesize_metric": $true "filesize_format": "kib" }; do { 40kb | into string } "#,
+        "39.1 KiB",
     )
 }
 
 #[test]
-fn hides_env_import_5() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" } }; use spam *; hide foo; $nu.env.foo"#,
-        "did you mean",
+fn config_filesize_format_with_metric_false_kib() -> TestResult {
+    // Note: this tests both the config variable and that it is properly captured into a block
+    run_test(
+        r#"let config = {"filesize_metric": $false "filesize_format": "kib" }; do { 40kb | into string } "#,
+        "39.1 KiB",
     )
 }
 
 #[test]
-fn hides_env_import_6() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" } }; use spam *; hide spam *; $nu.env.foo"#,
-        "did you mean",
+fn config_filesize_format_with_metric_false_kb() -> TestResult {
+    // Note: this tests both the config variable and that it is properly captured into a block
+    run_test(
+        r#"let config = {"filesize_metric": $false "filesize_format": "kb" }; do { 40kb | into string } "#,
+        "40.0 KB",
     )
 }
 
 #[test]
-fn hides_def_runs_env_import() -> TestResult {
+fn comment_skipping_1() -> TestResult {
     run_test(
-        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; hide foo; $nu.env.foo"#,
-        "foo",
+        r#"let x = {
+        y: 20
+        # foo
+    }; $x.y"#,
+        "20",
     )
 }
 
 #[test]
-fn hides_def_and_env_import_1() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; hide foo; hide foo; $nu.env.foo"#,
-        "did you mean",
+fn comment_skipping_2() -> TestResult {
+    run_test(
+        r#"let x = {
+        y: 20
+        # foo
+        z: 40
+    }; $x.z"#,
+        "40",
     )
 }
 
 #[test]
-fn hides_def_and_env_import_2() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "foo" }; export def foo [] { "bar" } }; use spam foo; hide foo; hide foo; foo"#,
-        not_found_msg(),
+fn command_filter_reject_1() -> TestResult {
+    run_test(
+        "[[lang, gems]; [nu, 100]] | reject gems | to json",
+        r#"[
+  {
+    "lang": "nu"
+  }
+]"#,
     )
 }
 
 #[test]
-fn def_twice_should_fail() -> TestResult {
-    fail_test(
-        r#"def foo [] { "foo" }; def foo [] { "bar" }"#,
-        "defined more than once",
+fn command_filter_reject_2() -> TestResult {
+    run_test(
+        "[[lang, gems, grade]; [nu, 100, a]] | reject gems grade | to json",
+        r#"[
+  {
+    "lang": "nu"
+  }
+]"#,
     )
 }
 
 #[test]
-fn use_def_import_after_hide() -> TestResult {
+fn command_filter_reject_3() -> TestResult {
     run_test(
-        r#"module spam { export def foo [] { "foo" } }; use spam foo; hide foo; use spam foo; foo"#,
-        "foo",
+        "[[lang, gems, grade]; [nu, 100, a]] | reject grade gems | to json",
+        r#"[
+  {
+    "lang": "nu"
+  }
+]"#,
     )
 }
 
 #[test]
-fn use_env_import_after_hide() -> TestResult {
+fn command_drop_column_1() -> TestResult {
     run_test(
-        r#"module spam { export env foo { "foo" } }; use spam foo; hide foo; use spam foo; $nu.env.foo"#,
-        "foo",
+        "[[lang, gems, grade]; [nu, 100, a]] | drop column 2 | to json",
+        r#"[
+  {
+    "lang": "nu"
+  }
+]"#,
     )
 }
 
 #[test]
-fn hide_shadowed_decl() -> TestResult {
-    run_test(
-        r#"module spam { export def foo [] { "bar" } }; def foo [] { "foo" }; do { use spam foo; hide foo; foo }"#,
-        "foo",
-    )
+fn drop_nth_out_of_range() -> TestResult {
+    run_test("[0 1 2] | drop nth 5 | length", "3")?;
+    run_test("[0 1 2] | drop nth 1..10 | length", "1")?;
+    run_test("[0 1 2] | drop nth 1..10 | get 0", "0")
 }
 
 #[test]
-fn hide_shadowed_env() -> TestResult {
-    run_test(
-        r#"module spam { export env foo { "bar" } }; let-env foo = "foo"; do { use spam foo; hide foo; $nu.env.foo }"#,
-        "foo",
-    )
+fn drop_nth_repeated_rows() -> TestResult {
+    run_test("[0 1 2 3] | drop nth 1 1 0..1 | length", "2")?;
+    run_test("[0 1 2 3] | drop nth 1 1 0..1 | math sum", "5")
 }
 
 #[test]
-fn hides_all_decls_within_scope() -> TestResult {
+fn join_rejects_an_empty_suffix() -> TestResult {
     fail_test(
-        r#"module spam { export def foo [] { "bar" } }; def foo [] { "foo" }; use spam foo; hide foo; foo"#,
-        not_found_msg(),
+        "[[id a]; [1 2]] | join [[id a]; [1 3]] id --suffix ''",
+        "the suffix can't be empty",
     )
 }
 
 #[test]
-fn hides_all_envs_within_scope() -> TestResult {
-    fail_test(
-        r#"module spam { export env foo { "bar" } }; let-env foo = "foo"; use spam foo; hide foo; $nu.env.foo"#,
-        "did you mean",
-    )
+fn chained_operator_typecheck() -> TestResult {
+    run_test("1 != 2 && 3 != 4 && 5 != 6", "true")
 }
 
 #[test]
-fn from_json_1() -> TestResult {
-    run_test(r#"('{"name": "Fred"}' | from json).name"#, "Fred")
+fn proper_shadow() -> TestResult {
+    run_test("let x = 10; let x = $x + 9; $x", "19")
 }
 
 #[test]
-fn from_json_2() -> TestResult {
+fn comment_multiline() -> TestResult {
     run_test(
-        r#"('{"name": "Fred"}
-                   {"name": "Sally"}' | from json -o).name.1"#,
-        "Sally",
+        r#"def foo [] {
+        let x = 1 + 2 # comment
+        let y = 3 + 4 # another comment
+        $x + $y
+    }; foo"#,
+        "10",
     )
 }
 
 #[test]
-fn wrap() -> TestResult {
-    run_test(r#"([1, 2, 3] | wrap foo).foo.1"#, "2")
+fn flatten_simple_list() -> TestResult {
+    run_test("[[N, u, s, h, e, l, l]] | flatten", "N\nu\ns\nh\ne\nl\nl")
 }
 
 #[test]
-fn get() -> TestResult {
-    run_test(
-        r#"[[name, grade]; [Alice, A], [Betty, B]] | get grade.1"#,
-        "B",
-    )
+fn flatten_get_simple_list() -> TestResult {
+    run_test("[[N, u, s, h, e, l, l]] | flatten | get 0", "N")
 }
 
 #[test]
-fn select() -> TestResult {
+fn flatten_table_get() -> TestResult {
     run_test(
-        r#"([[name, age]; [a, 1], [b, 2]]) | select name | get 1 | get name"#,
-        "b",
+        "[[origin, people]; [Ecuador, ([[name, meal]; ['Andres', 'arepa']])]] | flatten | get meal",
+        "arepa",
     )
 }
 
 #[test]
-fn string_cell_path() -> TestResult {
+fn flatten_table_column_get_last() -> TestResult {
     run_test(
-        r#"let x = "name"; [["name", "score"]; [a, b], [c, d]] | get $x | get 1"#,
-        "c",
+        "[[origin, crate, versions]; [World, ([[name]; ['nu-cli']]), ['0.21', '0.22']]] | flatten versions | last | get versions",
+        "0.22",
     )
 }
 
+#[cfg(unix)]
 #[test]
-fn split_row() -> TestResult {
-    run_test(r#""hello world" | split row " " | get 1"#, "world")
+fn external_arg_glob() -> TestResult {
+    run_test(r#"^echo Cargo.t*ml"#, "Cargo.toml")
 }
 
+#[cfg(unix)]
 #[test]
-fn split_column() -> TestResult {
+fn external_arg_quoted_glob() -> TestResult {
+    run_test(r#"^echo "Cargo.t*ml""#, "Cargo.t*ml")
+}
+
+#[cfg(unix)]
+#[test]
+fn external_arg_glob_no_match() -> TestResult {
+    run_test(r#"^echo no_such_file*"#, "no_such_file*")
+}
+
+#[cfg(unix)]
+#[test]
+fn external_arg_glob_from_variable() -> TestResult {
     run_test(
-        r#""hello world" | split column " " | get "Column1".0"#,
-        "hello",
+        r#"let pattern = "Cargo.t*ml"; ^echo $pattern"#,
+        "Cargo.t*ml",
     )
 }
 
+#[cfg(unix)]
 #[test]
-fn for_loops() -> TestResult {
-    run_test(r#"(for x in [1, 2, 3] { $x + 10 }).1"#, "12")
+fn external_arg_glob_skips_hidden_files() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    std::fs::write(dir.path().join(".hidden"), "")?;
+    std::fs::write(dir.path().join("shown"), "")?;
+
+    run_test(
+        &format!(r#"cd "{}"; ^echo *"#, dir.path().display()),
+        "shown",
+    )
 }
 
+#[cfg(unix)]
 #[test]
-fn par_each() -> TestResult {
+fn exec_replaces_process() -> TestResult {
+    run_test(r#"exec echo hello; echo unreachable"#, "hello")
+}
+
+#[cfg(unix)]
+#[test]
+fn exec_does_not_expand_variables() -> TestResult {
     run_test(
-        r#"1..10 | par-each --numbered { ([[index, item]; [$it.index, ($it.item > 5)]]).0 } | where index == 4 | get item.0"#,
-        "false",
+        r#"let pattern = "Cargo.t*ml"; exec echo $pattern"#,
+        "Cargo.t*ml",
     )
 }
 
 #[test]
-fn type_in_list_of_this_type() -> TestResult {
-    run_test(r#"42 in [41 42 43]"#, "true")
+fn parse_typed_column() -> TestResult {
+    run_test(
+        r#""3 apples" | parse "{count:int} {fruit}" | get count.0 | describe"#,
+        "int",
+    )
 }
 
 #[test]
-fn type_in_list_of_non_this_type() -> TestResult {
-    fail_test(r#"'hello' in [41 42 43]"#, "mismatched for operation")
+fn parse_greedy_column() -> TestResult {
+    run_test(
+        r#""a,b,c" | parse "{first:greedy},{last}" | get first.0"#,
+        "a,b",
+    )
 }
 
 #[test]
-fn string_in_string() -> TestResult {
-    run_test(r#"'z' in 'abc'"#, "false")
+fn parse_unknown_modifier() -> TestResult {
+    fail_test(r#""a" | parse "{first:nope}""#, "Unknown column modifier")
 }
 
 #[test]
-fn non_string_in_string() -> TestResult {
-    fail_test(r#"42 in 'abc'"#, "mismatched for operation")
+fn lines_keeps_empty_lines() -> TestResult {
+    run_test(r#"$"a(char nl)(char nl)b(char nl)" | lines | length"#, "3")
 }
 
 #[test]
-fn int_in_inc_range() -> TestResult {
-    run_test(r#"1 in -4..9.42"#, "true")
+fn lines_skip_empty() -> TestResult {
+    run_test(
+        r#"$"a(char nl)(char nl)b(char nl)" | lines --skip-empty | length"#,
+        "2",
+    )
 }
 
 #[test]
-fn int_in_dec_range() -> TestResult {
-    run_test(r#"1 in 9.42..-4"#, "true")
+fn lines_binary() -> TestResult {
+    run_test(
+        r#"$"a(char nl)b" | into binary | lines | str collect '|'"#,
+        "a|b",
+    )
 }
 
 #[test]
-fn int_in_exclusive_range() -> TestResult {
-    run_test(r#"3 in 0..<3"#, "false")
+fn lines_passes_errors_through() -> TestResult {
+    run_test(r#"[1 "a"] | each { $it + "b" } | lines | length"#, "2")
 }
 
 #[test]
-fn non_number_in_range() -> TestResult {
-    fail_test(r#"'a' in 1..3"#, "mismatched for operation")
+fn lines_rejects_non_text_in_streams() -> TestResult {
+    run_test(
+        r#"["a" 1] | each { $it } | lines | each --skip-errors { $it } | length"#,
+        "1",
+    )?;
+    run_test(
+        r#"["a" 1] | each { $it } | lines | last | describe"#,
+        "error",
+    )
 }
 
-#[test]
-fn string_in_record() -> TestResult {
-    run_test(r#""a" in ('{ "a": 13, "b": 14 }' | from json)"#, "true")
+#[test]
+fn lines_unknown_encoding() -> TestResult {
+    fail_test(r#""a" | lines --encoding nope"#, "Unknown encoding")
 }
 
+#[cfg(unix)]
 #[test]
-fn non_string_in_record() -> TestResult {
-    fail_test(
-        r#"4 in ('{ "a": 13, "b": 14 }' | from json)"#,
-        "mismatch during operation",
+fn complete_exit_code() -> TestResult {
+    run_test(
+        r#"complete { ^sh -c "echo out; echo err 1>&2; exit 3" } | get exit_code"#,
+        "3",
     )
 }
 
+#[cfg(unix)]
 #[test]
-fn string_in_valuestream() -> TestResult {
+fn complete_stdout_and_stderr() -> TestResult {
     run_test(
-        r#"
-    'Hello' in ("Hello
-    World" | lines)"#,
-        "true",
+        r#"let res = complete { ^sh -c "echo out; echo err 1>&2" }; $"($res.stdout | str trim) ($res.stderr | str trim)""#,
+        "out err",
     )
 }
 
 #[test]
-fn string_not_in_string() -> TestResult {
-    run_test(r#"'d' not-in 'abc'"#, "true")
+fn complete_missing_external() -> TestResult {
+    run_test(
+        r#"complete { ^nu_no_such_program } | get exit_code | describe"#,
+        "nothing",
+    )
 }
 
 #[test]
-fn float_not_in_inc_range() -> TestResult {
-    run_test(r#"1.4 not-in 2..9.42"#, "true")
+fn cd_sets_pwd() -> TestResult {
+    run_test(r#"cd crates; $nu.env.PWD | path basename"#, "crates")
 }
 
 #[test]
-fn earlier_errors() -> TestResult {
-    fail_test(
-        r#"[1, "bob"] | each { $it + 3 } | each { $it / $it } | table"#,
-        "int",
-    )
+fn cd_missing_directory() -> TestResult {
+    fail_test(r#"cd nu_no_such_directory"#, "Directory not found")
 }
 
 #[test]
-fn missing_column_error() -> TestResult {
-    fail_test(
-        r#"([([[name, size]; [ABC, 10], [DEF, 20]]).1, ([[name]; [HIJ]]).0]).size | table"#,
-        "did you mean 'name'?",
+fn auto_cd_bare_directory() -> TestResult {
+    run_test(
+        r#"do { let config = ($config | merge {auto_cd: $true}); crates/; $nu.env.PWD | path basename }"#,
+        "crates",
     )
 }
 
+#[cfg(unix)]
 #[test]
-fn missing_parameters() -> TestResult {
-    fail_test(r#"def foo {}"#, "expected [")
-}
+fn cd_keeps_symlinks_unless_physical() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    std::fs::create_dir(dir.path().join("real"))?;
+    std::os::unix::fs::symlink(dir.path().join("real"), dir.path().join("link"))?;
+    let link = dir.path().join("link");
 
-#[test]
-fn flag_param_value() -> TestResult {
     run_test(
-        r#"def foo [--bob: int] { $bob + 100 }; foo --bob 55"#,
-        "155",
+        &format!(r#"cd "{}"; $nu.env.PWD | path basename"#, link.display()),
+        "link",
+    )?;
+    run_test(
+        &format!(
+            r#"cd --physical "{}"; $nu.env.PWD | path basename"#,
+            link.display()
+        ),
+        "real",
     )
 }
 
 #[test]
-fn do_rest_args() -> TestResult {
-    run_test(r#"(do { |...rest| $rest } 1 2).1 + 10"#, "12")
+fn start_missing_file() -> TestResult {
+    fail_test(r#"start nu_no_such_file.txt"#, "File not found")
 }
 
 #[test]
-fn custom_switch1() -> TestResult {
-    run_test(
-        r#"def florb [ --dry-run: bool ] { if ($dry-run) { "foo" } else { "bar" } }; florb --dry-run"#,
-        "foo",
-    )
+fn load_env_from_record() -> TestResult {
+    run_test(r#"{FOO: "bar"} | load-env; $nu.env.FOO"#, "bar")
 }
 
 #[test]
-fn custom_switch2() -> TestResult {
+fn load_env_with_prefix() -> TestResult {
     run_test(
-        r#"def florb [ --dry-run: bool ] { if ($dry-run) { "foo" } else { "bar" } }; florb"#,
+        r#"load-env --prefix APP_ {FOO: "bar"}; $nu.env.APP_FOO"#,
         "bar",
     )
 }
 
 #[test]
-fn custom_switch3() -> TestResult {
-    run_test(
-        r#"def florb [ --dry-run ] { if ($dry-run) { "foo" } else { "bar" } }; florb --dry-run"#,
-        "foo",
-    )
+fn load_env_rejects_pwd() -> TestResult {
+    fail_test(r#"load-env {PWD: "/"}"#, "use cd")
 }
 
 #[test]
-fn custom_switch4() -> TestResult {
+fn load_env_sets_nothing_when_it_rejects_pwd() {
+    let mut engine_state = nu_command::create_default_context();
+    let mut stack = nu_protocol::engine::Stack::new();
+
+    // FOO comes before PWD, but isn't set either
+    let success = super::eval_source(
+        &mut engine_state,
+        &mut stack,
+        r#"load-env {FOO: "bar", PWD: "/"}"#,
+        "test",
+    );
+
+    assert!(!success);
+    assert!(stack.get_env_var("FOO").is_none());
+}
+
+#[test]
+fn ansi_link_wraps_text() -> TestResult {
     run_test(
-        r#"def florb [ --dry-run ] { if ($dry-run) { "foo" } else { "bar" } }; florb"#,
-        "bar",
+        r#"ansi link --text nu https://www.nushell.sh | ansi strip"#,
+        "nu",
     )
 }
 
 #[test]
-fn bad_var_name() -> TestResult {
-    fail_test(r#"let $"foo bar" = 4"#, "can't contain")
+fn pushd_and_popd() -> TestResult {
+    run_test(
+        r#"pushd crates; pushd nu-cli; popd; $nu.env.PWD | path basename"#,
+        "crates",
+    )
 }
 
 #[test]
-fn long_flag() -> TestResult {
+fn pushd_swaps_with_top() -> TestResult {
     run_test(
-        r#"([a, b, c] | each --numbered { if $it.index == 1 { 100 } else { 0 } }).1"#,
-        "100",
+        r#"pushd crates; pushd; pushd; $nu.env.PWD | path basename"#,
+        "crates",
+    )?;
+    run_test(
+        r#"pushd crates; pushd; dirs | get path.1 | path basename"#,
+        "crates",
     )
 }
 
 #[test]
-fn help_works_with_missing_requirements() -> TestResult {
-    run_test(r#"each --help | lines | length"#, "10")
+fn dirs_lists_stack() -> TestResult {
+    run_test(r#"pushd crates; pushd nu-cli; dirs | length"#, "3")
 }
 
 #[test]
-fn scope_variable() -> TestResult {
-    run_test(r#"let x = 3; $scope.vars.'$x'"#, "int")
+fn popd_empty_stack() -> TestResult {
+    fail_test(r#"popd"#, "Directory stack is empty")
 }
 
 #[test]
-fn zip_ranges() -> TestResult {
-    run_test(r#"1..3 | zip 4..6 | get 2.1"#, "6")
+fn enter_and_list_shells() -> TestResult {
+    run_test(r#"enter crates; enter src; shells | length"#, "3")?;
+    run_test(
+        r#"enter crates; shells | where active | get path.0 | path basename"#,
+        "crates",
+    )
 }
 
 #[test]
-fn shorthand_env_1() -> TestResult {
-    run_test(r#"FOO=BAZ $nu.env.FOO"#, "BAZ")
+fn switch_shells() -> TestResult {
+    run_test(
+        r#"enter crates; enter nu-command; p; $nu.env.PWD | path basename"#,
+        "crates",
+    )?;
+    run_test(
+        r#"enter crates; enter nu-command; n; n; $nu.env.PWD | path basename"#,
+        "crates",
+    )
 }
 
 #[test]
-fn shorthand_env_2() -> TestResult {
-    run_test(r#"FOO=BAZ FOO=MOO $nu.env.FOO"#, "MOO")
+fn exit_closes_entered_shell() -> TestResult {
+    run_test(
+        r#"enter crates; enter nu-command; exit; $nu.env.PWD | path basename"#,
+        "crates",
+    )?;
+    run_test(r#"enter crates; exit; shells | length"#, "1")
 }
 
 #[test]
-fn shorthand_env_3() -> TestResult {
-    run_test(r#"FOO=BAZ BAR=MOO $nu.env.FOO"#, "BAZ")
+fn enter_missing_directory() -> TestResult {
+    fail_test(r#"enter nu_no_such_directory"#, "Directory not found")
 }
 
 #[test]
-fn update_cell_path_1() -> TestResult {
+fn table_markdown_theme() -> TestResult {
     run_test(
-        r#"[[name, size]; [a, 1.1]] | into int size | get size.0"#,
-        "1",
+        r#"[[a b]; [1 'x|y']] | table --theme markdown"#,
+        "| #   | a   | b    |\n| --- | --- | ---- |\n| 0   | 1   | x\\|y |",
     )
 }
 
 #[test]
-fn range_and_reduction() -> TestResult {
-    run_test(r#"1..6..36 | math sum"#, "148")
+fn table_theme_from_env() -> TestResult {
+    run_test(
+        r#"let-env NU_TABLE_THEME = markdown; [[a]; [1]] | table"#,
+        "| #   | a   |\n| --- | --- |\n| 0   | 1   |",
+    )
 }
 
 #[test]
-fn precedence_of_or_groups() -> TestResult {
-    run_test(r#"4 mod 3 == 0 || 5 mod 5 == 0"#, "true")
+fn into_string_uses_locale() -> TestResult {
+    run_test(
+        r#"do { let config = ($config | merge {locale: "de_DE"}); 1234567.5 | into string }"#,
+        "1.234.567,5",
+    )?;
+    run_test(
+        r#"do { let config = ($config | merge {locale: "en_US.UTF-8"}); 1234567 | into string }"#,
+        "1,234,567",
+    )
 }
 
 #[test]
-fn where_on_ranges() -> TestResult {
-    run_test(r#"1..10 | where $it > 8 | math sum"#, "19")
+fn date_format_uses_locale() -> TestResult {
+    run_test(
+        r#"do { let config = ($config | merge {locale: "fr_FR"}); "2021-10-22 20:00:12 +01:00" | date format "%A %B" }"#,
+        "vendredi octobre",
+    )
 }
 
 #[test]
-fn index_on_list() -> TestResult {
-    run_test(r#"[1, 2, 3].1"#, "2")
+fn table_uses_locale() -> TestResult {
+    run_test(
+        r#"do { let config = ($config | merge {locale: "de_DE"}); [[a]; [12345]] | table --theme markdown }"#,
+        "| #   | a      |\n| --- | ------ |\n| 0   | 12.345 |",
+    )
 }
 
+#[cfg(unix)]
 #[test]
-fn in_variable_1() -> TestResult {
-    run_test(r#"[3] | if $in.0 > 4 { "yay!" } else { "boo" }"#, "boo")
-}
+fn chmod_sets_mode() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("a.txt");
+    std::fs::write(&file, "")?;
 
-#[test]
-fn in_variable_2() -> TestResult {
-    run_test(r#"3 | if $in > 2 { "yay!" } else { "boo" }"#, "yay!")
+    run_test(
+        &format!(r#"chmod 600 "{}" | get mode.0"#, file.display()),
+        "rw-------",
+    )?;
+    run_test(
+        &format!(r#"echo "{}" | chmod g+r,o=r | get mode.0"#, file.display()),
+        "rw-r--r--",
+    )
 }
 
 #[test]
-fn in_variable_3() -> TestResult {
-    run_test(r#"3 | if $in > 4 { "yay!" } else { $in }"#, "3")
+fn chmod_invalid_mode() -> TestResult {
+    fail_test(r#"chmod u+q nu_no_such_file.txt"#, "Invalid mode")
 }
 
+#[cfg(unix)]
 #[test]
-fn in_variable_4() -> TestResult {
-    run_test(r#"3 | do { $in }"#, "3")
+fn chown_unknown_user() -> TestResult {
+    fail_test(r#"chown nu_no_such_user Cargo.toml"#, "Unknown user")
 }
 
 #[test]
-fn in_variable_5() -> TestResult {
-    run_test(r#"3 | if $in > 2 { $in - 10 } else { $in * 10 }"#, "-7")
-}
+fn cp_force_skips_a_missing_source() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let missing = dir.path().join("missing.txt");
+    let destination = dir.path().join("out.txt");
 
-#[test]
-fn in_variable_6() -> TestResult {
-    run_test(r#"3 | if $in > 6 { $in - 10 } else { $in * 10 }"#, "30")
+    fail_test(
+        &format!(r#"cp "{}" "{}""#, missing.display(), destination.display()),
+        "File not found",
+    )?;
+    run_test(
+        &format!(
+            r#"cp -f "{}" "{}"; "done""#,
+            missing.display(),
+            destination.display()
+        ),
+        "done",
+    )?;
+
+    assert!(!destination.exists());
+    Ok(())
 }
 
 #[test]
-fn record_1() -> TestResult {
-    run_test(r#"{'a': 'b'} | get a"#, "b")
+fn mv_force_skips_a_missing_source() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let missing = dir.path().join("missing.txt");
+    let destination = dir.path().join("out.txt");
+
+    fail_test(
+        &format!(r#"mv "{}" "{}""#, missing.display(), destination.display()),
+        "File not found",
+    )?;
+    run_test(
+        &format!(
+            r#"mv -f "{}" "{}"; "done""#,
+            missing.display(),
+            destination.display()
+        ),
+        "done",
+    )?;
+
+    assert!(!destination.exists());
+    Ok(())
 }
 
 #[test]
-fn record_2() -> TestResult {
-    run_test(r#"{'b': 'c'}.b"#, "c")
+fn rm_force_skips_missing_paths() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("a.txt");
+    let missing = dir.path().join("missing.txt");
+    std::fs::write(&file, "")?;
+
+    fail_test(
+        &format!(r#"rm "{}" "{}""#, file.display(), missing.display()),
+        "File not found",
+    )?;
+    assert!(file.exists());
+
+    run_test(
+        &format!(
+            r#"rm -f "{}" "{}" | length"#,
+            file.display(),
+            missing.display()
+        ),
+        "1",
+    )?;
+
+    assert!(!file.exists());
+    Ok(())
 }
 
 #[test]
-fn multi_word_imports() -> TestResult {
+fn cp_verbose_reports_each_copy() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let source = dir.path().join("a.txt");
+    let destination = dir.path().join("b.txt");
+    let unreachable = dir.path().join("missing").join("b.txt");
+    std::fs::write(&source, "a")?;
+
     run_test(
-        r#"module spam { export def "foo bar" [] { 10 } }; use spam "foo bar"; foo bar"#,
-        "10",
+        &format!(
+            r#"let row = (cp --verbose "{}" "{}" | get 0); [$row.source $row.destination $row.status ($row.error | describe)] | str collect '|'"#,
+            source.display(),
+            destination.display()
+        ),
+        &format!("{}|{}|ok|nothing", source.display(), destination.display()),
+    )?;
+    run_test(
+        &format!(
+            r#"let row = (cp --verbose "{}" "{}" | get 0); [$row.destination $row.status ($row.error | describe)] | str collect '|'"#,
+            source.display(),
+            unreachable.display()
+        ),
+        &format!("{}|error|string", unreachable.display()),
     )
 }
 
 #[test]
-fn config_filesize_format_with_metric_true() -> TestResult {
-    // Note: this tests both the config variable and that it is properly captured into a block
+fn mv_verbose_reports_each_move() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let source = dir.path().join("a.txt");
+    let destination = dir.path().join("b.txt");
+    let unreachable = dir.path().join("missing").join("c.txt");
+    std::fs::write(&source, "a")?;
+
     run_test(
-        r#"let config = {"filesize_metric": $true "filesize_format": "kib" }; do { 40kb | into string } "#,
-        "39.1 KiB",
+        &format!(
+            r#"let row = (mv --verbose "{}" "{}" | get 0); [$row.source $row.destination $row.status ($row.error | describe)] | str collect '|'"#,
+            source.display(),
+            destination.display()
+        ),
+        &format!("{}|{}|ok|nothing", source.display(), destination.display()),
+    )?;
+    run_test(
+        &format!(
+            r#"let row = (mv --verbose "{}" "{}" | get 0); [$row.source $row.status $row.error] | str collect '|'"#,
+            destination.display(),
+            unreachable.display()
+        ),
+        &format!("{}|error|Directory not found", destination.display()),
     )
 }
 
 #[test]
-fn config_filesize_format_with_metric_false_kib() -> TestResult {
-    // Note: this tests both the config variable and that it is properly captured into a block
+fn rm_verbose_reports_each_removal() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("a.txt");
+    let folder = dir.path().join("folder");
+    std::fs::write(&file, "a")?;
+    std::fs::create_dir(&folder)?;
+    std::fs::write(folder.join("b.txt"), "b")?;
+
+    // The folder isn't empty, so it can't be removed without --recursive
     run_test(
-        r#"let config = {"filesize_metric": $false "filesize_format": "kib" }; do { 40kb | into string } "#,
-        "39.1 KiB",
-    )
+        &format!(
+            r#"rm --verbose "{}" "{}" | each {{ [$it.source ($it.destination | describe) $it.status ($it.error | describe)] | str collect '|' }} | str collect ','"#,
+            file.display(),
+            folder.display()
+        ),
+        &format!(
+            "{}|nothing|ok|nothing,{}|nothing|error|string",
+            file.display(),
+            folder.display()
+        ),
+    )?;
+
+    assert!(!file.exists());
+    assert!(folder.exists());
+    Ok(())
 }
 
 #[test]
-fn config_filesize_format_with_metric_false_kb() -> TestResult {
-    // Note: this tests both the config variable and that it is properly captured into a block
+fn mkdir_verbose_reports_each_folder() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let created = dir.path().join("created");
+    let blocked = dir.path().join("blocked");
+    std::fs::write(&blocked, "")?;
+
+    // A folder can't be created inside a file
     run_test(
-        r#"let config = {"filesize_metric": $false "filesize_format": "kb" }; do { 40kb | into string } "#,
-        "40.0 KB",
-    )
+        &format!(
+            r#"mkdir --verbose "{}" "{}" | each {{ [($it.source | describe) $it.destination $it.status ($it.error | describe)] | str collect '|' }} | str collect ','"#,
+            created.display(),
+            blocked.join("sub").display()
+        ),
+        &format!(
+            "nothing|{}|ok|nothing,nothing|{}|error|string",
+            created.display(),
+            blocked.join("sub").display()
+        ),
+    )?;
+
+    assert!(created.is_dir());
+    Ok(())
 }
 
 #[test]
-fn comment_skipping_1() -> TestResult {
+fn custom_completion_parameter() -> TestResult {
     run_test(
-        r#"let x = {
-        y: 20
-        # foo
-    }; $x.y"#,
-        "20",
+        r#"def animals [] { [cat dog eel] }; def pet [animal: string@"animals"] { $animal }; pet dog"#,
+        "dog",
     )
 }
 
 #[test]
-fn comment_skipping_2() -> TestResult {
+fn open_raw_round_trips_through_into_string() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("data.json");
+    std::fs::write(&file, r#"{"a": 1}"#)?;
+
     run_test(
-        r#"let x = {
-        y: 20
-        # foo
-        z: 40
-    }; $x.z"#,
-        "40",
+        &format!(r#"open --raw "{}" | describe"#, file.display()),
+        "binary",
+    )?;
+    run_test(
+        &format!(
+            r#"open --raw "{}" | into string | from json | get a"#,
+            file.display()
+        ),
+        "1",
     )
 }
 
 #[test]
-fn command_filter_reject_1() -> TestResult {
+fn into_string_decodes_binary() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("latin1.txt");
+    std::fs::write(&file, [b'c', b'a', b'f', 0xe9])?;
+
     run_test(
-        "[[lang, gems]; [nu, 100]] | reject gems | to json",
-        r#"[
-  {
-    "lang": "nu"
-  }
-]"#,
+        &format!(
+            r#"open --raw "{}" | into string --decode latin1"#,
+            file.display()
+        ),
+        "café",
+    )?;
+    fail_test(
+        &format!(r#"open --raw "{}" | into string"#, file.display()),
+        "Can't decode binary",
     )
 }
 
 #[test]
-fn command_filter_reject_2() -> TestResult {
+fn into_string_groups_digits_only_when_asked() -> TestResult {
+    run_test("1234567 | into string", "1234567")?;
     run_test(
-        "[[lang, gems, grade]; [nu, 100, a]] | reject gems grade | to json",
-        r#"[
-  {
-    "lang": "nu"
-  }
-]"#,
+        r#"do { let config = ($config | merge {locale: "de_DE"}); 1234567 | into string -g }"#,
+        "1.234.567",
     )
 }
 
 #[test]
-fn command_filter_reject_3() -> TestResult {
-    run_test(
-        "[[lang, gems, grade]; [nu, 100, a]] | reject grade gems | to json",
-        r#"[
-  {
-    "lang": "nu"
-  }
-]"#,
-    )
+fn deprecated_command_warns_and_runs() -> TestResult {
+    fail_test(
+        "'abc' | str find-replace b x",
+        "Deprecated command str find-replace",
+    )?;
+    run_test("'abc' | str find-replace b x", "axc")
 }
 
 #[test]
-fn command_drop_column_1() -> TestResult {
+fn str_collect_table_column() -> TestResult {
     run_test(
-        "[[lang, gems, grade]; [nu, 100, a]] | drop column 2 | to json",
-        r#"[
-  {
-    "lang": "nu"
-  }
-]"#,
+        "[[name]; [nu] [shell]] | str collect '-' --column name",
+        "nu-shell",
     )
 }
 
 #[test]
-fn chained_operator_typecheck() -> TestResult {
-    run_test("1 != 2 && 3 != 4 && 5 != 6", "true")
+fn str_collect_table_with_separator() -> TestResult {
+    fail_test(
+        "[[a]; [1] [2]] | str collect ','",
+        "Deprecated use of str collect on a table",
+    )?;
+    run_test("[[a]; [1] [2]] | str collect ','", "{a: 1},{a: 2}")
 }
 
-#[test]
-fn proper_shadow() -> TestResult {
-    run_test("let x = 10; let x = $x + 9; $x", "19")
+#[cfg(feature = "network")]
+// Answers one HTTP request on a local port with the given raw response, returning its URL
+fn serve_once(response: &'static [u8]) -> std::io::Result<String> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}/file.txt", listener.local_addr()?);
+
+    std::thread::spawn(move || {
+        if let Ok((mut stream, _)) = listener.accept() {
+            let mut request = [0; 4096];
+            let _ = stream.read(&mut request);
+            let _ = stream.write_all(response);
+        }
+    });
+
+    Ok(url)
 }
 
+#[cfg(feature = "network")]
 #[test]
-fn comment_multiline() -> TestResult {
+fn fetch_output_writes_the_body() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("out.txt");
+    let url =
+        serve_once(b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello")?;
+
     run_test(
-        r#"def foo [] {
-        let x = 1 + 2 # comment
-        let y = 3 + 4 # another comment
-        $x + $y
-    }; foo"#,
-        "10",
+        &format!(
+            r#"let-env no_proxy = "127.0.0.1"; fetch -o "{}" {}; open "{}""#,
+            path.display(),
+            url,
+            path.display()
+        ),
+        "hello",
     )
 }
 
+#[cfg(feature = "network")]
 #[test]
-fn flatten_simple_list() -> TestResult {
-    run_test("[[N, u, s, h, e, l, l]] | flatten", "N\nu\ns\nh\ne\nl\nl")
+fn fetch_output_keeps_the_file_when_the_download_fails() -> TestResult {
+    let dir = tempfile::tempdir()?;
+    let path = dir.path().join("out.txt");
+    std::fs::write(&path, "old")?;
+    // The connection is closed before the promised body is complete
+    let url =
+        serve_once(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\nConnection: close\r\n\r\nhello")?;
+
+    fail_test(
+        &format!(
+            r#"let-env no_proxy = "127.0.0.1"; fetch -o "{}" {}"#,
+            path.display(),
+            url
+        ),
+        "Network failure",
+    )?;
+
+    assert_eq!(std::fs::read_to_string(&path)?, "old");
+    assert_eq!(std::fs::read_dir(dir.path())?.count(), 1);
+
+    Ok(())
 }
 
 #[test]
-fn flatten_get_simple_list() -> TestResult {
-    run_test("[[N, u, s, h, e, l, l]] | flatten | get 0", "N")
+fn last_command_env_vars_are_ints() {
+    let engine_state = nu_command::create_default_context();
+    let mut stack = nu_protocol::engine::Stack::new();
+
+    super::set_last_command_env_vars(&engine_state, &mut stack, true, Instant::now());
+    assert!(matches!(
+        stack.get_env_var("LAST_EXIT_CODE"),
+        Some(Value::Int { val: 0, .. })
+    ));
+    assert!(matches!(
+        stack.get_env_var("CMD_DURATION_MS"),
+        Some(Value::Int { .. })
+    ));
+
+    super::set_last_command_env_vars(&engine_state, &mut stack, false, Instant::now());
+    assert!(matches!(
+        stack.get_env_var("LAST_EXIT_CODE"),
+        Some(Value::Int { val: 1, .. })
+    ));
 }
 
+#[cfg(unix)]
 #[test]
-fn flatten_table_get() -> TestResult {
-    run_test(
-        "[[origin, people]; [Ecuador, ([[name, meal]; ['Andres', 'arepa']])]] | flatten | get meal",
-        "arepa",
-    )
+fn last_exit_code_of_an_external_killed_by_a_signal() {
+    let mut engine_state = nu_command::create_default_context();
+    let mut stack = nu_protocol::engine::Stack::new();
+    super::gather_parent_env_vars(&mut engine_state, &mut stack);
+
+    let success = super::eval_source(&mut engine_state, &mut stack, "^sh -c 'kill -9 $$'", "test");
+    super::set_last_command_env_vars(&engine_state, &mut stack, success, Instant::now());
+
+    // Like other shells, 128 plus the number of the signal
+    assert!(matches!(
+        stack.get_env_var("LAST_EXIT_CODE"),
+        Some(Value::Int { val: 137, .. })
+    ));
 }
 
 #[test]
-fn flatten_table_column_get_last() -> TestResult {
-    run_test(
-        "[[origin, crate, versions]; [World, ([[name]; ['nu-cli']]), ['0.21', '0.22']]] | flatten versions | last | get versions",
-        "0.22",
-    )
+fn prompt_command_right_is_drawn_at_the_end_of_the_line() {
+    let mut engine_state = nu_command::create_default_context();
+    let mut stack = nu_protocol::engine::Stack::new();
+    assert!(super::eval_source(
+        &mut engine_state,
+        &mut stack,
+        "let-env PROMPT_COMMAND_RIGHT = { 'right' }",
+        "test"
+    ));
+
+    let mut nu_prompt = nu_cli::NushellPrompt::new();
+    let default_prompt = reedline::DefaultPrompt::new(1);
+    let prompt = super::update_prompt(&engine_state, &stack, &mut nu_prompt, &default_prompt);
+
+    // Saves the cursor, draws the right prompt in the last 5 columns, then restores the cursor
+    // for the (empty) left prompt
+    assert_eq!(prompt.render_prompt(20), "\x1b7\x1b[16Gright\x1b8");
 }